@@ -0,0 +1,108 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rustact::renderer::DamageStats;
+use rustact::{App, Element, GaugeNode, StateHandle, component};
+
+const PANELS: usize = 12;
+const ROWS: usize = 6;
+const FRAMES: u32 = 50;
+
+type Slot = Arc<Mutex<Option<StateHandle<u32>>>>;
+
+fn panel(index: usize) -> Element {
+    let mut rows: Vec<Element> = (0..ROWS)
+        .map(|row| Element::text(format!("service {index}.{row}: healthy")))
+        .collect();
+    rows.push(Element::gauge(
+        GaugeNode::new(index as f64 / PANELS as f64).label("load"),
+    ));
+    Element::block(format!("Panel {index}"), Element::vstack(rows))
+}
+
+fn app(slot: Slot) -> App {
+    App::new(
+        "DamageBench",
+        component("Dashboard", move |ctx| {
+            let (frame, set_frame) = ctx.use_state(|| 0u32);
+            *slot.lock().expect("slot") = Some(set_frame);
+            let columns = (0..3)
+                .map(|column| {
+                    Element::vstack((0..PANELS / 3).map(|row| panel(column * 4 + row)).collect())
+                })
+                .collect();
+            Element::vstack(vec![
+                Element::text(format!("frame {frame}")),
+                Element::hstack(columns),
+            ])
+        }),
+    )
+    .headless_size(160, 120)
+}
+
+struct Sample {
+    frame: Duration,
+    draw: Duration,
+    stats: DamageStats,
+}
+
+fn measure(cold: bool) -> Sample {
+    let slot = Slot::default();
+    let mut app = app(slot.clone());
+    app.render_once_to_string().expect("first frame");
+    let mut elapsed = Duration::ZERO;
+    let mut draw = Duration::ZERO;
+    for frame in 1..=FRAMES {
+        if cold {
+            app = self::app(slot.clone());
+        }
+        if let Some(handle) = slot.lock().expect("slot").as_ref() {
+            handle.set(frame);
+        }
+        let start = Instant::now();
+        app.render_once_to_string().expect("render frame");
+        elapsed += start.elapsed();
+        draw += app.damage_stats().draw_time;
+    }
+    Sample {
+        frame: elapsed / FRAMES,
+        draw: draw / FRAMES,
+        stats: app.damage_stats(),
+    }
+}
+
+fn main() {
+    let cold = measure(true);
+    let warm = measure(false);
+
+    println!("dashboard: {PANELS} static panels, header changes every frame");
+    println!(
+        "  cold cache: {} per frame, {} drawing ({} widgets drawn)",
+        format_duration(cold.frame),
+        format_duration(cold.draw),
+        cold.stats.rendered
+    );
+    println!(
+        "  damage tracked: {} per frame, {} drawing ({} widgets drawn, {} reused, {} cells copied)",
+        format_duration(warm.frame),
+        format_duration(warm.draw),
+        warm.stats.rendered,
+        warm.stats.reused,
+        warm.stats.reused_cells
+    );
+    // The frame time includes the headless flush and text dump, which damage
+    // tracking does not touch; the draw pass is the part it skips.
+    println!(
+        "  speedup: {:.1}x per frame, {:.1}x drawing",
+        speedup(cold.frame, warm.frame),
+        speedup(cold.draw, warm.draw)
+    );
+}
+
+fn speedup(cold: Duration, warm: Duration) -> f64 {
+    cold.as_secs_f64() / warm.as_secs_f64().max(f64::EPSILON)
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2} ms", duration.as_secs_f64() * 1000.0)
+}
@@ -0,0 +1,72 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rustact::{App, Element, StateHandle, component, component_memo};
+
+const DEPTH: usize = 5;
+const FANOUT: usize = 4;
+const FRAMES: u32 = 50;
+
+type Slot = Arc<Mutex<Option<StateHandle<u32>>>>;
+
+fn static_tree(depth: usize, label: &str) -> Element {
+    if depth == 0 {
+        return Element::text(format!("leaf {label}"));
+    }
+    Element::vstack(
+        (0..FANOUT)
+            .map(|index| static_tree(depth - 1, &format!("{label}.{index}")))
+            .collect(),
+    )
+}
+
+fn app(memoized: bool, slot: Slot) -> App {
+    App::new(
+        "MemoBench",
+        component("Root", move |ctx| {
+            let (frame, set_frame) = ctx.use_state(|| 0u32);
+            *slot.lock().expect("slot") = Some(set_frame);
+            let tree = if memoized {
+                component_memo("Tree", (), |_, _| static_tree(DEPTH, "0")).into()
+            } else {
+                component("Tree", |_| static_tree(DEPTH, "0")).into()
+            };
+            Element::vstack(vec![Element::text(format!("frame {frame}")), tree])
+        }),
+    )
+    .headless_size(120, 40)
+}
+
+fn measure(memoized: bool) -> Duration {
+    let slot = Slot::default();
+    let mut app = app(memoized, slot.clone());
+    app.render_once_to_string().expect("first frame");
+    let start = Instant::now();
+    for frame in 1..=FRAMES {
+        if let Some(handle) = slot.lock().expect("slot").as_ref() {
+            handle.set(frame);
+        }
+        app.render_once_to_string().expect("render frame");
+    }
+    start.elapsed() / FRAMES
+}
+
+fn main() {
+    let plain = measure(false);
+    let memoized = measure(true);
+
+    println!(
+        "memoized subtree: {} static leaves, sibling state changes every frame",
+        FANOUT.pow(DEPTH as u32)
+    );
+    println!("  component: {}", format_duration(plain));
+    println!("  component_memo: {}", format_duration(memoized));
+    println!(
+        "  speedup: {:.1}x",
+        plain.as_secs_f64() / memoized.as_secs_f64().max(f64::EPSILON)
+    );
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2} ms", duration.as_secs_f64() * 1000.0)
+}
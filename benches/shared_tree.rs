@@ -0,0 +1,92 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use rustact::{App, Element, StateHandle, component};
+
+const DEPTH: usize = 5;
+const FANOUT: usize = 4;
+const FRAMES: usize = 50;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+type Slot = Arc<Mutex<Option<StateHandle<usize>>>>;
+
+fn static_tree(depth: usize, label: &str) -> Element {
+    if depth == 0 {
+        return Element::text(format!("leaf {label}"));
+    }
+    Element::vstack(
+        (0..FANOUT)
+            .map(|index| static_tree(depth - 1, &format!("{label}.{index}")))
+            .collect(),
+    )
+}
+
+fn app(shared: bool, slot: Slot) -> App {
+    App::new(
+        "SharedBench",
+        component("Root", move |ctx| {
+            let (frame, set_frame) = ctx.use_state(|| 0usize);
+            *slot.lock().expect("slot") = Some(set_frame);
+            let tree = if shared {
+                Element::shared(ctx.use_memo((), || static_tree(DEPTH, "0")))
+            } else {
+                static_tree(DEPTH, "0")
+            };
+            Element::vstack(vec![Element::text(format!("frame {frame}")), tree])
+        }),
+    )
+    .headless_size(120, 40)
+}
+
+fn measure(shared: bool) -> (usize, usize) {
+    let slot = Slot::default();
+    let mut app = app(shared, slot.clone());
+    app.render_once_to_string().expect("first frame");
+    let (allocations, bytes) = (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        BYTES.load(Ordering::Relaxed),
+    );
+    for frame in 1..=FRAMES {
+        if let Some(handle) = slot.lock().expect("slot").as_ref() {
+            handle.set(frame);
+        }
+        app.render_once_to_string().expect("render frame");
+    }
+    (
+        (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / FRAMES,
+        (BYTES.load(Ordering::Relaxed) - bytes) / FRAMES,
+    )
+}
+
+fn main() {
+    let (rebuilt, rebuilt_bytes) = measure(false);
+    let (shared, shared_bytes) = measure(true);
+
+    println!(
+        "shared subtree: {} static leaves, sibling state changes every frame",
+        FANOUT.pow(DEPTH as u32)
+    );
+    println!("  rebuilt: {rebuilt} allocations ({rebuilt_bytes} bytes) per frame");
+    println!("  Element::shared: {shared} allocations ({shared_bytes} bytes) per frame");
+    println!("  reduction: {:.1}x", rebuilt as f64 / shared.max(1) as f64);
+}
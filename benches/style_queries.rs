@@ -0,0 +1,71 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rustact::{StyleNode, StyleQuery, Stylesheet};
+
+const RULES: usize = 300;
+const WIDGETS: usize = 2_000;
+const FRAMES: u32 = 20;
+
+fn stylesheet() -> Stylesheet {
+    let mut css = String::from(":root { --accent-color: #00e8aa; --gap: 1; }\n");
+    for index in 0..RULES {
+        css.push_str(&format!(
+            "panel.group-{group} button#button-{index} {{ color: var(--accent-color); padding: calc(var(--gap) * 2); }}\n\
+             button.kind-{kind}:hover {{ --filled: true; }}\n",
+            group = index % 10,
+            kind = index % 7,
+        ));
+    }
+    Stylesheet::parse(&css).expect("parse benchmark stylesheet")
+}
+
+fn render_frame(
+    sheet: &Stylesheet,
+    ids: &[String],
+    classes: &[[String; 1]],
+    panels: &[Vec<StyleNode>],
+) {
+    for index in 0..WIDGETS {
+        let class_refs = [classes[index % classes.len()][0].as_str()];
+        let query = StyleQuery::element("button")
+            .with_id(&ids[index % ids.len()])
+            .with_classes(&class_refs)
+            .with_ancestors(&panels[index % panels.len()]);
+        black_box(sheet.query(query));
+    }
+}
+
+fn main() {
+    let sheet = stylesheet();
+    let ids: Vec<String> = (0..RULES).map(|index| format!("button-{index}")).collect();
+    let classes: Vec<[String; 1]> = (0..7).map(|kind| [format!("kind-{kind}")]).collect();
+    let panels: Vec<Vec<StyleNode>> = (0..10)
+        .map(|group| vec![StyleNode::new("panel").with_class(format!("group-{group}"))])
+        .collect();
+
+    let start = Instant::now();
+    render_frame(&sheet, &ids, &classes, &panels);
+    let cold = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        render_frame(&sheet, &ids, &classes, &panels);
+    }
+    let warm = start.elapsed() / FRAMES;
+
+    println!(
+        "style queries: {WIDGETS} widgets against {} rules",
+        RULES * 2
+    );
+    println!("  first frame (cache cold): {}", format_duration(cold));
+    println!("  later frames (cache warm): {}", format_duration(warm));
+    println!(
+        "  speedup: {:.1}x",
+        cold.as_secs_f64() / warm.as_secs_f64().max(f64::EPSILON)
+    );
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2} ms", duration.as_secs_f64() * 1000.0)
+}
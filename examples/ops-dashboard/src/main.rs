@@ -1,16 +1,16 @@
 use std::path::Path;
 use std::time::Duration;
 
-use crossterm::event::KeyCode;
 use tokio::sync::broadcast::error::RecvError;
 use tracing::warn;
 
-use rustact::runtime::{AppConfig, Color, TabPaneNode};
+use rustact::runtime::{AppConfig, Color, Constraint, TabPaneNode};
 use rustact::styles::Stylesheet;
 use rustact::{
-    App, Element, FormFieldNode, FormFieldStatus, FormNode, FrameworkEvent, GaugeNode, LayeredNode,
-    ListItemNode, ListNode, ModalNode, Scope, StateHandle, TableCellNode, TableNode, TableRowNode,
-    TabsNode, ToastLevel, ToastNode, ToastStackNode, component,
+    App, Element, FormFieldNode, FormFieldStatus, FormNode, FrameworkEvent, GaugeNode,
+    KeyHintsNode, LayeredNode, ListItemNode, ListNode, ModalNode, Scope, SplitNode, StateHandle,
+    TableCellNode, TableNode, TableRowNode, TabsNode, ToastLevel, ToastNode, ToastStackNode,
+    component,
 };
 
 const APP_NAME: &str = "Rustact Ops Dashboard";
@@ -23,8 +23,10 @@ async fn main() -> anyhow::Result<()> {
     let mut app = App::new(APP_NAME, component("OpsRoot", ops_root))
         .with_config(AppConfig {
             tick_rate: Duration::from_millis(250),
+            ..AppConfig::default()
         })
-        .with_stylesheet(stylesheet);
+        .with_stylesheet(stylesheet)
+        .with_panic_hook();
     if should_watch_styles() {
         if Path::new(OPS_STYLES_PATH).exists() {
             app = app.watch_stylesheet(OPS_STYLES_PATH);
@@ -35,7 +37,8 @@ async fn main() -> anyhow::Result<()> {
             );
         }
     }
-    app.run().await
+    let code = app.run().await?;
+    std::process::exit(code)
 }
 
 fn load_ops_stylesheet() -> Stylesheet {
@@ -63,10 +66,14 @@ fn should_watch_styles() -> bool {
 }
 
 fn ops_root(ctx: &mut Scope) -> Element {
-    let (active_tab, set_active_tab) = ctx.use_state(|| 0usize);
+    let (active_tab, set_active_tab) = ctx.use_persistent_state("ops.active-tab", || 0usize);
     let (logs, set_logs) = ctx.use_state(Vec::<String>::new);
     let (incident, set_incident) = ctx.use_state(|| None as Option<IncidentDetails>);
     let (toasts, set_toasts) = ctx.use_state(Vec::<ToastMessage>::new);
+    ctx.use_keybinding("1", "overview", "tab.overview");
+    ctx.use_keybinding("2", "logs", "tab.logs");
+    ctx.use_keybinding("i", "incident", "incident.open");
+    ctx.use_keybinding("c", "dismiss toast", "toast.dismiss");
 
     let tab_handle = set_active_tab.clone();
     let log_handle = set_logs.clone();
@@ -74,7 +81,7 @@ fn ops_root(ctx: &mut Scope) -> Element {
     let toast_handle = set_toasts.clone();
     ctx.use_effect((), move |dispatcher| {
         let mut events = dispatcher.events().subscribe();
-        let handle = tokio::spawn(async move {
+        let handle = dispatcher.spawn(async move {
             let mut tick = 0usize;
             loop {
                 match events.recv().await {
@@ -102,12 +109,12 @@ fn ops_root(ctx: &mut Scope) -> Element {
                                 });
                             }
                         }
-                        FrameworkEvent::Key(key) => match key.code {
-                            KeyCode::Char('1') => tab_handle.set(0),
-                            KeyCode::Char('2') => tab_handle.set(1),
-                            KeyCode::Char('i') => open_incident_modal(&incident_handle),
-                            KeyCode::Esc => incident_handle.set(None),
-                            KeyCode::Char('c') => {
+                        FrameworkEvent::Command(command) => match command.as_str() {
+                            "tab.overview" => tab_handle.set(0),
+                            "tab.logs" => tab_handle.set(1),
+                            "incident.open" => open_incident_modal(&incident_handle),
+                            "incident.close" => incident_handle.set(None),
+                            "toast.dismiss" => {
                                 toast_handle.update(|stack| {
                                     if !stack.is_empty() {
                                         stack.remove(0);
@@ -184,12 +191,22 @@ fn overview_tab() -> Element {
     let capacity = Element::gauge(GaugeNode::new(0.72).label("Capacity").color(Color::Cyan));
 
     Element::vstack(vec![
-        Element::hstack(vec![health, release_form]),
-        Element::block(
-            "Capacity",
-            Element::vstack(vec![Element::text("Compute saturation"), capacity]),
+        Element::split(SplitNode::horizontal(
+            "overview-split",
+            health,
+            release_form,
+        )),
+        Element::sized(
+            Constraint::Length(4),
+            Element::block(
+                "Capacity",
+                Element::vstack(vec![Element::text("Compute saturation"), capacity]),
+            ),
+        ),
+        Element::sized(
+            Constraint::Length(1),
+            Element::key_hints(KeyHintsNode::new()),
         ),
-        Element::text("Keys: [1] Overview  [2] Logs  [i] Incident modal  [c] Dismiss toast"),
     ])
 }
 
@@ -215,16 +232,20 @@ fn logs_tab(logs: &[String]) -> Element {
 }
 
 fn build_incident_modal(details: &IncidentDetails) -> Element {
-    let content = Element::vstack(vec![
-        Element::text(format!("Incident #{}", details.id)),
-        Element::text(format!("Status: {}", details.status)),
-        Element::text(format!("Impact: {}", details.impact)),
-        Element::text(format!("Started: {}", details.started)),
-        Element::text(details.summary),
-        Element::text("Press Esc to close"),
-    ]);
+    let details = details.clone();
+    let content = component("IncidentDetails", move |ctx| {
+        ctx.use_keybinding("esc", "close", "incident.close");
+        Element::vstack(vec![
+            Element::text(format!("Incident #{}", details.id)),
+            Element::text(format!("Status: {}", details.status)),
+            Element::text(format!("Impact: {}", details.impact)),
+            Element::text(format!("Started: {}", details.started)),
+            Element::text(details.summary),
+            Element::key_hints(KeyHintsNode::new()),
+        ])
+    });
     Element::modal(
-        ModalNode::new(content)
+        ModalNode::new(content.into())
             .title("Major incident")
             .width(60)
             .height(12),
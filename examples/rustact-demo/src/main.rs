@@ -1,20 +1,19 @@
 use std::path::Path;
-use std::sync::Arc;
 use std::time::Duration;
 
 use crossterm::event::KeyCode;
-use crossterm::event::MouseButton;
 use tokio::sync::broadcast::error::RecvError;
 use tracing::warn;
 
+use rustact::components::DirBrowser;
 use rustact::runtime::{AppConfig, Color, TextInputNode};
-use rustact::styles::{ComputedStyle, StyleQuery, Stylesheet};
+use rustact::styles::{ComputedStyle, StyleQuery, Stylesheet, Theme};
 use rustact::{
-    App, ButtonNode, Element, FormFieldNode, FormFieldStatus, FormNode, FrameworkEvent, GaugeNode,
-    ListItemNode, ListNode, Scope, TableCellNode, TableNode, TableRowNode, TreeItemNode, TreeNode,
-    component,
+    App, ButtonNode, Element, FlexNode, FormFieldNode, FormFieldStatus, FormNode, FrameworkEvent,
+    GaugeNode, Justify, ListItemNode, ListNode, Scope, ScrollNode, SplitNode, TableCellNode,
+    TableNode, TableRowNode, component,
 };
-use rustact::{is_button_click, is_mouse_click, mouse_position, mouse_scroll_delta};
+use rustact::{clicked_list_index, is_button_click, mouse_scroll_delta};
 
 const APP_NAME: &str = "Rustact Demo";
 const DEMO_STYLES: &str = include_str!("../styles/demo.css");
@@ -26,6 +25,7 @@ const COUNTER_PANEL_ID: &str = "counter";
 const STATS_LIST_ID: &str = "stats";
 const SERVICES_TABLE_ID: &str = "services";
 const RELEASE_FORM_ID: &str = "release";
+const PROJECT_TREE_ID: &str = "project-tree";
 const FEEDBACK_NAME_INPUT: &str = "feedback-name";
 const FEEDBACK_EMAIL_INPUT: &str = "feedback-email";
 const FEEDBACK_TOKEN_INPUT: &str = "feedback-token";
@@ -36,8 +36,10 @@ async fn main() -> anyhow::Result<()> {
     let mut app = App::new(APP_NAME, component("AppRoot", app_root))
         .with_config(AppConfig {
             tick_rate: Duration::from_millis(200),
+            ..AppConfig::default()
         })
-        .with_stylesheet(stylesheet);
+        .with_stylesheet(stylesheet)
+        .with_panic_hook();
     if should_watch_styles() {
         if Path::new(DEMO_STYLES_PATH).exists() {
             app = app.watch_stylesheet(DEMO_STYLES_PATH);
@@ -48,7 +50,8 @@ async fn main() -> anyhow::Result<()> {
             );
         }
     }
-    app.run().await
+    let code = app.run().await?;
+    std::process::exit(code)
 }
 
 fn load_demo_stylesheet() -> Stylesheet {
@@ -76,59 +79,63 @@ fn should_watch_styles() -> bool {
 }
 
 fn app_root(ctx: &mut Scope) -> Element {
-    let root_style = ctx.styles().root();
-    let _theme = ctx.provide_context(Theme {
-        accent: root_style.color("--accent-color").unwrap_or(Color::Cyan),
-        warning: root_style.color("--warning-color").unwrap_or(Color::Yellow),
-        success: root_style.color("--success-color").unwrap_or(Color::Green),
-        danger: root_style.color("--danger-color").unwrap_or(Color::Red),
-        info: root_style.color("--info-color").unwrap_or(Color::Blue),
-    });
-    Element::block(
-        "rustact demo",
-        Element::vstack(vec![
-            component("Hero", hero).into(),
-            component("Meta", meta_banner).into(),
-            Element::hstack(vec![
-                component("Counter", counter_panel).into(),
-                component("Stats", stats_panel).into(),
-                component("Tips", tips_panel).into(),
-            ]),
-            Element::hstack(vec![
+    let theme = Theme::for_scheme(ctx.use_color_scheme()).merge_style(&ctx.styles().root());
+    let layout = FlexNode::column(vec![
+        component("Hero", hero).into(),
+        component("Meta", meta_banner).into(),
+        Element::hstack(vec![
+            component("Counter", counter_panel).into(),
+            component("Stats", stats_panel).into(),
+            component("Tips", tips_panel).into(),
+        ]),
+        Element::split(
+            SplitNode::horizontal(
+                "services-split",
                 component("Services", service_table).into(),
                 component("ProjectTree", tree_panel).into(),
-            ]),
-            Element::hstack(vec![
-                component("Events", event_log).into(),
-                component("Config", config_form).into(),
-                component("Feedback", feedback_panel).into(),
-            ]),
+            )
+            .ratio(0.6),
+        ),
+        Element::hstack(vec![
+            component("Events", event_log).into(),
+            component("Config", config_form).into(),
+            component("Feedback", feedback_panel).into(),
         ]),
-    )
+    ])
+    .id("root");
+    Element::theme_provider(theme, Element::block("rustact demo", Element::flex(layout)))
 }
 
 fn hero(ctx: &mut Scope) -> Element {
-    let theme = ctx
-        .use_context::<Theme>()
-        .unwrap_or_else(|| Arc::new(Theme::default()));
+    let theme = ctx.use_theme();
     let hero_style = ctx.styles().query(StyleQuery::element("hero"));
     let title_color = hero_style.color("color").unwrap_or(theme.accent);
     let subtitle_color = hero_style
         .color("--subtitle-color")
         .unwrap_or(Color::DarkGray);
-    Element::vstack(vec![
-        Element::colored_text("Welcome to rustact", title_color),
-        Element::colored_text(
+    let show_tips = hero_style.bool("--show-tips").unwrap_or(true);
+    let mut lines = vec![Element::colored_text("Welcome to rustact", title_color)];
+    if show_tips {
+        lines.push(Element::colored_text(
             "Press '+' / '-' or click the on-screen buttons to adjust the counter",
             subtitle_color,
-        ),
-        Element::colored_text(
+        ));
+        lines.push(Element::colored_text(
             "Use mouse scroll to browse stats; click buttons for actions",
             subtitle_color,
-        ),
-        Element::colored_text("Press Ctrl+C to quit", subtitle_color),
-        Element::colored_text("Edit styles/demo.css to reskin the UI", subtitle_color),
-    ])
+        ));
+    }
+    lines.push(Element::colored_text(
+        "Press Ctrl+C to quit",
+        subtitle_color,
+    ));
+    if show_tips {
+        lines.push(Element::colored_text(
+            "Edit styles/demo.css to reskin the UI",
+            subtitle_color,
+        ));
+    }
+    Element::vstack(lines)
 }
 
 fn counter_panel(ctx: &mut Scope) -> Element {
@@ -144,19 +151,14 @@ fn counter_panel(ctx: &mut Scope) -> Element {
         let value = count;
         ctx.use_memo(value, move || CounterSummary::new(value))
     };
-    let theme = ctx
-        .use_context::<Theme>()
-        .unwrap_or_else(|| Arc::new(Theme::default()));
+    let gauge_ratio = ctx.use_transition(summary.normalized(), Duration::from_millis(600));
+    let theme = ctx.use_theme();
     let panel_style = ctx
         .styles()
         .query(StyleQuery::element("panel").with_id(COUNTER_PANEL_ID));
     let instructions_color = panel_style.color("color").unwrap_or(theme.info);
-    let plus_style = ctx
-        .styles()
-        .query(StyleQuery::element("button").with_id(COUNTER_PLUS_BUTTON));
-    let minus_style = ctx
-        .styles()
-        .query(StyleQuery::element("button").with_id(COUNTER_MINUS_BUTTON));
+    let plus_style = ctx.style(StyleQuery::element("button").with_id(COUNTER_PLUS_BUTTON));
+    let minus_style = ctx.style(StyleQuery::element("button").with_id(COUNTER_MINUS_BUTTON));
     let gauge_style = ctx
         .styles()
         .query(StyleQuery::element("gauge").with_id(COUNTER_GAUGE_ID));
@@ -202,7 +204,7 @@ fn counter_panel(ctx: &mut Scope) -> Element {
     ctx.use_effect((), move |dispatcher| {
         let handler = key_handler.clone();
         let mut events = dispatcher.events().subscribe();
-        let handle = tokio::spawn(async move {
+        let handle = dispatcher.spawn(async move {
             loop {
                 match events.recv().await {
                     Ok(event) => {
@@ -224,22 +226,26 @@ fn counter_panel(ctx: &mut Scope) -> Element {
             Element::text(summary.label.clone()),
             Element::text(format!("Parity: {}", summary.parity)),
             Element::gauge(
-                GaugeNode::new(summary.normalized())
+                GaugeNode::new(gauge_ratio)
                     .label(gauge_label)
                     .color(gauge_color),
             ),
-            Element::hstack(vec![
-                Element::button(
-                    ButtonNode::new(COUNTER_MINUS_BUTTON, "-")
-                        .accent(minus_accent)
-                        .filled(minus_filled),
-                ),
-                Element::button(
-                    ButtonNode::new(COUNTER_PLUS_BUTTON, "+")
-                        .accent(plus_accent)
-                        .filled(plus_filled),
-                ),
-            ]),
+            Element::flex(
+                FlexNode::row(vec![
+                    Element::button(
+                        ButtonNode::new(COUNTER_MINUS_BUTTON, "-")
+                            .accent(minus_accent)
+                            .filled(minus_filled),
+                    ),
+                    Element::button(
+                        ButtonNode::new(COUNTER_PLUS_BUTTON, "+")
+                            .accent(plus_accent)
+                            .filled(plus_filled),
+                    ),
+                ])
+                .gap(1)
+                .justify_content(Justify::End),
+            ),
             Element::colored_text(
                 "Keys: +/-/r/q • Click buttons to adjust",
                 instructions_color,
@@ -253,7 +259,7 @@ fn event_log(ctx: &mut Scope) -> Element {
     let updater = set_status.clone();
     ctx.use_effect((), move |dispatcher| {
         let mut events = dispatcher.events().subscribe();
-        let handle = tokio::spawn(async move {
+        let handle = dispatcher.spawn(async move {
             loop {
                 match events.recv().await {
                     Ok(event) => updater.update(|state| state.record(&event)),
@@ -276,11 +282,9 @@ fn event_log(ctx: &mut Scope) -> Element {
 
 fn stats_panel(ctx: &mut Scope) -> Element {
     let (events, set_events) = ctx.use_state(Vec::<String>::new);
-    let (selected, set_selected) = ctx.use_state(|| 0usize);
+    let selection = ctx.use_list_state(events.len());
     let total_events = ctx.use_ref(|| 0usize);
-    let theme = ctx
-        .use_context::<Theme>()
-        .unwrap_or_else(|| Arc::new(Theme::default()));
+    let theme = ctx.use_theme();
     let list_style = ctx
         .styles()
         .query(StyleQuery::element("list").with_id(STATS_LIST_ID));
@@ -291,62 +295,47 @@ fn stats_panel(ctx: &mut Scope) -> Element {
     let instruction_color = list_style.color("color").unwrap_or(theme.info);
 
     let feed = set_events.clone();
-    let selection = set_selected.clone();
+    let list_state = selection.clone();
     let total_ref = total_events.clone();
     let max_items_limit = max_items.max(1);
     ctx.use_effect((), move |dispatcher| {
         let mut stream = dispatcher.events().subscribe();
         let max_items = max_items_limit;
-        let handle = tokio::spawn(async move {
+        let handle = dispatcher.spawn(async move {
             while let Ok(event) = stream.recv().await {
                 let label = match &event {
                     FrameworkEvent::Key(key) => format!("Key: {:?}", key.code),
                     FrameworkEvent::Mouse(mouse) => format!("Mouse: {:?}", mouse.kind),
                     FrameworkEvent::Resize(w, h) => format!("Resize: {w}x{h}"),
                     FrameworkEvent::Tick => "Tick".to_string(),
+                    FrameworkEvent::Command(command) => format!("Command: {command}"),
                 };
 
                 let mut new_len = 0usize;
+                let mut dropped = 0usize;
                 feed.update(|list| {
                     if list.len() >= max_items {
                         list.remove(0);
+                        dropped = 1;
                     }
                     list.push(label);
                     new_len = list.len();
                 });
                 total_ref.with_mut(|count| *count += 1);
 
+                list_state.resize(new_len);
                 match &event {
                     FrameworkEvent::Mouse(_) => {
                         let delta = mouse_scroll_delta(&event);
-                        if delta != 0 {
-                            selection.update(|sel| {
-                                if delta > 0 {
-                                    *sel = sel.saturating_sub(delta as usize);
-                                } else {
-                                    let steps = delta.unsigned_abs() as usize;
-                                    *sel = sel.saturating_add(steps);
-                                }
-                                if *sel >= new_len {
-                                    *sel = new_len.saturating_sub(1);
-                                }
-                            });
-                        } else if is_mouse_click(&event, MouseButton::Left) {
-                            if new_len > 0 {
-                                if let Some((col, row)) = mouse_position(&event) {
-                                    let seed = col as usize + row as usize;
-                                    selection.set(seed % new_len);
-                                }
-                            }
-                        } else {
-                            selection.update(|sel| {
-                                if *sel >= new_len {
-                                    *sel = new_len.saturating_sub(1);
-                                }
-                            });
+                        if delta > 0 {
+                            list_state.select_prev();
+                        } else if delta < 0 {
+                            list_state.select_next();
+                        } else if let Some(index) = clicked_list_index(&event, STATS_LIST_ID) {
+                            list_state.select(Some(index.saturating_sub(dropped)));
                         }
                     }
-                    _ => selection.set(new_len.saturating_sub(1)),
+                    _ => list_state.select_last(),
                 }
             }
         });
@@ -368,20 +357,17 @@ fn stats_panel(ctx: &mut Scope) -> Element {
         })
         .collect::<Vec<_>>();
 
-    let mut list = ListNode::new(list_items)
+    let list = ListNode::new(list_items)
         .title("Recent events (scroll to navigate)")
-        .highlight_color(highlight_color);
-    if !events.is_empty() {
-        let max_index = events.len().saturating_sub(1);
-        let highlight = selected.min(max_index);
-        list = list.highlight(highlight);
-    }
+        .id(STATS_LIST_ID)
+        .highlight_color(highlight_color)
+        .state(&selection);
 
     Element::block(
         "Stats",
         Element::vstack(vec![
             Element::colored_text(
-                "Mouse scroll cycles entries; left click jumps between them.",
+                "Mouse scroll cycles entries; click an entry to select it.",
                 instruction_color,
             ),
             Element::text(format!("Events observed (use_ref): {total_seen}")),
@@ -391,10 +377,7 @@ fn stats_panel(ctx: &mut Scope) -> Element {
 }
 
 fn meta_banner(ctx: &mut Scope) -> Element {
-    let accent = ctx
-        .use_context::<Theme>()
-        .map(|theme| theme.accent)
-        .unwrap_or(Color::Magenta);
+    let accent = ctx.use_theme().accent;
     let version = env!("CARGO_PKG_VERSION");
     Element::block(
         "Framework overview",
@@ -407,9 +390,7 @@ fn meta_banner(ctx: &mut Scope) -> Element {
 }
 
 fn service_table(ctx: &mut Scope) -> Element {
-    let theme = ctx
-        .use_context::<Theme>()
-        .unwrap_or_else(|| Arc::new(Theme::default()));
+    let theme = ctx.use_theme();
     let table_style = ctx
         .styles()
         .query(StyleQuery::element("table").with_id(SERVICES_TABLE_ID));
@@ -447,22 +428,8 @@ fn service_table(ctx: &mut Scope) -> Element {
 }
 
 fn tree_panel(_ctx: &mut Scope) -> Element {
-    let tree = TreeNode::new(vec![
-        TreeItemNode::new("src").children(vec![
-            TreeItemNode::new("main.rs"),
-            TreeItemNode::new("runtime").children(vec![TreeItemNode::new("mod.rs")]),
-            TreeItemNode::new("renderer").children(vec![TreeItemNode::new("mod.rs")]),
-        ]),
-        TreeItemNode::new("docs").children(vec![
-            TreeItemNode::new("README.md"),
-            TreeItemNode::new("architecture.md"),
-        ]),
-        TreeItemNode::new("Cargo.toml").expanded(false),
-    ])
-    .title("Workspace tree")
-    .highlight(2);
-
-    Element::block("Project", Element::tree(tree))
+    let browser = DirBrowser::new(PROJECT_TREE_ID, ".").title("Workspace tree");
+    Element::block("Project", browser.into())
 }
 
 fn config_form(ctx: &mut Scope) -> Element {
@@ -485,9 +452,7 @@ fn config_form(ctx: &mut Scope) -> Element {
 }
 
 fn feedback_panel(ctx: &mut Scope) -> Element {
-    let theme = ctx
-        .use_context::<Theme>()
-        .unwrap_or_else(|| Arc::new(Theme::default()));
+    let theme = ctx.use_theme();
     let name_input = ctx.use_text_input(FEEDBACK_NAME_INPUT, || "Rusty User".to_string());
     let email_input = ctx.use_text_input(FEEDBACK_EMAIL_INPUT, String::new);
     let token_input = ctx.use_text_input(FEEDBACK_TOKEN_INPUT, String::new);
@@ -521,7 +486,7 @@ fn feedback_panel(ctx: &mut Scope) -> Element {
     let email_snapshot = email_input.snapshot();
     let token_snapshot = token_input.snapshot();
 
-    let input_style = |id: &str| ctx.styles().query(StyleQuery::element("input").with_id(id));
+    let input_style = |id: &str| ctx.style(StyleQuery::element("input").with_id(id));
     let style_input = |mut node: TextInputNode, styles: &ComputedStyle| {
         if let Some(color) = styles.color("accent-color") {
             node = node.accent(color);
@@ -624,13 +589,11 @@ fn tips_panel(ctx: &mut Scope) -> Element {
         })
         .collect();
 
-    Element::block("Tips", Element::fragment(cards))
+    Element::block("Tips", Element::scroll(ScrollNode::new("tips", cards)))
 }
 
 fn tip_card(ctx: &mut Scope, tip: Tip) -> Element {
-    let theme = ctx
-        .use_context::<Theme>()
-        .unwrap_or_else(|| Arc::new(Theme::default()));
+    let theme = ctx.use_theme();
     let classes = [tip.class];
     let tip_style = ctx
         .styles()
@@ -645,27 +608,6 @@ fn tip_card(ctx: &mut Scope, tip: Tip) -> Element {
     )
 }
 
-#[derive(Clone)]
-struct Theme {
-    accent: Color,
-    warning: Color,
-    success: Color,
-    danger: Color,
-    info: Color,
-}
-
-impl Default for Theme {
-    fn default() -> Self {
-        Self {
-            accent: Color::Cyan,
-            warning: Color::Yellow,
-            success: Color::Green,
-            danger: Color::Red,
-            info: Color::Blue,
-        }
-    }
-}
-
 #[derive(Clone, Default)]
 struct EventStatus {
     description: String,
@@ -684,6 +626,9 @@ impl EventStatus {
             FrameworkEvent::Resize(w, h) => {
                 self.description = format!("Resize: {w}x{h}");
             }
+            FrameworkEvent::Command(command) => {
+                self.description = format!("Command: {command}");
+            }
             FrameworkEvent::Tick => {
                 self.description = "Tick".into();
                 self.ticks += 1;
@@ -747,7 +692,7 @@ const DEMO_TIPS: [Tip; 3] = [
     Tip {
         id: "context",
         title: "Context",
-        body: "The banner and tips share the accent color via provide_context.",
+        body: "The banner and tips share the accent color via ThemeProvider.",
         class: "context",
     },
 ];
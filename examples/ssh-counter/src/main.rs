@@ -0,0 +1,69 @@
+use tokio::net::TcpListener;
+use tokio::sync::broadcast::error::RecvError;
+
+use rustact::events::KeyCode;
+use rustact::ssh::SshServer;
+use rustact::{
+    App, ButtonNode, Element, FlexNode, FrameworkEvent, Scope, component, is_button_click,
+};
+
+const ADDR: &str = "127.0.0.1:2222";
+const MINUS_BUTTON: &str = "counter:minus";
+const PLUS_BUTTON: &str = "counter:plus";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let listener = TcpListener::bind(ADDR).await?;
+    println!("Serving the counter on {ADDR}; connect with `ssh -p 2222 you@127.0.0.1`");
+    // Every connection gets its own App, so each visitor has their own count.
+    let server = SshServer::new(|user| {
+        let greeting = format!("Hello, {}!", user.name);
+        App::new(
+            "SSH Counter",
+            component("Counter", move |ctx| counter(ctx, &greeting)),
+        )
+    })?;
+    server.serve(listener).await
+}
+
+fn counter(ctx: &mut Scope, greeting: &str) -> Element {
+    let (count, set_count) = ctx.use_state(|| 0i32);
+
+    ctx.use_effect((), move |dispatcher| {
+        let mut events = dispatcher.events().subscribe();
+        let handle = dispatcher.spawn(async move {
+            loop {
+                let event = match events.recv().await {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+                let step = match &event {
+                    FrameworkEvent::Key(key) if key.code == KeyCode::Char('+') => 1,
+                    FrameworkEvent::Key(key) if key.code == KeyCode::Char('-') => -1,
+                    _ if is_button_click(&event, PLUS_BUTTON) => 1,
+                    _ if is_button_click(&event, MINUS_BUTTON) => -1,
+                    _ => continue,
+                };
+                set_count.update(|count| *count += step);
+            }
+        });
+        Some(Box::new(move || handle.abort()))
+    });
+
+    Element::block(
+        "Counter",
+        Element::vstack(vec![
+            Element::text(greeting.to_string()),
+            Element::text(format!("Count: {count}")),
+            Element::flex(
+                FlexNode::row(vec![
+                    Element::button(ButtonNode::new(MINUS_BUTTON, "-")),
+                    Element::button(ButtonNode::new(PLUS_BUTTON, "+")),
+                ])
+                .gap(1),
+            ),
+            Element::text("Keys: +/- • Click the buttons • Ctrl+C disconnects"),
+        ]),
+    )
+}
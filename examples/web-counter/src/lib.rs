@@ -0,0 +1,59 @@
+use tokio::sync::broadcast::error::RecvError;
+use wasm_bindgen::prelude::*;
+
+use rustact::events::KeyCode;
+use rustact::web::{Xterm, mount};
+use rustact::{
+    App, ButtonNode, Element, FlexNode, FrameworkEvent, Scope, component, is_button_click,
+};
+
+const MINUS_BUTTON: &str = "counter:minus";
+const PLUS_BUTTON: &str = "counter:plus";
+
+// Called from index.html with an opened xterm.js `Terminal`.
+#[wasm_bindgen]
+pub fn start(terminal: Xterm) -> Result<(), JsError> {
+    let app = App::new("Web Counter", component("Counter", counter));
+    mount(app, terminal).map_err(|err| JsError::new(&format!("{err:#}")))
+}
+
+fn counter(ctx: &mut Scope) -> Element {
+    let (count, set_count) = ctx.use_state(|| 0i32);
+
+    ctx.use_effect((), move |dispatcher| {
+        let mut events = dispatcher.events().subscribe();
+        let handle = dispatcher.spawn(async move {
+            loop {
+                let event = match events.recv().await {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+                let step = match &event {
+                    FrameworkEvent::Key(key) if key.code == KeyCode::Char('+') => 1,
+                    FrameworkEvent::Key(key) if key.code == KeyCode::Char('-') => -1,
+                    _ if is_button_click(&event, PLUS_BUTTON) => 1,
+                    _ if is_button_click(&event, MINUS_BUTTON) => -1,
+                    _ => continue,
+                };
+                set_count.update(|count| *count += step);
+            }
+        });
+        Some(Box::new(move || handle.abort()))
+    });
+
+    Element::block(
+        "Counter",
+        Element::vstack(vec![
+            Element::text(format!("Count: {count}")),
+            Element::flex(
+                FlexNode::row(vec![
+                    Element::button(ButtonNode::new(MINUS_BUTTON, "-")),
+                    Element::button(ButtonNode::new(PLUS_BUTTON, "+")),
+                ])
+                .gap(1),
+            ),
+            Element::text("Keys: +/- • Click the buttons • Ctrl+C stops the app"),
+        ]),
+    )
+}
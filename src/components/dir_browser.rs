@@ -0,0 +1,277 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::events::{FrameworkEvent, KeyCode, KeyModifiers};
+use crate::file_picker::{FileEntry, sort_entries};
+use crate::hooks::{Scope, TreePath};
+use crate::rt;
+use crate::runtime::{ComponentElement, Element, TreeItemNode, TreeNode, component};
+
+type OpenFn = dyn Fn(PathBuf) + Send + Sync;
+
+const LOADING_LABEL: &str = "Loading…";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DirListing {
+    Loading,
+    Loaded(Vec<FileEntry>),
+    Failed(String),
+}
+
+#[derive(Default)]
+pub(crate) struct DirCache {
+    pub(crate) listings: HashMap<PathBuf, DirListing>,
+    pub(crate) paths: HashMap<TreePath, FileEntry>,
+    on_open: Option<Arc<OpenFn>>,
+}
+
+impl DirCache {
+    pub(crate) fn build_items(
+        &mut self,
+        dir: &Path,
+        expanded: &BTreeMap<TreePath, bool>,
+        show_hidden: bool,
+        pending: &mut Vec<PathBuf>,
+    ) -> Vec<TreeItemNode> {
+        self.paths.clear();
+        self.build_level(dir, &mut Vec::new(), expanded, show_hidden, pending)
+    }
+
+    fn build_level(
+        &mut self,
+        dir: &Path,
+        path: &mut TreePath,
+        expanded: &BTreeMap<TreePath, bool>,
+        show_hidden: bool,
+        pending: &mut Vec<PathBuf>,
+    ) -> Vec<TreeItemNode> {
+        let entries = match self.listings.get(dir) {
+            Some(DirListing::Loaded(entries)) => entries.clone(),
+            Some(DirListing::Failed(error)) => {
+                return vec![TreeItemNode::new(format!("⚠ {error}"))];
+            }
+            Some(DirListing::Loading) => return vec![TreeItemNode::new(LOADING_LABEL)],
+            None => {
+                self.listings.insert(dir.to_path_buf(), DirListing::Loading);
+                pending.push(dir.to_path_buf());
+                return vec![TreeItemNode::new(LOADING_LABEL)];
+            }
+        };
+        entries
+            .into_iter()
+            .filter(|entry| show_hidden || !entry.is_hidden())
+            .enumerate()
+            .map(|(index, entry)| {
+                path.push(index);
+                self.paths.insert(path.clone(), entry.clone());
+                let node = if entry.is_dir {
+                    let open = expanded.get(path.as_slice()).copied().unwrap_or(false);
+                    let children = if open {
+                        self.build_level(&entry.path, path, expanded, show_hidden, pending)
+                    } else {
+                        vec![TreeItemNode::new(LOADING_LABEL)]
+                    };
+                    TreeItemNode::new(format!("{}/", entry.name))
+                        .children(children)
+                        .expanded(open)
+                } else {
+                    TreeItemNode::new(entry.name)
+                };
+                path.pop();
+                node
+            })
+            .collect()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn read_dir_entries(dir: &Path) -> std::io::Result<Vec<FileEntry>> {
+    let mut reader = tokio::fs::read_dir(dir).await?;
+    let mut entries = Vec::new();
+    while let Some(entry) = reader.next_entry().await? {
+        let path = entry.path();
+        let is_dir = tokio::fs::metadata(&path)
+            .await
+            .is_ok_and(|metadata| metadata.is_dir());
+        entries.push(FileEntry {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            path,
+            is_dir,
+        });
+    }
+    sort_entries(&mut entries);
+    Ok(entries)
+}
+
+// tokio has no file system on wasm32. Browsers have none either, and std
+// reports that as an unsupported error.
+#[cfg(target_arch = "wasm32")]
+pub async fn read_dir_entries(dir: &Path) -> std::io::Result<Vec<FileEntry>> {
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        entries.push(FileEntry {
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            is_dir: path.is_dir(),
+            path,
+        });
+    }
+    sort_entries(&mut entries);
+    Ok(entries)
+}
+
+#[derive(Clone)]
+pub struct DirBrowser {
+    id: String,
+    root: PathBuf,
+    title: Option<String>,
+    show_hidden: bool,
+    keyboard: bool,
+    on_open: Option<Arc<OpenFn>>,
+}
+
+impl DirBrowser {
+    pub fn new(id: impl Into<String>, root: impl Into<PathBuf>) -> Self {
+        Self {
+            id: id.into(),
+            root: root.into(),
+            title: None,
+            show_hidden: false,
+            keyboard: false,
+            on_open: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    pub fn keyboard(mut self, enabled: bool) -> Self {
+        self.keyboard = enabled;
+        self
+    }
+
+    pub fn on_open<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(PathBuf) + Send + Sync + 'static,
+    {
+        self.on_open = Some(Arc::new(handler));
+        self
+    }
+
+    pub fn into_component(self) -> ComponentElement {
+        let key = self.id.clone();
+        component("DirBrowser", move |ctx| self.render(ctx)).key(key)
+    }
+
+    fn render(&self, ctx: &mut Scope) -> Element {
+        let tree = ctx.use_tree_state();
+        let cache = ctx.use_ref(DirCache::default);
+        let mut pending = Vec::new();
+        let expanded = tree.snapshot().expanded;
+        let items = cache.with_mut(|cache| {
+            cache.on_open = self.on_open.clone();
+            cache.build_items(&self.root, &expanded, self.show_hidden, &mut pending)
+        });
+
+        let loader = cache.clone();
+        ctx.use_effect(pending.clone(), move |dispatcher| {
+            if pending.is_empty() {
+                return None;
+            }
+            rt::spawn(async move {
+                for dir in pending {
+                    let listing = match read_dir_entries(&dir).await {
+                        Ok(entries) => DirListing::Loaded(entries),
+                        Err(err) => DirListing::Failed(err.to_string()),
+                    };
+                    loader.with_mut(|cache| cache.listings.insert(dir, listing));
+                }
+                dispatcher.request_render();
+            });
+            None
+        });
+
+        let id = self.id.clone();
+        let keyboard = self.keyboard;
+        let handle = tree.clone();
+        ctx.use_effect((id.clone(), keyboard), move |dispatcher| {
+            let mut events = dispatcher.events().subscribe();
+            let task = dispatcher.clone().spawn(async move {
+                loop {
+                    let event = match events.recv().await {
+                        Ok(event) => event,
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => break,
+                    };
+                    let key = match &event {
+                        FrameworkEvent::Key(_) if !keyboard => continue,
+                        FrameworkEvent::Key(key) => Some(*key),
+                        _ => None,
+                    };
+                    if let Some(key) = key {
+                        let refresh = key.code == KeyCode::F(5)
+                            || (key.code == KeyCode::Char('r')
+                                && key.modifiers.contains(KeyModifiers::CONTROL));
+                        if refresh {
+                            cache.with_mut(|cache| cache.listings.clear());
+                            dispatcher.request_render();
+                            continue;
+                        }
+                    }
+                    if !handle.handle_event(&event, &id) {
+                        continue;
+                    }
+                    if key.is_some_and(|key| key.code == KeyCode::Enter) {
+                        let opened = handle.highlighted().and_then(|path| {
+                            cache.with(|cache| {
+                                let entry = cache.paths.get(&path).filter(|entry| !entry.is_dir)?;
+                                Some((entry.path.clone(), cache.on_open.clone()?))
+                            })
+                        });
+                        if let Some((path, on_open)) = opened {
+                            on_open(path);
+                        }
+                    }
+                }
+            });
+            Some(Box::new(move || task.abort()))
+        });
+
+        let mut node = TreeNode::new(items).id(self.id.clone()).state(&tree);
+        if let Some(title) = &self.title {
+            node = node.title(title.clone());
+        }
+        Element::tree(node)
+    }
+}
+
+impl From<DirBrowser> for Element {
+    fn from(value: DirBrowser) -> Self {
+        value.into_component().into()
+    }
+}
+
+impl fmt::Debug for DirBrowser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DirBrowser")
+            .field("id", &self.id)
+            .field("root", &self.root)
+            .field("show_hidden", &self.show_hidden)
+            .field("keyboard", &self.keyboard)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,6 @@
+mod dir_browser;
+
+#[cfg(test)]
+mod tests;
+
+pub use dir_browser::{DirBrowser, DirListing, read_dir_entries};
@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::dir_browser::DirCache;
+use super::{DirListing, read_dir_entries};
+
+fn fixture(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("rustact-browser-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src/runtime")).unwrap();
+    fs::write(root.join("src/lib.rs"), "").unwrap();
+    fs::write(root.join("Cargo.toml"), "").unwrap();
+    fs::write(root.join(".gitignore"), "").unwrap();
+    root
+}
+
+#[tokio::test]
+async fn reads_directories_first_with_tokio_fs() {
+    let root = fixture("read");
+    let entries = read_dir_entries(&root).await.unwrap();
+    let names: Vec<_> = entries.iter().map(|entry| entry.name.as_str()).collect();
+    assert_eq!(names, ["src", ".gitignore", "Cargo.toml"]);
+    assert!(entries[0].is_dir);
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn cache_requests_each_directory_once_and_expands_lazily() {
+    let root = fixture("lazy");
+    let mut cache = DirCache::default();
+    let mut pending = Vec::new();
+    let collapsed = BTreeMap::new();
+
+    let items = cache.build_items(&root, &collapsed, false, &mut pending);
+    assert_eq!(items[0].label, "Loading…");
+    assert_eq!(pending, vec![root.clone()]);
+
+    pending.clear();
+    cache.build_items(&root, &collapsed, false, &mut pending);
+    assert!(pending.is_empty());
+
+    let listing = DirListing::Loaded(read_dir_entries(&root).await.unwrap());
+    cache.listings.insert(root.clone(), listing);
+    let items = cache.build_items(&root, &collapsed, false, &mut pending);
+    let labels: Vec<_> = items.iter().map(|item| item.label.as_str()).collect();
+    assert_eq!(labels, ["src/", "Cargo.toml"]);
+    assert!(!items[0].expanded);
+    assert!(pending.is_empty());
+
+    let expanded = BTreeMap::from([(vec![0], true)]);
+    cache.build_items(&root, &expanded, false, &mut pending);
+    assert_eq!(pending, [root.join("src")]);
+    assert_eq!(cache.paths[&vec![1]].path, root.join("Cargo.toml"));
+    fs::remove_dir_all(root).unwrap();
+}
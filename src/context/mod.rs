@@ -2,33 +2,62 @@ use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::styles::StyleNode;
+
 type AnyArc = Arc<dyn Any + Send + Sync>;
 
 #[derive(Default, Debug)]
 pub struct ContextStack {
     layers: HashMap<TypeId, Vec<AnyArc>>,
+    style_path: Vec<StyleNode>,
 }
 
 impl ContextStack {
     pub fn new() -> Self {
         Self {
             layers: HashMap::new(),
+            style_path: Vec::new(),
         }
     }
 
+    pub(crate) fn push_style(&mut self, node: StyleNode) {
+        self.style_path.push(node);
+    }
+
+    pub(crate) fn pop_style(&mut self) {
+        self.style_path.pop();
+    }
+
+    pub(crate) fn style_path(&self) -> &[StyleNode] {
+        &self.style_path
+    }
+
     pub fn provide<T>(&mut self, value: T) -> ContextGuard<'_>
     where
         T: Send + Sync + 'static,
     {
-        let type_id = TypeId::of::<T>();
-        let entry = self.layers.entry(type_id).or_default();
-        entry.push(Arc::new(value));
+        self.push(value);
         ContextGuard {
             stack: self,
-            type_id,
+            type_id: TypeId::of::<T>(),
         }
     }
 
+    pub(crate) fn push<T>(&mut self, value: T)
+    where
+        T: Send + Sync + 'static,
+    {
+        let entry = self.layers.entry(TypeId::of::<T>()).or_default();
+        entry.push(Arc::new(value));
+    }
+
+    pub(crate) fn pop_value<T>(&mut self)
+    where
+        T: Send + Sync + 'static,
+    {
+        self.pop(TypeId::of::<T>());
+    }
+
     pub fn get<T>(&self) -> Option<Arc<T>>
     where
         T: Send + Sync + 'static,
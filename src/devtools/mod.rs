@@ -0,0 +1,278 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::events::{KeyCode, KeyEvent};
+use crate::keymap::KeyBinding;
+use crate::runtime::{
+    Anchor, AnchorNode, BlockNode, ComponentId, Constraint, Element, FlexNode, ListItemNode,
+    ListNode, ParagraphNode,
+};
+use crate::styles::{StyleNode, StyleQuery, Stylesheet};
+
+mod timeline;
+
+pub(crate) use timeline::{RecordAction, ReducerTimeline, TimelineRegistry};
+
+use timeline::Timeline;
+
+const PANEL_WIDTH: u16 = 48;
+const DETAIL_ROWS: u16 = 16;
+const HISTORY_ROWS: usize = 4;
+
+pub(crate) struct Devtools {
+    key: KeyBinding,
+    state: Mutex<DevtoolsState>,
+}
+
+#[derive(Default)]
+struct DevtoolsState {
+    open: bool,
+    selected: usize,
+    stack: Vec<ComponentId>,
+    components: HashMap<ComponentId, Inspected>,
+}
+
+struct Inspected {
+    name: &'static str,
+    key: Option<String>,
+    parent: Option<ComponentId>,
+    hooks: usize,
+    style_path: Vec<StyleNode>,
+}
+
+struct Row<'a> {
+    id: &'a ComponentId,
+    depth: usize,
+    component: &'a Inspected,
+}
+
+impl Devtools {
+    pub(crate) fn new(key: KeyBinding) -> Self {
+        Self {
+            key,
+            state: Mutex::new(DevtoolsState::default()),
+        }
+    }
+
+    pub(crate) fn is_open(&self) -> bool {
+        self.state.lock().open
+    }
+
+    pub(crate) fn begin_frame(&self) {
+        self.state.lock().stack.clear();
+    }
+
+    pub(crate) fn enter(&self, id: &ComponentId, name: &'static str, key: Option<&str>) {
+        let mut state = self.state.lock();
+        let parent = state.stack.last().cloned();
+        state.stack.push(id.clone());
+        let component = state
+            .components
+            .entry(id.clone())
+            .or_insert_with(|| Inspected {
+                name,
+                key: None,
+                parent: None,
+                hooks: 0,
+                style_path: Vec::new(),
+            });
+        component.name = name;
+        component.key = key.map(str::to_string);
+        component.parent = parent;
+    }
+
+    pub(crate) fn rendered(&self, id: &ComponentId, hooks: usize, style_path: &[StyleNode]) {
+        let mut state = self.state.lock();
+        if let Some(component) = state.components.get_mut(id) {
+            component.hooks = hooks;
+            component.style_path = style_path.to_vec();
+        }
+    }
+
+    pub(crate) fn exit(&self) {
+        self.state.lock().stack.pop();
+    }
+
+    pub(crate) fn prune(&self, live: &HashSet<ComponentId>) {
+        self.state
+            .lock()
+            .components
+            .retain(|id, _| live.contains(id));
+    }
+
+    pub(crate) fn handle_key(&self, key: &KeyEvent) -> bool {
+        let mut state = self.state.lock();
+        if self.key.matches(key) {
+            state.open = !state.open;
+            return true;
+        }
+        if !state.open {
+            return false;
+        }
+        match key.code {
+            KeyCode::Esc => state.open = false,
+            KeyCode::Up | KeyCode::Char('k') => state.selected = state.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                let last = state.components.len().saturating_sub(1);
+                state.selected = (state.selected + 1).min(last);
+            }
+            KeyCode::Home => state.selected = 0,
+            KeyCode::End => state.selected = state.components.len().saturating_sub(1),
+            KeyCode::Left | KeyCode::Char('h') => {
+                if let Some(timeline) = selected_timeline(&state) {
+                    timeline.seek(timeline.cursor().saturating_sub(1));
+                }
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                if let Some(timeline) = selected_timeline(&state) {
+                    timeline.seek(timeline.cursor() + 1);
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    pub(crate) fn overlay(&self, styles: &Stylesheet, height: u16) -> Element {
+        let mut state = self.state.lock();
+        let state = &mut *state;
+        let rows = rows(&state.components);
+        state.selected = state.selected.min(rows.len().saturating_sub(1));
+        let items = rows
+            .iter()
+            .map(|row| {
+                let key = row
+                    .component
+                    .key
+                    .as_deref()
+                    .map(|key| format!(" #{key}"))
+                    .unwrap_or_default();
+                ListItemNode::new(format!(
+                    "{}{}{key} ({} hooks)",
+                    "  ".repeat(row.depth),
+                    row.component.name,
+                    row.component.hooks
+                ))
+            })
+            .collect();
+        let tree = Element::list(
+            ListNode::new(items)
+                .title("Components")
+                .highlight(state.selected),
+        );
+        let details = rows
+            .get(state.selected)
+            .map(|row| details(row, styles))
+            .unwrap_or_default();
+        let details = Element::block_node(
+            BlockNode::new(Element::paragraph(ParagraphNode::new(details.join("\n"))))
+                .title("Selected"),
+        );
+        let panel = FlexNode::column(vec![
+            tree,
+            Element::sized(Constraint::Length(DETAIL_ROWS), details),
+        ]);
+        Element::anchored(
+            AnchorNode::new(Anchor::TopRight, Element::flex(panel)).size(PANEL_WIDTH, height),
+        )
+    }
+}
+
+fn rows(components: &HashMap<ComponentId, Inspected>) -> Vec<Row<'_>> {
+    let mut children: HashMap<Option<&ComponentId>, Vec<&ComponentId>> = HashMap::new();
+    for (id, component) in components {
+        let parent = component
+            .parent
+            .as_ref()
+            .filter(|parent| components.contains_key(*parent));
+        children.entry(parent).or_default().push(id);
+    }
+    for siblings in children.values_mut() {
+        siblings.sort_by_key(|id| id.path());
+    }
+    let mut rows = Vec::with_capacity(components.len());
+    let mut pending: Vec<(&ComponentId, usize)> = children
+        .get(&None)
+        .into_iter()
+        .flatten()
+        .rev()
+        .map(|id| (*id, 0))
+        .collect();
+    while let Some((id, depth)) = pending.pop() {
+        rows.push(Row {
+            id,
+            depth,
+            component: &components[id],
+        });
+        if let Some(nested) = children.get(&Some(id)) {
+            pending.extend(nested.iter().rev().map(|child| (*child, depth + 1)));
+        }
+    }
+    rows
+}
+
+fn selected_timeline(state: &DevtoolsState) -> Option<Arc<dyn Timeline>> {
+    let rows = rows(&state.components);
+    let row = rows.get(state.selected.min(rows.len().saturating_sub(1)))?;
+    TimelineRegistry::global().first_for(row.id)
+}
+
+fn details(row: &Row<'_>, styles: &Stylesheet) -> Vec<String> {
+    let component = row.component;
+    let mut lines = vec![
+        format!("id {}", row.id),
+        format!("key {}", component.key.as_deref().unwrap_or("-")),
+        format!("hooks {}", component.hooks),
+        format!(
+            "path {}",
+            component
+                .style_path
+                .iter()
+                .map(|node| node.element.as_str())
+                .collect::<Vec<_>>()
+                .join(" > ")
+        ),
+    ];
+    if let Some((node, ancestors)) = component.style_path.split_last() {
+        let style = styles.query(StyleQuery::element(&node.element).with_ancestors(ancestors));
+        let mut props: Vec<_> = style.props().collect();
+        props.sort();
+        if props.is_empty() {
+            lines.push("no computed style".to_string());
+        }
+        lines.extend(
+            props
+                .into_iter()
+                .map(|(name, value)| format!("{name}: {value}")),
+        );
+    }
+    if let Some(timeline) = TimelineRegistry::global().first_for(row.id) {
+        let entries = timeline.entries();
+        let cursor = timeline.cursor();
+        lines.push(format!(
+            "history {}/{} (left/right to step)",
+            cursor + 1,
+            entries.len()
+        ));
+        let start = cursor
+            .saturating_sub(HISTORY_ROWS / 2)
+            .min(entries.len().saturating_sub(HISTORY_ROWS));
+        lines.extend(
+            entries
+                .iter()
+                .enumerate()
+                .skip(start)
+                .take(HISTORY_ROWS)
+                .map(|(index, entry)| {
+                    let marker = if index == cursor { ">" } else { " " };
+                    format!("{marker} {} -> {}", entry.action, entry.state)
+                }),
+        );
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests;
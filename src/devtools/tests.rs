@@ -0,0 +1,141 @@
+use std::sync::Arc;
+use std::thread;
+
+use parking_lot::Mutex;
+use tokio::sync::mpsc;
+
+use super::timeline::{ReducerTimeline, Timeline};
+use crate::events::EventBus;
+use crate::hooks::ReducerDispatch;
+use crate::keymap::KeyBinding;
+use crate::runtime::{App, Dispatcher, Element, component};
+use crate::styles::Stylesheet;
+use crate::testing::TestApp;
+
+fn app() -> App {
+    App::new(
+        "Inspected",
+        component("Root", |ctx| {
+            let _ = ctx.use_state(|| 0u32);
+            Element::vstack(vec![
+                component("Sidebar", |_| Element::text("sidebar")).into(),
+                component("Row", |ctx| {
+                    let _ = ctx.use_state(|| 1u32);
+                    let _ = ctx.use_state(|| 2u32);
+                    Element::text("row")
+                })
+                .key("first")
+                .into(),
+            ])
+        }),
+    )
+    .with_stylesheet(Stylesheet::parse("root row { color: cyan; }").expect("stylesheet"))
+    .devtools(KeyBinding::parse("f12").expect("binding"))
+    .headless_size(80, 30)
+}
+
+#[tokio::test]
+async fn inspector_lists_the_component_tree_and_follows_the_selection() {
+    let mut app = TestApp::new(app()).await.expect("start app");
+    assert!(!app.text().contains("Components"));
+
+    app.press_key("f12").await.expect("open devtools");
+    app.expect_text("Components")
+        .expect_text("Root (1 hooks)")
+        .expect_text("  Sidebar (0 hooks)")
+        .expect_text("  Row #first (2 hooks)")
+        .expect_text("id 0:Root");
+
+    app.press_key("down").await.expect("select sidebar");
+    app.press_key("down").await.expect("select row");
+    app.expect_text("key first")
+        .expect_text("path root > flex > row")
+        .expect_text("color: cyan");
+
+    app.press_key("esc").await.expect("close devtools");
+    assert!(!app.text().contains("Components"), "{}", app.text());
+}
+
+#[derive(Debug)]
+enum Action {
+    Add(u32),
+}
+
+#[tokio::test]
+async fn reducer_history_steps_back_and_forward() {
+    let app = App::new(
+        "Timeline",
+        component("Counter", |ctx| {
+            let (total, dispatch) = ctx.use_reducer_with_history(
+                || 0u32,
+                |total, action: Action| match action {
+                    Action::Add(amount) => *total += amount,
+                },
+            );
+            ctx.use_effect((), move |_| {
+                dispatch.dispatch(Action::Add(2));
+                dispatch.dispatch(Action::Add(3));
+                None
+            });
+            Element::text(format!("total {total}"))
+        }),
+    )
+    .devtools(KeyBinding::parse("f12").expect("binding"))
+    .headless_size(80, 30);
+    let mut app = TestApp::new(app).await.expect("start app");
+    app.expect_text("total 5");
+
+    app.press_key("f12").await.expect("open devtools");
+    app.expect_text("history 3/3")
+        .expect_text("Add(2) -> 2")
+        .expect_text("> Add(3) -> 5");
+
+    app.press_key("left").await.expect("step back");
+    app.expect_text("total 2").expect_text("history 2/3");
+
+    app.press_key("h").await.expect("step back");
+    app.expect_text("total 0").expect_text("> init -> 0");
+
+    app.press_key("right").await.expect("step forward");
+    app.press_key("l").await.expect("step forward");
+    app.expect_text("total 5").expect_text("history 3/3");
+}
+
+#[test]
+fn concurrent_dispatches_record_the_state_each_action_produced() {
+    let (tx, _rx) = mpsc::channel(8);
+    let shared = Arc::new(Mutex::new(Vec::<u32>::new()));
+    let timeline = Arc::new(ReducerTimeline::new(shared.clone()));
+    let dispatch = ReducerDispatch::new(
+        shared,
+        Arc::new(|pushed: &mut Vec<u32>, value: u32| pushed.push(value)),
+        Dispatcher::new(tx, EventBus::new(8)),
+    )
+    .with_timeline(timeline.clone());
+
+    let workers: Vec<_> = (0..4)
+        .map(|worker| {
+            let dispatch = dispatch.clone();
+            thread::spawn(move || {
+                for step in 0..50 {
+                    dispatch.dispatch(worker * 100 + step);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().expect("worker");
+    }
+
+    let entries = timeline.entries();
+    assert_eq!(entries.len(), 201);
+    for (index, entry) in entries.iter().enumerate().skip(1) {
+        let pushed: Vec<&str> = entry
+            .state
+            .trim_matches(|c| c == '[' || c == ']')
+            .split(", ")
+            .collect();
+        assert_eq!(pushed.len(), index);
+        assert_eq!(pushed.last(), Some(&entry.action.as_str()));
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::runtime::{ComponentId, Current, Registries};
+
+const MAX_HISTORY: usize = 256;
+
+pub(crate) trait Timeline: Send + Sync {
+    fn entries(&self) -> Vec<TimelineEntry>;
+
+    fn cursor(&self) -> usize;
+
+    fn seek(&self, index: usize) -> bool;
+}
+
+// `record` runs while the reducer's state is still locked, so each action is
+// paired with the state it produced and pushed in the order it was applied.
+pub(crate) trait RecordAction<S, A>: Send + Sync {
+    fn describe(&self, action: &A) -> String;
+
+    fn record(&self, action: String, state: &S);
+}
+
+pub(crate) struct TimelineEntry {
+    pub(crate) action: String,
+    pub(crate) state: String,
+}
+
+pub(crate) struct ReducerTimeline<S> {
+    shared: Arc<Mutex<S>>,
+    history: Mutex<History<S>>,
+}
+
+struct History<S> {
+    snapshots: Vec<Snapshot<S>>,
+    cursor: usize,
+}
+
+struct Snapshot<S> {
+    action: String,
+    debug: String,
+    state: S,
+}
+
+impl<S: Clone + Debug + Send> ReducerTimeline<S> {
+    pub(crate) fn new(shared: Arc<Mutex<S>>) -> Self {
+        let state = shared.lock().clone();
+        Self {
+            history: Mutex::new(History {
+                snapshots: vec![Snapshot {
+                    action: "init".to_string(),
+                    debug: format!("{state:?}"),
+                    state,
+                }],
+                cursor: 0,
+            }),
+            shared,
+        }
+    }
+}
+
+impl<S: Clone + Debug + Send, A: Debug> RecordAction<S, A> for ReducerTimeline<S> {
+    fn describe(&self, action: &A) -> String {
+        format!("{action:?}")
+    }
+
+    fn record(&self, action: String, state: &S) {
+        let state = state.clone();
+        let mut history = self.history.lock();
+        let keep = history.cursor + 1;
+        history.snapshots.truncate(keep);
+        history.snapshots.push(Snapshot {
+            action,
+            debug: format!("{state:?}"),
+            state,
+        });
+        if history.snapshots.len() > MAX_HISTORY {
+            history.snapshots.remove(0);
+        }
+        history.cursor = history.snapshots.len() - 1;
+    }
+}
+
+impl<S: Clone + Send> Timeline for ReducerTimeline<S> {
+    fn entries(&self) -> Vec<TimelineEntry> {
+        self.history
+            .lock()
+            .snapshots
+            .iter()
+            .map(|snapshot| TimelineEntry {
+                action: snapshot.action.clone(),
+                state: snapshot.debug.clone(),
+            })
+            .collect()
+    }
+
+    fn cursor(&self) -> usize {
+        self.history.lock().cursor
+    }
+
+    fn seek(&self, index: usize) -> bool {
+        // Same lock order as `ReducerDispatch::dispatch`: state, then history.
+        let mut state = self.shared.lock();
+        let mut history = self.history.lock();
+        let Some(snapshot) = history.snapshots.get(index) else {
+            return false;
+        };
+        *state = snapshot.state.clone();
+        history.cursor = index;
+        true
+    }
+}
+
+pub(crate) struct TimelineRegistry {
+    timelines: Mutex<HashMap<(ComponentId, usize), Arc<dyn Timeline>>>,
+}
+
+impl TimelineRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            timelines: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn global() -> Current<Self> {
+        Registries::current(|registries| &registries.timelines)
+    }
+
+    pub(crate) fn register(&self, id: &ComponentId, slot: usize, timeline: Arc<dyn Timeline>) {
+        self.timelines.lock().insert((id.clone(), slot), timeline);
+    }
+
+    pub(crate) fn first_for(&self, id: &ComponentId) -> Option<Arc<dyn Timeline>> {
+        self.timelines
+            .lock()
+            .iter()
+            .filter(|((owner, _), _)| owner == id)
+            .min_by_key(|((_, slot), _)| *slot)
+            .map(|(_, timeline)| timeline.clone())
+    }
+
+    pub(crate) fn prune(&self, live: &HashSet<ComponentId>) {
+        self.timelines.lock().retain(|(id, _), _| live.contains(id));
+    }
+}
@@ -1,18 +1,31 @@
 use std::time::Duration;
 
-use crossterm::event::{
-    Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent,
-    MouseEventKind,
-};
+#[cfg(not(target_arch = "wasm32"))]
+use crossterm::event::Event as CrosstermEvent;
 use tokio::sync::broadcast;
 use tracing::trace;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+#[cfg(target_arch = "wasm32")]
+pub use keys::{
+    KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+
+#[cfg(target_arch = "wasm32")]
+mod keys;
+
 #[derive(Clone, Debug)]
 pub enum FrameworkEvent {
     Key(KeyEvent),
     Mouse(MouseEvent),
     Resize(u16, u16),
     Tick,
+    Command(String),
 }
 
 #[derive(Clone)]
@@ -34,8 +47,13 @@ impl EventBus {
     pub fn subscribe(&self) -> broadcast::Receiver<FrameworkEvent> {
         self.tx.subscribe()
     }
+
+    pub fn lag(&self) -> usize {
+        self.tx.len()
+    }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 pub fn map_terminal_event(event: CrosstermEvent) -> Option<FrameworkEvent> {
     match event {
         CrosstermEvent::Key(key) => Some(FrameworkEvent::Key(key)),
@@ -77,6 +95,10 @@ pub fn mouse_scroll_delta(event: &FrameworkEvent) -> i32 {
     }
 }
 
+pub fn is_command(event: &FrameworkEvent, command: &str) -> bool {
+    matches!(event, FrameworkEvent::Command(id) if id == command)
+}
+
 pub fn mouse_position(event: &FrameworkEvent) -> Option<(u16, u16)> {
     if let FrameworkEvent::Mouse(mouse) = event {
         Some((mouse.column, mouse.row))
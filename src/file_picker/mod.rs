@@ -0,0 +1,259 @@
+use std::fmt;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::events::{KeyCode, KeyEvent, KeyModifiers};
+use crate::hooks::RefHandle;
+use crate::runtime::Dispatcher;
+
+const PAGE_STEP: isize = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub is_dir: bool,
+}
+
+impl FileEntry {
+    pub fn is_hidden(&self) -> bool {
+        self.name.starts_with('.')
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FilePickerState {
+    pub dir: PathBuf,
+    pub filter: String,
+    pub show_hidden: bool,
+    pub selected: usize,
+    pub chosen: Option<PathBuf>,
+    pub error: Option<String>,
+    entries: Vec<FileEntry>,
+}
+
+impl FilePickerState {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        let mut state = Self {
+            dir: dir.into(),
+            ..Self::default()
+        };
+        state.load();
+        state
+    }
+
+    pub fn visible(&self) -> Vec<&FileEntry> {
+        let filter = self.filter.to_lowercase();
+        self.entries
+            .iter()
+            .filter(|entry| self.show_hidden || !entry.is_hidden())
+            .filter(|entry| entry.name.to_lowercase().contains(&filter))
+            .collect()
+    }
+
+    pub(crate) fn load(&mut self) {
+        self.selected = 0;
+        match read_entries(&self.dir) {
+            Ok(entries) => {
+                self.entries = entries;
+                self.error = None;
+            }
+            Err(err) => {
+                self.entries.clear();
+                self.error = Some(err.to_string());
+            }
+        }
+    }
+
+    fn highlighted(&self) -> Option<FileEntry> {
+        self.visible()
+            .get(self.selected)
+            .map(|entry| (*entry).clone())
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        let last = self.visible().len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+    }
+
+    fn open(&mut self, dir: PathBuf) {
+        self.dir = dir;
+        self.filter.clear();
+        self.load();
+    }
+
+    fn open_parent(&mut self) {
+        let Some(parent) = self.dir.parent().map(Path::to_path_buf) else {
+            return;
+        };
+        let previous = self.dir.clone();
+        self.open(parent);
+        if let Some(index) = self
+            .visible()
+            .iter()
+            .position(|entry| entry.path == previous)
+        {
+            self.selected = index;
+        }
+    }
+
+    fn set_filter(&mut self, filter: String) {
+        self.filter = filter;
+        self.selected = 0;
+    }
+}
+
+fn read_entries(dir: &Path) -> std::io::Result<Vec<FileEntry>> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        entries.push(FileEntry {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            is_dir: path.is_dir(),
+            path,
+        });
+    }
+    sort_entries(&mut entries);
+    Ok(entries)
+}
+
+pub(crate) fn sort_entries(entries: &mut [FileEntry]) {
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+}
+
+pub fn breadcrumbs(dir: &Path) -> String {
+    let parts: Vec<String> = dir
+        .components()
+        .map(|component| match component {
+            Component::RootDir => "/".to_string(),
+            Component::Normal(part) => part.to_string_lossy().into_owned(),
+            Component::Prefix(prefix) => prefix.as_os_str().to_string_lossy().into_owned(),
+            Component::CurDir => ".".to_string(),
+            Component::ParentDir => "..".to_string(),
+        })
+        .collect();
+    if parts.is_empty() {
+        return ".".to_string();
+    }
+    parts.join(" › ")
+}
+
+#[derive(Clone)]
+pub struct FilePickerHandle {
+    state: RefHandle<FilePickerState>,
+    dispatcher: Dispatcher,
+}
+
+impl FilePickerHandle {
+    pub(crate) fn new(state: RefHandle<FilePickerState>, dispatcher: Dispatcher) -> Self {
+        Self { state, dispatcher }
+    }
+
+    pub fn snapshot(&self) -> FilePickerState {
+        self.state.with(Clone::clone)
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        self.state.with(|state| state.dir.clone())
+    }
+
+    pub fn filter(&self) -> String {
+        self.state.with(|state| state.filter.clone())
+    }
+
+    pub fn chosen(&self) -> Option<PathBuf> {
+        self.state.with(|state| state.chosen.clone())
+    }
+
+    pub fn entries(&self) -> Vec<FileEntry> {
+        self.state
+            .with(|state| state.visible().into_iter().cloned().collect())
+    }
+
+    pub fn set_dir(&self, dir: impl Into<PathBuf>) {
+        let dir = dir.into();
+        self.mutate(|state| state.open(dir));
+    }
+
+    pub fn set_filter(&self, filter: impl Into<String>) {
+        let filter = filter.into();
+        self.mutate(|state| state.set_filter(filter));
+    }
+
+    pub fn toggle_hidden(&self) {
+        self.mutate(|state| {
+            state.show_hidden = !state.show_hidden;
+            state.selected = 0;
+        });
+    }
+
+    pub fn refresh(&self) {
+        self.mutate(FilePickerState::load);
+    }
+
+    pub fn handle_key(&self, key: &KeyEvent) -> Option<PathBuf> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let mut chosen = None;
+        self.mutate(|state| match key.code {
+            KeyCode::Up => state.move_by(-1),
+            KeyCode::Down => state.move_by(1),
+            KeyCode::PageUp => state.move_by(-PAGE_STEP),
+            KeyCode::PageDown => state.move_by(PAGE_STEP),
+            KeyCode::Home => state.selected = 0,
+            KeyCode::End => state.move_by(isize::MAX),
+            KeyCode::Left => state.open_parent(),
+            KeyCode::Right => {
+                if let Some(entry) = state.highlighted().filter(|entry| entry.is_dir) {
+                    state.open(entry.path);
+                }
+            }
+            KeyCode::Enter => match state.highlighted() {
+                Some(entry) if entry.is_dir => state.open(entry.path),
+                Some(entry) => {
+                    state.chosen = Some(entry.path.clone());
+                    chosen = Some(entry.path);
+                }
+                None => {}
+            },
+            KeyCode::Backspace if state.filter.is_empty() => state.open_parent(),
+            KeyCode::Backspace => {
+                let mut filter = state.filter.clone();
+                filter.pop();
+                state.set_filter(filter);
+            }
+            KeyCode::Esc => state.set_filter(String::new()),
+            KeyCode::Char('h') if alt => {
+                state.show_hidden = !state.show_hidden;
+                state.selected = 0;
+            }
+            KeyCode::Char(c) if !ctrl && !alt => {
+                let filter = format!("{}{c}", state.filter);
+                state.set_filter(filter);
+            }
+            _ => {}
+        });
+        chosen
+    }
+
+    fn mutate(&self, f: impl FnOnce(&mut FilePickerState)) {
+        self.state.with_mut(f);
+        self.dispatcher.request_render();
+    }
+}
+
+impl fmt::Debug for FilePickerHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FilePickerHandle")
+            .field("dir", &self.dir())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests;
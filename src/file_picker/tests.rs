@@ -0,0 +1,80 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+use super::*;
+use crate::events::EventBus;
+
+fn fixture(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("rustact-picker-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/main.rs"), "").unwrap();
+    fs::write(root.join("Cargo.toml"), "").unwrap();
+    fs::write(root.join("README.md"), "").unwrap();
+    fs::write(root.join(".env"), "").unwrap();
+    root
+}
+
+fn picker(dir: &Path) -> FilePickerHandle {
+    let (tx, _rx) = mpsc::channel(8);
+    let dispatcher = Dispatcher::new(tx, EventBus::new(8));
+    let state = RefHandle::new(Arc::new(Mutex::new(FilePickerState::new(dir))));
+    FilePickerHandle::new(state, dispatcher)
+}
+
+fn names(picker: &FilePickerHandle) -> Vec<String> {
+    picker
+        .entries()
+        .into_iter()
+        .map(|entry| entry.name)
+        .collect()
+}
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+#[test]
+fn lists_directories_first_and_hides_dotfiles() {
+    let root = fixture("listing");
+    let picker = picker(&root);
+    assert_eq!(names(&picker), ["src", "Cargo.toml", "README.md"]);
+
+    picker.handle_key(&KeyEvent::new(KeyCode::Char('h'), KeyModifiers::ALT));
+    assert_eq!(names(&picker), ["src", ".env", "Cargo.toml", "README.md"]);
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn typing_filters_and_enter_navigates_or_chooses() {
+    let root = fixture("navigate");
+    let picker = picker(&root);
+
+    picker.handle_key(&key(KeyCode::Char('e')));
+    assert_eq!(names(&picker), ["README.md"]);
+    picker.handle_key(&key(KeyCode::Backspace));
+    picker.handle_key(&key(KeyCode::Char('s')));
+    assert_eq!(names(&picker), ["src"]);
+    picker.handle_key(&key(KeyCode::Enter));
+    assert_eq!(picker.dir(), root.join("src"));
+    assert_eq!(picker.filter(), "");
+
+    let chosen = picker.handle_key(&key(KeyCode::Enter));
+    assert_eq!(chosen, Some(root.join("src/main.rs")));
+    assert_eq!(picker.chosen(), chosen);
+
+    picker.handle_key(&key(KeyCode::Backspace));
+    assert_eq!(picker.dir(), root);
+    assert_eq!(picker.snapshot().selected, 0);
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn breadcrumbs_join_path_components() {
+    assert_eq!(breadcrumbs(Path::new("/home/ops")), "/ › home › ops");
+}
@@ -1,8 +1,15 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
 use std::sync::Arc;
 
 use parking_lot::Mutex;
 
-use crate::runtime::Dispatcher;
+#[cfg(feature = "devtools")]
+use crate::devtools::RecordAction;
+use crate::events::{FrameworkEvent, KeyCode, KeyEvent, KeyModifiers};
+use crate::interactions::{clicked_row, table_source_row, tree_row_click};
+use crate::runtime::{CheckState, Dispatcher};
+use crate::text_input::{TextInputHandle, TextInputs};
 
 pub type ReducerFn<S, A> = dyn Fn(&mut S, A) + Send + Sync + 'static;
 
@@ -36,6 +43,8 @@ pub struct ReducerDispatch<S: Send + 'static, A: Send + 'static> {
     pub(crate) shared: Arc<Mutex<S>>,
     pub(crate) reducer: Arc<ReducerFn<S, A>>,
     dispatcher: Dispatcher,
+    #[cfg(feature = "devtools")]
+    timeline: Option<Arc<dyn RecordAction<S, A>>>,
 }
 
 impl<S: Send + 'static, A: Send + 'static> ReducerDispatch<S, A> {
@@ -48,13 +57,30 @@ impl<S: Send + 'static, A: Send + 'static> ReducerDispatch<S, A> {
             shared,
             reducer,
             dispatcher,
+            #[cfg(feature = "devtools")]
+            timeline: None,
         }
     }
 
+    #[cfg(feature = "devtools")]
+    pub(crate) fn with_timeline(mut self, timeline: Arc<dyn RecordAction<S, A>>) -> Self {
+        self.timeline = Some(timeline);
+        self
+    }
+
     pub fn dispatch(&self, action: A) {
+        #[cfg(feature = "devtools")]
+        let label = self
+            .timeline
+            .as_ref()
+            .map(|timeline| timeline.describe(&action));
         {
             let mut state = self.shared.lock();
             (self.reducer)(&mut state, action);
+            #[cfg(feature = "devtools")]
+            if let (Some(timeline), Some(label)) = (&self.timeline, label) {
+                timeline.record(label, &state);
+            }
         }
         self.dispatcher.request_render();
     }
@@ -65,11 +91,18 @@ impl<S: Send + 'static, A: Send + 'static> ReducerDispatch<S, A> {
     }
 }
 
-#[derive(Clone)]
 pub struct RefHandle<T: Send + 'static> {
     shared: Arc<Mutex<T>>,
 }
 
+impl<T: Send + 'static> Clone for RefHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
 impl<T: Send + 'static> RefHandle<T> {
     pub(crate) fn new(shared: Arc<Mutex<T>>) -> Self {
         Self { shared }
@@ -89,3 +122,798 @@ impl<T: Send + 'static> RefHandle<T> {
         *self.shared.lock() = next;
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ListSelection {
+    pub selected: Option<usize>,
+    pub offset: usize,
+    pub len: usize,
+    pub page_size: usize,
+    pub checked: BTreeSet<usize>,
+}
+
+impl ListSelection {
+    pub fn new(len: usize) -> Self {
+        Self {
+            selected: if len > 0 { Some(0) } else { None },
+            offset: 0,
+            len,
+            page_size: DEFAULT_PAGE_SIZE,
+            checked: BTreeSet::new(),
+        }
+    }
+
+    pub(crate) fn resize(&mut self, len: usize) {
+        self.len = len;
+        self.selected = match self.selected {
+            _ if len == 0 => None,
+            Some(index) => Some(index.min(len - 1)),
+            None => Some(0),
+        };
+        self.offset = self.offset.min(len.saturating_sub(1));
+        self.checked.retain(|index| *index < len);
+        self.keep_selection_visible();
+    }
+
+    pub fn is_checked(&self, index: usize) -> bool {
+        self.checked.contains(&index)
+    }
+
+    fn toggle(&mut self, index: usize) {
+        if index >= self.len {
+            return;
+        }
+        if !self.checked.remove(&index) {
+            self.checked.insert(index);
+        }
+    }
+
+    fn select(&mut self, index: Option<usize>) {
+        self.selected = index.filter(|_| self.len > 0).map(|i| i.min(self.len - 1));
+        self.keep_selection_visible();
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        if self.len == 0 {
+            return;
+        }
+        let current = self.selected.unwrap_or(0);
+        let next = current.saturating_add_signed(delta).min(self.len - 1);
+        self.select(Some(next));
+    }
+
+    fn keep_selection_visible(&mut self) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+        let page = self.page_size.max(1);
+        if selected < self.offset {
+            self.offset = selected;
+        } else if selected >= self.offset + page {
+            self.offset = selected + 1 - page;
+        }
+    }
+}
+
+const DEFAULT_PAGE_SIZE: usize = 10;
+
+pub type TreePath = Vec<usize>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TreeRowState {
+    pub(crate) path: TreePath,
+    pub(crate) has_children: bool,
+    pub(crate) expanded: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TreeSelection {
+    pub highlighted: Option<TreePath>,
+    pub expanded: BTreeMap<TreePath, bool>,
+    pub checked: BTreeSet<TreePath>,
+    pub(crate) rows: Vec<TreeRowState>,
+    pub(crate) leaves: Vec<TreePath>,
+}
+
+impl TreeSelection {
+    pub fn check_state(&self, path: &[usize]) -> CheckState {
+        let (total, checked) = self
+            .leaves
+            .iter()
+            .filter(|leaf| leaf.starts_with(path))
+            .fold((0, 0), |(total, checked), leaf| {
+                (
+                    total + 1,
+                    checked + usize::from(self.checked.contains(leaf)),
+                )
+            });
+        match checked {
+            0 => CheckState::Unchecked,
+            _ if checked == total => CheckState::Checked,
+            _ => CheckState::Partial,
+        }
+    }
+
+    fn toggle_checked(&mut self, path: &[usize]) {
+        let check = self.check_state(path) != CheckState::Checked;
+        for leaf in self.leaves.iter().filter(|leaf| leaf.starts_with(path)) {
+            if check {
+                self.checked.insert(leaf.clone());
+            } else {
+                self.checked.remove(leaf);
+            }
+        }
+    }
+
+    pub(crate) fn sync_leaves(&mut self, leaves: Vec<TreePath>) {
+        self.checked.retain(|path| leaves.contains(path));
+        self.leaves = leaves;
+    }
+
+    pub(crate) fn sync(&mut self, rows: Vec<TreeRowState>) {
+        let visible = self
+            .highlighted
+            .as_ref()
+            .is_some_and(|path| rows.iter().any(|row| &row.path == path));
+        if !visible {
+            self.highlighted = self
+                .highlighted
+                .as_ref()
+                .and_then(|path| {
+                    rows.iter()
+                        .filter(|row| path.starts_with(&row.path))
+                        .max_by_key(|row| row.path.len())
+                })
+                .or(rows.first())
+                .map(|row| row.path.clone());
+        }
+        self.rows = rows;
+    }
+
+    fn position(&self) -> Option<usize> {
+        let path = self.highlighted.as_ref()?;
+        self.rows.iter().position(|row| &row.path == path)
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let next = match self.position() {
+            Some(index) => index.saturating_add_signed(delta).min(self.rows.len() - 1),
+            None => 0,
+        };
+        self.highlighted = Some(self.rows[next].path.clone());
+    }
+
+    fn row(&self, path: &[usize]) -> Option<&TreeRowState> {
+        self.rows.iter().find(|row| row.path == path)
+    }
+
+    fn set_expanded(&mut self, path: &[usize], expanded: bool) {
+        self.expanded.insert(path.to_vec(), expanded);
+        if let Some(row) = self.rows.iter_mut().find(|row| row.path == path) {
+            row.expanded = expanded && row.has_children;
+        }
+    }
+
+    fn toggle(&mut self, path: &[usize]) {
+        if let Some(row) = self.row(path).filter(|row| row.has_children) {
+            let expanded = !row.expanded;
+            self.set_expanded(path, expanded);
+        }
+    }
+
+    fn expand_or_descend(&mut self) {
+        let Some(row) = self.position().map(|index| self.rows[index].clone()) else {
+            return;
+        };
+        if !row.has_children {
+            return;
+        }
+        if row.expanded {
+            self.move_by(1);
+        } else {
+            self.set_expanded(&row.path, true);
+        }
+    }
+
+    fn collapse_or_ascend(&mut self) {
+        let Some(row) = self.position().map(|index| self.rows[index].clone()) else {
+            return;
+        };
+        if row.expanded {
+            self.set_expanded(&row.path, false);
+        } else if row.path.len() > 1 {
+            self.highlighted = Some(row.path[..row.path.len() - 1].to_vec());
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ListStateHandle {
+    shared: Arc<Mutex<ListSelection>>,
+    dispatcher: Dispatcher,
+}
+
+impl ListStateHandle {
+    pub(crate) fn new(shared: Arc<Mutex<ListSelection>>, dispatcher: Dispatcher) -> Self {
+        Self { shared, dispatcher }
+    }
+
+    pub fn snapshot(&self) -> ListSelection {
+        self.shared.lock().clone()
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.shared.lock().selected
+    }
+
+    pub fn offset(&self) -> usize {
+        self.shared.lock().offset
+    }
+
+    pub fn len(&self) -> usize {
+        self.shared.lock().len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn resize(&self, len: usize) {
+        self.mutate(|state| state.resize(len));
+    }
+
+    pub fn set_page_size(&self, page_size: usize) {
+        self.mutate(|state| {
+            state.page_size = page_size.max(1);
+            state.keep_selection_visible();
+        });
+    }
+
+    pub fn select(&self, index: Option<usize>) {
+        self.mutate(|state| state.select(index));
+    }
+
+    pub fn select_next(&self) {
+        self.mutate(|state| state.move_by(1));
+    }
+
+    pub fn select_prev(&self) {
+        self.mutate(|state| state.move_by(-1));
+    }
+
+    pub fn select_first(&self) {
+        self.mutate(|state| state.select(Some(0)));
+    }
+
+    pub fn select_last(&self) {
+        self.mutate(|state| state.select(state.len.checked_sub(1)));
+    }
+
+    pub fn page(&self) -> usize {
+        let state = self.shared.lock();
+        state.selected.unwrap_or(0) / state.page_size.max(1)
+    }
+
+    pub fn page_count(&self) -> usize {
+        let state = self.shared.lock();
+        state.len.div_ceil(state.page_size.max(1)).max(1)
+    }
+
+    pub fn next_page(&self) {
+        self.mutate(|state| {
+            let page_size = state.page_size.max(1);
+            let page = state.selected.unwrap_or(0) / page_size;
+            if (page + 1) * page_size < state.len {
+                state.select(Some((page + 1) * page_size));
+            }
+        });
+    }
+
+    pub fn prev_page(&self) {
+        self.mutate(|state| {
+            let page_size = state.page_size.max(1);
+            let page = state.selected.unwrap_or(0) / page_size;
+            state.select(Some(page.saturating_sub(1) * page_size));
+        });
+    }
+
+    pub fn page_down(&self) {
+        self.mutate(|state| state.move_by(state.page_size.max(1) as isize));
+    }
+
+    pub fn page_up(&self) {
+        self.mutate(|state| state.move_by(-(state.page_size.max(1) as isize)));
+    }
+
+    pub fn checked(&self) -> Vec<usize> {
+        self.shared.lock().checked.iter().copied().collect()
+    }
+
+    pub fn is_checked(&self, index: usize) -> bool {
+        self.shared.lock().is_checked(index)
+    }
+
+    pub fn toggle(&self, index: usize) {
+        self.mutate(|state| state.toggle(index));
+    }
+
+    pub fn toggle_selected(&self) {
+        self.mutate(|state| {
+            if let Some(index) = state.selected {
+                state.toggle(index);
+            }
+        });
+    }
+
+    pub fn check_all(&self) {
+        self.mutate(|state| state.checked = (0..state.len).collect());
+    }
+
+    pub fn check_none(&self) {
+        self.mutate(|state| state.checked.clear());
+    }
+
+    pub fn handle_key(&self, key: &KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Up => self.select_prev(),
+            KeyCode::Down => self.select_next(),
+            KeyCode::PageUp => self.page_up(),
+            KeyCode::PageDown => self.page_down(),
+            KeyCode::Home => self.select_first(),
+            KeyCode::End => self.select_last(),
+            KeyCode::Char(' ') => self.toggle_selected(),
+            KeyCode::Char('a') if ctrl => self.check_all(),
+            KeyCode::Char('n') if ctrl => self.check_none(),
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn handle_event(&self, event: &FrameworkEvent, list_id: &str) -> bool {
+        if let FrameworkEvent::Key(key) = event {
+            return self.handle_key(key);
+        }
+        let _app = self.dispatcher.enter();
+        match clicked_row(event, list_id) {
+            Some(index) => {
+                self.select(Some(index));
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn mutate(&self, f: impl FnOnce(&mut ListSelection)) {
+        let changed = {
+            let mut state = self.shared.lock();
+            let before = state.clone();
+            f(&mut state);
+            *state != before
+        };
+        if changed {
+            self.dispatcher.request_render();
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TableEditState {
+    pub column: usize,
+    pub columns: usize,
+    pub editing: Option<(usize, usize)>,
+    pub source_row: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellEdit {
+    pub row: usize,
+    pub column: usize,
+    pub value: String,
+}
+
+#[derive(Clone)]
+pub struct TableEditHandle {
+    table_id: String,
+    state: RefHandle<TableEditState>,
+    input: TextInputHandle,
+    dispatcher: Dispatcher,
+}
+
+impl TableEditHandle {
+    pub(crate) fn new(
+        table_id: String,
+        state: RefHandle<TableEditState>,
+        input: TextInputHandle,
+        dispatcher: Dispatcher,
+    ) -> Self {
+        Self {
+            table_id,
+            state,
+            input,
+            dispatcher,
+        }
+    }
+
+    pub fn snapshot(&self) -> TableEditState {
+        self.state.with(|state| *state)
+    }
+
+    pub fn column(&self) -> usize {
+        self.state.with(|state| state.column)
+    }
+
+    pub fn editing(&self) -> Option<(usize, usize)> {
+        self.state.with(|state| state.editing)
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing().is_some()
+    }
+
+    pub fn input(&self) -> &TextInputHandle {
+        &self.input
+    }
+
+    // `row` is in display order, like the table's highlight; `source_row` maps it to
+    // the row the table was built from so sorting and paging don't move the edit.
+    pub fn source_row(&self, row: usize) -> usize {
+        let _app = self.dispatcher.enter();
+        table_source_row(&self.table_id, row).unwrap_or(row)
+    }
+
+    pub fn begin(&self, row: usize, column: usize, value: impl Into<String>) {
+        let source = self.source_row(row);
+        self.state.with_mut(|state| {
+            state.column = column;
+            state.editing = Some((row, column));
+            state.source_row = Some(source);
+        });
+        let value = value.into();
+        let cursor = value.len();
+        self.input.set_value(value);
+        self.input.set_cursor(cursor);
+        self.input.focus();
+    }
+
+    pub fn cancel(&self) {
+        let cancelled = self.state.with_mut(|state| {
+            state.source_row = None;
+            state.editing.take()
+        });
+        if cancelled.is_some() {
+            TextInputs::focus(None, &self.dispatcher);
+            self.dispatcher.request_render();
+        }
+    }
+
+    pub fn commit(&self) -> Option<CellEdit> {
+        let (row, column, source) = self.state.with_mut(|state| {
+            let (row, column) = state.editing.take()?;
+            Some((row, column, state.source_row.take()))
+        })?;
+        TextInputs::focus(None, &self.dispatcher);
+        self.dispatcher.request_render();
+        Some(CellEdit {
+            row: source.unwrap_or(row),
+            column,
+            value: self.input.value(),
+        })
+    }
+
+    pub fn handle_key<F>(&self, key: &KeyEvent, row: Option<usize>, value: F) -> Option<CellEdit>
+    where
+        F: FnOnce(usize, usize) -> String,
+    {
+        if self.is_editing() {
+            match key.code {
+                KeyCode::Enter => return self.commit(),
+                KeyCode::Esc => self.cancel(),
+                _ => {}
+            }
+            return None;
+        }
+        match key.code {
+            KeyCode::Left => self.move_column(-1),
+            KeyCode::Right => self.move_column(1),
+            KeyCode::Enter => {
+                if let Some(row) = row {
+                    let column = self.column();
+                    self.begin(row, column, value(self.source_row(row), column));
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn move_column(&self, delta: isize) {
+        let changed = self.state.with_mut(|state| {
+            let last = state.columns.saturating_sub(1);
+            let next = state.column.saturating_add_signed(delta).min(last);
+            std::mem::replace(&mut state.column, next) != next
+        });
+        if changed {
+            self.dispatcher.request_render();
+        }
+    }
+}
+
+impl fmt::Debug for TableEditHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableEditHandle")
+            .field("state", &self.snapshot())
+            .field("input", &self.input)
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+pub struct TreeStateHandle {
+    shared: Arc<Mutex<TreeSelection>>,
+    dispatcher: Dispatcher,
+}
+
+impl TreeStateHandle {
+    pub(crate) fn new(shared: Arc<Mutex<TreeSelection>>, dispatcher: Dispatcher) -> Self {
+        Self { shared, dispatcher }
+    }
+
+    pub fn snapshot(&self) -> TreeSelection {
+        self.shared.lock().clone()
+    }
+
+    pub fn highlighted(&self) -> Option<TreePath> {
+        self.shared.lock().highlighted.clone()
+    }
+
+    pub fn is_expanded(&self, path: &[usize]) -> bool {
+        let state = self.shared.lock();
+        match state.row(path) {
+            Some(row) => row.expanded,
+            None => state.expanded.get(path).copied().unwrap_or(false),
+        }
+    }
+
+    pub fn select(&self, path: impl Into<TreePath>) {
+        let path = path.into();
+        self.mutate(|state| state.highlighted = Some(path));
+    }
+
+    pub fn set_expanded(&self, path: &[usize], expanded: bool) {
+        self.mutate(|state| state.set_expanded(path, expanded));
+    }
+
+    pub fn checked(&self) -> Vec<TreePath> {
+        self.shared.lock().checked.iter().cloned().collect()
+    }
+
+    pub fn check_state(&self, path: &[usize]) -> CheckState {
+        self.shared.lock().check_state(path)
+    }
+
+    pub fn toggle_checked(&self, path: &[usize]) {
+        self.mutate(|state| state.toggle_checked(path));
+    }
+
+    pub fn toggle(&self, path: &[usize]) {
+        self.mutate(|state| state.toggle(path));
+    }
+
+    pub fn handle_key(&self, key: &KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Up => self.mutate(|state| state.move_by(-1)),
+            KeyCode::Down => self.mutate(|state| state.move_by(1)),
+            KeyCode::Home => self.mutate(|state| state.move_by(isize::MIN)),
+            KeyCode::End => self.mutate(|state| state.move_by(isize::MAX)),
+            KeyCode::Right => self.mutate(TreeSelection::expand_or_descend),
+            KeyCode::Left => self.mutate(TreeSelection::collapse_or_ascend),
+            KeyCode::Enter => self.mutate(|state| {
+                if let Some(path) = state.highlighted.clone() {
+                    state.toggle(&path);
+                }
+            }),
+            KeyCode::Char(' ') => self.mutate(|state| {
+                if let Some(path) = state.highlighted.clone() {
+                    state.toggle_checked(&path);
+                }
+            }),
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn handle_click(&self, path: &[usize]) {
+        self.mutate(|state| {
+            state.highlighted = Some(path.to_vec());
+            state.toggle(path);
+        });
+    }
+
+    pub fn handle_event(&self, event: &FrameworkEvent, tree_id: &str) -> bool {
+        if let FrameworkEvent::Key(key) = event {
+            return self.handle_key(key);
+        }
+        let _app = self.dispatcher.enter();
+        match tree_row_click(event, tree_id) {
+            Some(path) => {
+                self.handle_click(&path);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn sync(&self, rows: Vec<TreeRowState>, leaves: Vec<TreePath>) {
+        let mut state = self.shared.lock();
+        state.sync_leaves(leaves);
+        state.sync(rows);
+    }
+
+    fn mutate(&self, f: impl FnOnce(&mut TreeSelection)) {
+        let changed = {
+            let mut state = self.shared.lock();
+            let before = (
+                state.highlighted.clone(),
+                state.expanded.clone(),
+                state.checked.clone(),
+            );
+            f(&mut state);
+            (
+                state.highlighted.clone(),
+                state.expanded.clone(),
+                state.checked.clone(),
+            ) != before
+        };
+        if changed {
+            self.dispatcher.request_render();
+        }
+    }
+}
+
+impl fmt::Debug for TreeStateHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeStateHandle")
+            .field("highlighted", &self.highlighted())
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WizardState {
+    pub current: usize,
+    pub steps: usize,
+    pub furthest: usize,
+    pub completed: bool,
+    pub error: Option<String>,
+}
+
+impl WizardState {
+    pub fn is_first(&self) -> bool {
+        self.current == 0
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.current + 1 >= self.steps
+    }
+
+    pub(crate) fn resize(&mut self, steps: usize) {
+        self.steps = steps;
+        let last = steps.saturating_sub(1);
+        self.current = self.current.min(last);
+        self.furthest = self.furthest.min(last);
+    }
+}
+
+#[derive(Clone)]
+pub struct WizardHandle {
+    state: RefHandle<WizardState>,
+    dispatcher: Dispatcher,
+}
+
+impl WizardHandle {
+    pub(crate) fn new(state: RefHandle<WizardState>, dispatcher: Dispatcher) -> Self {
+        Self { state, dispatcher }
+    }
+
+    pub fn snapshot(&self) -> WizardState {
+        self.state.with(Clone::clone)
+    }
+
+    pub fn current(&self) -> usize {
+        self.state.with(|state| state.current)
+    }
+
+    pub fn steps(&self) -> usize {
+        self.state.with(|state| state.steps)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.state.with(|state| state.completed)
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.state.with(|state| state.error.clone())
+    }
+
+    pub fn next(&self) -> bool {
+        self.advance(|_| Ok(()))
+    }
+
+    pub fn advance<F>(&self, gate: F) -> bool
+    where
+        F: FnOnce(usize) -> Result<(), String>,
+    {
+        let current = self.current();
+        let result = gate(current);
+        let advanced = self.state.with_mut(|state| match result {
+            Ok(()) => {
+                state.error = None;
+                if state.is_last() {
+                    state.completed = state.steps > 0;
+                } else {
+                    state.current += 1;
+                    state.furthest = state.furthest.max(state.current);
+                }
+                true
+            }
+            Err(message) => {
+                state.error = Some(message);
+                false
+            }
+        });
+        self.dispatcher.request_render();
+        advanced
+    }
+
+    pub fn back(&self) -> bool {
+        let moved = self.state.with_mut(|state| {
+            state.error = None;
+            state.completed = false;
+            if state.is_first() {
+                return false;
+            }
+            state.current -= 1;
+            true
+        });
+        self.dispatcher.request_render();
+        moved
+    }
+
+    pub fn go_to(&self, step: usize) -> bool {
+        let moved = self.state.with_mut(|state| {
+            if step > state.furthest || step >= state.steps {
+                return false;
+            }
+            state.current = step;
+            state.completed = false;
+            state.error = None;
+            true
+        });
+        if moved {
+            self.dispatcher.request_render();
+        }
+        moved
+    }
+
+    pub fn reset(&self) {
+        self.state.with_mut(|state| {
+            *state = WizardState {
+                steps: state.steps,
+                ..WizardState::default()
+            };
+        });
+        self.dispatcher.request_render();
+    }
+}
+
+impl fmt::Debug for WizardHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WizardHandle")
+            .field("state", &self.snapshot())
+            .finish()
+    }
+}
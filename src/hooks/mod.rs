@@ -3,7 +3,15 @@ mod registry;
 mod scope;
 #[cfg(test)]
 mod tests;
+mod transition;
 
-pub use handles::{ReducerDispatch, RefHandle, StateHandle};
+pub(crate) use handles::TreeRowState;
+pub use handles::{
+    CellEdit, ListSelection, ListStateHandle, ReducerDispatch, RefHandle, StateHandle,
+    TableEditHandle, TableEditState, TreePath, TreeSelection, TreeStateHandle, WizardHandle,
+    WizardState,
+};
+pub(crate) use registry::MemoWatchers;
 pub use registry::{EffectHook, EffectInvocation, HookRegistry};
 pub use scope::Scope;
+pub use transition::Easing;
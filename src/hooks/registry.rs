@@ -1,12 +1,20 @@
 use std::any::Any;
 use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Weak};
 
 use parking_lot::Mutex;
 
+use crate::rt::Instant;
 use crate::runtime::{ComponentId, Dispatcher};
+
+use super::handles::{ListSelection, TreeSelection};
+use super::transition::{StyleTransition, TransitionState};
 use crate::text_input::{TextInputHandle, TextInputs};
 
+const CONDITIONAL_HINT: &str = "hint: hooks must be called in the same order on every render; \
+     move hook calls out of conditions, loops and early returns";
+
 pub(crate) type AnySlot = dyn Any + Send + Sync;
 pub type Cleanup = Box<dyn FnOnce() + Send + Sync>;
 
@@ -42,6 +50,19 @@ impl HookRegistry {
         });
     }
 
+    pub(crate) fn slot_count(&self) -> usize {
+        let guard = self.stores.lock();
+        guard.values().map(|store| store.lock().slot_count()).sum()
+    }
+
+    pub(crate) fn slot_counts(&self) -> Vec<(ComponentId, usize)> {
+        let guard = self.stores.lock();
+        guard
+            .iter()
+            .map(|(id, store)| (id.clone(), store.lock().slot_count()))
+            .collect()
+    }
+
     pub fn with_effect_slot<F, R>(&self, id: &ComponentId, slot_index: usize, f: F) -> R
     where
         F: FnOnce(&mut EffectHook) -> R,
@@ -53,7 +74,7 @@ impl HookRegistry {
             if matches!(slot, HookSlot::Vacant) {
                 *slot = HookSlot::Effect(EffectHook::default());
             } else {
-                panic!("effect slot type mismatch");
+                order_mismatch(id, slot_index, "use_effect", slot.kind());
             }
         }
         match slot {
@@ -63,12 +84,54 @@ impl HookRegistry {
     }
 }
 
+// Stale flags of the memoized subtrees that contain a component, set when it
+// asks to render again.
+#[derive(Default)]
+pub(crate) struct MemoWatchers(Mutex<Vec<Weak<AtomicBool>>>);
+
+impl MemoWatchers {
+    pub(crate) fn watch(&self, stale: &Arc<AtomicBool>) {
+        let mut watchers = self.0.lock();
+        watchers.retain(|watcher| watcher.strong_count() > 0);
+        watchers.push(Arc::downgrade(stale));
+    }
+
+    pub(crate) fn notify(&self) {
+        for watcher in self.0.lock().drain(..) {
+            if let Some(stale) = watcher.upgrade() {
+                stale.store(true, Ordering::Release);
+            }
+        }
+    }
+}
+
 #[derive(Default)]
 pub(crate) struct HookStore {
     slots: Vec<HookSlot>,
+    style_transitions: HashMap<String, StyleTransition>,
+    watchers: Arc<MemoWatchers>,
+    hooks_called: Option<usize>,
 }
 
 impl HookStore {
+    pub(crate) fn slot_count(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub(crate) fn check_hook_count(&mut self, id: &ComponentId, called: usize) {
+        match self.hooks_called.replace(called) {
+            Some(previous) if previous != called => panic!(
+                "hook count changed in {}: the previous render called {previous} hooks, this one called {called}\n{CONDITIONAL_HINT}",
+                describe(id)
+            ),
+            _ => {}
+        }
+    }
+
+    pub(crate) fn watchers(&self) -> Arc<MemoWatchers> {
+        self.watchers.clone()
+    }
+
     pub(crate) fn slot(&mut self, index: usize) -> &mut HookSlot {
         while self.slots.len() <= index {
             self.slots.push(HookSlot::Vacant);
@@ -76,6 +139,12 @@ impl HookStore {
         &mut self.slots[index]
     }
 
+    pub(crate) fn style_transition(&mut self, key: String, value: &str) -> &mut StyleTransition {
+        self.style_transitions
+            .entry(key)
+            .or_insert_with(|| StyleTransition::new(value, Instant::now()))
+    }
+
     pub(crate) fn drain(&mut self) {
         for slot in &mut self.slots {
             match slot {
@@ -93,6 +162,8 @@ impl HookStore {
             }
         }
         self.slots.clear();
+        self.style_transitions.clear();
+        self.hooks_called = None;
     }
 }
 
@@ -106,6 +177,40 @@ pub(crate) enum HookSlot {
     Reducer(Box<AnySlot>),
     RefCell(Box<AnySlot>),
     TextInput(Box<AnySlot>),
+    ListState(Arc<Mutex<ListSelection>>),
+    TreeState(Arc<Mutex<TreeSelection>>),
+    Transition(TransitionState),
+}
+
+impl HookSlot {
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            HookSlot::Vacant => "no hook",
+            HookSlot::State(_) => "use_state",
+            HookSlot::Effect(_) => "use_effect",
+            HookSlot::Memo(_) => "use_memo",
+            HookSlot::Reducer(_) => "use_reducer",
+            HookSlot::RefCell(_) => "use_ref",
+            HookSlot::TextInput(_) => "use_text_input",
+            HookSlot::ListState(_) => "use_list_state",
+            HookSlot::TreeState(_) => "use_tree_state",
+            HookSlot::Transition(_) => "use_transition",
+        }
+    }
+}
+
+pub(crate) fn order_mismatch(id: &ComponentId, index: usize, expected: &str, found: &str) -> ! {
+    panic!(
+        "hook order mismatch in {} at hook slot {index}: expected {expected}, found {found}\n{CONDITIONAL_HINT}",
+        describe(id)
+    )
+}
+
+fn describe(id: &ComponentId) -> String {
+    match id.key() {
+        Some(key) => format!("component `{}` (key `{key}`, id `{id}`)", id.name()),
+        None => format!("component `{}` (id `{id}`)", id.name()),
+    }
 }
 
 #[derive(Default)]
@@ -1,16 +1,33 @@
+use std::fmt::Debug;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use parking_lot::Mutex;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 
 use crate::context::{ContextGuard, ContextStack};
+#[cfg(feature = "devtools")]
+use crate::devtools::{ReducerTimeline, TimelineRegistry};
+use crate::file_picker::{FilePickerHandle, FilePickerState};
+use crate::interactions::{is_focused, is_hovered};
+use crate::keymap::{Keymap, KeymapEntry, KeymapRegistry, parse_or_panic};
+use crate::persistence::PersistentStore;
+use crate::rt::Instant;
 use crate::runtime::{ComponentId, Dispatcher, FormFieldStatus};
-use crate::styles::Stylesheet;
-use crate::text_input::{TextInputHandle, TextInputSnapshot};
+use crate::styles::{ColorScheme, ComputedStyle, StyleNode, StyleQuery, Stylesheet, Theme};
+use crate::text_input::{TextAreaHandle, TextInputHandle, TextInputSnapshot};
 
-use super::handles::{ReducerDispatch, ReducerFn, RefHandle, StateHandle};
+use super::handles::{
+    ListSelection, ListStateHandle, ReducerDispatch, ReducerFn, RefHandle, StateHandle,
+    TableEditHandle, TableEditState, TreeSelection, TreeStateHandle, WizardHandle, WizardState,
+};
 use super::registry::{
     AnySlot, Cleanup, EffectHook, EffectInvocation, HookSlot, HookStore, TextInputEntry,
+    order_mismatch,
 };
+use super::transition::{Easing, TransitionState};
 
 pub struct Scope<'a> {
     component_id: ComponentId,
@@ -20,6 +37,8 @@ pub struct Scope<'a> {
     context: &'a mut ContextStack,
     pending_effects: Vec<EffectInvocation>,
     styles: Arc<Stylesheet>,
+    persistence: Arc<PersistentStore>,
+    keymap: Arc<KeymapRegistry>,
 }
 
 impl<'a> Scope<'a> {
@@ -29,6 +48,8 @@ impl<'a> Scope<'a> {
         dispatcher: Dispatcher,
         context: &'a mut ContextStack,
         styles: Arc<Stylesheet>,
+        persistence: Arc<PersistentStore>,
+        keymap: Arc<KeymapRegistry>,
     ) -> Self {
         Self {
             component_id,
@@ -38,6 +59,8 @@ impl<'a> Scope<'a> {
             context,
             pending_effects: Vec::new(),
             styles,
+            persistence,
+            keymap,
         }
     }
 
@@ -58,9 +81,16 @@ impl<'a> Scope<'a> {
                 }
                 HookSlot::State(existing) => existing
                     .downcast_ref::<Arc<Mutex<T>>>()
-                    .expect("use_state hook order mismatch")
+                    .unwrap_or_else(|| {
+                        order_mismatch(
+                            &self.component_id,
+                            index,
+                            "use_state",
+                            "use_state with a different type",
+                        )
+                    })
                     .clone(),
-                _ => panic!("use_state hook order mismatch"),
+                other => order_mismatch(&self.component_id, index, "use_state", other.kind()),
             }
         };
         let value = shared.lock().clone();
@@ -68,6 +98,22 @@ impl<'a> Scope<'a> {
         (value, handle)
     }
 
+    pub fn use_persistent_state<T, F>(
+        &mut self,
+        key: impl Into<String>,
+        init: F,
+    ) -> (T, StateHandle<T>)
+    where
+        T: Clone + Serialize + DeserializeOwned + Send + 'static,
+        F: FnOnce() -> T,
+    {
+        let key = key.into();
+        let persistence = self.persistence.clone();
+        let (value, handle) = self.use_state(|| persistence.load(&key).unwrap_or_else(init));
+        persistence.store(&key, &value);
+        (value, handle)
+    }
+
     pub fn use_effect<D, F>(&mut self, deps: D, effect: F)
     where
         D: PartialEq + Clone + Send + Sync + 'static,
@@ -88,7 +134,7 @@ impl<'a> Scope<'a> {
                     .and_then(|value| value.downcast_ref::<D>())
                     .map(|existing| existing != &deps)
                     .unwrap_or(true),
-                _ => panic!("use_effect hook order mismatch"),
+                other => order_mismatch(&self.component_id, index, "use_effect", other.kind()),
             }
         };
 
@@ -134,13 +180,44 @@ impl<'a> Scope<'a> {
                 }
                 HookSlot::Memo(entry) => entry
                     .downcast_mut::<MemoEntry>()
-                    .expect("use_memo hook order mismatch")
+                    .unwrap_or_else(|| {
+                        order_mismatch(
+                            &self.component_id,
+                            index,
+                            "use_memo",
+                            "use_memo with a different type",
+                        )
+                    })
                     .apply_or_update(deps, compute),
-                _ => panic!("use_memo hook order mismatch"),
+                other => order_mismatch(&self.component_id, index, "use_memo", other.kind()),
             }
         }
     }
 
+    pub fn use_reducer_with_history<S, A, Init, R>(
+        &mut self,
+        init: Init,
+        reducer: R,
+    ) -> (S, ReducerDispatch<S, A>)
+    where
+        S: Clone + Debug + Send + 'static,
+        A: Debug + Send + 'static,
+        Init: FnOnce() -> S,
+        R: Fn(&mut S, A) + Send + Sync + 'static,
+    {
+        #[cfg(feature = "devtools")]
+        {
+            let slot = self.hook_cursor;
+            let (value, dispatch) = self.use_reducer(init, reducer);
+            let shared = dispatch.shared.clone();
+            let timeline = self.use_memo((), move || ReducerTimeline::new(shared));
+            TimelineRegistry::global().register(&self.component_id, slot, timeline.clone());
+            (value, dispatch.with_timeline(timeline))
+        }
+        #[cfg(not(feature = "devtools"))]
+        self.use_reducer(init, reducer)
+    }
+
     pub fn use_callback<T, D, F>(&mut self, deps: D, factory: F) -> Arc<T>
     where
         T: Send + Sync + 'static,
@@ -178,12 +255,19 @@ impl<'a> Scope<'a> {
                 HookSlot::Reducer(entry) => {
                     let entry = entry
                         .downcast_mut::<ReducerEntry<S, A>>()
-                        .expect("use_reducer hook order mismatch");
+                        .unwrap_or_else(|| {
+                            order_mismatch(
+                                &self.component_id,
+                                index,
+                                "use_reducer",
+                                "use_reducer with a different type",
+                            )
+                        });
                     let reducer = into_reducer_arc(reducer);
                     entry.update_reducer(reducer.clone());
                     (entry.state.clone(), entry.reducer.clone())
                 }
-                _ => panic!("use_reducer hook order mismatch"),
+                other => order_mismatch(&self.component_id, index, "use_reducer", other.kind()),
             }
         };
         let value = shared.lock().clone();
@@ -208,15 +292,92 @@ impl<'a> Scope<'a> {
                 }
                 HookSlot::RefCell(entry) => entry
                     .downcast_mut::<RefEntry<T>>()
-                    .expect("use_ref hook order mismatch")
+                    .unwrap_or_else(|| {
+                        order_mismatch(
+                            &self.component_id,
+                            index,
+                            "use_ref",
+                            "use_ref with a different type",
+                        )
+                    })
                     .handle
                     .clone(),
-                _ => panic!("use_ref hook order mismatch"),
+                other => order_mismatch(&self.component_id, index, "use_ref", other.kind()),
             }
         };
         RefHandle::new(shared)
     }
 
+    pub fn use_list_state(&mut self, len: usize) -> ListStateHandle {
+        let index = self.next_index();
+        let shared = {
+            let mut store = self.store.lock();
+            let slot = store.slot(index);
+            match slot {
+                HookSlot::Vacant => {
+                    let state = Arc::new(Mutex::new(ListSelection::new(len)));
+                    *slot = HookSlot::ListState(state.clone());
+                    state
+                }
+                HookSlot::ListState(existing) => existing.clone(),
+                other => order_mismatch(&self.component_id, index, "use_list_state", other.kind()),
+            }
+        };
+        shared.lock().resize(len);
+        ListStateHandle::new(shared, self.dispatcher.clone())
+    }
+
+    pub fn use_file_picker(&mut self, start: impl Into<PathBuf>) -> FilePickerHandle {
+        let start = start.into();
+        let state = self.use_ref(|| FilePickerState::new(start));
+        FilePickerHandle::new(state, self.dispatcher.clone())
+    }
+
+    pub fn use_tree_state(&mut self) -> TreeStateHandle {
+        let index = self.next_index();
+        let shared = {
+            let mut store = self.store.lock();
+            let slot = store.slot(index);
+            match slot {
+                HookSlot::Vacant => {
+                    let state = Arc::new(Mutex::new(TreeSelection::default()));
+                    *slot = HookSlot::TreeState(state.clone());
+                    state
+                }
+                HookSlot::TreeState(existing) => existing.clone(),
+                other => order_mismatch(&self.component_id, index, "use_tree_state", other.kind()),
+            }
+        };
+        TreeStateHandle::new(shared, self.dispatcher.clone())
+    }
+
+    pub fn use_transition(&mut self, target: f64, duration: Duration) -> f64 {
+        self.use_transition_with(target, duration, Easing::default())
+    }
+
+    pub fn use_transition_with(&mut self, target: f64, duration: Duration, easing: Easing) -> f64 {
+        let index = self.next_index();
+        let now = Instant::now();
+        let (value, running) = {
+            let mut store = self.store.lock();
+            let slot = store.slot(index);
+            if matches!(slot, HookSlot::Vacant) {
+                *slot = HookSlot::Transition(TransitionState::new(target, now));
+            }
+            match slot {
+                HookSlot::Transition(state) => {
+                    state.retarget(target, duration, easing, now);
+                    (state.value_at(now), state.is_running(now))
+                }
+                other => order_mismatch(&self.component_id, index, "use_transition", other.kind()),
+            }
+        };
+        if running {
+            self.dispatcher.request_animation_frame();
+        }
+        value
+    }
+
     pub fn use_text_input<F>(&mut self, id: impl Into<String>, init: F) -> TextInputHandle
     where
         F: FnOnce() -> String,
@@ -234,17 +395,94 @@ impl<'a> Scope<'a> {
                     handle
                 }
                 HookSlot::TextInput(entry) => {
-                    let entry = entry
-                        .downcast_mut::<TextInputEntry>()
-                        .expect("use_text_input hook order mismatch");
+                    let entry = entry.downcast_mut::<TextInputEntry>().unwrap_or_else(|| {
+                        order_mismatch(
+                            &self.component_id,
+                            index,
+                            "use_text_input",
+                            "use_text_input with a different type",
+                        )
+                    });
                     entry.ensure_id(&id);
                     entry.handle()
                 }
-                _ => panic!("use_text_input hook order mismatch"),
+                other => order_mismatch(&self.component_id, index, "use_text_input", other.kind()),
+            }
+        }
+    }
+
+    pub fn use_text_area<F>(&mut self, id: impl Into<String>, init: F) -> TextAreaHandle
+    where
+        F: FnOnce() -> String,
+    {
+        let index = self.next_index();
+        let id = id.into();
+        let dispatcher = self.dispatcher.clone();
+        {
+            let mut store = self.store.lock();
+            let slot = store.slot(index);
+            match slot {
+                HookSlot::Vacant => {
+                    let handle = TextAreaHandle::new(id.clone(), init(), dispatcher);
+                    *slot = HookSlot::TextInput(Box::new(TextInputEntry::new(
+                        id,
+                        handle.input().clone(),
+                    )));
+                    handle
+                }
+                HookSlot::TextInput(entry) => {
+                    let entry = entry.downcast_mut::<TextInputEntry>().unwrap_or_else(|| {
+                        order_mismatch(
+                            &self.component_id,
+                            index,
+                            "use_text_area",
+                            "use_text_input with a different type",
+                        )
+                    });
+                    entry.ensure_id(&id);
+                    TextAreaHandle::from_input(entry.handle())
+                }
+                other => order_mismatch(&self.component_id, index, "use_text_area", other.kind()),
             }
         }
     }
 
+    pub fn use_table_editor(&mut self, id: impl Into<String>, columns: usize) -> TableEditHandle {
+        let id = id.into();
+        let input = self.use_text_input(format!("{id}::editor"), String::new);
+        let state = self.use_ref(TableEditState::default);
+        state.with_mut(|state| {
+            state.columns = columns;
+            state.column = state.column.min(columns.saturating_sub(1));
+        });
+        TableEditHandle::new(id, state, input, self.dispatcher.clone())
+    }
+
+    pub fn use_wizard(&mut self, steps: usize) -> WizardHandle {
+        let state = self.use_ref(WizardState::default);
+        state.with_mut(|state| state.resize(steps));
+        WizardHandle::new(state, self.dispatcher.clone())
+    }
+
+    pub fn use_keybinding(
+        &mut self,
+        key: &str,
+        description: impl Into<String>,
+        command: impl Into<String>,
+    ) {
+        self.keymap.declare(KeymapEntry {
+            binding: parse_or_panic(key),
+            description: description.into(),
+            command: Some(command.into()),
+        });
+    }
+
+    pub fn use_keymap(&mut self, keymap: &Keymap) {
+        for entry in keymap.entries() {
+            self.keymap.declare(entry.clone());
+        }
+    }
+
     pub fn use_text_input_validation<F>(
         &mut self,
         handle: &TextInputHandle,
@@ -263,10 +501,78 @@ impl<'a> Scope<'a> {
         &self.dispatcher
     }
 
+    pub fn exit(&self, code: i32) {
+        self.dispatcher.exit(code);
+    }
+
     pub fn styles(&self) -> &Stylesheet {
         &self.styles
     }
 
+    pub fn style(&self, query: StyleQuery<'_>) -> ComputedStyle {
+        let theme = self.use_theme();
+        let mut query = query.with_ancestors(self.context.style_path());
+        if query.theme.is_none() {
+            query = query.with_theme(&theme);
+        }
+        if let Some(id) = query.id {
+            query.state.focus |= is_focused(id);
+            query.state.hover |= is_hovered(id);
+        }
+        let mut style = self.styles.query(query);
+        self.apply_transitions(&query, &mut style);
+        style
+    }
+
+    fn apply_transitions(&self, query: &StyleQuery<'_>, style: &mut ComputedStyle) {
+        let specs = style.transitions();
+        if specs.is_empty() {
+            return;
+        }
+        let now = Instant::now();
+        let key = query.transition_key();
+        let mut running = false;
+        let updates: Vec<(String, String)> = {
+            let mut store = self.store.lock();
+            style
+                .props()
+                .filter(|(name, _)| *name != "transition")
+                .filter_map(|(name, value)| {
+                    let spec = specs.iter().find(|spec| spec.applies_to(name))?;
+                    let transition = store.style_transition(format!("{key}/{name}"), value);
+                    transition.retarget(value, spec, now);
+                    running |= transition.is_running(now);
+                    Some((name.to_string(), transition.value_at(now)))
+                })
+                .collect()
+        };
+        for (name, value) in updates {
+            style.set(&name, value);
+        }
+        if running {
+            self.dispatcher.request_animation_frame();
+        }
+    }
+
+    pub fn style_ancestors(&self) -> &[StyleNode] {
+        self.context.style_path()
+    }
+
+    pub fn use_color_scheme(&self) -> ColorScheme {
+        self.styles.color_scheme().unwrap_or_default()
+    }
+
+    pub fn use_theme(&self) -> Arc<Theme> {
+        self.use_context::<Theme>()
+            .unwrap_or_else(|| Arc::new(Theme::for_scheme(self.use_color_scheme())))
+    }
+
+    pub(crate) fn check_hook_count(&self) {
+        self.store
+            .lock()
+            .check_hook_count(&self.component_id, self.hook_cursor);
+    }
+
     pub(crate) fn take_effects(&mut self) -> Vec<EffectInvocation> {
         std::mem::take(&mut self.pending_effects)
     }
@@ -0,0 +1,310 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::mpsc;
+
+use crate::events::EventBus;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::hooks::{
+    CellEdit, ListSelection, ListStateHandle, RefHandle, TableEditHandle, TableEditState,
+    TreeRowState, TreeSelection, TreeStateHandle, WizardHandle, WizardState,
+};
+use crate::runtime::CheckState;
+use crate::runtime::Dispatcher;
+use crate::text_input::TextInputHandle;
+
+fn list_state(len: usize) -> ListStateHandle {
+    let (tx, _rx) = mpsc::channel(8);
+    let dispatcher = Dispatcher::new(tx, EventBus::new(8));
+    ListStateHandle::new(Arc::new(Mutex::new(ListSelection::new(len))), dispatcher)
+}
+
+#[test]
+fn list_state_moves_selection_within_bounds() {
+    let state = list_state(3);
+    assert_eq!(state.selected(), Some(0));
+
+    state.select_prev();
+    assert_eq!(state.selected(), Some(0));
+
+    state.select_next();
+    state.select_next();
+    state.select_next();
+    assert_eq!(state.selected(), Some(2));
+
+    state.select_first();
+    assert_eq!(state.selected(), Some(0));
+    state.select_last();
+    assert_eq!(state.selected(), Some(2));
+}
+
+#[test]
+fn list_state_pages_and_keeps_selection_visible() {
+    let state = list_state(50);
+    state.set_page_size(5);
+
+    state.page_down();
+    assert_eq!(state.selected(), Some(5));
+    assert_eq!(state.offset(), 1);
+
+    state.page_down();
+    state.page_up();
+    assert_eq!(state.selected(), Some(5));
+    assert_eq!(state.offset(), 5);
+
+    state.select_first();
+    assert_eq!(state.offset(), 0);
+}
+
+#[test]
+fn list_state_steps_between_whole_pages() {
+    let state = list_state(23);
+    state.set_page_size(10);
+    state.select(Some(4));
+    assert_eq!((state.page(), state.page_count()), (0, 3));
+
+    state.next_page();
+    assert_eq!(state.selected(), Some(10));
+    state.next_page();
+    state.next_page();
+    assert_eq!(state.selected(), Some(20));
+    assert_eq!(state.page(), 2);
+
+    state.prev_page();
+    assert_eq!(state.selected(), Some(10));
+}
+
+#[test]
+fn list_state_resize_clamps_selection() {
+    let state = list_state(10);
+    state.select_last();
+    state.resize(4);
+    assert_eq!(state.selected(), Some(3));
+
+    state.resize(0);
+    assert_eq!(state.selected(), None);
+    assert!(state.is_empty());
+
+    state.resize(2);
+    assert_eq!(state.selected(), Some(0));
+}
+
+#[test]
+fn list_state_toggles_checked_items_with_keys() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let state = list_state(4);
+    let key = |code, modifiers| KeyEvent::new(code, modifiers);
+    assert!(state.handle_key(&key(KeyCode::Char(' '), KeyModifiers::NONE)));
+    assert!(state.handle_key(&key(KeyCode::Down, KeyModifiers::NONE)));
+    assert!(state.handle_key(&key(KeyCode::Down, KeyModifiers::NONE)));
+    state.handle_key(&key(KeyCode::Char(' '), KeyModifiers::NONE));
+    assert_eq!(state.checked(), vec![0, 2]);
+
+    state.handle_key(&key(KeyCode::Char(' '), KeyModifiers::NONE));
+    assert_eq!(state.checked(), vec![0]);
+
+    state.handle_key(&key(KeyCode::Char('a'), KeyModifiers::CONTROL));
+    assert_eq!(state.checked(), vec![0, 1, 2, 3]);
+    state.handle_key(&key(KeyCode::Char('n'), KeyModifiers::CONTROL));
+    assert!(state.checked().is_empty());
+    assert!(!state.handle_key(&key(KeyCode::Char('x'), KeyModifiers::NONE)));
+}
+
+#[test]
+fn list_state_resize_drops_checked_indices_out_of_range() {
+    let state = list_state(5);
+    state.check_all();
+    state.resize(2);
+    assert_eq!(state.checked(), vec![0, 1]);
+    state.toggle(7);
+    assert_eq!(state.checked(), vec![0, 1]);
+}
+
+fn table_editor(id: &str, columns: usize) -> TableEditHandle {
+    let (tx, _rx) = mpsc::channel(8);
+    let dispatcher = Dispatcher::new(tx, EventBus::new(8));
+    let state = RefHandle::new(Arc::new(Mutex::new(TableEditState {
+        columns,
+        ..TableEditState::default()
+    })));
+    let input = TextInputHandle::new(id.into(), String::new(), dispatcher.clone());
+    TableEditHandle::new(id.into(), state, input, dispatcher)
+}
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+#[test]
+fn table_editor_moves_between_columns_within_bounds() {
+    let editor = table_editor("grid.columns", 3);
+    editor.handle_key(&key(KeyCode::Left), Some(0), |_, _| String::new());
+    assert_eq!(editor.column(), 0);
+    for _ in 0..5 {
+        editor.handle_key(&key(KeyCode::Right), Some(0), |_, _| String::new());
+    }
+    assert_eq!(editor.column(), 2);
+}
+
+#[test]
+fn table_editor_commits_and_cancels_cell_edits() {
+    let editor = table_editor("grid.edit", 2);
+    editor.handle_key(&key(KeyCode::Right), Some(4), |_, _| String::new());
+    editor.handle_key(&key(KeyCode::Enter), Some(4), |row, column| {
+        format!("r{row}c{column}")
+    });
+    assert_eq!(editor.editing(), Some((4, 1)));
+    assert_eq!(editor.input().value(), "r4c1");
+    assert_eq!(editor.input().cursor(), 4);
+
+    editor.input().set_value("updated");
+    let edit = editor.handle_key(&key(KeyCode::Enter), Some(4), |_, _| unreachable!());
+    assert_eq!(
+        edit,
+        Some(CellEdit {
+            row: 4,
+            column: 1,
+            value: "updated".into(),
+        })
+    );
+    assert!(!editor.is_editing());
+
+    editor.begin(2, 0, "keep");
+    assert_eq!(
+        editor.handle_key(&key(KeyCode::Esc), Some(2), |_, _| unreachable!()),
+        None
+    );
+    assert!(!editor.is_editing());
+}
+
+fn tree_state() -> TreeStateHandle {
+    let (tx, _rx) = mpsc::channel(8);
+    let dispatcher = Dispatcher::new(tx, EventBus::new(8));
+    TreeStateHandle::new(Arc::new(Mutex::new(TreeSelection::default())), dispatcher)
+}
+
+fn tree_row(path: &[usize], has_children: bool, expanded: bool) -> TreeRowState {
+    TreeRowState {
+        path: path.to_vec(),
+        has_children,
+        expanded,
+    }
+}
+
+#[test]
+fn tree_state_navigates_and_toggles_with_keys() {
+    let tree = tree_state();
+    tree.sync(
+        vec![
+            tree_row(&[0], true, true),
+            tree_row(&[0, 0], false, false),
+            tree_row(&[1], true, false),
+        ],
+        vec![vec![0, 0], vec![1, 0]],
+    );
+    assert_eq!(tree.highlighted(), Some(vec![0]));
+
+    tree.handle_key(&key(KeyCode::Right));
+    assert_eq!(tree.highlighted(), Some(vec![0, 0]));
+    tree.handle_key(&key(KeyCode::Left));
+    assert_eq!(tree.highlighted(), Some(vec![0]));
+    tree.handle_key(&key(KeyCode::Left));
+    assert!(!tree.is_expanded(&[0]));
+
+    tree.handle_key(&key(KeyCode::End));
+    tree.handle_key(&key(KeyCode::Enter));
+    assert_eq!(tree.highlighted(), Some(vec![1]));
+    assert!(tree.is_expanded(&[1]));
+    assert_eq!(tree.snapshot().expanded.get(&vec![1]), Some(&true));
+}
+
+#[test]
+fn tree_state_keeps_highlight_on_nearest_visible_ancestor() {
+    let tree = tree_state();
+    tree.select(vec![0, 2, 1]);
+    tree.sync(
+        vec![tree_row(&[0], true, true), tree_row(&[0, 2], true, false)],
+        Vec::new(),
+    );
+    assert_eq!(tree.highlighted(), Some(vec![0, 2]));
+
+    tree.handle_click(&[0, 2]);
+    assert!(tree.is_expanded(&[0, 2]));
+}
+
+#[test]
+fn tree_checkboxes_cascade_to_leaves_and_report_partial_parents() {
+    let tree = tree_state();
+    tree.sync(
+        vec![tree_row(&[0], true, true), tree_row(&[0, 0], false, false)],
+        vec![vec![0, 0], vec![0, 1, 0], vec![1]],
+    );
+
+    tree.handle_key(&key(KeyCode::Char(' ')));
+    assert_eq!(tree.checked(), [vec![0, 0], vec![0, 1, 0]]);
+    assert_eq!(tree.check_state(&[0]), CheckState::Checked);
+    assert_eq!(tree.check_state(&[1]), CheckState::Unchecked);
+
+    tree.toggle_checked(&[0, 1]);
+    assert_eq!(tree.check_state(&[0]), CheckState::Partial);
+    assert_eq!(tree.check_state(&[0, 1]), CheckState::Unchecked);
+
+    tree.toggle_checked(&[0]);
+    assert_eq!(tree.check_state(&[0]), CheckState::Checked);
+    tree.toggle_checked(&[0]);
+    assert!(tree.checked().is_empty());
+}
+
+fn wizard(steps: usize) -> WizardHandle {
+    let (tx, _rx) = mpsc::channel(8);
+    let dispatcher = Dispatcher::new(tx, EventBus::new(8));
+    let state = RefHandle::new(Arc::new(Mutex::new(WizardState {
+        steps,
+        ..WizardState::default()
+    })));
+    WizardHandle::new(state, dispatcher)
+}
+
+#[test]
+fn wizard_gates_block_advancing_and_record_the_error() {
+    let wizard = wizard(3);
+    assert!(!wizard.advance(|step| {
+        assert_eq!(step, 0);
+        Err("name is required".to_string())
+    }));
+    assert_eq!(wizard.current(), 0);
+    assert_eq!(wizard.error().as_deref(), Some("name is required"));
+
+    assert!(wizard.advance(|_| Ok(())));
+    assert_eq!(wizard.current(), 1);
+    assert_eq!(wizard.error(), None);
+    assert!(wizard.next());
+    assert!(!wizard.is_complete());
+    assert!(wizard.next());
+    assert!(wizard.is_complete());
+    assert_eq!(wizard.current(), 2);
+}
+
+#[test]
+fn wizard_jumps_only_to_visited_steps() {
+    let wizard = wizard(4);
+    wizard.next();
+    wizard.next();
+    assert!(wizard.back());
+    assert_eq!(wizard.current(), 1);
+    assert!(wizard.go_to(2));
+    assert!(!wizard.go_to(3));
+    assert!(wizard.go_to(0));
+    assert!(!wizard.back());
+    assert_eq!(wizard.snapshot().furthest, 2);
+    wizard.reset();
+    assert_eq!(
+        wizard.snapshot(),
+        WizardState {
+            steps: 4,
+            ..WizardState::default()
+        }
+    );
+}
@@ -1 +1,4 @@
+mod handles;
+mod order;
 mod registry;
+mod transition;
@@ -0,0 +1,45 @@
+use crate::runtime::{App, ComponentElement, Element, component};
+use crate::testing::TestApp;
+
+fn conditional_effect(trailing_memo: bool) -> ComponentElement {
+    component("Toggle", move |ctx| {
+        let (count, set_count) = ctx.use_state(|| 0u32);
+        if count == 0 {
+            ctx.use_effect((), move |_| {
+                set_count.set(1);
+                None
+            });
+        }
+        if trailing_memo {
+            let _ = ctx.use_memo((), || 0u32);
+        }
+        Element::text(format!("count {count}"))
+    })
+}
+
+#[tokio::test]
+#[should_panic(
+    expected = "hook order mismatch in component `Toggle` (key `main`, id `0#main:Toggle`) at hook slot 1: expected use_memo, found use_effect\nhint: hooks must be called in the same order on every render"
+)]
+async fn order_mismatch_names_the_component_slot_and_hooks() {
+    let root = component("Root", |_| conditional_effect(true).key("main").into());
+    let _ = TestApp::new(App::new("Hooks", root)).await;
+}
+
+#[tokio::test]
+#[should_panic(
+    expected = "hook count changed in component `Toggle` (id `0:Toggle`): the previous render called 2 hooks, this one called 1"
+)]
+async fn validate_hooks_catches_dropped_hooks() {
+    let root = component("Root", |_| conditional_effect(false).into());
+    let _ = TestApp::new(App::new("Hooks", root).validate_hooks(true)).await;
+}
+
+#[tokio::test]
+async fn dropped_trailing_hooks_pass_without_validation() {
+    let root = component("Root", |_| conditional_effect(false).into());
+    let app = TestApp::new(App::new("Hooks", root))
+        .await
+        .expect("start app");
+    app.expect_text("count 1");
+}
@@ -0,0 +1,82 @@
+use std::time::{Duration, Instant};
+
+use crate::hooks::Easing;
+use crate::hooks::transition::{StyleTransition, TransitionState};
+use crate::styles::TransitionSpec;
+
+#[test]
+fn easing_curves_start_and_end_at_bounds() {
+    for easing in [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+        Easing::EaseOutCubic,
+    ] {
+        assert_eq!(easing.apply(0.0), 0.0, "{easing:?} start");
+        assert!(
+            (easing.apply(1.0) - 1.0).abs() < f64::EPSILON,
+            "{easing:?} end"
+        );
+    }
+    assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    assert!(Easing::EaseIn.apply(0.5) < 0.5);
+    assert!(Easing::EaseOut.apply(0.5) > 0.5);
+}
+
+#[test]
+fn transition_interpolates_towards_new_target() {
+    let start = Instant::now();
+    let mut state = TransitionState::new(0.0, start);
+    assert!(!state.is_running(start));
+
+    state.retarget(1.0, Duration::from_millis(100), Easing::Linear, start);
+    let halfway = start + Duration::from_millis(50);
+    assert!((state.value_at(halfway) - 0.5).abs() < 1e-9);
+    assert!(state.is_running(halfway));
+
+    let done = start + Duration::from_millis(150);
+    assert_eq!(state.value_at(done), 1.0);
+    assert!(!state.is_running(done));
+}
+
+#[test]
+fn retargeting_mid_flight_starts_from_current_value() {
+    let start = Instant::now();
+    let mut state = TransitionState::new(0.0, start);
+    state.retarget(10.0, Duration::from_millis(100), Easing::Linear, start);
+
+    let midway = start + Duration::from_millis(50);
+    state.retarget(0.0, Duration::from_millis(100), Easing::Linear, midway);
+    assert!((state.value_at(midway) - 5.0).abs() < 1e-9);
+    assert_eq!(state.value_at(midway + Duration::from_millis(100)), 0.0);
+}
+
+#[test]
+fn style_transitions_interpolate_colors_and_numbers() {
+    let specs = TransitionSpec::parse_list("color 200ms linear, --width 1s ease-out, --bad fast");
+    assert_eq!(specs.len(), 2);
+    assert_eq!(specs[0].property, "color");
+    assert_eq!(specs[0].duration, Duration::from_millis(200));
+    assert_eq!(specs[1].easing, Easing::EaseOut);
+
+    let start = Instant::now();
+    let mut color = StyleTransition::new("#000000", start);
+    color.retarget("#ff8000", &specs[0], start);
+    let halfway = start + Duration::from_millis(100);
+    assert_eq!(color.value_at(halfway), "#804000");
+    assert!(color.is_running(halfway));
+    assert_eq!(
+        color.value_at(start + Duration::from_millis(200)),
+        "#ff8000"
+    );
+
+    let mut width = StyleTransition::new("10", start);
+    width.retarget("20", &specs[0], start);
+    assert_eq!(width.value_at(halfway), "15");
+
+    let mut named = StyleTransition::new("#000000", start);
+    named.retarget("red", &specs[0], start);
+    assert_eq!(named.value_at(start), "red");
+    assert!(!named.is_running(start));
+}
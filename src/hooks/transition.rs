@@ -0,0 +1,129 @@
+use std::time::Duration;
+
+use crate::rt::Instant;
+use crate::styles::{TransitionSpec, interpolate, is_animatable};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    #[default]
+    EaseInOut,
+    EaseOutCubic,
+}
+
+impl Easing {
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+            Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct TransitionState {
+    from: f64,
+    to: f64,
+    started: Instant,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl TransitionState {
+    pub(crate) fn new(value: f64, now: Instant) -> Self {
+        Self {
+            from: value,
+            to: value,
+            started: now,
+            duration: Duration::ZERO,
+            easing: Easing::default(),
+        }
+    }
+
+    pub(crate) fn retarget(
+        &mut self,
+        target: f64,
+        duration: Duration,
+        easing: Easing,
+        now: Instant,
+    ) {
+        self.easing = easing;
+        if target == self.to {
+            return;
+        }
+        self.from = self.value_at(now);
+        self.to = target;
+        self.started = now;
+        self.duration = duration;
+    }
+
+    pub(crate) fn value_at(&self, now: Instant) -> f64 {
+        let progress = self.progress_at(now);
+        self.from + (self.to - self.from) * self.easing.apply(progress)
+    }
+
+    pub(crate) fn is_running(&self, now: Instant) -> bool {
+        self.progress_at(now) < 1.0
+    }
+
+    fn progress_at(&self, now: Instant) -> f64 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        let elapsed = now.saturating_duration_since(self.started);
+        (elapsed.as_secs_f64() / self.duration.as_secs_f64()).min(1.0)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct StyleTransition {
+    from: String,
+    to: String,
+    progress: TransitionState,
+}
+
+impl StyleTransition {
+    pub(crate) fn new(value: &str, now: Instant) -> Self {
+        Self {
+            from: value.to_string(),
+            to: value.to_string(),
+            progress: TransitionState::new(1.0, now),
+        }
+    }
+
+    pub(crate) fn retarget(&mut self, target: &str, spec: &TransitionSpec, now: Instant) {
+        if target == self.to {
+            return;
+        }
+        let current = self.value_at(now);
+        self.to = target.to_string();
+        if !is_animatable(&current) || !is_animatable(target) {
+            self.from = target.to_string();
+            self.progress = TransitionState::new(1.0, now);
+            return;
+        }
+        self.from = current;
+        self.progress = TransitionState::new(0.0, now);
+        self.progress.retarget(1.0, spec.duration, spec.easing, now);
+    }
+
+    pub(crate) fn value_at(&self, now: Instant) -> String {
+        interpolate(&self.from, &self.to, self.progress.value_at(now))
+    }
+
+    pub(crate) fn is_running(&self, now: Instant) -> bool {
+        self.progress.is_running(now)
+    }
+}
@@ -1,10 +1,16 @@
+use std::cell::Cell;
 use std::collections::HashMap;
-use std::sync::OnceLock;
+use std::time::Duration;
 
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use ratatui::layout::Rect;
 
-use crate::events::{FrameworkEvent, mouse_position};
-use crossterm::event::{MouseButton, MouseEventKind};
+use crate::events::{FrameworkEvent, KeyCode, MouseButton, MouseEventKind, mouse_position};
+use crate::rt::time::Instant;
+use crate::runtime::{Current, Dispatcher, Registries, SortDirection};
+use crate::select::Selects;
+use crate::text_input::TextInputs;
+use crate::time_picker::TimePickers;
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Hitbox {
@@ -12,22 +18,154 @@ pub struct Hitbox {
     pub y: u16,
     pub width: u16,
     pub height: u16,
+    pub layer: usize,
 }
 
-pub struct ButtonRegistry {
+impl Hitbox {
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            layer: current_layer(),
+        }
+    }
+
+    pub fn contains(&self, column: u16, row: u16) -> bool {
+        self.covers(column, row) && !is_occluded(self, column, row)
+    }
+
+    fn covers(&self, column: u16, row: u16) -> bool {
+        column >= self.x
+            && column < self.x.saturating_add(self.width)
+            && row >= self.y
+            && row < self.y.saturating_add(self.height)
+    }
+}
+
+impl From<Rect> for Hitbox {
+    fn from(rect: Rect) -> Self {
+        Self::new(rect.x, rect.y, rect.width, rect.height)
+    }
+}
+
+thread_local! {
+    static CURRENT_LAYER: Cell<usize> = const { Cell::new(0) };
+    static NEXT_LAYER: Cell<usize> = const { Cell::new(0) };
+}
+
+pub(crate) struct InteractionRegistry {
+    surfaces: RwLock<Vec<Hitbox>>,
+    regions: RwLock<HashMap<String, Hitbox>>,
+    hovered: Mutex<Targets>,
+    gestures: Mutex<Gestures>,
+    table_headers: RwLock<HashMap<String, TableHeaderHit>>,
+    table_sources: RwLock<HashMap<String, Vec<usize>>>,
+    tree_rows: RwLock<TreeRowHits>,
+    rows: RwLock<RowHits>,
+}
+
+impl InteractionRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            surfaces: RwLock::new(Vec::new()),
+            regions: RwLock::new(HashMap::new()),
+            hovered: Mutex::new(Targets::default()),
+            gestures: Mutex::new(Gestures::default()),
+            table_headers: RwLock::new(HashMap::new()),
+            table_sources: RwLock::new(HashMap::new()),
+            tree_rows: RwLock::new(HashMap::new()),
+            rows: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+fn surfaces() -> Current<RwLock<Vec<Hitbox>>> {
+    Registries::current(|registries| &registries.interactions.surfaces)
+}
+
+pub(crate) fn current_layer() -> usize {
+    CURRENT_LAYER.with(Cell::get)
+}
+
+pub(crate) fn enter_layer() -> usize {
+    let layer = NEXT_LAYER.with(|next| {
+        next.set(next.get() + 1);
+        next.get()
+    });
+    CURRENT_LAYER.with(|current| current.replace(layer))
+}
+
+pub(crate) fn leave_layer(previous: usize) {
+    CURRENT_LAYER.with(|current| current.set(previous));
+}
+
+pub(crate) fn register_surface(hitbox: Hitbox) {
+    surfaces().write().push(hitbox);
+}
+
+pub(crate) fn reset_layers() {
+    CURRENT_LAYER.with(|current| current.set(0));
+    NEXT_LAYER.with(|next| next.set(0));
+    surfaces().write().clear();
+}
+
+fn regions() -> Current<RwLock<HashMap<String, Hitbox>>> {
+    Registries::current(|registries| &registries.interactions.regions)
+}
+
+impl InteractionRegistry {
+    pub(crate) fn region(&self, id: &str) -> Option<Rect> {
+        self.regions
+            .read()
+            .get(id)
+            .map(|hitbox| Rect::new(hitbox.x, hitbox.y, hitbox.width, hitbox.height))
+    }
+}
+
+pub(crate) fn register_region(id: &str, hitbox: Hitbox) {
+    regions().write().insert(id.to_string(), hitbox);
+}
+
+pub(crate) fn reset_regions() {
+    regions().write().clear();
+}
+
+pub fn region(id: &str) -> Option<Rect> {
+    Registries::current(|registries| &registries.interactions).region(id)
+}
+
+pub fn is_click(event: &FrameworkEvent, id: &str) -> bool {
+    let FrameworkEvent::Mouse(mouse) = event else {
+        return false;
+    };
+    if !matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) {
+        return false;
+    }
+    mouse_position(event).is_some_and(|(column, row)| hits(id, column, row))
+}
+
+fn is_occluded(hitbox: &Hitbox, column: u16, row: u16) -> bool {
+    surfaces()
+        .read()
+        .iter()
+        .any(|surface| surface.layer > hitbox.layer && surface.covers(column, row))
+}
+
+pub(crate) struct ButtonRegistry {
     hitboxes: RwLock<HashMap<String, Hitbox>>,
 }
 
 impl ButtonRegistry {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             hitboxes: RwLock::new(HashMap::new()),
         }
     }
 
-    fn global() -> &'static Self {
-        static REGISTRY: OnceLock<ButtonRegistry> = OnceLock::new();
-        REGISTRY.get_or_init(Self::new)
+    fn global() -> Current<Self> {
+        Registries::current(|registries| &registries.buttons)
     }
 
     pub fn reset() {
@@ -43,16 +181,238 @@ impl ButtonRegistry {
     pub fn contains(id: &str, column: u16, row: u16) -> bool {
         let registry = Self::global();
         let boxes = registry.hitboxes.read();
-        if let Some(hitbox) = boxes.get(id) {
-            return column >= hitbox.x
-                && column < hitbox.x.saturating_add(hitbox.width)
-                && row >= hitbox.y
-                && row < hitbox.y.saturating_add(hitbox.height);
+        boxes
+            .get(id)
+            .is_some_and(|hitbox| hitbox.contains(column, row))
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Eq)]
+struct Targets {
+    ids: Vec<String>,
+    row: Option<(String, usize)>,
+}
+
+impl Targets {
+    fn at(column: u16, row: u16) -> Self {
+        let mut ids: Vec<String> = [
+            &*regions().read(),
+            &*ButtonRegistry::global().hitboxes.read(),
+            &*CheckboxRegistry::global().hitboxes.read(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|(_, hitbox)| hitbox.contains(column, row))
+        .map(|(id, _)| id.clone())
+        .collect();
+        ids.sort();
+        ids.dedup();
+        let target = rows().read().iter().find_map(|(id, hits)| {
+            hits.iter()
+                .find(|(hitbox, _)| hitbox.contains(column, row))
+                .map(|(_, index)| (id.clone(), *index))
+        });
+        Self { ids, row: target }
+    }
+
+    fn contains(&self, id: &str) -> bool {
+        self.ids.iter().any(|candidate| candidate == id)
+    }
+
+    fn row_of(&self, id: &str) -> Option<usize> {
+        match &self.row {
+            Some((owner, index)) if owner == id => Some(*index),
+            _ => None,
+        }
+    }
+
+    fn shared(&self, other: &Targets) -> Targets {
+        let ids = other
+            .ids
+            .iter()
+            .filter(|id| self.contains(id) && self.row_of(id) == other.row_of(id))
+            .cloned()
+            .collect();
+        let row = other
+            .row
+            .clone()
+            .filter(|row| self.row.as_ref() == Some(row));
+        Targets { ids, row }
+    }
+}
+
+fn hovered() -> Current<Mutex<Targets>> {
+    Registries::current(|registries| &registries.interactions.hovered)
+}
+
+pub fn is_hovered(id: &str) -> bool {
+    hovered().lock().contains(id)
+}
+
+pub fn hovered_row(id: &str) -> Option<usize> {
+    hovered().lock().row_of(id)
+}
+
+pub fn is_hovering(event: &FrameworkEvent, id: &str) -> bool {
+    let FrameworkEvent::Mouse(mouse) = event else {
+        return false;
+    };
+    matches!(mouse.kind, MouseEventKind::Moved | MouseEventKind::Drag(_))
+        && mouse_position(event).is_some_and(|(column, row)| hits(id, column, row))
+}
+
+fn hits(id: &str, column: u16, row: u16) -> bool {
+    regions()
+        .read()
+        .get(id)
+        .is_some_and(|hitbox| hitbox.contains(column, row))
+        || ButtonRegistry::contains(id, column, row)
+        || CheckboxRegistry::global()
+            .hitboxes
+            .read()
+            .get(id)
+            .is_some_and(|hitbox| hitbox.contains(column, row))
+}
+
+pub fn is_focused(id: &str) -> bool {
+    TextInputs::is_focused(id)
+        || Selects::is_focused(id)
+        || TimePickers::is_focused(id)
+        || is_checkbox_focused(id)
+}
+
+pub(crate) fn track_hover(event: &FrameworkEvent, dispatcher: &Dispatcher) {
+    let FrameworkEvent::Mouse(mouse) = event else {
+        return;
+    };
+    if !matches!(
+        mouse.kind,
+        MouseEventKind::Moved | MouseEventKind::Drag(_) | MouseEventKind::Down(_)
+    ) {
+        return;
+    }
+    let Some((column, row)) = mouse_position(event) else {
+        return;
+    };
+    let next = Targets::at(column, row);
+    let hovered = hovered();
+    let mut current = hovered.lock();
+    if *current != next {
+        *current = next;
+        dispatcher.request_full_render();
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gesture {
+    DoubleClick,
+    LongPress,
+}
+
+#[derive(Default)]
+struct Gestures {
+    last_click: Option<(Instant, Targets)>,
+    press: Option<Press>,
+    double_click: Option<((u16, u16), Targets)>,
+    long_press: Option<Targets>,
+}
+
+struct Press {
+    at: Instant,
+    position: (u16, u16),
+    targets: Targets,
+    fired: bool,
+}
+
+fn gestures() -> Current<Mutex<Gestures>> {
+    Registries::current(|registries| &registries.interactions.gestures)
+}
+
+pub(crate) fn track_gestures(event: &FrameworkEvent, double_click: Duration, long_press: Duration) {
+    let gestures = gestures();
+    let mut gestures = gestures.lock();
+    let now = Instant::now();
+    match event {
+        FrameworkEvent::Mouse(mouse) => {
+            let position = (mouse.column, mouse.row);
+            match mouse.kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    let targets = Targets::at(mouse.column, mouse.row);
+                    let repeated = gestures
+                        .last_click
+                        .take()
+                        .filter(|(at, _)| now.duration_since(*at) <= double_click)
+                        .map(|(_, previous)| previous.shared(&targets))
+                        .filter(|shared| !shared.ids.is_empty());
+                    if repeated.is_none() {
+                        gestures.last_click = Some((now, targets.clone()));
+                    }
+                    gestures.double_click = repeated.map(|shared| (position, shared));
+                    gestures.press = Some(Press {
+                        at: now,
+                        position,
+                        targets,
+                        fired: false,
+                    });
+                }
+                MouseEventKind::Down(_) => {
+                    gestures.last_click = None;
+                    gestures.press = None;
+                }
+                MouseEventKind::Up(_) => gestures.press = None,
+                MouseEventKind::Drag(_)
+                    if gestures
+                        .press
+                        .as_ref()
+                        .is_some_and(|press| press.position != position) =>
+                {
+                    gestures.press = None;
+                }
+                _ => {}
+            }
         }
-        false
+        FrameworkEvent::Tick => {
+            gestures.long_press = None;
+            if let Some(press) = gestures
+                .press
+                .as_mut()
+                .filter(|press| !press.fired && now.duration_since(press.at) >= long_press)
+            {
+                press.fired = true;
+                let targets = press.targets.clone();
+                gestures.long_press = Some(targets);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn gesture_targets(event: &FrameworkEvent) -> Option<(Gesture, Targets)> {
+    let gestures = gestures();
+    let gestures = gestures.lock();
+    match event {
+        FrameworkEvent::Mouse(mouse)
+            if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+        {
+            let (position, targets) = gestures.double_click.as_ref()?;
+            (*position == (mouse.column, mouse.row))
+                .then(|| (Gesture::DoubleClick, targets.clone()))
+        }
+        FrameworkEvent::Tick => Some((Gesture::LongPress, gestures.long_press.clone()?)),
+        _ => None,
     }
 }
 
+pub fn gesture(event: &FrameworkEvent, id: &str) -> Option<Gesture> {
+    let (gesture, targets) = gesture_targets(event)?;
+    targets.contains(id).then_some(gesture)
+}
+
+pub fn row_gesture(event: &FrameworkEvent, id: &str) -> Option<(Gesture, usize)> {
+    let (gesture, targets) = gesture_targets(event)?;
+    targets.row_of(id).map(|index| (gesture, index))
+}
+
 pub(crate) fn register_button_hitbox(id: &str, hitbox: Hitbox) {
     ButtonRegistry::record(id, hitbox);
 }
@@ -72,5 +432,220 @@ pub fn is_button_click(event: &FrameworkEvent, button_id: &str) -> bool {
     false
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageStep {
+    Prev,
+    Next,
+}
+
+pub(crate) fn table_pager_id(table_id: &str, step: PageStep) -> String {
+    match step {
+        PageStep::Prev => format!("{table_id}::prev-page"),
+        PageStep::Next => format!("{table_id}::next-page"),
+    }
+}
+
+pub fn table_page_click(event: &FrameworkEvent, table_id: &str) -> Option<PageStep> {
+    [PageStep::Prev, PageStep::Next]
+        .into_iter()
+        .find(|step| is_button_click(event, &table_pager_id(table_id, *step)))
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TableHeaderHit {
+    pub(crate) sort: Option<(usize, SortDirection)>,
+    pub(crate) columns: Vec<Hitbox>,
+}
+
+fn table_headers() -> Current<RwLock<HashMap<String, TableHeaderHit>>> {
+    Registries::current(|registries| &registries.interactions.table_headers)
+}
+
+pub(crate) fn register_table_header(id: &str, header: TableHeaderHit) {
+    table_headers().write().insert(id.to_string(), header);
+}
+
+pub(crate) fn reset_table_headers() {
+    table_headers().write().clear();
+}
+
+pub fn table_sort_change(event: &FrameworkEvent, table_id: &str) -> Option<(usize, SortDirection)> {
+    if !is_mouse_down(event) {
+        return None;
+    }
+    let (column, row) = mouse_position(event)?;
+    let headers = table_headers();
+    let headers = headers.read();
+    let header = headers.get(table_id)?;
+    let clicked = header
+        .columns
+        .iter()
+        .position(|hitbox| hitbox.contains(column, row))?;
+    let direction = match header.sort {
+        Some((current, direction)) if current == clicked => direction.toggled(),
+        _ => SortDirection::Ascending,
+    };
+    Some((clicked, direction))
+}
+
+fn table_sources() -> Current<RwLock<HashMap<String, Vec<usize>>>> {
+    Registries::current(|registries| &registries.interactions.table_sources)
+}
+
+// Recorded while the view is built rather than drawn, so the mapping is there even
+// when the renderer reuses the table's cells from the previous frame.
+pub(crate) fn register_table_sources(id: &str, sources: Vec<usize>) {
+    table_sources().write().insert(id.to_string(), sources);
+}
+
+// Maps a row index in display order (after sorting, counting earlier pages), as used
+// by `.highlight(..)`, `clicked_table_row` and list state handles, back to the index
+// of the row in the `Vec` the table was built from.
+pub fn table_source_row(table_id: &str, row: usize) -> Option<usize> {
+    table_sources().read().get(table_id)?.get(row).copied()
+}
+
+type TreeRowHits = HashMap<String, Vec<(Hitbox, Vec<usize>)>>;
+
+fn tree_rows() -> Current<RwLock<TreeRowHits>> {
+    Registries::current(|registries| &registries.interactions.tree_rows)
+}
+
+pub(crate) fn register_tree_rows(id: &str, rows: Vec<(Hitbox, Vec<usize>)>) {
+    tree_rows().write().insert(id.to_string(), rows);
+}
+
+pub(crate) fn reset_tree_rows() {
+    tree_rows().write().clear();
+}
+
+pub fn tree_row_click(event: &FrameworkEvent, tree_id: &str) -> Option<Vec<usize>> {
+    if !is_mouse_down(event) {
+        return None;
+    }
+    let (column, row) = mouse_position(event)?;
+    let trees = tree_rows();
+    let trees = trees.read();
+    trees
+        .get(tree_id)?
+        .iter()
+        .find_map(|(hitbox, path)| hitbox.contains(column, row).then(|| path.clone()))
+}
+
+type RowHits = HashMap<String, Vec<(Hitbox, usize)>>;
+
+fn rows() -> Current<RwLock<RowHits>> {
+    Registries::current(|registries| &registries.interactions.rows)
+}
+
+pub(crate) fn register_rows(id: &str, hits: Vec<(Hitbox, usize)>) {
+    rows().write().insert(id.to_string(), hits);
+}
+
+pub(crate) fn reset_rows() {
+    rows().write().clear();
+}
+
+pub(crate) fn clicked_row(event: &FrameworkEvent, id: &str) -> Option<usize> {
+    if !is_mouse_down(event) {
+        return None;
+    }
+    let (column, row) = mouse_position(event)?;
+    let rows = rows();
+    let rows = rows.read();
+    rows.get(id)?
+        .iter()
+        .find_map(|(hitbox, index)| hitbox.contains(column, row).then_some(*index))
+}
+
+pub fn clicked_list_index(event: &FrameworkEvent, list_id: &str) -> Option<usize> {
+    clicked_row(event, list_id)
+}
+
+pub fn clicked_table_row(event: &FrameworkEvent, table_id: &str) -> Option<usize> {
+    clicked_row(event, table_id)
+}
+
+pub(crate) struct CheckboxRegistry {
+    hitboxes: RwLock<HashMap<String, Hitbox>>,
+    focused: Mutex<Option<String>>,
+}
+
+impl CheckboxRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            hitboxes: RwLock::new(HashMap::new()),
+            focused: Mutex::new(None),
+        }
+    }
+
+    fn global() -> Current<Self> {
+        Registries::current(|registries| &registries.checkboxes)
+    }
+
+    fn hit(&self, column: u16, row: u16) -> Option<String> {
+        self.hitboxes
+            .read()
+            .iter()
+            .find_map(|(id, hitbox)| hitbox.contains(column, row).then(|| id.clone()))
+    }
+}
+
+pub(crate) fn register_checkbox_hitbox(id: &str, hitbox: Hitbox) {
+    let registry = CheckboxRegistry::global();
+    registry.hitboxes.write().insert(id.to_string(), hitbox);
+}
+
+pub(crate) fn reset_checkbox_hitboxes() {
+    let registry = CheckboxRegistry::global();
+    registry.hitboxes.write().clear();
+}
+
+pub(crate) fn is_checkbox_focused(id: &str) -> bool {
+    let registry = CheckboxRegistry::global();
+    registry.focused.lock().as_deref() == Some(id)
+}
+
+pub(crate) fn handle_checkbox_event(event: &FrameworkEvent, dispatcher: &Dispatcher) {
+    if !is_mouse_down(event) {
+        return;
+    }
+    let Some((column, row)) = mouse_position(event) else {
+        return;
+    };
+    let registry = CheckboxRegistry::global();
+    let next = registry.hit(column, row);
+    let mut focused = registry.focused.lock();
+    if *focused != next {
+        *focused = next;
+        dispatcher.request_full_render();
+    }
+}
+
+pub fn focus_checkbox(id: Option<&str>) {
+    let registry = CheckboxRegistry::global();
+    *registry.focused.lock() = id.map(str::to_string);
+    Registries::current(|registries| registries).invalidate();
+}
+
+pub fn is_checkbox_toggle(event: &FrameworkEvent, checkbox_id: &str) -> bool {
+    match event {
+        FrameworkEvent::Key(key) if key.code == KeyCode::Char(' ') => {
+            is_checkbox_focused(checkbox_id) && !TextInputs::has_focus()
+        }
+        FrameworkEvent::Mouse(_) if is_mouse_down(event) => mouse_position(event)
+            .and_then(|(column, row)| CheckboxRegistry::global().hit(column, row))
+            .is_some_and(|id| id == checkbox_id),
+        _ => false,
+    }
+}
+
+fn is_mouse_down(event: &FrameworkEvent) -> bool {
+    matches!(
+        event,
+        FrameworkEvent::Mouse(mouse) if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left))
+    )
+}
+
 #[cfg(test)]
 mod tests;
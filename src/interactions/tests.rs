@@ -1,21 +1,21 @@
-use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
 use crate::events::FrameworkEvent;
 
-use super::{Hitbox, is_button_click, register_button_hitbox, reset_button_hitboxes};
+use crate::runtime::{Registries, SortDirection};
+
+use super::{
+    Hitbox, TableHeaderHit, enter_layer, focus_checkbox, is_button_click, is_checkbox_toggle,
+    is_click, is_hovering, leave_layer, register_button_hitbox, register_checkbox_hitbox,
+    register_region, register_surface, register_table_header, reset_button_hitboxes, reset_regions,
+    table_sort_change,
+};
 
 #[test]
 fn button_click_detects_coordinates_within_hitbox() {
+    let _app = Registries::isolated();
     reset_button_hitboxes();
-    register_button_hitbox(
-        "submit",
-        Hitbox {
-            x: 10,
-            y: 5,
-            width: 4,
-            height: 2,
-        },
-    );
+    register_button_hitbox("submit", Hitbox::new(10, 5, 4, 2));
     let event = FrameworkEvent::Mouse(MouseEvent {
         kind: MouseEventKind::Down(MouseButton::Left),
         column: 11,
@@ -28,16 +28,9 @@ fn button_click_detects_coordinates_within_hitbox() {
 
 #[test]
 fn reset_clears_hitboxes_and_prevents_future_matches() {
+    let _app = Registries::isolated();
     reset_button_hitboxes();
-    register_button_hitbox(
-        "danger",
-        Hitbox {
-            x: 0,
-            y: 0,
-            width: 2,
-            height: 1,
-        },
-    );
+    register_button_hitbox("danger", Hitbox::new(0, 0, 2, 1));
     let click = FrameworkEvent::Mouse(MouseEvent {
         kind: MouseEventKind::Down(MouseButton::Left),
         column: 1,
@@ -49,3 +42,190 @@ fn reset_clears_hitboxes_and_prevents_future_matches() {
     reset_button_hitboxes();
     assert!(!is_button_click(&click, "danger"));
 }
+
+#[test]
+fn any_rendered_node_with_an_id_is_clickable() {
+    let _app = Registries::isolated();
+    reset_regions();
+    register_region("sidebar", Hitbox::new(0, 20, 12, 4));
+    let click = |column| {
+        FrameworkEvent::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 21,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    assert!(is_click(&click(3), "sidebar"));
+    assert!(!is_click(&click(12), "sidebar"));
+    assert!(!is_button_click(&click(3), "sidebar"));
+
+    reset_regions();
+    assert!(!is_click(&click(3), "sidebar"));
+}
+
+#[test]
+fn hovering_matches_pointer_moves_over_a_node() {
+    let _app = Registries::isolated();
+    reset_regions();
+    register_region("card", Hitbox::new(4, 30, 6, 2));
+    let pointer = |kind, column| {
+        FrameworkEvent::Mouse(MouseEvent {
+            kind,
+            column,
+            row: 31,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    assert!(is_hovering(&pointer(MouseEventKind::Moved, 5), "card"));
+    assert!(is_hovering(
+        &pointer(MouseEventKind::Drag(MouseButton::Left), 9),
+        "card"
+    ));
+    assert!(!is_hovering(&pointer(MouseEventKind::Moved, 10), "card"));
+    assert!(!is_hovering(
+        &pointer(MouseEventKind::Down(MouseButton::Left), 5),
+        "card"
+    ));
+}
+
+#[test]
+fn checkbox_toggles_on_click_inside_its_hitbox() {
+    let _app = Registries::isolated();
+    register_checkbox_hitbox("notify", Hitbox::new(2, 8, 10, 1));
+    let click = |column| {
+        FrameworkEvent::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 8,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    assert!(is_checkbox_toggle(&click(5), "notify"));
+    assert!(!is_checkbox_toggle(&click(12), "notify"));
+    assert!(!is_checkbox_toggle(&click(5), "other"));
+}
+
+#[test]
+fn space_toggles_only_the_focused_checkbox() {
+    let _app = Registries::isolated();
+    let space = FrameworkEvent::Key(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+    focus_checkbox(Some("dark-mode"));
+    assert!(is_checkbox_toggle(&space, "dark-mode"));
+    assert!(!is_checkbox_toggle(&space, "telemetry"));
+    focus_checkbox(None);
+    assert!(!is_checkbox_toggle(&space, "dark-mode"));
+}
+
+#[test]
+fn header_click_toggles_sort_on_the_same_column() {
+    let _app = Registries::isolated();
+    let header = |x| Hitbox::new(x, 1, 5, 1);
+    register_table_header(
+        "inventory",
+        TableHeaderHit {
+            sort: Some((1, SortDirection::Ascending)),
+            columns: vec![header(1), header(7)],
+        },
+    );
+    let click = |column| {
+        FrameworkEvent::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 1,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+
+    assert_eq!(
+        table_sort_change(&click(8), "inventory"),
+        Some((1, SortDirection::Descending))
+    );
+    assert_eq!(
+        table_sort_change(&click(2), "inventory"),
+        Some((0, SortDirection::Ascending))
+    );
+    assert_eq!(table_sort_change(&click(6), "inventory"), None);
+}
+
+#[test]
+fn surfaces_on_higher_layers_block_covered_hitboxes() {
+    let _app = Registries::isolated();
+    let covered = Hitbox::new(200, 100, 10, 3);
+    let previous = enter_layer();
+    let overlay = Hitbox::new(206, 100, 4, 1);
+    register_surface(Hitbox::new(204, 99, 10, 5));
+    leave_layer(previous);
+
+    assert!(covered.contains(201, 101));
+    assert!(!covered.contains(205, 101));
+    assert!(overlay.contains(207, 100));
+}
+
+#[tokio::test(start_paused = true)]
+async fn repeated_clicks_and_held_presses_become_gestures() {
+    use std::time::Duration;
+
+    use crate::runtime::{App, Element, ListItemNode, ListNode, component};
+    use crate::testing::TestApp;
+
+    use super::{Gesture, gesture, row_gesture};
+
+    let mut app = TestApp::new(
+        App::new(
+            "Files",
+            component("Files", |_ctx| {
+                let items = (0..3)
+                    .map(|index| ListItemNode::new(format!("file {index}")))
+                    .collect();
+                Element::list(ListNode::new(items).id("files"))
+            }),
+        )
+        .headless_size(20, 5),
+    )
+    .await
+    .expect("start app");
+    let _app = app.enter();
+    let mouse = |kind, row| {
+        FrameworkEvent::Mouse(MouseEvent {
+            kind,
+            column: 3,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    let down = |row| mouse(MouseEventKind::Down(MouseButton::Left), row);
+
+    app.click(3, 1).await.expect("first click");
+    assert_eq!(row_gesture(&down(1), "files"), None);
+    app.click(3, 1).await.expect("second click");
+    assert_eq!(
+        row_gesture(&down(1), "files"),
+        Some((Gesture::DoubleClick, 1))
+    );
+    assert_eq!(gesture(&down(1), "files"), Some(Gesture::DoubleClick));
+    assert_eq!(gesture(&down(1), "elsewhere"), None);
+    app.click(3, 1).await.expect("third click");
+    assert_eq!(row_gesture(&down(1), "files"), None);
+
+    app.click(3, 0).await.expect("click another row");
+    assert_eq!(row_gesture(&down(0), "files"), None);
+    tokio::time::advance(Duration::from_millis(500)).await;
+    app.click(3, 0).await.expect("click after the window");
+    assert_eq!(row_gesture(&down(0), "files"), None);
+
+    app.send(down(2)).await.expect("press");
+    app.tick().await.expect("early tick");
+    assert_eq!(row_gesture(&FrameworkEvent::Tick, "files"), None);
+    tokio::time::advance(Duration::from_millis(600)).await;
+    app.tick().await.expect("tick while held");
+    assert_eq!(
+        row_gesture(&FrameworkEvent::Tick, "files"),
+        Some((Gesture::LongPress, 2))
+    );
+    app.tick().await.expect("next tick");
+    assert_eq!(row_gesture(&FrameworkEvent::Tick, "files"), None);
+    app.send(mouse(MouseEventKind::Up(MouseButton::Left), 2))
+        .await
+        .expect("release");
+}
@@ -0,0 +1,57 @@
+use super::{KeyBinding, KeymapEntry};
+use crate::events::{KeyCode, KeyEvent, KeyModifiers};
+use crate::runtime::{Color, Element, ModalNode, TableCellNode, TableNode, TableRowNode};
+
+const BUILT_IN: &[(&str, &str)] = &[
+    ("Tab", "Next input"),
+    ("Shift+Tab", "Previous input"),
+    ("?", "Toggle this help"),
+    ("Esc", "Close help"),
+];
+
+pub(crate) fn is_help_toggle(key: &KeyEvent) -> bool {
+    KeyBinding::new(KeyCode::Char('?'), KeyModifiers::NONE).matches(key)
+}
+
+pub(crate) fn closes_help(key: &KeyEvent) -> bool {
+    key.code == KeyCode::Esc || is_help_toggle(key)
+}
+
+pub(crate) fn help_rows(entries: &[KeymapEntry]) -> Vec<(String, String)> {
+    let mut rows: Vec<(String, String)> = entries
+        .iter()
+        .map(|entry| (entry.binding.to_string(), entry.description.clone()))
+        .collect();
+    for (key, description) in BUILT_IN {
+        if !rows.iter().any(|(existing, _)| existing == key) {
+            rows.push((key.to_string(), description.to_string()));
+        }
+    }
+    rows
+}
+
+pub(crate) fn help_overlay(entries: &[KeymapEntry]) -> Element {
+    let rows = help_rows(entries);
+    let height = (rows.len() as u16).saturating_add(6);
+    let table = TableNode::new(
+        rows.into_iter()
+            .map(|(key, description)| {
+                TableRowNode::new(vec![
+                    TableCellNode::new(key).color(Color::Cyan).bold(),
+                    TableCellNode::new(description),
+                ])
+            })
+            .collect(),
+    )
+    .header(TableRowNode::new(vec![
+        TableCellNode::new("Key").bold(),
+        TableCellNode::new("Action").bold(),
+    ]))
+    .widths(vec![30, 70]);
+    Element::modal(
+        ModalNode::new(Element::table(table))
+            .title("Keyboard shortcuts")
+            .width(56)
+            .height(height),
+    )
+}
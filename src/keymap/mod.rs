@@ -0,0 +1,270 @@
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use parking_lot::{Mutex, RwLock};
+
+use crate::events::{KeyCode, KeyEvent, KeyModifiers};
+use crate::text_input::TextInputs;
+
+mod help;
+
+pub(crate) use help::{closes_help, help_overlay, is_help_toggle};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let (prefix, key) = match input.strip_suffix("++") {
+            Some(prefix) => (prefix, "+"),
+            None => match input.rsplit_once('+') {
+                Some((prefix, key)) if !key.is_empty() => (prefix, key),
+                _ => ("", input),
+            },
+        };
+        let mut modifiers = KeyModifiers::NONE;
+        for part in prefix.split('+').filter(|part| !part.is_empty()) {
+            modifiers |= match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "option" | "meta" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+        }
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" | "ins" => KeyCode::Insert,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" | "pgup" => KeyCode::PageUp,
+                "pagedown" | "pgdn" => KeyCode::PageDown,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                name => KeyCode::F(name.strip_prefix('f')?.parse().ok()?),
+            },
+        };
+        Some(Self { code, modifiers })
+    }
+
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        match (self.code, key.code) {
+            (KeyCode::Char(expected), KeyCode::Char(actual)) => {
+                expected == actual
+                    && self.modifiers - KeyModifiers::SHIFT == key.modifiers - KeyModifiers::SHIFT
+            }
+            (KeyCode::BackTab, KeyCode::BackTab) => {
+                self.modifiers - KeyModifiers::SHIFT == key.modifiers - KeyModifiers::SHIFT
+            }
+            (expected, actual) => expected == actual && self.modifiers == key.modifiers,
+        }
+    }
+
+    fn is_plain_char(&self) -> bool {
+        matches!(self.code, KeyCode::Char(_))
+            && !self
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) && !matches!(self.code, KeyCode::Char(_)) {
+            f.write_str("Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            KeyCode::BackTab => f.write_str("Shift+Tab"),
+            KeyCode::PageUp => f.write_str("PgUp"),
+            KeyCode::PageDown => f.write_str("PgDn"),
+            KeyCode::Up => f.write_str("↑"),
+            KeyCode::Down => f.write_str("↓"),
+            KeyCode::Left => f.write_str("←"),
+            KeyCode::Right => f.write_str("→"),
+            code => write!(f, "{code:?}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeymapEntry {
+    pub binding: KeyBinding,
+    pub description: String,
+    pub command: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Keymap {
+    entries: Vec<KeymapEntry>,
+}
+
+impl Keymap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bind(
+        mut self,
+        key: &str,
+        description: impl Into<String>,
+        command: impl Into<String>,
+    ) -> Self {
+        self.entries.push(KeymapEntry {
+            binding: parse_or_panic(key),
+            description: description.into(),
+            command: Some(command.into()),
+        });
+        self
+    }
+
+    pub fn hint(mut self, key: &str, description: impl Into<String>) -> Self {
+        self.entries.push(KeymapEntry {
+            binding: parse_or_panic(key),
+            description: description.into(),
+            command: None,
+        });
+        self
+    }
+
+    pub fn entries(&self) -> &[KeymapEntry] {
+        &self.entries
+    }
+
+    pub fn command_for(&self, key: &KeyEvent) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.command.is_some() && entry.binding.matches(key))
+            .and_then(|entry| entry.command.as_deref())
+    }
+}
+
+pub(crate) fn parse_or_panic(key: &str) -> KeyBinding {
+    KeyBinding::parse(key).unwrap_or_else(|| panic!("invalid key binding `{key}`"))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DeclaredBinding {
+    entry: KeymapEntry,
+    modal: bool,
+}
+
+#[derive(Default)]
+pub(crate) struct KeymapRegistry {
+    pending: Mutex<Vec<DeclaredBinding>>,
+    declared: RwLock<Vec<DeclaredBinding>>,
+    modal_depth: AtomicUsize,
+}
+
+impl KeymapRegistry {
+    pub(crate) fn begin_frame(&self) {
+        self.pending.lock().clear();
+        self.modal_depth.store(0, Ordering::SeqCst);
+    }
+
+    pub(crate) fn declare(&self, entry: KeymapEntry) {
+        let modal = self.modal_depth.load(Ordering::SeqCst) > 0;
+        self.pending.lock().push(DeclaredBinding { entry, modal });
+    }
+
+    pub(crate) fn enter_modal(&self) {
+        self.modal_depth.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn leave_modal(&self) {
+        self.modal_depth.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn declared_count(&self) -> usize {
+        self.pending.lock().len()
+    }
+
+    pub(crate) fn discard_since(&self, count: usize) {
+        self.pending.lock().truncate(count);
+    }
+
+    pub(crate) fn declared_since(&self, count: usize) -> Vec<DeclaredBinding> {
+        self.pending
+            .lock()
+            .get(count..)
+            .unwrap_or_default()
+            .to_vec()
+    }
+
+    pub(crate) fn redeclare(&self, bindings: &[DeclaredBinding]) {
+        self.pending.lock().extend_from_slice(bindings);
+    }
+
+    pub(crate) fn commit(&self) -> bool {
+        let pending = std::mem::take(&mut *self.pending.lock());
+        let mut declared = self.declared.write();
+        if *declared == pending {
+            return false;
+        }
+        *declared = pending;
+        true
+    }
+
+    pub(crate) fn active(&self) -> Vec<KeymapEntry> {
+        self.filtered(TextInputs::has_focus())
+    }
+
+    fn filtered(&self, typing: bool) -> Vec<KeymapEntry> {
+        let declared = self.declared.read();
+        let modal = declared.iter().any(|binding| binding.modal);
+        let mut active: Vec<KeymapEntry> = Vec::new();
+        for binding in declared.iter() {
+            if binding.modal != modal || (typing && binding.entry.binding.is_plain_char()) {
+                continue;
+            }
+            if active
+                .iter()
+                .any(|entry| entry.binding == binding.entry.binding)
+            {
+                continue;
+            }
+            active.push(binding.entry.clone());
+        }
+        active
+    }
+
+    pub(crate) fn command_for(&self, key: &KeyEvent) -> Option<String> {
+        command_in(self.active(), key)
+    }
+}
+
+fn command_in(entries: Vec<KeymapEntry>, key: &KeyEvent) -> Option<String> {
+    entries
+        .into_iter()
+        .find(|entry| entry.command.is_some() && entry.binding.matches(key))
+        .and_then(|entry| entry.command)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,104 @@
+use super::*;
+
+fn entry(key: &str, description: &str, command: &str) -> KeymapEntry {
+    KeymapEntry {
+        binding: parse_or_panic(key),
+        description: description.to_string(),
+        command: Some(command.to_string()),
+    }
+}
+
+#[test]
+fn parses_modifiers_named_keys_and_plus() {
+    let save = KeyBinding::parse("ctrl+s").expect("binding");
+    assert_eq!(
+        save,
+        KeyBinding::new(KeyCode::Char('s'), KeyModifiers::CONTROL)
+    );
+    assert_eq!(save.to_string(), "Ctrl+s");
+    assert_eq!(KeyBinding::parse("F5").map(|b| b.code), Some(KeyCode::F(5)));
+    assert_eq!(
+        KeyBinding::parse("alt+pgdn")
+            .map(|b| b.to_string())
+            .as_deref(),
+        Some("Alt+PgDn")
+    );
+    assert_eq!(
+        KeyBinding::parse("ctrl++").map(|b| b.code),
+        Some(KeyCode::Char('+'))
+    );
+    assert_eq!(
+        KeyBinding::parse("space").map(|b| b.to_string()).as_deref(),
+        Some("Space")
+    );
+    assert_eq!(KeyBinding::parse("hyper+x"), None);
+    assert_eq!(KeyBinding::parse("nope"), None);
+}
+
+#[test]
+fn character_bindings_ignore_shift() {
+    let binding = KeyBinding::parse("?").expect("binding");
+    assert!(binding.matches(&KeyEvent::new(KeyCode::Char('?'), KeyModifiers::SHIFT)));
+    assert!(!binding.matches(&KeyEvent::new(KeyCode::Char('?'), KeyModifiers::CONTROL)));
+    let keymap = Keymap::new()
+        .hint("tab", "focus")
+        .bind("q", "quit", "app.quit");
+    let quit = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+    assert_eq!(keymap.command_for(&quit), Some("app.quit"));
+    let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+    assert_eq!(keymap.command_for(&tab), None);
+}
+
+#[test]
+fn modal_bindings_replace_the_rest_while_declared() {
+    let registry = KeymapRegistry::default();
+    registry.begin_frame();
+    registry.declare(entry("q", "quit", "app.quit"));
+    registry.declare(entry("i", "incident", "incident.open"));
+    assert!(registry.commit());
+    let descriptions: Vec<String> = registry
+        .filtered(false)
+        .into_iter()
+        .map(|entry| entry.description)
+        .collect();
+    assert_eq!(descriptions, ["quit", "incident"]);
+    assert!(registry.filtered(true).is_empty());
+
+    registry.begin_frame();
+    registry.declare(entry("q", "quit", "app.quit"));
+    registry.enter_modal();
+    registry.declare(entry("esc", "close", "incident.close"));
+    registry.leave_modal();
+    assert!(registry.commit());
+    let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+    let active = registry.filtered(false);
+    assert_eq!(
+        command_in(active.clone(), &esc).as_deref(),
+        Some("incident.close")
+    );
+    let quit = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+    assert_eq!(command_in(active, &quit), None);
+
+    registry.begin_frame();
+    registry.declare(entry("q", "quit", "app.quit"));
+    registry.enter_modal();
+    registry.declare(entry("esc", "close", "incident.close"));
+    registry.leave_modal();
+    assert!(!registry.commit());
+}
+
+#[test]
+fn help_rows_list_bindings_before_built_ins() {
+    let rows = help::help_rows(&[
+        entry("ctrl+s", "save", "file.save"),
+        entry("esc", "close dialog", "dialog.close"),
+    ]);
+    assert_eq!(rows[0], ("Ctrl+s".to_string(), "save".to_string()));
+    assert_eq!(rows[1], ("Esc".to_string(), "close dialog".to_string()));
+    assert!(rows.iter().any(|(key, _)| key == "?"));
+    assert_eq!(rows.iter().filter(|(key, _)| key == "Esc").count(), 1);
+    assert!(help::is_help_toggle(&KeyEvent::new(
+        KeyCode::Char('?'),
+        KeyModifiers::SHIFT
+    )));
+}
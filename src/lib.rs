@@ -1,20 +1,70 @@
+pub mod components;
 pub mod context;
+#[cfg(feature = "devtools")]
+mod devtools;
 pub mod events;
+pub mod file_picker;
 pub mod hooks;
 mod interactions;
+pub mod keymap;
+pub mod logging;
+pub mod menu;
+pub mod persistence;
+pub mod profiling;
+pub mod recording;
+#[cfg(feature = "remote")]
+pub mod remote;
 pub mod renderer;
+mod rt;
 pub mod runtime;
+pub mod scroll;
+pub mod select;
+pub mod split;
+#[cfg(feature = "ssh")]
+pub mod ssh;
 pub mod styles;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod text_input;
+pub mod time_picker;
+pub mod tooltip;
+#[cfg(feature = "web")]
+pub mod web;
 
-pub use events::{FrameworkEvent, is_ctrl_c, is_mouse_click, mouse_position, mouse_scroll_delta};
-pub use hooks::{ReducerDispatch, RefHandle, Scope, StateHandle};
-pub use interactions::is_button_click;
+pub use events::{
+    FrameworkEvent, is_command, is_ctrl_c, is_mouse_click, mouse_position, mouse_scroll_delta,
+};
+pub use file_picker::FilePickerHandle;
+pub use hooks::{
+    CellEdit, Easing, ListSelection, ListStateHandle, ReducerDispatch, RefHandle, Scope,
+    StateHandle, TableEditHandle, TreePath, TreeSelection, TreeStateHandle, WizardHandle,
+    WizardState,
+};
+pub use interactions::{
+    Gesture, PageStep, clicked_list_index, clicked_table_row, focus_checkbox, gesture, hovered_row,
+    is_button_click, is_checkbox_toggle, is_click, is_focused, is_hovered, is_hovering, region,
+    row_gesture, table_page_click, table_sort_change, table_source_row, tree_row_click,
+};
+pub use keymap::{KeyBinding, Keymap};
+pub use logging::LogPanelNode;
+pub use profiling::{Metrics, PerfOverlayNode};
 pub use runtime::{
-    App, AppConfig, ButtonNode, ComponentElement, Dispatcher, Element, FlexDirection,
-    FormFieldNode, FormFieldStatus, FormNode, GaugeNode, LayeredNode, ListItemNode, ListNode,
-    ModalNode, TabPaneNode, TableCellNode, TableNode, TableRowNode, TabsNode, TextInputNode,
-    ToastLevel, ToastNode, ToastStackNode, TreeItemNode, TreeNode, View, component,
+    Align, Anchor, AnchorNode, App, AppConfig, BlockNode, BorderStyle, ButtonNode, CheckState,
+    CheckboxNode, ComponentElement, ConstrainNode, CustomView, CustomWidget, Dimensions,
+    Dispatcher, Element, EmbeddedApp, FilePickerNode, FlexDirection, FlexNode, FormFieldNode,
+    FormFieldStatus, FormNode, GaugeNode, Justify, KeyHintsNode, LayeredNode, ListItemNode,
+    ListNode, MenuBarNode, MenuItemNode, ModalNode, NumericInputNode, ParagraphNode, ProgressNode,
+    ProgressStyle, ScrollNode, SelectNode, SizedNode, SortDirection, Spacing, SpinnerNode,
+    SpinnerStyle, SplitNode, StatusBarNode, StatusSegment, StepperNode, TabPaneNode, TableCellNode,
+    TableNode, TableRowNode, TabsNode, TextAlign, TextAreaNode, TextInputNode, TextNode,
+    TextOverflow, ThemeProvider, TimePickerNode, ToastLevel, ToastNode, ToastStackNode,
+    TooltipNode, TreeItemNode, TreeNode, View, VirtualListNode, component, component_memo,
+};
+pub use styles::{
+    ColorScheme, ComputedStyle, ElementStyle, LayoutStyle, StyleDiagnostic, StyleNode,
+    StyleParseError, StyleQuery, StyleState, Stylesheet, Theme,
+};
+pub use text_input::{
+    InputMask, NumericFormat, SuggestionProvider, TextAreaHandle, TextInputHandle, TextInputState,
 };
-pub use styles::{ComputedStyle, StyleQuery, Stylesheet};
-pub use text_input::{TextInputHandle, TextInputState};
+pub use time_picker::{TimeField, TimeValue};
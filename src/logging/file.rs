@@ -0,0 +1,149 @@
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::Context;
+use tracing::{Level, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::{Layer, fmt};
+
+use super::{CaptureLayer, LogBuffer, buffer};
+
+const DEFAULT_MAX_SIZE: u64 = 10 * 1024 * 1024;
+const DEFAULT_MAX_FILES: usize = 3;
+
+pub fn init(path: impl Into<PathBuf>) -> anyhow::Result<()> {
+    LogFile::new(path).init()
+}
+
+#[derive(Clone, Debug)]
+pub struct LogFile {
+    path: PathBuf,
+    level: Level,
+    max_size: u64,
+    max_files: usize,
+    buffer: LogBuffer,
+}
+
+impl LogFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            level: Level::INFO,
+            max_size: DEFAULT_MAX_SIZE,
+            max_files: DEFAULT_MAX_FILES,
+            buffer: buffer(),
+        }
+    }
+
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = bytes;
+        self
+    }
+
+    pub fn max_files(mut self, count: usize) -> Self {
+        self.max_files = count;
+        self
+    }
+
+    pub fn buffer(mut self, buffer: LogBuffer) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    pub fn init(self) -> anyhow::Result<()> {
+        let subscriber = self.subscriber()?;
+        tracing::subscriber::set_global_default(subscriber)
+            .context("a global tracing subscriber is already installed")
+    }
+
+    pub fn subscriber(self) -> anyhow::Result<impl Subscriber + Send + Sync> {
+        let file = RollingFile::open(&self.path, self.max_size, self.max_files)?;
+        let writer = fmt::layer()
+            .with_ansi(false)
+            .with_writer(Mutex::new(file))
+            .with_filter(LevelFilter::from_level(self.level));
+        Ok(tracing_subscriber::registry()
+            .with(writer)
+            .with(CaptureLayer::new(self.buffer).level(self.level)))
+    }
+}
+
+pub struct RollingFile {
+    path: PathBuf,
+    file: File,
+    written: u64,
+    max_size: u64,
+    max_files: usize,
+}
+
+impl RollingFile {
+    pub fn open(path: impl Into<PathBuf>, max_size: u64, max_files: usize) -> anyhow::Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("create log directory {}", parent.display()))?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("open log file {}", path.display()))?;
+        let written = file.metadata().map_or(0, |metadata| metadata.len());
+        Ok(Self {
+            path,
+            file,
+            written,
+            max_size: max_size.max(1),
+            max_files,
+        })
+    }
+
+    fn roll(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_files > 0 {
+            for index in (1..self.max_files).rev() {
+                match fs::rename(rotated(&self.path, index), rotated(&self.path, index + 1)) {
+                    Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                    _ => {}
+                }
+            }
+            fs::rename(&self.path, rotated(&self.path, 1))?;
+        }
+        self.file = File::create(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for RollingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_size {
+            self.roll()?;
+        }
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn rotated(path: &Path, index: usize) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(format!(".{index}"));
+    PathBuf::from(name)
+}
@@ -0,0 +1,216 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Write};
+use std::sync::{Arc, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use tokio::sync::watch;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+
+mod file;
+mod panel;
+
+pub use file::{LogFile, RollingFile, init};
+pub use panel::LogPanelNode;
+
+pub const DEFAULT_CAPACITY: usize = 1000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogRecord {
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+    pub timestamp: SystemTime,
+}
+
+impl LogRecord {
+    pub fn new(level: Level, target: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            target: target.into(),
+            message: message.into(),
+            timestamp: SystemTime::now(),
+        }
+    }
+}
+
+impl fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seconds = self
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+            % 86_400;
+        write!(
+            f,
+            "{:02}:{:02}:{:02} {:>5} {}: {}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct LogBuffer {
+    records: Arc<Mutex<VecDeque<LogRecord>>>,
+    capacity: usize,
+    version: Arc<watch::Sender<u64>>,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: Arc::new(Mutex::new(VecDeque::new())),
+            capacity: capacity.max(1),
+            version: Arc::new(watch::Sender::new(0)),
+        }
+    }
+
+    pub fn push(&self, record: LogRecord) {
+        {
+            let mut records = self.records.lock();
+            if records.len() == self.capacity {
+                records.pop_front();
+            }
+            records.push_back(record);
+        }
+        self.version.send_modify(|version| *version += 1);
+    }
+
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.records.lock().iter().cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn clear(&self) {
+        self.records.lock().clear();
+        self.version.send_modify(|version| *version += 1);
+    }
+
+    pub(crate) fn subscribe(&self) -> watch::Receiver<u64> {
+        self.version.subscribe()
+    }
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl fmt::Debug for LogBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogBuffer")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+pub fn buffer() -> LogBuffer {
+    static BUFFER: OnceLock<LogBuffer> = OnceLock::new();
+    BUFFER.get_or_init(LogBuffer::default).clone()
+}
+
+pub fn capture() -> CaptureLayer {
+    CaptureLayer::new(buffer())
+}
+
+#[derive(Clone, Debug)]
+pub struct CaptureLayer {
+    buffer: LogBuffer,
+    level: Level,
+}
+
+impl CaptureLayer {
+    pub fn new(buffer: LogBuffer) -> Self {
+        Self {
+            buffer,
+            level: Level::INFO,
+        }
+    }
+
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn buffer(&self) -> &LogBuffer {
+        &self.buffer
+    }
+}
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if *metadata.level() > self.level {
+            return;
+        }
+        let mut message = MessageVisitor::default();
+        event.record(&mut message);
+        self.buffer.push(LogRecord::new(
+            *metadata.level(),
+            metadata.target(),
+            message.finish(),
+        ));
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        match (self.message.is_empty(), self.fields.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.fields,
+            (false, false) => format!("{} {}", self.message, self.fields),
+        }
+    }
+
+    fn field(&mut self, field: &Field, value: fmt::Arguments<'_>) {
+        if field.name() == "message" {
+            let _ = self.message.write_fmt(value);
+            return;
+        }
+        if !self.fields.is_empty() {
+            self.fields.push(' ');
+        }
+        let _ = write!(self.fields, "{}={value}", field.name());
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.field(field, format_args!("{value}"));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.field(field, format_args!("{value:?}"));
+    }
+}
+
+#[cfg(test)]
+mod tests;
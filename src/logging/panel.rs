@@ -0,0 +1,228 @@
+use std::fmt;
+use std::time::Duration;
+
+use ratatui::style::Color;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::Level;
+
+use crate::events::{FrameworkEvent, KeyCode, KeyEvent, KeyModifiers};
+use crate::hooks::Scope;
+use crate::rt;
+use crate::rt::time::{Instant, sleep};
+use crate::runtime::{ComponentElement, Element, ListItemNode, ListNode, component};
+
+use super::{LogBuffer, LogRecord, buffer};
+
+const VERBOSITY: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+const PAGE: usize = 10;
+// Batches bursts of log events, and keeps a panel that shows the runtime's own
+// trace events from re-rendering in a tight loop.
+const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Clone)]
+pub struct LogPanelNode {
+    id: String,
+    title: String,
+    level: Level,
+    follow: bool,
+    keyboard: bool,
+    buffer: LogBuffer,
+}
+
+struct PanelState {
+    level: Level,
+    follow: bool,
+    cursor: usize,
+}
+
+impl LogPanelNode {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: "Logs".to_string(),
+            level: Level::TRACE,
+            follow: true,
+            keyboard: false,
+            buffer: buffer(),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    pub fn keyboard(mut self, enabled: bool) -> Self {
+        self.keyboard = enabled;
+        self
+    }
+
+    pub fn buffer(mut self, buffer: LogBuffer) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    pub fn into_component(self) -> ComponentElement {
+        let key = self.id.clone();
+        component("LogPanel", move |ctx| self.render(ctx)).key(key)
+    }
+
+    fn render(&self, ctx: &mut Scope) -> Element {
+        let state = ctx.use_ref(|| PanelState {
+            level: self.level,
+            follow: self.follow,
+            cursor: 0,
+        });
+
+        let buffer = self.buffer.clone();
+        let keyboard = self.keyboard;
+        let handle = state.clone();
+        ctx.use_effect((self.id.clone(), keyboard), move |dispatcher| {
+            let mut versions = buffer.subscribe();
+            let mut events = dispatcher.events().subscribe();
+            let task = rt::spawn(async move {
+                let refresh = sleep(Duration::ZERO);
+                tokio::pin!(refresh);
+                let mut stale = false;
+                loop {
+                    tokio::select! {
+                        changed = versions.changed(), if !stale => {
+                            if changed.is_err() {
+                                break;
+                            }
+                            stale = true;
+                        }
+                        () = &mut refresh, if stale => {
+                            stale = false;
+                            dispatcher.request_render();
+                            refresh.as_mut().reset(Instant::now() + REFRESH_INTERVAL);
+                        }
+                        event = events.recv(), if keyboard => match event {
+                            Ok(FrameworkEvent::Key(key)) => {
+                                if handle.with_mut(|state| state.handle_key(&key, &buffer)) {
+                                    dispatcher.request_render();
+                                }
+                            }
+                            Ok(_) | Err(RecvError::Lagged(_)) => {}
+                            Err(RecvError::Closed) => break,
+                        },
+                    }
+                }
+            });
+            Some(Box::new(move || task.abort()))
+        });
+
+        let (level, follow, cursor) = state.with(|state| (state.level, state.follow, state.cursor));
+        let records = visible(&self.buffer, level);
+        let mode = if follow { "follow" } else { "paused" };
+        let items: Vec<ListItemNode> = records
+            .iter()
+            .map(|record| ListItemNode::new(record.to_string()).color(level_color(record.level)))
+            .collect();
+        let last = items.len().saturating_sub(1);
+        let highlight = if follow { last } else { cursor.min(last) };
+        Element::list(
+            ListNode::new(items)
+                .title(format!("{} · {level}+ · {mode}", self.title))
+                .highlight(highlight),
+        )
+    }
+}
+
+impl PanelState {
+    fn handle_key(&mut self, key: &KeyEvent, buffer: &LogBuffer) -> bool {
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return false;
+        }
+        let last = visible(buffer, self.level).len().saturating_sub(1);
+        if self.follow {
+            self.cursor = last;
+        }
+        match key.code {
+            KeyCode::Char('f') => self.follow = !self.follow,
+            KeyCode::Char('+') => self.level = step_level(self.level, 1),
+            KeyCode::Char('-') => self.level = step_level(self.level, -1),
+            KeyCode::Char('c') => {
+                buffer.clear();
+                self.cursor = 0;
+            }
+            KeyCode::Up => self.scroll_to(self.cursor.saturating_sub(1)),
+            KeyCode::Down => self.scroll_to((self.cursor + 1).min(last)),
+            KeyCode::PageUp => self.scroll_to(self.cursor.saturating_sub(PAGE)),
+            KeyCode::PageDown => self.scroll_to((self.cursor + PAGE).min(last)),
+            KeyCode::Home => self.scroll_to(0),
+            KeyCode::End => self.follow = true,
+            _ => return false,
+        }
+        true
+    }
+
+    fn scroll_to(&mut self, cursor: usize) {
+        self.follow = false;
+        self.cursor = cursor;
+    }
+}
+
+impl From<LogPanelNode> for Element {
+    fn from(value: LogPanelNode) -> Self {
+        value.into_component().into()
+    }
+}
+
+impl fmt::Debug for LogPanelNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogPanelNode")
+            .field("id", &self.id)
+            .field("title", &self.title)
+            .field("level", &self.level)
+            .field("follow", &self.follow)
+            .field("keyboard", &self.keyboard)
+            .finish_non_exhaustive()
+    }
+}
+
+fn visible(buffer: &LogBuffer, level: Level) -> Vec<LogRecord> {
+    buffer
+        .records()
+        .into_iter()
+        .filter(|record| record.level <= level)
+        .collect()
+}
+
+fn step_level(level: Level, delta: isize) -> Level {
+    let index = VERBOSITY
+        .iter()
+        .position(|candidate| *candidate == level)
+        .unwrap_or(VERBOSITY.len() - 1);
+    let next = index.saturating_add_signed(delta).min(VERBOSITY.len() - 1);
+    VERBOSITY[next]
+}
+
+fn level_color(level: Level) -> Color {
+    match level {
+        Level::ERROR => Color::Red,
+        Level::WARN => Color::Yellow,
+        Level::INFO => Color::Green,
+        Level::DEBUG => Color::Cyan,
+        _ => Color::DarkGray,
+    }
+}
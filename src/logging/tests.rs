@@ -0,0 +1,139 @@
+use std::io::Write;
+
+use tracing::{Level, debug, info, warn};
+use tracing_subscriber::layer::SubscriberExt;
+
+use crate::runtime::{App, component};
+use crate::testing::TestApp;
+
+use super::{CaptureLayer, LogBuffer, LogFile, LogPanelNode, LogRecord, RollingFile};
+
+#[test]
+fn capture_layer_keeps_recent_events_in_a_ring() {
+    let buffer = LogBuffer::new(2);
+    let subscriber =
+        tracing_subscriber::registry().with(CaptureLayer::new(buffer.clone()).level(Level::DEBUG));
+    tracing::subscriber::with_default(subscriber, || {
+        info!(target: "app", "first");
+        debug!(target: "app::db", rows = 3, table = "users", "loaded");
+        tracing::trace!(target: "app", "too verbose");
+        warn!(target: "app", "disk almost full");
+    });
+
+    let records = buffer.records();
+    let summary: Vec<_> = records
+        .iter()
+        .map(|record| {
+            (
+                record.level,
+                record.target.as_str(),
+                record.message.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (Level::DEBUG, "app::db", "loaded rows=3 table=users"),
+            (Level::WARN, "app", "disk almost full"),
+        ]
+    );
+    assert!(
+        records[1]
+            .to_string()
+            .ends_with(" WARN app: disk almost full")
+    );
+}
+
+fn panel_app(buffer: LogBuffer) -> App {
+    App::new(
+        "Logs",
+        component("Root", move |_| {
+            LogPanelNode::new("console")
+                .buffer(buffer.clone())
+                .level(Level::WARN)
+                .keyboard(true)
+                .into()
+        }),
+    )
+    .headless_size(60, 8)
+}
+
+#[tokio::test]
+async fn log_panel_filters_follows_and_pauses() {
+    let buffer = LogBuffer::default();
+    buffer.push(LogRecord::new(Level::INFO, "app", "started"));
+    buffer.push(LogRecord::new(Level::ERROR, "app", "request failed"));
+    let mut app = TestApp::new(panel_app(buffer.clone()))
+        .await
+        .expect("start app");
+    app.expect_text("Logs · WARN+ · follow")
+        .expect_text("ERROR app: request failed");
+    assert!(!app.text().contains("started"), "{}", app.text());
+
+    app.press_key("+").await.expect("more verbose");
+    app.expect_text("Logs · INFO+ · follow")
+        .expect_text(" INFO app: started");
+
+    buffer.push(LogRecord::new(Level::WARN, "app", "retrying"));
+    app.tick().await.expect("refresh");
+    app.expect_text(" WARN app: retrying");
+
+    app.press_key("up").await.expect("scroll back");
+    app.expect_text("· paused");
+    app.press_key("end").await.expect("follow again");
+    app.expect_text("· follow");
+
+    app.press_key("c").await.expect("clear");
+    app.expect_text("(no entries)");
+    assert!(buffer.is_empty());
+}
+
+fn log_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("rustact-logs-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn log_file_writes_events_to_disk_and_the_panel_buffer() {
+    let dir = log_dir("file");
+    let path = dir.join("app.log");
+    let buffer = LogBuffer::default();
+    let subscriber = LogFile::new(&path)
+        .buffer(buffer.clone())
+        .subscriber()
+        .expect("open log file");
+    tracing::subscriber::with_default(subscriber, || {
+        info!(target: "app", user = "ada", "signed in");
+        debug!(target: "app", "filtered out");
+    });
+
+    let written = std::fs::read_to_string(&path).expect("read log");
+    assert!(
+        written.contains("INFO app: signed in user=\"ada\""),
+        "{written}"
+    );
+    assert!(!written.contains("filtered out"), "{written}");
+    assert_eq!(buffer.len(), 1);
+    assert_eq!(buffer.records()[0].message, "signed in user=ada");
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn rolling_file_rotates_when_full() {
+    let dir = log_dir("rolling");
+    let path = dir.join("app.log");
+    let mut file = RollingFile::open(&path, 8, 2).expect("open log file");
+    for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+        file.write_all(line.as_bytes()).expect("write");
+    }
+    file.flush().expect("flush");
+
+    let read = |suffix: &str| std::fs::read_to_string(dir.join(format!("app.log{suffix}")));
+    assert_eq!(read("").unwrap(), "fourth\n");
+    assert_eq!(read(".1").unwrap(), "third\n");
+    assert_eq!(read(".2").unwrap(), "second\n");
+    assert!(read(".3").is_err());
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+
+use parking_lot::RwLock;
+
+use crate::events::{
+    FrameworkEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind, mouse_position,
+};
+use crate::interactions::Hitbox;
+use crate::runtime::{Current, Dispatcher, MenuItemNode, Registries};
+use crate::text_input::TextInputs;
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MenuState {
+    pub(crate) menus: Vec<MenuItemNode>,
+    pub(crate) active: usize,
+    pub(crate) path: Vec<usize>,
+}
+
+impl MenuState {
+    pub(crate) fn is_open(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    pub(crate) fn level_items(&self, level: usize) -> &[MenuItemNode] {
+        let Some(mut item) = self.menus.get(self.active) else {
+            return &[];
+        };
+        for index in self.path.iter().take(level) {
+            match item.children.get(*index) {
+                Some(child) => item = child,
+                None => return &[],
+            }
+        }
+        &item.children
+    }
+
+    fn highlighted(&self) -> Option<&MenuItemNode> {
+        let level = self.path.len().checked_sub(1)?;
+        self.level_items(level).get(self.path[level])
+    }
+
+    pub(crate) fn open(&mut self, index: usize) {
+        if index < self.menus.len() {
+            self.active = index;
+            self.path = vec![0];
+        }
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.path.clear();
+    }
+
+    fn cycle_menu(&mut self, delta: isize) {
+        let len = self.menus.len();
+        if len == 0 {
+            return;
+        }
+        let next = (self.active as isize + delta).rem_euclid(len as isize) as usize;
+        self.open(next);
+    }
+
+    pub(crate) fn move_highlight(&mut self, delta: isize) {
+        let Some(level) = self.path.len().checked_sub(1) else {
+            return;
+        };
+        let len = self.level_items(level).len();
+        if len == 0 {
+            return;
+        }
+        let current = self.path[level] as isize;
+        self.path[level] = (current + delta).rem_euclid(len as isize) as usize;
+    }
+
+    fn has_submenu(&self) -> bool {
+        self.highlighted()
+            .is_some_and(|item| !item.children.is_empty())
+    }
+
+    pub(crate) fn enter_submenu(&mut self) -> bool {
+        let has_children = self.has_submenu();
+        if has_children {
+            self.path.push(0);
+        }
+        has_children
+    }
+
+    pub(crate) fn leave_submenu(&mut self) -> bool {
+        if self.path.len() > 1 {
+            self.path.pop();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn activate(&mut self) -> Option<String> {
+        if self.enter_submenu() {
+            return None;
+        }
+        let command = self.highlighted()?.command.clone()?;
+        self.close();
+        Some(command)
+    }
+}
+
+pub(crate) struct MenuRegistry {
+    states: RwLock<HashMap<String, MenuState>>,
+    titles: RwLock<Vec<(String, usize, Hitbox)>>,
+    items: RwLock<Vec<(String, Vec<usize>, Hitbox)>>,
+}
+
+impl MenuRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            states: RwLock::new(HashMap::new()),
+            titles: RwLock::new(Vec::new()),
+            items: RwLock::new(Vec::new()),
+        }
+    }
+
+    fn global() -> Current<Self> {
+        Registries::current(|registries| &registries.menus)
+    }
+
+    fn update<R>(&self, id: &str, update: impl FnOnce(&mut MenuState) -> R) -> R {
+        let mut states = self.states.write();
+        update(states.entry(id.to_string()).or_default())
+    }
+
+    fn open_id(&self) -> Option<String> {
+        self.states
+            .read()
+            .iter()
+            .find(|(_, state)| state.is_open())
+            .map(|(id, _)| id.clone())
+    }
+
+    fn close_all(&self) -> bool {
+        let mut closed = false;
+        for state in self.states.write().values_mut() {
+            closed |= state.is_open();
+            state.close();
+        }
+        closed
+    }
+}
+
+fn dispatch_command(command: String, dispatcher: &Dispatcher) {
+    dispatcher
+        .events()
+        .publish(FrameworkEvent::Command(command));
+}
+
+pub struct MenuBars;
+
+impl MenuBars {
+    pub fn is_open(id: &str) -> bool {
+        let registry = MenuRegistry::global();
+        registry
+            .states
+            .read()
+            .get(id)
+            .is_some_and(MenuState::is_open)
+    }
+
+    pub fn any_open() -> bool {
+        MenuRegistry::global().open_id().is_some()
+    }
+
+    pub fn close(id: &str, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        MenuRegistry::global().update(id, MenuState::close);
+        dispatcher.request_full_render();
+    }
+
+    pub(crate) fn sync(id: &str, menus: Vec<MenuItemNode>) -> MenuState {
+        MenuRegistry::global().update(id, |state| {
+            state.menus = menus;
+            if state.active >= state.menus.len() {
+                state.active = 0;
+                state.close();
+            }
+            while state.is_open() && state.level_items(state.path.len() - 1).is_empty() {
+                state.path.pop();
+            }
+            state.clone()
+        })
+    }
+
+    pub(crate) fn record_title(id: &str, index: usize, hitbox: Hitbox) {
+        let registry = MenuRegistry::global();
+        registry
+            .titles
+            .write()
+            .push((id.to_string(), index, hitbox));
+    }
+
+    pub(crate) fn record_item(id: &str, path: Vec<usize>, hitbox: Hitbox) {
+        let registry = MenuRegistry::global();
+        registry.items.write().push((id.to_string(), path, hitbox));
+    }
+
+    pub(crate) fn reset_hitboxes() {
+        let registry = MenuRegistry::global();
+        registry.titles.write().clear();
+        registry.items.write().clear();
+    }
+
+    pub fn handle_event(event: &FrameworkEvent, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        match event {
+            FrameworkEvent::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+            {
+                if let Some((column, row)) = mouse_position(event) {
+                    Self::handle_click(column, row, dispatcher);
+                }
+            }
+            FrameworkEvent::Key(key) => Self::handle_key(key, dispatcher),
+            _ => {}
+        }
+    }
+
+    fn handle_click(column: u16, row: u16, dispatcher: &Dispatcher) {
+        let registry = MenuRegistry::global();
+        let item = registry
+            .items
+            .read()
+            .iter()
+            .rev()
+            .find(|(_, _, hitbox)| hitbox.contains(column, row))
+            .map(|(id, path, _)| (id.clone(), path.clone()));
+        if let Some((id, path)) = item {
+            let command = registry.update(&id, |state| {
+                state.path = path;
+                state.activate()
+            });
+            if let Some(command) = command {
+                dispatch_command(command, dispatcher);
+            }
+            dispatcher.request_full_render();
+            return;
+        }
+        let title = registry
+            .titles
+            .read()
+            .iter()
+            .find(|(_, _, hitbox)| hitbox.contains(column, row))
+            .map(|(id, index, _)| (id.clone(), *index));
+        match title {
+            Some((id, index)) => {
+                let reopen =
+                    !registry.update(&id, |state| state.is_open() && state.active == index);
+                registry.close_all();
+                if reopen {
+                    registry.update(&id, |state| state.open(index));
+                }
+                dispatcher.request_full_render();
+            }
+            None => {
+                if registry.close_all() {
+                    dispatcher.request_full_render();
+                }
+            }
+        }
+    }
+
+    fn handle_key(key: &KeyEvent, dispatcher: &Dispatcher) {
+        let registry = MenuRegistry::global();
+        let Some(id) = registry.open_id() else {
+            Self::handle_shortcut(key, dispatcher);
+            return;
+        };
+        let command = registry.update(&id, |state| {
+            match key.code {
+                KeyCode::Up => state.move_highlight(-1),
+                KeyCode::Down => state.move_highlight(1),
+                KeyCode::Left | KeyCode::Esc if state.path.len() > 1 => {
+                    state.leave_submenu();
+                }
+                KeyCode::Left => state.cycle_menu(-1),
+                KeyCode::Right if state.has_submenu() => {
+                    state.enter_submenu();
+                }
+                KeyCode::Right => state.cycle_menu(1),
+                KeyCode::Enter | KeyCode::Char(' ') => return state.activate(),
+                KeyCode::Esc | KeyCode::F(10) => state.close(),
+                _ => {}
+            }
+            None
+        });
+        if let Some(command) = command {
+            dispatch_command(command, dispatcher);
+        }
+        dispatcher.request_full_render();
+    }
+
+    fn handle_shortcut(key: &KeyEvent, dispatcher: &Dispatcher) {
+        if TextInputs::has_focus() {
+            return;
+        }
+        let registry = MenuRegistry::global();
+        let titles = registry.titles.read();
+        let target = match key.code {
+            KeyCode::F(10) => titles.first().map(|(id, index, _)| (id.clone(), *index)),
+            KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::ALT) => {
+                let states = registry.states.read();
+                titles
+                    .iter()
+                    .find(|(id, index, _)| {
+                        states
+                            .get(id)
+                            .and_then(|state| state.menus.get(*index))
+                            .and_then(MenuItemNode::mnemonic)
+                            == Some(c.to_ascii_lowercase())
+                    })
+                    .map(|(id, index, _)| (id.clone(), *index))
+            }
+            _ => None,
+        };
+        drop(titles);
+        if let Some((id, index)) = target {
+            registry.update(&id, |state| state.open(index));
+            dispatcher.request_full_render();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
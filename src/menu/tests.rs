@@ -0,0 +1,78 @@
+use crossterm::event::{KeyModifiers, MouseEvent};
+use tokio::sync::mpsc;
+
+use super::*;
+use crate::events::EventBus;
+
+fn menus() -> Vec<MenuItemNode> {
+    vec![
+        MenuItemNode::submenu(
+            "File",
+            vec![
+                MenuItemNode::new("Open", "file.open").shortcut("Ctrl+O"),
+                MenuItemNode::submenu(
+                    "Export",
+                    vec![
+                        MenuItemNode::new("CSV", "file.export.csv"),
+                        MenuItemNode::new("JSON", "file.export.json"),
+                    ],
+                ),
+            ],
+        ),
+        MenuItemNode::submenu("View", vec![MenuItemNode::new("Logs", "view.logs")]),
+    ]
+}
+
+#[test]
+fn keyboard_walks_into_submenus_and_returns_commands() {
+    let mut state = MenuState {
+        menus: menus(),
+        ..MenuState::default()
+    };
+    state.open(0);
+    state.move_highlight(1);
+    assert!(state.enter_submenu());
+    assert_eq!(state.path, vec![1, 0]);
+    state.move_highlight(-1);
+    assert_eq!(state.level_items(1)[state.path[1]].label, "JSON");
+    assert_eq!(state.activate().as_deref(), Some("file.export.json"));
+    assert!(!state.is_open());
+}
+
+#[test]
+fn left_and_right_cycle_top_level_menus() {
+    let mut state = MenuState {
+        menus: menus(),
+        ..MenuState::default()
+    };
+    state.open(0);
+    state.cycle_menu(1);
+    assert_eq!((state.active, state.path.clone()), (1, vec![0]));
+    state.cycle_menu(1);
+    assert_eq!(state.active, 0);
+    assert!(!state.leave_submenu());
+}
+
+#[test]
+fn clicking_an_item_publishes_its_command() {
+    let (tx, _rx) = mpsc::channel(8);
+    let bus = EventBus::new(8);
+    let mut commands = bus.subscribe();
+    let dispatcher = Dispatcher::new(tx, bus);
+    let _app = dispatcher.enter();
+    MenuBars::sync("menu-click", menus());
+    MenuRegistry::global().update("menu-click", |state| state.open(1));
+    MenuBars::record_item("menu-click", vec![0], Hitbox::new(60, 40, 10, 1));
+    let click = FrameworkEvent::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 62,
+        row: 40,
+        modifiers: KeyModifiers::NONE,
+    });
+    MenuBars::handle_event(&click, &dispatcher);
+    assert!(!MenuBars::is_open("menu-click"));
+    match commands.try_recv().expect("command published") {
+        FrameworkEvent::Command(command) => assert_eq!(command, "view.logs"),
+        other => panic!("unexpected event: {other:?}"),
+    }
+}
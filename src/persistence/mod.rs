@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use parking_lot::Mutex;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::runtime::Handle;
+use tracing::{trace, warn};
+
+use crate::rt::{self, time::sleep};
+
+const FLUSH_DELAY: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Default)]
+pub struct PersistentStore {
+    path: Option<PathBuf>,
+    inner: Mutex<PersistentEntries>,
+    writing: Mutex<()>,
+}
+
+#[derive(Debug, Default)]
+struct PersistentEntries {
+    values: HashMap<String, Value>,
+    revision: u64,
+    flushed: u64,
+    scheduled: bool,
+}
+
+impl PersistentStore {
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let values = match read_entries(&path) {
+            Ok(values) => values,
+            Err(err) => {
+                warn!(path = %path.display(), error = ?err, "failed to restore persistent state");
+                HashMap::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            inner: Mutex::new(PersistentEntries {
+                values,
+                ..PersistentEntries::default()
+            }),
+            writing: Mutex::new(()),
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn load<T>(&self, key: &str) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        let inner = self.inner.lock();
+        let value = inner.values.get(key)?.clone();
+        match serde_json::from_value(value) {
+            Ok(decoded) => Some(decoded),
+            Err(err) => {
+                warn!(key, error = ?err, "discarding persisted value with mismatched shape");
+                None
+            }
+        }
+    }
+
+    pub fn store<T>(&self, key: &str, value: &T)
+    where
+        T: Serialize,
+    {
+        let encoded = match serde_json::to_value(value) {
+            Ok(encoded) => encoded,
+            Err(err) => {
+                warn!(key, error = ?err, "failed to serialize persistent state");
+                return;
+            }
+        };
+        let mut inner = self.inner.lock();
+        if inner.values.get(key) != Some(&encoded) {
+            inner.values.insert(key.to_string(), encoded);
+            inner.revision += 1;
+        }
+    }
+
+    // Writes at most once per FLUSH_DELAY on a blocking thread, off the render loop.
+    pub fn flush_later(self: &Arc<Self>) {
+        if self.path.is_none() {
+            return;
+        }
+        {
+            let mut inner = self.inner.lock();
+            if inner.revision == inner.flushed || inner.scheduled {
+                return;
+            }
+            inner.scheduled = true;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if Handle::try_current().is_err() {
+            self.inner.lock().scheduled = false;
+            if let Err(err) = self.flush() {
+                warn!(error = ?err, "failed to persist state");
+            }
+            return;
+        }
+        let store = self.clone();
+        rt::spawn(async move {
+            sleep(FLUSH_DELAY).await;
+            store.inner.lock().scheduled = false;
+            match rt::blocking(move || store.flush()).await {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => warn!(error = ?err, "failed to persist state"),
+                Err(err) => warn!(error = ?err, "persistent state writer stopped"),
+            }
+        });
+    }
+
+    pub fn flush(&self) -> anyhow::Result<()> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        let _writing = self.writing.lock();
+        let (revision, contents) = {
+            let inner = self.inner.lock();
+            if inner.revision == inner.flushed {
+                return Ok(());
+            }
+            let contents =
+                serde_json::to_string_pretty(&inner.values).context("encode persistent state")?;
+            (inner.revision, contents)
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("create state directory {}", parent.display()))?;
+        }
+        let staging = path.with_extension("json.tmp");
+        fs::write(&staging, contents)
+            .with_context(|| format!("write persistent state {}", staging.display()))?;
+        fs::rename(&staging, path)
+            .with_context(|| format!("replace persistent state {}", path.display()))?;
+        self.inner.lock().flushed = revision;
+        trace!(path = %path.display(), "persistent state flushed");
+        Ok(())
+    }
+}
+
+pub fn default_state_path(app_name: &str) -> PathBuf {
+    let slug = app_name
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>();
+    let base = env::var_os("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state")))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join(slug).join("state.json")
+}
+
+fn read_entries(path: &Path) -> anyhow::Result<HashMap<String, Value>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents).context("decode persistent state"),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(HashMap::new()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,80 @@
+use std::fs;
+
+use super::*;
+
+fn scratch_path(name: &str) -> PathBuf {
+    env::temp_dir()
+        .join(format!("rustact-persistence-{}", std::process::id()))
+        .join(name)
+}
+
+#[test]
+fn store_round_trips_values_through_disk() {
+    let path = scratch_path("round-trip.json");
+    let _ = fs::remove_file(&path);
+
+    let store = PersistentStore::open(&path);
+    assert_eq!(store.load::<usize>("tab"), None);
+    store.store("tab", &2usize);
+    store.store("draft", &"hello".to_string());
+    store.flush().expect("flush state");
+
+    let restored = PersistentStore::open(&path);
+    assert_eq!(restored.load::<usize>("tab"), Some(2));
+    assert_eq!(restored.load::<String>("draft").as_deref(), Some("hello"));
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn failed_flushes_keep_changes_for_the_next_attempt() {
+    let blocker = scratch_path("blocker");
+    let path = blocker.join("state.json");
+    let _ = fs::remove_dir_all(&blocker);
+    fs::create_dir_all(blocker.parent().expect("scratch dir")).expect("scratch dir");
+    fs::write(&blocker, "not a directory").expect("create blocker");
+
+    let store = PersistentStore::open(&path);
+    store.store("tab", &3usize);
+    assert!(store.flush().is_err());
+
+    fs::remove_file(&blocker).expect("remove blocker");
+    store.flush().expect("retry flush");
+    assert_eq!(PersistentStore::open(&path).load::<usize>("tab"), Some(3));
+    let _ = fs::remove_dir_all(&blocker);
+}
+
+#[tokio::test]
+async fn flush_later_writes_in_the_background_after_a_delay() {
+    let path = scratch_path("debounced.json");
+    let _ = fs::remove_file(&path);
+
+    let store = Arc::new(PersistentStore::open(&path));
+    store.store("tab", &1usize);
+    store.flush_later();
+    store.store("tab", &2usize);
+    store.flush_later();
+    assert!(!path.exists());
+
+    for _ in 0..100 {
+        if path.exists() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(PersistentStore::open(&path).load::<usize>("tab"), Some(2));
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn mismatched_shapes_fall_back_to_none() {
+    let store = PersistentStore::in_memory();
+    store.store("tab", &"not a number");
+    assert_eq!(store.load::<usize>("tab"), None);
+    store.flush().expect("in-memory flush is a no-op");
+}
+
+#[test]
+fn default_state_path_slugs_app_name() {
+    let path = default_state_path("Rustact Demo");
+    assert!(path.ends_with("rustact-demo/state.json"));
+}
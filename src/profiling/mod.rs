@@ -0,0 +1,254 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::rt::Instant;
+use crate::runtime::{Anchor, AnchorNode, ComponentId, Element};
+
+mod tree;
+
+pub(crate) use tree::debug_tree;
+
+const DEFAULT_OVERLAY_COMPONENTS: usize = 5;
+const FPS_WINDOW: Duration = Duration::from_secs(1);
+const STATS_WIDTH: u16 = 26;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Metrics {
+    pub frames: u64,
+    pub fps: u32,
+    pub last_frame: Duration,
+    pub slowest_frame: Duration,
+    pub effects_run: u64,
+    pub latency: Latency,
+    pub components: Vec<ComponentMetrics>,
+}
+
+impl Metrics {
+    pub fn component(&self, id: &str) -> Option<&ComponentMetrics> {
+        self.components.iter().find(|component| component.id == id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Latency {
+    pub samples: u64,
+    pub last: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentMetrics {
+    pub id: String,
+    pub renders: u64,
+    pub last: Duration,
+    pub max: Duration,
+    pub total: Duration,
+}
+
+impl ComponentMetrics {
+    fn new(id: &ComponentId) -> Self {
+        Self {
+            id: id.to_string(),
+            renders: 0,
+            last: Duration::ZERO,
+            max: Duration::ZERO,
+            total: Duration::ZERO,
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        if self.renders == 0 {
+            Duration::ZERO
+        } else {
+            self.total.div_f64(self.renders as f64)
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Profiler {
+    state: Mutex<ProfilerState>,
+}
+
+#[derive(Default)]
+struct ProfilerState {
+    frames: u64,
+    last_frame: Duration,
+    slowest_frame: Duration,
+    effects_run: u64,
+    recent: VecDeque<Instant>,
+    requested: Option<Instant>,
+    latency: Latency,
+    latency_total: Duration,
+    components: HashMap<ComponentId, ComponentMetrics>,
+}
+
+impl Profiler {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn render_requested(&self) {
+        self.state.lock().requested.get_or_insert_with(Instant::now);
+    }
+
+    pub(crate) fn component_rendered(&self, id: &ComponentId, duration: Duration) {
+        let mut state = self.state.lock();
+        let metrics = state
+            .components
+            .entry(id.clone())
+            .or_insert_with(|| ComponentMetrics::new(id));
+        metrics.renders += 1;
+        metrics.last = duration;
+        metrics.max = metrics.max.max(duration);
+        metrics.total += duration;
+    }
+
+    pub(crate) fn effects_run(&self, count: usize) {
+        self.state.lock().effects_run += count as u64;
+    }
+
+    pub(crate) fn frame_drawn(&self, duration: Duration, live: &HashSet<ComponentId>) {
+        let mut state = self.state.lock();
+        state.frames += 1;
+        state.last_frame = duration;
+        state.slowest_frame = state.slowest_frame.max(duration);
+        let now = Instant::now();
+        while state
+            .recent
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= FPS_WINDOW)
+        {
+            state.recent.pop_front();
+        }
+        state.recent.push_back(now);
+        state.components.retain(|id, _| live.contains(id));
+        if let Some(requested) = state.requested.take() {
+            let latency = requested.elapsed();
+            state.latency_total += latency;
+            let samples = state.latency.samples + 1;
+            state.latency = Latency {
+                samples,
+                last: latency,
+                max: state.latency.max.max(latency),
+                mean: state.latency_total.div_f64(samples as f64),
+            };
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> Metrics {
+        let state = self.state.lock();
+        let mut components: Vec<ComponentMetrics> = state.components.values().cloned().collect();
+        components.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.id.cmp(&b.id)));
+        Metrics {
+            frames: state.frames,
+            fps: state.recent.len() as u32,
+            last_frame: state.last_frame,
+            slowest_frame: state.slowest_frame,
+            effects_run: state.effects_run,
+            latency: state.latency,
+            components,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PerfOverlayNode {
+    pub title: String,
+    pub components: usize,
+}
+
+impl PerfOverlayNode {
+    pub fn new() -> Self {
+        Self {
+            title: "Performance".to_string(),
+            components: DEFAULT_OVERLAY_COMPONENTS,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn components(mut self, count: usize) -> Self {
+        self.components = count;
+        self
+    }
+
+    pub(crate) fn element(&self, metrics: &Metrics) -> Element {
+        let mut lines = vec![
+            Element::text(format!(
+                "frames {}  last {}  slowest {}",
+                metrics.frames,
+                millis(metrics.last_frame),
+                millis(metrics.slowest_frame)
+            )),
+            Element::text(format!(
+                "latency {}  mean {}  max {}",
+                millis(metrics.latency.last),
+                millis(metrics.latency.mean),
+                millis(metrics.latency.max)
+            )),
+            Element::text(format!("effects {}", metrics.effects_run)),
+        ];
+        lines.extend(
+            metrics
+                .components
+                .iter()
+                .take(self.components)
+                .map(|component| {
+                    Element::text(format!(
+                        "{} x{}  {}",
+                        component.id,
+                        component.renders,
+                        millis(component.mean())
+                    ))
+                }),
+        );
+        Element::block(self.title.clone(), Element::vstack(lines))
+    }
+}
+
+impl Default for PerfOverlayNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) struct RuntimeStats {
+    pub(crate) live_components: usize,
+    pub(crate) hook_slots: usize,
+    pub(crate) event_lag: usize,
+}
+
+pub(crate) fn stats_overlay(metrics: &Metrics, stats: RuntimeStats) -> Element {
+    let lines = [
+        format!("fps {}", metrics.fps),
+        format!("frame {}", millis(metrics.last_frame)),
+        format!("components {}", stats.live_components),
+        format!("hook slots {}", stats.hook_slots),
+        format!("event lag {}", stats.event_lag),
+    ];
+    let height = lines.len() as u16 + 2;
+    Element::anchored(
+        AnchorNode::new(
+            Anchor::TopRight,
+            Element::block(
+                "Stats",
+                Element::vstack(lines.into_iter().map(Element::text).collect()),
+            ),
+        )
+        .size(STATS_WIDTH, height),
+    )
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.2} ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests;
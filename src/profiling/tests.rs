@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::keymap::KeyBinding;
+use crate::runtime::{App, Dispatcher, Element, component};
+use crate::testing::TestApp;
+
+use super::PerfOverlayNode;
+
+type Slot = Arc<Mutex<Option<Dispatcher>>>;
+
+fn app(slot: Slot) -> App {
+    App::new(
+        "Profiled",
+        component("Root", move |ctx| {
+            *slot.lock() = Some(ctx.dispatcher().clone());
+            let (count, set_count) = ctx.use_state(|| 0u32);
+            ctx.use_effect(count, move |_| {
+                if count < 2 {
+                    set_count.set(count + 1);
+                }
+                None
+            });
+            Element::vstack(vec![
+                component("Child", move |_| Element::text(format!("count {count}"))).into(),
+                Element::perf_overlay(PerfOverlayNode::new().components(1)),
+            ])
+        }),
+    )
+    .headless_size(60, 16)
+}
+
+#[tokio::test]
+async fn dispatcher_metrics_count_renders_effects_and_latency() {
+    let slot = Slot::default();
+    let app = TestApp::new(app(slot.clone())).await.expect("start app");
+    app.expect_text("count 2");
+
+    let metrics = slot.lock().as_ref().expect("dispatcher").metrics();
+    assert_eq!(metrics.frames, 3);
+    assert_eq!(metrics.effects_run, 3);
+    assert_eq!(metrics.latency.samples, 3);
+    assert!(metrics.latency.max >= metrics.latency.last);
+    assert!(metrics.slowest_frame >= metrics.last_frame);
+    let child = metrics.component("0.0:Child").expect("child metrics");
+    assert_eq!(child.renders, 3);
+    assert!(child.max <= child.total);
+}
+
+#[tokio::test]
+async fn perf_overlay_shows_the_latest_metrics() {
+    let app = TestApp::new(app(Slot::default())).await.expect("start app");
+    app.expect_text("Performance")
+        .expect_text("frames 2")
+        .expect_text("effects 2");
+}
+
+#[tokio::test]
+async fn debug_key_toggles_the_stats_overlay() {
+    let key = KeyBinding::parse("f12").expect("binding");
+    let mut app = TestApp::new(app(Slot::default()).stats_overlay(key))
+        .await
+        .expect("start app");
+    assert!(!app.text().contains("Stats"));
+
+    app.press_key("f12").await.expect("open stats");
+    app.expect_text("Stats")
+        .expect_text("fps ")
+        .expect_text("components 2")
+        .expect_text("hook slots 2")
+        .expect_text("event lag ");
+
+    app.press_key("f12").await.expect("close stats");
+    assert!(!app.text().contains("Stats"), "{}", app.text());
+}
+
+#[tokio::test]
+async fn debug_tree_lists_mounted_components_with_hooks_and_timings() {
+    let slot = Slot::default();
+    let mut app = TestApp::new(app(slot.clone())).await.expect("start app");
+    let tree = app.debug_tree();
+    let lines: Vec<_> = tree.lines().collect();
+    assert_eq!(lines.len(), 2, "{tree}");
+    assert!(
+        lines[0].starts_with("Root [0:Root] hooks 2, last render "),
+        "{tree}"
+    );
+    assert!(
+        lines[1].starts_with("  Child [0.0:Child] hooks 0, last render "),
+        "{tree}"
+    );
+    assert!(lines[1].ends_with(" ms"), "{tree}");
+
+    let dispatcher = slot.lock().clone().expect("dispatcher");
+    let request = tokio::spawn(async move { dispatcher.debug_tree().await });
+    app.tick().await.expect("handle request");
+    let dumped = request.await.expect("join").expect("debug tree");
+    let shape = |tree: &str| -> Vec<String> {
+        tree.lines()
+            .filter_map(|line| line.split_once(", last render"))
+            .map(|(node, _)| node.to_string())
+            .collect()
+    };
+    assert_eq!(shape(&dumped), shape(&tree));
+}
@@ -0,0 +1,31 @@
+use std::fmt::Write;
+
+use crate::runtime::ComponentId;
+
+use super::{Metrics, millis};
+
+pub(crate) fn debug_tree(components: Vec<(ComponentId, usize)>, metrics: &Metrics) -> String {
+    let mut nodes: Vec<_> = components
+        .into_iter()
+        .map(|(id, hooks)| (id.path(), id, hooks))
+        .collect();
+    nodes.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.0.cmp(&b.1.0)));
+    let mut tree = String::new();
+    for (path, id, hooks) in &nodes {
+        let depth = nodes
+            .iter()
+            .filter(|(other, _, _)| other.len() < path.len() && path.starts_with(other))
+            .count();
+        let key = id.key().map(|key| format!(" #{key}")).unwrap_or_default();
+        let last = metrics
+            .component(&id.to_string())
+            .map_or_else(|| "-".to_string(), |component| millis(component.last));
+        let _ = writeln!(
+            tree,
+            "{}{}{key} [{id}] hooks {hooks}, last render {last}",
+            "  ".repeat(depth),
+            id.name()
+        );
+    }
+    tree
+}
@@ -0,0 +1,429 @@
+use std::fs::{self, File};
+use std::future;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, anyhow};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Notify, mpsc};
+use tracing::{debug, warn};
+
+use crate::events::{
+    FrameworkEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use crate::rt::time::{Instant, sleep_until, timeout};
+use crate::rt::{self, JoinHandle};
+use crate::runtime::{AppMessage, RuntimeDriver, View};
+
+const SETTLE_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "entry", rename_all = "snake_case")]
+pub enum RecordEntry {
+    Event { at_ms: u64, event: RecordedEvent },
+    Frame { at_ms: u64, hash: String },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecordedEvent {
+    Key {
+        code: String,
+        modifiers: u8,
+    },
+    Mouse {
+        kind: String,
+        column: u16,
+        row: u16,
+        modifiers: u8,
+    },
+    Resize {
+        width: u16,
+        height: u16,
+    },
+    Tick,
+    Command {
+        name: String,
+    },
+}
+
+impl RecordedEvent {
+    pub fn from_event(event: &FrameworkEvent) -> Option<Self> {
+        let recorded = match event {
+            FrameworkEvent::Key(key) => Self::Key {
+                code: key_code_name(key.code)?,
+                modifiers: key.modifiers.bits(),
+            },
+            FrameworkEvent::Mouse(mouse) => Self::Mouse {
+                kind: mouse_kind_name(mouse.kind),
+                column: mouse.column,
+                row: mouse.row,
+                modifiers: mouse.modifiers.bits(),
+            },
+            FrameworkEvent::Resize(width, height) => Self::Resize {
+                width: *width,
+                height: *height,
+            },
+            FrameworkEvent::Tick => Self::Tick,
+            FrameworkEvent::Command(name) => Self::Command { name: name.clone() },
+        };
+        Some(recorded)
+    }
+
+    pub fn to_event(&self) -> Option<FrameworkEvent> {
+        let event = match self {
+            Self::Key { code, modifiers } => FrameworkEvent::Key(KeyEvent::new(
+                parse_key_code(code)?,
+                KeyModifiers::from_bits_truncate(*modifiers),
+            )),
+            Self::Mouse {
+                kind,
+                column,
+                row,
+                modifiers,
+            } => FrameworkEvent::Mouse(MouseEvent {
+                kind: parse_mouse_kind(kind)?,
+                column: *column,
+                row: *row,
+                modifiers: KeyModifiers::from_bits_truncate(*modifiers),
+            }),
+            Self::Resize { width, height } => FrameworkEvent::Resize(*width, *height),
+            Self::Tick => FrameworkEvent::Tick,
+            Self::Command { name } => FrameworkEvent::Command(name.clone()),
+        };
+        Some(event)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Recording {
+    pub entries: Vec<RecordEntry>,
+}
+
+impl Recording {
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)
+            .with_context(|| format!("read recording {}", path.display()))?;
+        let entries = source
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("{}:{}: invalid entry", path.display(), index + 1))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { entries })
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = (u64, &RecordedEvent)> {
+        self.entries.iter().filter_map(|entry| match entry {
+            RecordEntry::Event { at_ms, event } => Some((*at_ms, event)),
+            RecordEntry::Frame { .. } => None,
+        })
+    }
+
+    pub fn frames(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().filter_map(|entry| match entry {
+            RecordEntry::Frame { hash, .. } => Some(hash.as_str()),
+            RecordEntry::Event { .. } => None,
+        })
+    }
+}
+
+pub(crate) struct SessionRecorder {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    started: Instant,
+}
+
+impl SessionRecorder {
+    pub(crate) fn create(path: &Path) -> anyhow::Result<Self> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("create recording directory {}", parent.display()))?;
+        }
+        let file =
+            File::create(path).with_context(|| format!("create recording {}", path.display()))?;
+        debug!(path = %path.display(), "recording session");
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: BufWriter::new(file),
+            started: Instant::now(),
+        })
+    }
+
+    pub(crate) fn event(&mut self, event: &FrameworkEvent) {
+        if let Some(event) = RecordedEvent::from_event(event) {
+            let at_ms = self.elapsed_ms();
+            self.write(RecordEntry::Event { at_ms, event });
+        }
+    }
+
+    pub(crate) fn frame(&mut self, hash: u64) {
+        let at_ms = self.elapsed_ms();
+        self.write(RecordEntry::Frame {
+            at_ms,
+            hash: format_hash(hash),
+        });
+    }
+
+    fn elapsed_ms(&self) -> u64 {
+        u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX)
+    }
+
+    fn write(&mut self, entry: RecordEntry) {
+        let result = serde_json::to_string(&entry)
+            .map_err(anyhow::Error::from)
+            .and_then(|line| {
+                writeln!(self.writer, "{line}")?;
+                self.writer.flush()?;
+                Ok(())
+            });
+        if let Err(err) = result {
+            warn!(path = %self.path.display(), error = ?err, "failed to write session recording");
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PlaybackDriver {
+    recording: Arc<Recording>,
+    speed: f64,
+    played: Arc<Mutex<Vec<String>>>,
+    frame_drawn: Arc<Notify>,
+}
+
+impl PlaybackDriver {
+    pub fn new(recording: Recording) -> Self {
+        Self {
+            recording: Arc::new(recording),
+            speed: 1.0,
+            played: Arc::new(Mutex::new(Vec::new())),
+            frame_drawn: Arc::new(Notify::new()),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        Ok(Self::new(Recording::load(path)?))
+    }
+
+    pub fn speed(mut self, factor: f64) -> Self {
+        self.speed = factor;
+        self
+    }
+
+    pub fn instant(self) -> Self {
+        self.speed(f64::INFINITY)
+    }
+
+    pub fn played_frames(&self) -> Vec<String> {
+        self.played.lock().clone()
+    }
+
+    pub fn verify(&self) -> anyhow::Result<()> {
+        let played = self.played.lock();
+        let expected: Vec<&str> = self.recording.frames().collect();
+        let mut remaining = played.iter();
+        let reproduced = expected
+            .iter()
+            .filter(|hash| remaining.any(|played| played == *hash))
+            .count();
+        match (expected.last(), played.last()) {
+            (Some(expected_last), Some(played_last)) if expected_last == played_last => Ok(()),
+            (None, _) => Ok(()),
+            (Some(expected_last), played_last) => Err(anyhow!(
+                "playback diverged: final frame {} expected, got {} ({reproduced} of {} recorded frames reproduced)",
+                expected_last,
+                played_last.map_or("none", String::as_str),
+                expected.len(),
+            )),
+        }
+    }
+
+    fn delay(&self, at_ms: u64) -> Duration {
+        if self.speed.is_finite() && self.speed > 0.0 {
+            Duration::from_secs_f64(at_ms as f64 / 1000.0 / self.speed)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    async fn wait_for_final_frame(&self) {
+        let Some(expected) = self.recording.frames().last().map(str::to_string) else {
+            return;
+        };
+        let deadline = Instant::now() + SETTLE_TIMEOUT;
+        loop {
+            let notified = self.frame_drawn.notified();
+            if self.played.lock().last() == Some(&expected) {
+                return;
+            }
+            let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+                return;
+            };
+            if timeout(remaining, notified).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+impl RuntimeDriver for PlaybackDriver {
+    fn spawn_terminal_events(&self, tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+        let driver = self.clone();
+        rt::spawn(async move {
+            let started = Instant::now();
+            for (at_ms, event) in driver.recording.events() {
+                let Some(event) = event.to_event() else {
+                    continue;
+                };
+                sleep_until(started + driver.delay(at_ms)).await;
+                if tx.send(AppMessage::ExternalEvent(event)).await.is_err() {
+                    return;
+                }
+            }
+            driver.wait_for_final_frame().await;
+            debug!("session playback finished");
+            let _ = tx.send(AppMessage::Shutdown).await;
+        })
+    }
+
+    fn spawn_tick_loop(&self, _tx: mpsc::Sender<AppMessage>, _rate: Duration) -> JoinHandle<()> {
+        rt::spawn(future::pending())
+    }
+
+    fn spawn_shutdown_watcher(&self, _tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+        rt::spawn(future::pending())
+    }
+
+    fn observes_frames(&self) -> bool {
+        true
+    }
+
+    fn frame_drawn(&self, hash: u64) {
+        self.played.lock().push(format_hash(hash));
+        self.frame_drawn.notify_waiters();
+    }
+}
+
+pub(crate) fn frame_hash(view: &View) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    format!("{view:?}").bytes().fold(OFFSET, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+fn format_hash(hash: u64) -> String {
+    format!("{hash:016x}")
+}
+
+fn key_code_name(code: KeyCode) -> Option<String> {
+    let name = match code {
+        KeyCode::Char(c) => return Some(format!("char:{c}")),
+        KeyCode::F(n) => return Some(format!("f{n}")),
+        KeyCode::Backspace => "backspace",
+        KeyCode::Enter => "enter",
+        KeyCode::Left => "left",
+        KeyCode::Right => "right",
+        KeyCode::Up => "up",
+        KeyCode::Down => "down",
+        KeyCode::Home => "home",
+        KeyCode::End => "end",
+        KeyCode::PageUp => "pageup",
+        KeyCode::PageDown => "pagedown",
+        KeyCode::Tab => "tab",
+        KeyCode::BackTab => "backtab",
+        KeyCode::Delete => "delete",
+        KeyCode::Insert => "insert",
+        KeyCode::Esc => "esc",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+fn parse_key_code(name: &str) -> Option<KeyCode> {
+    if let Some(c) = name.strip_prefix("char:") {
+        let mut chars = c.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(KeyCode::Char(c)),
+            _ => None,
+        };
+    }
+    let code = match name {
+        "backspace" => KeyCode::Backspace,
+        "enter" => KeyCode::Enter,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "delete" => KeyCode::Delete,
+        "insert" => KeyCode::Insert,
+        "esc" => KeyCode::Esc,
+        name => KeyCode::F(name.strip_prefix('f')?.parse().ok()?),
+    };
+    Some(code)
+}
+
+fn mouse_kind_name(kind: MouseEventKind) -> String {
+    let (action, button) = match kind {
+        MouseEventKind::Down(button) => ("down", Some(button)),
+        MouseEventKind::Up(button) => ("up", Some(button)),
+        MouseEventKind::Drag(button) => ("drag", Some(button)),
+        MouseEventKind::Moved => ("moved", None),
+        MouseEventKind::ScrollDown => ("scroll_down", None),
+        MouseEventKind::ScrollUp => ("scroll_up", None),
+        MouseEventKind::ScrollLeft => ("scroll_left", None),
+        MouseEventKind::ScrollRight => ("scroll_right", None),
+    };
+    match button {
+        Some(MouseButton::Left) => format!("{action}:left"),
+        Some(MouseButton::Right) => format!("{action}:right"),
+        Some(MouseButton::Middle) => format!("{action}:middle"),
+        None => action.to_string(),
+    }
+}
+
+fn parse_mouse_kind(name: &str) -> Option<MouseEventKind> {
+    let (action, button) = match name.split_once(':') {
+        Some((action, button)) => (
+            action,
+            Some(match button {
+                "left" => MouseButton::Left,
+                "right" => MouseButton::Right,
+                "middle" => MouseButton::Middle,
+                _ => return None,
+            }),
+        ),
+        None => (name, None),
+    };
+    let kind = match (action, button) {
+        ("down", Some(button)) => MouseEventKind::Down(button),
+        ("up", Some(button)) => MouseEventKind::Up(button),
+        ("drag", Some(button)) => MouseEventKind::Drag(button),
+        ("moved", None) => MouseEventKind::Moved,
+        ("scroll_down", None) => MouseEventKind::ScrollDown,
+        ("scroll_up", None) => MouseEventKind::ScrollUp,
+        ("scroll_left", None) => MouseEventKind::ScrollLeft,
+        ("scroll_right", None) => MouseEventKind::ScrollRight,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+#[cfg(test)]
+mod tests;
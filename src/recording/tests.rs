@@ -0,0 +1,115 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+use super::{PlaybackDriver, RecordEntry, RecordedEvent, Recording};
+use crate::events::FrameworkEvent;
+use crate::runtime::{App, Element, component};
+use crate::testing::TestApp;
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    std::env::temp_dir().join(format!(
+        "rustact-recording-{}-{}-{name}",
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::SeqCst)
+    ))
+}
+
+fn typing_app() -> App {
+    App::new(
+        "Typing",
+        component("Typing", |ctx| {
+            let (typed, set_typed) = ctx.use_state(String::new);
+            ctx.use_effect((), move |dispatcher| {
+                let mut events = dispatcher.events().subscribe();
+                let handle = tokio::spawn(async move {
+                    while let Ok(event) = events.recv().await {
+                        if let FrameworkEvent::Key(key) = event {
+                            if let KeyCode::Char(c) = key.code {
+                                set_typed.update(|typed| typed.push(c));
+                            }
+                        }
+                    }
+                });
+                Some(Box::new(move || handle.abort()))
+            });
+            Element::text(format!("typed: {typed}"))
+        }),
+    )
+    .headless_size(20, 2)
+}
+
+#[test]
+fn recorded_events_round_trip() {
+    let events = [
+        FrameworkEvent::Key(KeyEvent::new(KeyCode::Char('+'), KeyModifiers::CONTROL)),
+        FrameworkEvent::Key(KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE)),
+        FrameworkEvent::Mouse(MouseEvent {
+            kind: MouseEventKind::Drag(MouseButton::Right),
+            column: 3,
+            row: 7,
+            modifiers: KeyModifiers::SHIFT,
+        }),
+        FrameworkEvent::Resize(100, 30),
+        FrameworkEvent::Command("save".into()),
+    ];
+    for event in events {
+        let recorded = RecordedEvent::from_event(&event).expect("recordable");
+        let json = serde_json::to_string(&recorded).expect("serialize");
+        let decoded: RecordedEvent = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(
+            format!("{:?}", decoded.to_event()),
+            format!("{:?}", Some(event))
+        );
+    }
+}
+
+#[tokio::test]
+async fn recorded_sessions_replay_to_the_same_frames() {
+    let path = temp_path("typing.jsonl");
+    let mut app = TestApp::new(typing_app().record_session(&path))
+        .await
+        .expect("start app");
+    app.type_text("hi").await.expect("type");
+    app.expect_text("typed: hi");
+    drop(app);
+
+    let recording = Recording::load(&path).expect("load recording");
+    let keys = recording
+        .events()
+        .filter(|(_, event)| matches!(event, RecordedEvent::Key { .. }))
+        .count();
+    assert_eq!(keys, 2);
+    assert!(matches!(
+        recording.entries.first(),
+        Some(RecordEntry::Frame { .. })
+    ));
+
+    let driver = PlaybackDriver::new(recording.clone()).instant();
+    typing_app()
+        .headless()
+        .with_driver(driver.clone())
+        .run()
+        .await
+        .expect("playback");
+    driver.verify().expect("frames match");
+
+    let mut diverged = recording;
+    diverged.entries.retain(|entry| {
+        !matches!(entry, RecordEntry::Event { event: RecordedEvent::Key { code, .. }, .. } if code == "char:i")
+    });
+    let driver = PlaybackDriver::new(diverged).instant();
+    typing_app()
+        .headless()
+        .with_driver(driver.clone())
+        .run()
+        .await
+        .expect("playback");
+    let err = driver
+        .verify()
+        .expect_err("missing key changes the final frame");
+    assert!(err.to_string().contains("playback diverged"));
+
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,293 @@
+use std::future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, anyhow, bail};
+use crossterm::event::EventStream;
+use futures::StreamExt;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio::sync::{Mutex, mpsc};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::events::{FrameworkEvent, map_terminal_event};
+use crate::recording::RecordedEvent;
+use crate::renderer::{Renderer, render_frame};
+use crate::runtime::{App, AppMessage, RuntimeDriver, View, spawn_tick_loop};
+
+mod patch;
+
+pub use patch::{PatchOp, apply_patch, diff_values};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RemoteFrame {
+    Full { seq: u64, view: Value },
+    Patch { seq: u64, ops: Vec<PatchOp> },
+}
+
+#[derive(Default)]
+pub struct FrameEncoder {
+    seq: u64,
+    last: Option<Value>,
+}
+
+impl FrameEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn encode(&mut self, view: &View) -> anyhow::Result<RemoteFrame> {
+        let value = serde_json::to_value(view).context("encode view")?;
+        self.seq += 1;
+        let frame = match &self.last {
+            Some(last) => RemoteFrame::Patch {
+                seq: self.seq,
+                ops: diff_values(last, &value),
+            },
+            None => RemoteFrame::Full {
+                seq: self.seq,
+                view: value.clone(),
+            },
+        };
+        self.last = Some(value);
+        Ok(frame)
+    }
+
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+#[derive(Default)]
+pub struct FrameDecoder {
+    seq: u64,
+    current: Option<Value>,
+}
+
+impl FrameDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn decode(&mut self, frame: RemoteFrame) -> anyhow::Result<View> {
+        let current = match frame {
+            RemoteFrame::Full { seq, view } => {
+                self.seq = seq;
+                self.current.insert(view)
+            }
+            RemoteFrame::Patch { seq, ops } => {
+                let Some(current) = self.current.as_mut() else {
+                    bail!("patch {seq} arrived before a full frame");
+                };
+                if seq != self.seq + 1 {
+                    bail!("patch {seq} does not follow frame {}", self.seq);
+                }
+                apply_patch(current, &ops)?;
+                self.seq = seq;
+                current
+            }
+        };
+        serde_json::from_value(current.clone()).context("decode view")
+    }
+}
+
+pub struct RemoteRenderer {
+    terminal: Terminal<TestBackend>,
+    encoder: FrameEncoder,
+    frames: mpsc::UnboundedSender<String>,
+}
+
+impl RemoteRenderer {
+    pub(crate) fn size(&self) -> anyhow::Result<Rect> {
+        Ok(self.terminal.size()?)
+    }
+
+    pub(crate) fn resize(&mut self, width: u16, height: u16) -> anyhow::Result<()> {
+        self.terminal.backend_mut().resize(width, height);
+        self.terminal.resize(Rect::new(0, 0, width, height))?;
+        self.encoder.reset();
+        Ok(())
+    }
+
+    pub(crate) fn draw(&mut self, view: &View) -> anyhow::Result<()> {
+        self.terminal
+            .draw(|frame| render_frame(frame, frame.size(), view))?;
+        let frame = self.encoder.encode(view)?;
+        let line = serde_json::to_string(&frame).context("encode remote frame")?;
+        self.frames
+            .send(line)
+            .map_err(|_| anyhow!("remote client disconnected"))
+    }
+}
+
+pub struct RemoteDriver {
+    lines: Arc<Mutex<Lines<BufReader<OwnedReadHalf>>>>,
+}
+
+impl RuntimeDriver for RemoteDriver {
+    fn spawn_terminal_events(&self, tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+        let lines = self.lines.clone();
+        tokio::spawn(async move {
+            let mut lines = lines.lock().await;
+            while let Ok(Some(line)) = lines.next_line().await {
+                let event = match serde_json::from_str::<RecordedEvent>(&line) {
+                    Ok(event) => event.to_event(),
+                    Err(err) => {
+                        warn!(error = ?err, "ignoring malformed remote event");
+                        continue;
+                    }
+                };
+                let Some(event) = event else {
+                    continue;
+                };
+                if tx.send(AppMessage::ExternalEvent(event)).await.is_err() {
+                    return;
+                }
+            }
+            debug!("remote client disconnected");
+            let _ = tx.send(AppMessage::Shutdown).await;
+        })
+    }
+
+    fn spawn_tick_loop(&self, tx: mpsc::Sender<AppMessage>, rate: Duration) -> JoinHandle<()> {
+        spawn_tick_loop(tx, rate)
+    }
+
+    fn spawn_shutdown_watcher(&self, _tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+        tokio::spawn(future::pending())
+    }
+}
+
+pub struct RemoteSession {
+    renderer: RemoteRenderer,
+    driver: RemoteDriver,
+}
+
+impl RemoteSession {
+    pub async fn accept(listener: &TcpListener) -> anyhow::Result<Self> {
+        let (stream, peer) = listener.accept().await.context("accept remote client")?;
+        debug!(%peer, "remote client connected");
+        Self::from_stream(stream).await
+    }
+
+    pub async fn from_stream(stream: TcpStream) -> anyhow::Result<Self> {
+        let (read, write) = stream.into_split();
+        let mut lines = BufReader::new(read).lines();
+        let hello = lines
+            .next_line()
+            .await?
+            .context("remote client closed before sending its size")?;
+        let RecordedEvent::Resize { width, height } =
+            serde_json::from_str(&hello).context("decode remote handshake")?
+        else {
+            bail!("the first remote message must be a resize");
+        };
+        let (frames, outgoing) = mpsc::unbounded_channel();
+        tokio::spawn(write_lines(write, outgoing));
+        let renderer = RemoteRenderer {
+            terminal: Terminal::new(TestBackend::new(width, height))
+                .context("build remote terminal")?,
+            encoder: FrameEncoder::new(),
+            frames,
+        };
+        let driver = RemoteDriver {
+            lines: Arc::new(Mutex::new(lines)),
+        };
+        Ok(Self { renderer, driver })
+    }
+
+    pub fn attach(self, app: App) -> App {
+        app.with_driver(self.driver)
+            .with_renderer(Renderer::remote(self.renderer))
+    }
+}
+
+async fn write_lines(mut write: OwnedWriteHalf, mut outgoing: mpsc::UnboundedReceiver<String>) {
+    while let Some(mut line) = outgoing.recv().await {
+        line.push('\n');
+        if write.write_all(line.as_bytes()).await.is_err() {
+            break;
+        }
+    }
+}
+
+pub struct RemoteClient {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    write: OwnedWriteHalf,
+    decoder: FrameDecoder,
+}
+
+impl RemoteClient {
+    pub async fn connect(
+        addr: impl ToSocketAddrs,
+        width: u16,
+        height: u16,
+    ) -> anyhow::Result<Self> {
+        let stream = TcpStream::connect(addr)
+            .await
+            .context("connect to remote app")?;
+        let (read, write) = stream.into_split();
+        let mut client = Self {
+            lines: BufReader::new(read).lines(),
+            write,
+            decoder: FrameDecoder::new(),
+        };
+        client.send(&FrameworkEvent::Resize(width, height)).await?;
+        Ok(client)
+    }
+
+    pub async fn next_view(&mut self) -> anyhow::Result<Option<View>> {
+        let Some(line) = self.lines.next_line().await? else {
+            return Ok(None);
+        };
+        let frame = serde_json::from_str(&line).context("decode remote frame")?;
+        self.decoder.decode(frame).map(Some)
+    }
+
+    pub async fn send(&mut self, event: &FrameworkEvent) -> anyhow::Result<()> {
+        let Some(event) = RecordedEvent::from_event(event) else {
+            return Ok(());
+        };
+        let mut line = serde_json::to_string(&event)?;
+        line.push('\n');
+        self.write
+            .write_all(line.as_bytes())
+            .await
+            .context("send event to remote app")
+    }
+}
+
+pub async fn run_client(addr: impl ToSocketAddrs) -> anyhow::Result<()> {
+    let mut renderer = Renderer::new("rustact remote")?;
+    let size = renderer.size()?;
+    let mut client = RemoteClient::connect(addr, size.width, size.height).await?;
+    let mut events = EventStream::new();
+    loop {
+        tokio::select! {
+            view = client.next_view() => match view? {
+                Some(view) => renderer.draw(&view)?,
+                None => return Ok(()),
+            },
+            event = events.next() => match event {
+                Some(Ok(event)) => {
+                    if let Some(event) = map_terminal_event(event) {
+                        client.send(&event).await?;
+                    }
+                }
+                Some(Err(err)) => return Err(err).context("read terminal event"),
+                None => return Ok(()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
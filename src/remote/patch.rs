@@ -0,0 +1,56 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PatchOp {
+    pub path: String,
+    pub value: Value,
+}
+
+pub fn diff_values(old: &Value, new: &Value) -> Vec<PatchOp> {
+    let mut ops = Vec::new();
+    diff_into(old, new, &mut String::new(), &mut ops);
+    ops
+}
+
+pub fn apply_patch(target: &mut Value, ops: &[PatchOp]) -> anyhow::Result<()> {
+    for op in ops {
+        let slot = target
+            .pointer_mut(&op.path)
+            .with_context(|| format!("patch path `{}` does not exist", op.path))?;
+        *slot = op.value.clone();
+    }
+    Ok(())
+}
+
+fn diff_into(old: &Value, new: &Value, path: &mut String, ops: &mut Vec<PatchOp>) {
+    if old == new {
+        return;
+    }
+    let len = path.len();
+    match (old, new) {
+        (Value::Object(before), Value::Object(after))
+            if before.len() == after.len() && after.keys().all(|key| before.contains_key(key)) =>
+        {
+            for (key, value) in after {
+                path.push('/');
+                path.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                diff_into(&before[key], value, path, ops);
+                path.truncate(len);
+            }
+        }
+        (Value::Array(before), Value::Array(after)) if before.len() == after.len() => {
+            for (index, (before, after)) in before.iter().zip(after).enumerate() {
+                path.push('/');
+                path.push_str(&index.to_string());
+                diff_into(before, after, path, ops);
+                path.truncate(len);
+            }
+        }
+        _ => ops.push(PatchOp {
+            path: path.clone(),
+            value: new.clone(),
+        }),
+    }
+}
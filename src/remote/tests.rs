@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde_json::json;
+use tokio::net::TcpListener;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::timeout;
+
+use super::{FrameDecoder, FrameEncoder, RemoteClient, RemoteFrame, RemoteSession};
+use super::{apply_patch, diff_values};
+use crate::events::FrameworkEvent;
+use crate::runtime::{App, Element, ListItemNode, TextView, View, VirtualRows, component};
+
+#[test]
+fn diff_values_replaces_only_changed_leaves() {
+    let old = json!({"Flex": {"children": [{"Text": {"content": "a"}}, "Empty"], "gap": 0}});
+    let new = json!({"Flex": {"children": [{"Text": {"content": "b"}}, "Empty"], "gap": 1}});
+
+    let ops = diff_values(&old, &new);
+    let paths: Vec<_> = ops.iter().map(|op| op.path.as_str()).collect();
+    assert_eq!(paths, ["/Flex/children/0/Text/content", "/Flex/gap"]);
+
+    let mut patched = old.clone();
+    apply_patch(&mut patched, &ops).expect("apply patch");
+    assert_eq!(patched, new);
+    assert_eq!(diff_values(&json!([1]), &json!([1, 2]))[0].path, "");
+}
+
+#[test]
+fn frames_round_trip_views_as_full_frames_then_patches() {
+    let text = |content: &str| {
+        View::Text(TextView {
+            content: content.into(),
+            color: None,
+            modifiers: Default::default(),
+            overflow: Default::default(),
+            id: None,
+            classes: Vec::new(),
+        })
+    };
+    let mut encoder = FrameEncoder::new();
+    let mut decoder = FrameDecoder::new();
+
+    let first = encoder.encode(&text("one")).expect("encode");
+    assert!(matches!(first, RemoteFrame::Full { seq: 1, .. }));
+    assert_eq!(decoder.decode(first).expect("decode"), text("one"));
+
+    let second = encoder.encode(&text("two")).expect("encode");
+    assert!(matches!(&second, RemoteFrame::Patch { seq: 2, ops } if ops.len() == 1));
+    assert_eq!(decoder.decode(second.clone()).expect("decode"), text("two"));
+    assert!(decoder.decode(second).is_err());
+}
+
+#[test]
+fn shared_views_travel_as_the_view_they_wrap() {
+    let text = View::Text(TextView {
+        content: "cached".into(),
+        color: None,
+        modifiers: Default::default(),
+        overflow: Default::default(),
+        id: None,
+        classes: Vec::new(),
+    });
+    let shared = View::Shared(std::sync::Arc::new(text.clone()));
+
+    let value = serde_json::to_value(&shared).expect("encode view");
+    assert_eq!(value, serde_json::to_value(&text).expect("encode text"));
+    let decoded: View = serde_json::from_value(value).expect("decode view");
+    assert_eq!(decoded, text);
+}
+
+#[test]
+fn virtual_rows_travel_as_a_screenful_of_built_rows() {
+    let rows = VirtualRows {
+        count: 100_000,
+        start: 40,
+        factory: std::sync::Arc::new(|index| ListItemNode::new(format!("row {index}"))),
+    };
+
+    let screenful = rows.rows(256).len();
+    let value = serde_json::to_value(&rows).expect("encode rows");
+    assert_eq!(value["items"].as_array().map(Vec::len), Some(screenful));
+    let decoded: VirtualRows = serde_json::from_value(value).expect("decode rows");
+    assert_eq!(decoded.start, 40);
+    assert_eq!(decoded.rows(3), rows.rows(3));
+    assert_eq!(decoded.rows(300).len(), screenful);
+}
+
+#[tokio::test]
+async fn remote_sessions_stream_views_and_receive_client_events() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().expect("local addr");
+    let server = tokio::spawn(async move {
+        let session = RemoteSession::accept(&listener).await?;
+        session.attach(counter_app()).run().await
+    });
+
+    let mut client = RemoteClient::connect(addr, 20, 2).await.expect("connect");
+    let next_text = async |client: &mut RemoteClient| {
+        let view = timeout(Duration::from_secs(1), client.next_view())
+            .await
+            .expect("frame arrives")
+            .expect("frame decodes")
+            .expect("server is still running");
+        match view {
+            View::Text(text) => text.content,
+            other => panic!("expected text, got {other:?}"),
+        }
+    };
+    assert_eq!(next_text(&mut client).await, "count 0");
+
+    let key = |code, modifiers| FrameworkEvent::Key(KeyEvent::new(code, modifiers));
+    client
+        .send(&key(KeyCode::Char('+'), KeyModifiers::NONE))
+        .await
+        .expect("send key");
+    assert_eq!(next_text(&mut client).await, "count 1");
+
+    client
+        .send(&key(KeyCode::Char('c'), KeyModifiers::CONTROL))
+        .await
+        .expect("send ctrl+c");
+    let code = timeout(Duration::from_secs(1), server)
+        .await
+        .expect("server stops")
+        .expect("server task")
+        .expect("app run succeeds");
+    assert_eq!(code, 0);
+    assert!(client.next_view().await.expect("clean close").is_none());
+}
+
+fn counter_app() -> App {
+    App::new(
+        "RemoteTest",
+        component("Counter", |ctx| {
+            let (count, set_count) = ctx.use_state(|| 0);
+            ctx.use_effect((), move |dispatcher| {
+                let mut events = dispatcher.events().subscribe();
+                let handle = tokio::spawn(async move {
+                    loop {
+                        match events.recv().await {
+                            Ok(FrameworkEvent::Key(key)) if key.code == KeyCode::Char('+') => {
+                                set_count.update(|count| *count += 1);
+                            }
+                            Ok(_) | Err(RecvError::Lagged(_)) => {}
+                            Err(RecvError::Closed) => break,
+                        }
+                    }
+                });
+                Some(Box::new(move || handle.abort()))
+            });
+            Element::text(format!("count {count}"))
+        }),
+    )
+}
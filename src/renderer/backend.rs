@@ -0,0 +1,46 @@
+use ratatui::Frame;
+use ratatui::Terminal;
+use ratatui::backend::Backend;
+use ratatui::layout::Rect;
+
+pub trait RendererBackend: Send {
+    fn size(&self) -> anyhow::Result<Rect>;
+
+    fn draw(&mut self, render: &mut dyn FnMut(&mut Frame<'_>)) -> anyhow::Result<()>;
+
+    fn resize(&mut self, _width: u16, _height: u16) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn suspend(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn resume(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+impl<B> RendererBackend for Terminal<B>
+where
+    B: Backend + Send,
+{
+    fn size(&self) -> anyhow::Result<Rect> {
+        Ok(Terminal::size(self)?)
+    }
+
+    fn draw(&mut self, render: &mut dyn FnMut(&mut Frame<'_>)) -> anyhow::Result<()> {
+        Terminal::draw(self, |frame| render(frame))?;
+        Ok(())
+    }
+
+    fn resize(&mut self, width: u16, height: u16) -> anyhow::Result<()> {
+        Terminal::resize(self, Rect::new(0, 0, width, height))?;
+        Ok(())
+    }
+
+    fn resume(&mut self) -> anyhow::Result<()> {
+        self.clear()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use std::env;
+use std::time::Duration;
+
+use crate::styles::ColorScheme;
+
+// Slow links (SSH, multiplexers) can answer late. Waiting for the full reply keeps it from
+// reaching the event reader as stray key presses.
+const QUERY_TIMEOUT: Duration = Duration::from_millis(500);
+
+pub(crate) fn detect_color_scheme() -> Option<ColorScheme> {
+    query_background()
+        .as_deref()
+        .and_then(ColorScheme::from_osc11)
+        .or_else(|| {
+            env::var("COLORFGBG")
+                .ok()
+                .as_deref()
+                .and_then(ColorScheme::from_colorfgbg)
+        })
+}
+
+#[cfg(unix)]
+fn query_background() -> Option<String> {
+    use std::fs::OpenOptions;
+    use std::io::{Read, Write};
+    use std::os::fd::AsRawFd;
+    use std::time::Instant;
+
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    // Ask for the background colour, then for the device attributes. Every terminal answers the
+    // second query, so its reply marks the end of the response even when OSC 11 is unsupported.
+    tty.write_all(b"\x1b]11;?\x1b\\\x1b[c").ok()?;
+    tty.flush().ok()?;
+
+    let deadline = Instant::now() + QUERY_TIMEOUT;
+    let mut response = Vec::new();
+    let mut buffer = [0u8; 64];
+    while !ends_with_device_attributes(&response) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        let mut fd = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: `fd` points at a single valid pollfd for the duration of the call.
+        let ready = unsafe { libc::poll(&mut fd, 1, remaining.as_millis() as libc::c_int) };
+        if ready <= 0 {
+            break;
+        }
+        match tty.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => response.extend_from_slice(&buffer[..read]),
+        }
+    }
+    if !ends_with_device_attributes(&response) {
+        // Drop whatever part of the reply is still queued instead of handing it to the app.
+        // SAFETY: the descriptor stays open for the duration of the call.
+        unsafe { libc::tcflush(tty.as_raw_fd(), libc::TCIFLUSH) };
+    }
+    String::from_utf8(response).ok()
+}
+
+#[cfg(not(unix))]
+fn query_background() -> Option<String> {
+    None
+}
+
+#[cfg(unix)]
+fn ends_with_device_attributes(response: &[u8]) -> bool {
+    response.ends_with(b"c") && response.windows(3).any(|window| window == b"\x1b[?")
+}
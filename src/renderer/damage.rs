@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::mem;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::Rect;
+
+use crate::interactions::{Hitbox, register_region, register_surface};
+use crate::runtime::{Current, Registries, View};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DamageStats {
+    pub rendered: usize,
+    pub reused: usize,
+    pub reused_cells: usize,
+    pub draw_time: Duration,
+}
+
+pub(crate) struct DamageCache {
+    state: Mutex<DamageState>,
+}
+
+#[derive(Default)]
+struct DamageState {
+    previous: HashMap<Rect, Vec<Snapshot>>,
+    current: HashMap<Rect, Vec<Snapshot>>,
+    frame: DamageStats,
+    last: DamageStats,
+    recording: usize,
+    regions: Vec<(String, Hitbox)>,
+    surfaces: Vec<Hitbox>,
+}
+
+// A snapshot without cells marks an area whose view changed on the last frame,
+// so its children get the chance to be cached instead. Shared views are keyed
+// by address, so the snapshot holds on to them.
+struct Snapshot {
+    key: u64,
+    _pinned: Vec<Arc<View>>,
+    before: Option<Vec<Cell>>,
+    after: Option<Vec<Cell>>,
+    regions: Vec<(String, Hitbox)>,
+    surfaces: Vec<Hitbox>,
+}
+
+pub(crate) enum Damage {
+    Reused,
+    Untracked,
+    Dirty(Pending),
+}
+
+pub(crate) struct Pending {
+    area: Rect,
+    key: u64,
+    pinned: Vec<Arc<View>>,
+    before: Option<Vec<Cell>>,
+}
+
+impl DamageCache {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Mutex::new(DamageState::default()),
+        }
+    }
+
+    pub(crate) fn global() -> Current<Self> {
+        Registries::current(|registries| &registries.damage)
+    }
+
+    pub(crate) fn begin_frame(&self) {
+        let mut state = self.state.lock();
+        let state = &mut *state;
+        state.previous = mem::take(&mut state.current);
+        state.frame = DamageStats::default();
+        state.recording = 0;
+        state.regions.clear();
+        state.surfaces.clear();
+    }
+
+    pub(crate) fn end_frame(&self, draw_time: Duration) {
+        let mut state = self.state.lock();
+        state.previous.clear();
+        state.last = DamageStats {
+            draw_time,
+            ..state.frame
+        };
+    }
+
+    pub(crate) fn stats(&self) -> DamageStats {
+        self.state.lock().last
+    }
+
+    pub(crate) fn check(&self, buffer: &mut Buffer, area: Rect, view: &View) -> Damage {
+        let area = area.intersection(buffer.area);
+        let mut state = self.state.lock();
+        let state = &mut *state;
+        // Only the outermost static view is recorded; its snapshot covers the whole subtree.
+        if area.is_empty() || state.recording > 0 || !is_static(view) {
+            state.frame.rendered += 1;
+            return Damage::Untracked;
+        }
+        let (key, pinned) = fingerprint(view);
+        let snapshots = state.previous.entry(area).or_default();
+        let snapshot = match snapshots.iter().position(|snapshot| snapshot.key == key) {
+            Some(index) => Some(snapshots.swap_remove(index)),
+            None if snapshots.is_empty() => None,
+            None => {
+                snapshots.swap_remove(0);
+                state.frame.rendered += 1;
+                state.current.entry(area).or_default().push(Snapshot {
+                    key,
+                    _pinned: pinned,
+                    before: None,
+                    after: None,
+                    regions: Vec::new(),
+                    surfaces: Vec::new(),
+                });
+                return Damage::Untracked;
+            }
+        };
+        match snapshot {
+            Some(snapshot)
+                if snapshot.after.is_some()
+                    && same_underlay(buffer, area, snapshot.before.as_deref()) =>
+            {
+                let after = snapshot.after.as_deref().unwrap_or_default();
+                restore(buffer, area, after);
+                state.frame.reused += count(view);
+                state.frame.reused_cells += after.len();
+                for (id, hitbox) in &snapshot.regions {
+                    register_region(id, *hitbox);
+                }
+                for hitbox in &snapshot.surfaces {
+                    register_surface(*hitbox);
+                }
+                state.current.entry(area).or_default().push(snapshot);
+                let current = &mut state.current;
+                state.previous.retain(|rect, snapshots| {
+                    if rect.intersection(area) != *rect {
+                        return true;
+                    }
+                    current.entry(*rect).or_default().append(snapshots);
+                    false
+                });
+                Damage::Reused
+            }
+            _ => {
+                state.frame.rendered += 1;
+                state.recording += 1;
+                Damage::Dirty(Pending {
+                    area,
+                    key,
+                    pinned,
+                    before: underlay(buffer, area),
+                })
+            }
+        }
+    }
+
+    // Ids and surfaces drawn inside a recorded view are registered again when it is reused.
+    pub(crate) fn capture_region(&self, id: &str, hitbox: Hitbox) {
+        let mut state = self.state.lock();
+        if state.recording > 0 {
+            state.regions.push((id.to_string(), hitbox));
+        }
+    }
+
+    pub(crate) fn capture_surface(&self, hitbox: Hitbox) {
+        let mut state = self.state.lock();
+        if state.recording > 0 {
+            state.surfaces.push(hitbox);
+        }
+    }
+
+    pub(crate) fn record(&self, buffer: &Buffer, pending: Pending) {
+        let mut state = self.state.lock();
+        state.recording -= 1;
+        let snapshot = Snapshot {
+            key: pending.key,
+            _pinned: pending.pinned,
+            before: pending.before,
+            after: Some(cells(buffer, pending.area)),
+            regions: mem::take(&mut state.regions),
+            surfaces: mem::take(&mut state.surfaces),
+        };
+        state
+            .current
+            .entry(pending.area)
+            .or_default()
+            .push(snapshot);
+    }
+}
+
+fn is_static(view: &View) -> bool {
+    match view {
+        View::Empty
+        | View::Text(_)
+        | View::Paragraph(_)
+        | View::Gauge(_)
+        | View::Spinner(_)
+        | View::Progress(_)
+        | View::StatusBar(_)
+        | View::KeyHints(_)
+        | View::Stepper(_)
+        | View::Form(_) => true,
+        // Lists with an id register row hitboxes while they draw, and virtual
+        // lists only build their rows then.
+        View::List(list) => list.id.is_none() && list.virtual_rows.is_none(),
+        View::Flex(flex) => flex.children.iter().all(is_static),
+        View::Sized(sized) => is_static(&sized.content),
+        View::Constrained(constrained) => is_static(&constrained.content),
+        View::Block(block) => block.child.as_deref().is_none_or(is_static),
+        View::Shared(shared) => is_static(shared),
+        _ => false,
+    }
+}
+
+fn count(view: &View) -> usize {
+    1 + match view {
+        View::Flex(flex) => flex.children.iter().map(count).sum(),
+        View::Sized(sized) => count(&sized.content),
+        View::Constrained(constrained) => count(&constrained.content),
+        View::Block(block) => block.child.as_deref().map_or(0, count),
+        View::Shared(shared) => count(shared) - 1,
+        _ => 0,
+    }
+}
+
+// Hashes the fields of static views directly; other views are never keyed.
+fn fingerprint(view: &View) -> (u64, Vec<Arc<View>>) {
+    let mut hasher = DefaultHasher::new();
+    let mut pinned = Vec::new();
+    hash_view(view, &mut hasher, &mut pinned);
+    (hasher.finish(), pinned)
+}
+
+fn hash_view(view: &View, state: &mut DefaultHasher, pinned: &mut Vec<Arc<View>>) {
+    mem::discriminant(view).hash(state);
+    match view {
+        View::Text(text) => text.hash(state),
+        View::Paragraph(paragraph) => paragraph.hash(state),
+        View::Spinner(spinner) => spinner.hash(state),
+        View::StatusBar(status_bar) => status_bar.hash(state),
+        View::KeyHints(hints) => hints.hash(state),
+        View::Stepper(stepper) => stepper.hash(state),
+        View::Form(form) => form.hash(state),
+        View::Gauge(gauge) => {
+            gauge.ratio.to_bits().hash(state);
+            (&gauge.label, gauge.color, &gauge.id, &gauge.classes).hash(state);
+        }
+        View::Progress(progress) => {
+            progress.ratio.map(f64::to_bits).hash(state);
+            (
+                progress.phase,
+                progress.style,
+                &progress.label,
+                progress.color,
+            )
+                .hash(state);
+            (&progress.id, &progress.classes).hash(state);
+        }
+        View::List(list) => {
+            (
+                &list.title,
+                &list.items,
+                list.highlight,
+                list.highlight_style,
+            )
+                .hash(state);
+            (list.hovered, list.hover_style, list.offset, &list.checked).hash(state);
+            (list.border_style, &list.id, &list.classes).hash(state);
+        }
+        View::Flex(flex) => {
+            (flex.direction, &flex.constraints, flex.gap, flex.padding).hash(state);
+            (flex.margin, flex.justify_content, flex.align_items).hash(state);
+            (flex.background_color, &flex.id, &flex.classes).hash(state);
+            flex.children.len().hash(state);
+            for child in &flex.children {
+                hash_view(child, state, pinned);
+            }
+        }
+        View::Sized(sized) => {
+            sized.constraint.hash(state);
+            hash_view(&sized.content, state, pinned);
+        }
+        View::Constrained(constrained) => {
+            constrained.dimensions.hash(state);
+            hash_view(&constrained.content, state, pinned);
+        }
+        View::Block(block) => {
+            (
+                &block.title,
+                block.padding,
+                block.margin,
+                block.border_style,
+            )
+                .hash(state);
+            (block.background_color, &block.id, &block.classes).hash(state);
+            if let Some(child) = &block.child {
+                hash_view(child, state, pinned);
+            }
+        }
+        // A memoized subtree hands back the same `Arc` until it renders again.
+        View::Shared(shared) => {
+            Arc::as_ptr(shared).hash(state);
+            pinned.push(shared.clone());
+        }
+        _ => {}
+    }
+}
+
+// The cells a view was drawn over. Frames start from an empty buffer, so this
+// is usually `None`, meaning the area was still blank.
+fn underlay(buffer: &Buffer, area: Rect) -> Option<Vec<Cell>> {
+    (!is_blank(buffer, area)).then(|| cells(buffer, area))
+}
+
+fn same_underlay(buffer: &Buffer, area: Rect, before: Option<&[Cell]>) -> bool {
+    match before {
+        None => is_blank(buffer, area),
+        Some(before) => rows(buffer, area)
+            .zip(before.chunks(usize::from(area.width)))
+            .all(|(row, before)| row == before),
+    }
+}
+
+fn is_blank(buffer: &Buffer, area: Rect) -> bool {
+    let blank = Cell::default();
+    rows(buffer, area).all(|row| row.iter().all(|cell| *cell == blank))
+}
+
+fn rows(buffer: &Buffer, area: Rect) -> impl Iterator<Item = &[Cell]> {
+    (area.top()..area.bottom()).map(move |y| {
+        let start = buffer.index_of(area.x, y);
+        &buffer.content[start..start + usize::from(area.width)]
+    })
+}
+
+fn cells(buffer: &Buffer, area: Rect) -> Vec<Cell> {
+    rows(buffer, area).flatten().cloned().collect()
+}
+
+fn restore(buffer: &mut Buffer, area: Rect, cells: &[Cell]) {
+    for (y, row) in (area.top()..area.bottom()).zip(cells.chunks(usize::from(area.width))) {
+        let start = buffer.index_of(area.x, y);
+        buffer.content[start..start + row.len()].clone_from_slice(row);
+    }
+}
@@ -0,0 +1,360 @@
+use std::fmt::Write;
+
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use unicode_width::UnicodeWidthStr;
+
+type Rgb = (u8, u8, u8);
+
+const DEFAULT_FG: Rgb = (0xd4, 0xd4, 0xd4);
+const DEFAULT_BG: Rgb = (0x1e, 0x1e, 0x1e);
+const CELL_WIDTH: usize = 9;
+const CELL_HEIGHT: usize = 18;
+const FONT_SIZE: usize = 15;
+const FONT_FAMILY: &str = "ui-monospace, Menlo, Consolas, monospace";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Ansi,
+    Html,
+    Svg,
+}
+
+struct Run {
+    column: usize,
+    columns: usize,
+    text: String,
+    fg: Color,
+    bg: Color,
+    modifier: Modifier,
+}
+
+impl Run {
+    fn same_style(&self, fg: Color, bg: Color, modifier: Modifier) -> bool {
+        self.fg == fg && self.bg == bg && self.modifier == modifier
+    }
+
+    fn is_blank(&self) -> bool {
+        self.bg == Color::Reset
+            && !self
+                .modifier
+                .intersects(Modifier::REVERSED | Modifier::UNDERLINED)
+            && self.text.trim().is_empty()
+    }
+
+    fn colors(&self) -> (Option<Rgb>, Option<Rgb>) {
+        let (fg, bg) = (rgb(self.fg), rgb(self.bg));
+        if self.modifier.contains(Modifier::REVERSED) {
+            (
+                Some(bg.unwrap_or(DEFAULT_BG)),
+                Some(fg.unwrap_or(DEFAULT_FG)),
+            )
+        } else {
+            (fg, bg)
+        }
+    }
+}
+
+pub(crate) fn export(buffer: &Buffer, format: ExportFormat) -> String {
+    let rows = rows(buffer);
+    match format {
+        ExportFormat::Ansi => ansi(&rows),
+        ExportFormat::Html => html(&rows),
+        ExportFormat::Svg => svg(&rows, usize::from(buffer.area.width)),
+    }
+}
+
+fn rows(buffer: &Buffer) -> Vec<Vec<Run>> {
+    let width = usize::from(buffer.area.width.max(1));
+    buffer
+        .content
+        .chunks(width)
+        .map(|cells| {
+            let mut runs: Vec<Run> = Vec::new();
+            let mut skip = 0usize;
+            for (column, cell) in cells.iter().enumerate() {
+                if skip > 0 {
+                    skip -= 1;
+                    if let Some(run) = runs.last_mut() {
+                        run.columns += 1;
+                    }
+                    continue;
+                }
+                skip = cell.symbol().width().saturating_sub(1);
+                match runs.last_mut() {
+                    Some(run) if run.same_style(cell.fg, cell.bg, cell.modifier) => {
+                        run.text.push_str(cell.symbol());
+                        run.columns += 1;
+                    }
+                    _ => runs.push(Run {
+                        column,
+                        columns: 1,
+                        text: cell.symbol().to_string(),
+                        fg: cell.fg,
+                        bg: cell.bg,
+                        modifier: cell.modifier,
+                    }),
+                }
+            }
+            while runs.last().is_some_and(Run::is_blank) {
+                runs.pop();
+            }
+            if let Some(run) = runs.last_mut() {
+                if run.bg == Color::Reset && !run.modifier.contains(Modifier::REVERSED) {
+                    let trimmed = run.text.trim_end().len();
+                    run.columns -= run.text.len() - trimmed;
+                    run.text.truncate(trimmed);
+                }
+            }
+            runs
+        })
+        .collect()
+}
+
+fn ansi(rows: &[Vec<Run>]) -> String {
+    let mut out = String::new();
+    for row in rows {
+        for run in row {
+            let mut codes = vec!["0".to_string()];
+            codes.extend(ansi_modifiers(run.modifier).iter().map(ToString::to_string));
+            codes.extend(ansi_color(run.fg, 30));
+            codes.extend(ansi_color(run.bg, 40));
+            let _ = write!(out, "\x1b[{}m{}", codes.join(";"), run.text);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+fn ansi_modifiers(modifier: Modifier) -> Vec<u8> {
+    [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::RAPID_BLINK, 6),
+        (Modifier::REVERSED, 7),
+        (Modifier::HIDDEN, 8),
+        (Modifier::CROSSED_OUT, 9),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| modifier.contains(*flag))
+    .map(|(_, code)| code)
+    .collect()
+}
+
+fn ansi_color(color: Color, base: u8) -> Option<String> {
+    let code = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{r};{g};{b}", base + 8)),
+        Color::Indexed(index) => return Some(format!("{};5;{index}", base + 8)),
+        Color::Black => base,
+        Color::Red => base + 1,
+        Color::Green => base + 2,
+        Color::Yellow => base + 3,
+        Color::Blue => base + 4,
+        Color::Magenta => base + 5,
+        Color::Cyan => base + 6,
+        Color::Gray => base + 7,
+        Color::DarkGray => base + 60,
+        Color::LightRed => base + 61,
+        Color::LightGreen => base + 62,
+        Color::LightYellow => base + 63,
+        Color::LightBlue => base + 64,
+        Color::LightMagenta => base + 65,
+        Color::LightCyan => base + 66,
+        Color::White => base + 67,
+    };
+    Some(code.to_string())
+}
+
+fn html(rows: &[Vec<Run>]) -> String {
+    let mut out = format!(
+        "<pre class=\"rustact-frame\" style=\"background:{};color:{};font-family:{FONT_FAMILY};line-height:1.2\">",
+        hex(DEFAULT_BG),
+        hex(DEFAULT_FG),
+    );
+    for (index, row) in rows.iter().enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        for run in row {
+            let mut style = Vec::new();
+            let (fg, bg) = run.colors();
+            if let Some(fg) = fg {
+                style.push(format!("color:{}", hex(fg)));
+            }
+            if let Some(bg) = bg {
+                style.push(format!("background:{}", hex(bg)));
+            }
+            style.extend(css_modifiers(run.modifier));
+            if style.is_empty() {
+                out.push_str(&escape(&run.text));
+            } else {
+                let _ = write!(
+                    out,
+                    "<span style=\"{}\">{}</span>",
+                    style.join(";"),
+                    escape(&run.text)
+                );
+            }
+        }
+    }
+    out.push_str("</pre>\n");
+    out
+}
+
+fn svg(rows: &[Vec<Run>], width: usize) -> String {
+    let (width, height) = (width * CELL_WIDTH, rows.len() * CELL_HEIGHT);
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" font-family=\"{FONT_FAMILY}\" font-size=\"{FONT_SIZE}\">\n<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+        hex(DEFAULT_BG),
+    );
+    for (line, row) in rows.iter().enumerate() {
+        let y = line * CELL_HEIGHT;
+        for run in row {
+            let (fg, bg) = run.colors();
+            let (x, run_width) = (run.column * CELL_WIDTH, run.columns * CELL_WIDTH);
+            if let Some(bg) = bg {
+                let _ = writeln!(
+                    out,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{run_width}\" height=\"{CELL_HEIGHT}\" fill=\"{}\"/>",
+                    hex(bg)
+                );
+            }
+            if run.text.trim().is_empty() || run.modifier.contains(Modifier::HIDDEN) {
+                continue;
+            }
+            let mut attributes = format!(" fill=\"{}\"", hex(fg.unwrap_or(DEFAULT_FG)));
+            if run.modifier.contains(Modifier::BOLD) {
+                attributes.push_str(" font-weight=\"bold\"");
+            }
+            if run.modifier.contains(Modifier::ITALIC) {
+                attributes.push_str(" font-style=\"italic\"");
+            }
+            if run.modifier.contains(Modifier::DIM) {
+                attributes.push_str(" opacity=\"0.6\"");
+            }
+            if let Some(decoration) = text_decoration(run.modifier) {
+                let _ = write!(attributes, " text-decoration=\"{decoration}\"");
+            }
+            let _ = writeln!(
+                out,
+                "<text x=\"{x}\" y=\"{}\" textLength=\"{run_width}\" lengthAdjust=\"spacingAndGlyphs\" xml:space=\"preserve\"{attributes}>{}</text>",
+                y + CELL_HEIGHT - 4,
+                escape(&run.text)
+            );
+        }
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+fn css_modifiers(modifier: Modifier) -> Vec<String> {
+    let mut style = Vec::new();
+    if modifier.contains(Modifier::BOLD) {
+        style.push("font-weight:bold".to_string());
+    }
+    if modifier.contains(Modifier::ITALIC) {
+        style.push("font-style:italic".to_string());
+    }
+    if modifier.contains(Modifier::DIM) {
+        style.push("opacity:0.6".to_string());
+    }
+    if modifier.contains(Modifier::HIDDEN) {
+        style.push("visibility:hidden".to_string());
+    }
+    if let Some(decoration) = text_decoration(modifier) {
+        style.push(format!("text-decoration:{decoration}"));
+    }
+    style
+}
+
+fn text_decoration(modifier: Modifier) -> Option<&'static str> {
+    match (
+        modifier.contains(Modifier::UNDERLINED),
+        modifier.contains(Modifier::CROSSED_OUT),
+    ) {
+        (true, true) => Some("underline line-through"),
+        (true, false) => Some("underline"),
+        (false, true) => Some("line-through"),
+        (false, false) => None,
+    }
+}
+
+fn rgb(color: Color) -> Option<Rgb> {
+    let rgb = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index) => return Some(indexed(index)),
+        Color::Black => (0x00, 0x00, 0x00),
+        Color::Red => (0xcd, 0x31, 0x31),
+        Color::Green => (0x0d, 0xbc, 0x79),
+        Color::Yellow => (0xe5, 0xe5, 0x10),
+        Color::Blue => (0x24, 0x72, 0xc8),
+        Color::Magenta => (0xbc, 0x3f, 0xbc),
+        Color::Cyan => (0x11, 0xa8, 0xcd),
+        Color::Gray => (0xe5, 0xe5, 0xe5),
+        Color::DarkGray => (0x66, 0x66, 0x66),
+        Color::LightRed => (0xf1, 0x4c, 0x4c),
+        Color::LightGreen => (0x23, 0xd1, 0x8b),
+        Color::LightYellow => (0xf5, 0xf5, 0x43),
+        Color::LightBlue => (0x3b, 0x8e, 0xea),
+        Color::LightMagenta => (0xd6, 0x70, 0xd6),
+        Color::LightCyan => (0x29, 0xb8, 0xdb),
+        Color::White => (0xff, 0xff, 0xff),
+    };
+    Some(rgb)
+}
+
+fn indexed(index: u8) -> Rgb {
+    const NAMED: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+        Color::DarkGray,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ];
+    match index {
+        0..=15 => rgb(NAMED[usize::from(index)]).unwrap_or(DEFAULT_FG),
+        16..=231 => {
+            let level = |value: u8| if value == 0 { 0 } else { value * 40 + 55 };
+            let index = index - 16;
+            (level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        _ => {
+            let gray = (index - 232) * 10 + 8;
+            (gray, gray, gray)
+        }
+    }
+}
+
+fn hex((r, g, b): Rgb) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,177 @@
+use ratatui::layout::Constraint;
+use unicode_width::UnicodeWidthStr;
+
+use crate::runtime::{FlexDirection, View};
+
+pub(crate) fn measure_height(view: &View) -> u16 {
+    match view {
+        View::Empty | View::ToastStack(_) | View::Anchored(_) => 0,
+        View::Paragraph(paragraph) => paragraph.content.lines().count().max(1) as u16,
+        View::Text(_)
+        | View::Gauge(_)
+        | View::Checkbox(_)
+        | View::Spinner(_)
+        | View::MenuBar(_)
+        | View::StatusBar(_)
+        | View::KeyHints(_)
+        | View::Progress(_) => 1,
+        View::Flex(flex) => {
+            let heights = flex.children.iter().enumerate().map(|(index, child)| {
+                match flex.constraints.get(index).copied().flatten() {
+                    Some(constraint) => constrained_height(constraint, measure_height(child)),
+                    None => measure_height(child),
+                }
+            });
+            let content = match flex.direction {
+                FlexDirection::Column => heights.fold(0u16, u16::saturating_add).saturating_add(
+                    flex.gap
+                        .saturating_mul(flex.children.len().saturating_sub(1) as u16),
+                ),
+                FlexDirection::Row => heights.max().unwrap_or(0),
+            };
+            content
+                .saturating_add(flex.padding.vertical())
+                .saturating_add(flex.margin.vertical())
+        }
+        View::Block(block) => block
+            .child
+            .as_deref()
+            .map(measure_height)
+            .unwrap_or(0)
+            .saturating_add(block.border_style.edges())
+            .saturating_add(block.padding.vertical())
+            .saturating_add(block.margin.vertical()),
+        View::List(list) => {
+            let rows = match &list.virtual_rows {
+                Some(rows) => rows.count - rows.start,
+                None => list.items.len(),
+            };
+            u16::try_from(rows.max(1))
+                .unwrap_or(u16::MAX)
+                .saturating_add(list.border_style.edges())
+        }
+        View::Button(_) => 3,
+        View::Table(table) => {
+            let header = u16::from(table.header.is_some());
+            (table.rows.len().max(1) as u16)
+                .saturating_add(header)
+                .saturating_add(table.border_style.edges())
+        }
+        View::Tree(tree) => (tree.rows.len().max(1) as u16).saturating_add(2),
+        View::Form(form) => (form.fields.len().max(1) as u16).saturating_add(2),
+        View::Input(input) => 1 + input.border_style.edges() + u16::from(input.label.is_some()),
+        View::Select(_) | View::TimePicker(_) => 3,
+        View::Stepper(stepper) => 1 + u16::from(stepper.error.is_some()),
+        View::TextArea(text_area) => text_area.height.unwrap_or(5),
+        View::Tabs(tabs) => {
+            let active = tabs
+                .tabs
+                .get(tabs.active)
+                .map(|tab| measure_height(&tab.content))
+                .unwrap_or(0);
+            active.saturating_add(3)
+        }
+        View::Layered(layers) => layers.layers.iter().map(measure_height).max().unwrap_or(0),
+        View::Modal(modal) => measure_height(&modal.content).saturating_add(2),
+        View::Split(split) => match split.direction {
+            FlexDirection::Row => measure_height(&split.first).max(measure_height(&split.second)),
+            FlexDirection::Column => measure_height(&split.first)
+                .saturating_add(measure_height(&split.second))
+                .saturating_add(1),
+        },
+        View::Sized(sized) => constrained_height(sized.constraint, measure_height(&sized.content)),
+        View::Constrained(constrained) => constrained
+            .dimensions
+            .clamp_height(measure_height(&constrained.content)),
+        View::Tooltip(tooltip) => measure_height(&tooltip.content),
+        View::Shared(shared) => measure_height(shared),
+        View::Custom(custom) => custom
+            .view
+            .height
+            .unwrap_or_else(|| custom.view.text.len().max(1) as u16),
+        View::Scroll(scroll) => scroll
+            .children
+            .iter()
+            .map(measure_height)
+            .fold(0u16, u16::saturating_add),
+    }
+}
+
+pub(crate) fn measure_width(view: &View) -> Option<u16> {
+    match view {
+        View::Empty => Some(0),
+        View::Text(text) => Some(text_width(&text.content)),
+        View::Paragraph(paragraph) => Some(text_width(&paragraph.content)),
+        View::Button(button) => Some(text_width(&button.label).saturating_add(4)),
+        View::Checkbox(checkbox) => Some(text_width(&checkbox.label).saturating_add(4)),
+        View::Flex(flex) => {
+            let mut widths = Vec::with_capacity(flex.children.len());
+            for (index, child) in flex.children.iter().enumerate() {
+                let width = match flex.constraints.get(index).copied().flatten() {
+                    Some(Constraint::Length(length)) => length,
+                    Some(Constraint::Min(min)) => measure_width(child)?.max(min),
+                    Some(Constraint::Max(max)) => measure_width(child)?.min(max),
+                    _ => measure_width(child)?,
+                };
+                widths.push(width);
+            }
+            let content = match flex.direction {
+                FlexDirection::Row => widths
+                    .into_iter()
+                    .fold(0u16, u16::saturating_add)
+                    .saturating_add(
+                        flex.gap
+                            .saturating_mul(flex.children.len().saturating_sub(1) as u16),
+                    ),
+                FlexDirection::Column => widths.into_iter().max().unwrap_or(0),
+            };
+            Some(
+                content
+                    .saturating_add(flex.padding.horizontal())
+                    .saturating_add(flex.margin.horizontal()),
+            )
+        }
+        View::Block(block) => {
+            let child = match block.child.as_deref() {
+                Some(child) => measure_width(child)?,
+                None => 0,
+            };
+            Some(
+                child
+                    .saturating_add(block.border_style.edges())
+                    .saturating_add(block.padding.horizontal())
+                    .saturating_add(block.margin.horizontal()),
+            )
+        }
+        View::Sized(sized) => match sized.constraint {
+            Constraint::Length(length) => Some(length),
+            _ => measure_width(&sized.content),
+        },
+        View::Constrained(constrained) => match constrained.dimensions.width {
+            Some(_) => Some(constrained.dimensions.clamp_width(0)),
+            None => measure_width(&constrained.content)
+                .map(|width| constrained.dimensions.clamp_width(width)),
+        },
+        View::Tooltip(tooltip) => measure_width(&tooltip.content),
+        View::Shared(shared) => measure_width(shared),
+        View::Custom(custom) => custom.view.width,
+        _ => None,
+    }
+}
+
+fn text_width(content: &str) -> u16 {
+    content
+        .lines()
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0) as u16
+}
+
+fn constrained_height(constraint: Constraint, content: u16) -> u16 {
+    match constraint {
+        Constraint::Length(length) => length,
+        Constraint::Min(min) => content.max(min),
+        Constraint::Max(max) => content.min(max),
+        _ => content,
+    }
+}
@@ -1,117 +1,339 @@
-use std::io::{Stdout, stdout};
-
 use anyhow::Context;
-use crossterm::cursor::{Hide, Show};
-use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
-use crossterm::execute;
-use crossterm::terminal::{
-    EnterAlternateScreen, LeaveAlternateScreen, SetTitle, disable_raw_mode, enable_raw_mode,
-};
-use ratatui::backend::{CrosstermBackend, TestBackend};
+use ratatui::backend::TestBackend;
 use ratatui::layout::Rect;
-use ratatui::{Frame, Terminal};
+use ratatui::{Frame, Terminal, TerminalOptions, Viewport};
+use unicode_width::UnicodeWidthStr;
 
-use crate::interactions::reset_button_hitboxes;
+use crate::interactions::{
+    Hitbox, register_region, reset_button_hitboxes, reset_checkbox_hitboxes, reset_layers,
+    reset_regions, reset_rows, reset_table_headers, reset_tree_rows,
+};
+use crate::menu::MenuBars;
+use crate::rt::Instant;
 use crate::runtime::View;
+use crate::scroll::Scrolls;
+use crate::select::Selects;
+use crate::split::Splits;
+use crate::styles::ColorScheme;
 use crate::text_input::TextInputs;
+use crate::time_picker::TimePickers;
+use crate::tooltip::Tooltips;
+use damage::Damage;
 
+mod backend;
+#[cfg(not(target_arch = "wasm32"))]
+mod background;
+mod damage;
+mod export;
+mod measure;
+mod popover;
+#[cfg(not(target_arch = "wasm32"))]
+mod terminal;
 mod widgets;
 
+pub use backend::RendererBackend;
+pub(crate) use damage::DamageCache;
+pub use damage::DamageStats;
+pub use export::ExportFormat;
+#[cfg(not(target_arch = "wasm32"))]
+pub use terminal::install_panic_hook;
+
 use widgets::{
-    render_block, render_button, render_flex, render_form, render_gauge, render_layers,
-    render_list, render_modal, render_table, render_tabs, render_text, render_text_input,
-    render_toast_stack, render_tree,
+    render_anchored, render_block, render_button, render_checkbox, render_flex, render_form,
+    render_gauge, render_key_hints, render_layers, render_list, render_menu_bar, render_modal,
+    render_paragraph, render_progress, render_scroll, render_select, render_spinner, render_split,
+    render_status_bar, render_stepper, render_table, render_tabs, render_text, render_text_area,
+    render_text_input, render_time_picker, render_toast_stack, render_tooltip, render_tree,
 };
 
 pub struct Renderer {
     terminal: RendererKind,
+    color_scheme: Option<ColorScheme>,
+    viewport: Option<Rect>,
 }
 
 enum RendererKind {
-    Crossterm(Terminal<CrosstermBackend<Stdout>>),
+    #[cfg(not(target_arch = "wasm32"))]
+    Crossterm(terminal::CrosstermTerminal),
     Headless(Terminal<TestBackend>),
+    Backend(Box<dyn RendererBackend>),
+    #[cfg(feature = "remote")]
+    Remote(crate::remote::RemoteRenderer),
+    Embedded,
 }
 
 impl Renderer {
-    pub fn new(title: &str) -> anyhow::Result<Self> {
-        enable_raw_mode().context("enable raw mode")?;
-        let mut stdout = stdout();
-        execute!(
-            stdout,
-            EnterAlternateScreen,
-            EnableMouseCapture,
-            Hide,
-            SetTitle(title)
-        )
-        .context("prepare terminal")?;
-        let backend = CrosstermBackend::new(stdout);
-        let terminal = Terminal::new(backend).context("build terminal")?;
+    pub fn headless() -> anyhow::Result<Self> {
+        Self::headless_with_size(80, 24)
+    }
+
+    pub fn headless_with_size(width: u16, height: u16) -> anyhow::Result<Self> {
+        let backend = TestBackend::new(width, height);
+        let terminal = Terminal::new(backend).context("build headless terminal")?;
         Ok(Self {
-            terminal: RendererKind::Crossterm(terminal),
+            terminal: RendererKind::Headless(terminal),
+            color_scheme: None,
+            viewport: None,
         })
     }
 
-    pub fn headless() -> anyhow::Result<Self> {
-        let backend = TestBackend::new(80, 24);
-        let terminal = Terminal::new(backend).context("build headless terminal")?;
+    pub fn headless_with_viewport(width: u16, height: u16, area: Rect) -> anyhow::Result<Self> {
+        let area = area.intersection(Rect::new(0, 0, width, height));
+        let terminal = Terminal::with_options(
+            TestBackend::new(width, height),
+            TerminalOptions {
+                viewport: Viewport::Fixed(area),
+            },
+        )
+        .context("build headless terminal")?;
         Ok(Self {
             terminal: RendererKind::Headless(terminal),
+            color_scheme: None,
+            viewport: Some(area),
         })
     }
 
+    pub fn with_backend<B>(backend: B) -> Self
+    where
+        B: RendererBackend + 'static,
+    {
+        Self {
+            terminal: RendererKind::Backend(Box::new(backend)),
+            color_scheme: None,
+            viewport: None,
+        }
+    }
+
+    #[cfg(feature = "remote")]
+    pub(crate) fn remote(renderer: crate::remote::RemoteRenderer) -> Self {
+        Self {
+            terminal: RendererKind::Remote(renderer),
+            color_scheme: None,
+            viewport: None,
+        }
+    }
+
+    pub(crate) fn embedded(area: Rect) -> Self {
+        Self {
+            terminal: RendererKind::Embedded,
+            color_scheme: None,
+            viewport: Some(area),
+        }
+    }
+
+    pub(crate) fn place(&mut self, area: Rect) -> bool {
+        let moved = self.viewport != Some(area);
+        self.viewport = Some(area);
+        moved
+    }
+
+    pub fn color_scheme(&self) -> Option<ColorScheme> {
+        self.color_scheme
+    }
+
+    pub fn viewport(&self) -> Option<Rect> {
+        self.viewport
+    }
+
+    pub fn size(&self) -> anyhow::Result<Rect> {
+        if let Some(area) = self.viewport {
+            return Ok(area);
+        }
+        let size = match &self.terminal {
+            #[cfg(not(target_arch = "wasm32"))]
+            RendererKind::Crossterm(terminal) => terminal.size()?,
+            RendererKind::Headless(terminal) => terminal.size()?,
+            RendererKind::Backend(backend) => backend.size()?,
+            #[cfg(feature = "remote")]
+            RendererKind::Remote(remote) => remote.size()?,
+            RendererKind::Embedded => Rect::default(),
+        };
+        Ok(size)
+    }
+
+    pub fn resize(&mut self, width: u16, height: u16) -> anyhow::Result<()> {
+        if let (RendererKind::Headless(_), Some(area)) = (&self.terminal, self.viewport) {
+            *self = Self::headless_with_viewport(width, height, area)?;
+        } else if let RendererKind::Headless(terminal) = &mut self.terminal {
+            terminal.backend_mut().resize(width, height);
+            terminal
+                .resize(Rect::new(0, 0, width, height))
+                .context("resize headless terminal")?;
+        } else if let RendererKind::Backend(backend) = &mut self.terminal {
+            backend.resize(width, height)?;
+        }
+        #[cfg(feature = "remote")]
+        if let RendererKind::Remote(remote) = &mut self.terminal {
+            remote.resize(width, height)?;
+        }
+        Ok(())
+    }
+
+    pub fn buffer_text(&self) -> Option<String> {
+        let RendererKind::Headless(terminal) = &self.terminal else {
+            return None;
+        };
+        let buffer = terminal.backend().buffer();
+        let width = usize::from(buffer.area.width.max(1));
+        let lines: Vec<String> = buffer
+            .content
+            .chunks(width)
+            .map(|cells| {
+                let mut line = String::new();
+                let mut skip = 0usize;
+                for cell in cells {
+                    if skip == 0 {
+                        line.push_str(cell.symbol());
+                    }
+                    skip = skip.max(cell.symbol().width()).saturating_sub(1);
+                }
+                line.trim_end().to_string()
+            })
+            .collect();
+        Some(lines.join("\n"))
+    }
+
+    pub fn suspend(&mut self) -> anyhow::Result<()> {
+        if let RendererKind::Backend(backend) = &mut self.terminal {
+            return backend.suspend();
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let RendererKind::Crossterm(_) = self.terminal {
+            terminal::release(self.viewport)?;
+        }
+        Ok(())
+    }
+
+    pub fn resume(&mut self) -> anyhow::Result<()> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let RendererKind::Crossterm(_) = self.terminal {
+            terminal::reclaim(self.viewport)?;
+        }
+        match &mut self.terminal {
+            #[cfg(not(target_arch = "wasm32"))]
+            RendererKind::Crossterm(terminal) => terminal.clear()?,
+            RendererKind::Headless(terminal) => terminal.clear()?,
+            RendererKind::Backend(backend) => backend.resume()?,
+            #[cfg(feature = "remote")]
+            RendererKind::Remote(_) => {}
+            RendererKind::Embedded => {}
+        }
+        Ok(())
+    }
+
+    pub fn export(&self, format: ExportFormat) -> Option<String> {
+        let RendererKind::Headless(terminal) = &self.terminal else {
+            return None;
+        };
+        Some(export::export(terminal.backend().buffer(), format))
+    }
+
     pub fn draw(&mut self, view: &View) -> anyhow::Result<()> {
-        reset_button_hitboxes();
-        TextInputs::reset_hitboxes();
         match &mut self.terminal {
+            #[cfg(not(target_arch = "wasm32"))]
             RendererKind::Crossterm(terminal) => {
-                terminal.draw(|frame| {
-                    let area = frame.size();
-                    render_view(frame, area, view);
-                })?;
+                terminal.draw(|frame| render_frame(frame, frame.size(), view))?;
             }
             RendererKind::Headless(terminal) => {
-                terminal.draw(|frame| {
-                    let area = frame.size();
-                    render_view(frame, area, view);
-                })?;
+                terminal.draw(|frame| render_frame(frame, frame.size(), view))?;
+            }
+            RendererKind::Backend(backend) => {
+                backend.draw(&mut |frame| render_frame(frame, frame.size(), view))?;
             }
+            #[cfg(feature = "remote")]
+            RendererKind::Remote(remote) => remote.draw(view)?,
+            RendererKind::Embedded => {}
         }
         Ok(())
     }
 }
 
-impl Drop for Renderer {
-    fn drop(&mut self) {
-        if matches!(self.terminal, RendererKind::Crossterm(_)) {
-            let _ = disable_raw_mode();
-            let mut stdout = stdout();
-            let _ = execute!(
-                stdout,
-                Show,
-                DisableMouseCapture,
-                LeaveAlternateScreen,
-                SetTitle("Terminal")
-            );
+pub(crate) fn render_frame(frame: &mut Frame<'_>, area: Rect, view: &View) {
+    let damage = DamageCache::global();
+    damage.begin_frame();
+    reset_layers();
+    reset_button_hitboxes();
+    reset_regions();
+    reset_checkbox_hitboxes();
+    reset_table_headers();
+    reset_tree_rows();
+    reset_rows();
+    TextInputs::reset_hitboxes();
+    Scrolls::reset_hitboxes();
+    Splits::reset_hitboxes();
+    Selects::reset_hitboxes();
+    TimePickers::reset_hitboxes();
+    MenuBars::reset_hitboxes();
+    Tooltips::reset_hitboxes();
+    let started = Instant::now();
+    render_view(frame, area, view);
+    popover::render_queued(frame);
+    damage.end_frame(started.elapsed());
+}
+
+fn render_view(frame: &mut Frame<'_>, area: Rect, view: &View) {
+    let damage = DamageCache::global();
+    if let Some(id) = view.id() {
+        let hitbox = Hitbox::from(area);
+        register_region(id, hitbox);
+        damage.capture_region(id, hitbox);
+    }
+    match damage.check(frame.buffer_mut(), area, view) {
+        Damage::Reused => {}
+        Damage::Untracked => draw_view(frame, area, view),
+        Damage::Dirty(pending) => {
+            draw_view(frame, area, view);
+            damage.record(frame.buffer_mut(), pending);
         }
     }
 }
 
-fn render_view(frame: &mut Frame<'_>, area: Rect, view: &View) {
+fn draw_view(frame: &mut Frame<'_>, area: Rect, view: &View) {
     match view {
         View::Empty => {}
         View::Text(text) => render_text(frame, area, text),
+        View::Paragraph(paragraph) => render_paragraph(frame, area, paragraph),
         View::Flex(flex) => render_flex(frame, area, flex, render_view),
+        View::Sized(sized) => render_view(frame, area, &sized.content),
+        View::Shared(shared) => render_view(frame, area, shared),
+        View::Constrained(constrained) => {
+            let dimensions = constrained.dimensions;
+            let area = Rect {
+                width: dimensions.clamp_width(area.width).min(area.width),
+                height: dimensions.clamp_height(area.height).min(area.height),
+                ..area
+            };
+            render_view(frame, area, &constrained.content)
+        }
         View::Block(block) => render_block(frame, area, block, render_view),
         View::List(list) => render_list(frame, area, list),
         View::Gauge(gauge) => render_gauge(frame, area, gauge),
+        View::Spinner(spinner) => render_spinner(frame, area, spinner),
+        View::Progress(progress) => render_progress(frame, area, progress),
         View::Button(button) => render_button(frame, area, button),
+        View::Checkbox(checkbox) => render_checkbox(frame, area, checkbox),
+        View::Select(select) => render_select(frame, area, select),
+        View::MenuBar(menu_bar) => render_menu_bar(frame, area, menu_bar),
+        View::StatusBar(status_bar) => render_status_bar(frame, area, status_bar),
+        View::KeyHints(hints) => render_key_hints(frame, area, hints),
+        View::Stepper(stepper) => render_stepper(frame, area, stepper),
+        View::TimePicker(picker) => render_time_picker(frame, area, picker),
         View::Table(table) => render_table(frame, area, table),
         View::Tree(tree) => render_tree(frame, area, tree),
         View::Form(form) => render_form(frame, area, form),
         View::Input(input) => render_text_input(frame, area, input),
+        View::TextArea(text_area) => render_text_area(frame, area, text_area),
         View::Tabs(tabs) => render_tabs(frame, area, tabs, render_view),
         View::Layered(layers) => render_layers(frame, area, layers, render_view),
         View::Modal(modal) => render_modal(frame, area, modal, render_view),
+        View::Anchored(anchored) => render_anchored(frame, area, anchored, render_view),
         View::ToastStack(stack) => render_toast_stack(frame, area, stack),
+        View::Scroll(scroll) => render_scroll(frame, area, scroll, render_view),
+        View::Split(split) => render_split(frame, area, split, render_view),
+        View::Tooltip(tooltip) => render_tooltip(frame, area, tooltip, render_view),
+        View::Custom(custom) => custom.render(frame, area),
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,116 @@
+use std::cell::RefCell;
+
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::interactions::{Hitbox, enter_layer, leave_layer, register_surface};
+use crate::select::Selects;
+
+const MAX_VISIBLE_ITEMS: usize = 6;
+
+pub(crate) struct Popover {
+    pub anchor: Rect,
+    pub items: Vec<String>,
+    pub highlighted: Option<usize>,
+    pub accent: Color,
+    pub owner: Option<String>,
+}
+
+type Overlay = Box<dyn FnOnce(&mut Frame<'_>)>;
+
+thread_local! {
+    static QUEUE: RefCell<Vec<Popover>> = const { RefCell::new(Vec::new()) };
+    static OVERLAYS: RefCell<Vec<Overlay>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) fn queue(popover: Popover) {
+    QUEUE.with(|queue| queue.borrow_mut().push(popover));
+}
+
+pub(crate) fn defer(overlay: impl FnOnce(&mut Frame<'_>) + 'static) {
+    OVERLAYS.with(|overlays| overlays.borrow_mut().push(Box::new(overlay)));
+}
+
+pub(crate) fn render_queued(frame: &mut Frame<'_>) {
+    let previous = enter_layer();
+    let popovers = QUEUE.with(|queue| std::mem::take(&mut *queue.borrow_mut()));
+    let bounds = frame.size();
+    for popover in popovers {
+        render_popover(frame, bounds, &popover);
+    }
+    let overlays = OVERLAYS.with(|overlays| std::mem::take(&mut *overlays.borrow_mut()));
+    for overlay in overlays {
+        overlay(frame);
+    }
+    leave_layer(previous);
+}
+
+fn render_popover(frame: &mut Frame<'_>, bounds: Rect, popover: &Popover) {
+    if popover.items.is_empty() {
+        return;
+    }
+    let visible = popover.items.len().min(MAX_VISIBLE_ITEMS);
+    let height = visible as u16 + 2;
+    let anchor = popover.anchor;
+    let below = anchor.y.saturating_add(anchor.height);
+    let space_below = bounds.y.saturating_add(bounds.height).saturating_sub(below);
+    let y = if space_below >= height || space_below >= anchor.y.saturating_sub(bounds.y) {
+        below
+    } else {
+        anchor.y.saturating_sub(height)
+    };
+    let area = Rect {
+        x: anchor.x,
+        y,
+        width: anchor.width,
+        height,
+    }
+    .intersection(bounds);
+    if area.width < 3 || area.height < 3 {
+        return;
+    }
+
+    let highlighted = popover.highlighted.unwrap_or(0);
+    let first = (highlighted + 1).saturating_sub(visible);
+    let lines: Vec<Line> = popover
+        .items
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible)
+        .map(|(index, item)| {
+            let mut style = Style::default();
+            if popover.highlighted == Some(index) {
+                style = style
+                    .fg(Color::Black)
+                    .bg(popover.accent)
+                    .add_modifier(Modifier::BOLD);
+            }
+            Line::styled(item.clone(), style)
+        })
+        .collect();
+    if let Some(owner) = &popover.owner {
+        let rows = area.height.saturating_sub(2) as usize;
+        for (row, index) in (first..first + visible).take(rows).enumerate() {
+            Selects::record_option(
+                owner,
+                index,
+                Hitbox::new(
+                    area.x + 1,
+                    area.y + 1 + row as u16,
+                    area.width.saturating_sub(2),
+                    1,
+                ),
+            );
+        }
+    }
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(popover.accent));
+    frame.render_widget(Clear, area);
+    register_surface(area.into());
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
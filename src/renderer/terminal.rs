@@ -0,0 +1,128 @@
+use std::io::{Stdout, stdout};
+use std::panic;
+use std::sync::Once;
+
+use anyhow::Context;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::execute;
+use crossterm::terminal::{
+    EnterAlternateScreen, LeaveAlternateScreen, SetTitle, disable_raw_mode, enable_raw_mode,
+    is_raw_mode_enabled,
+};
+use ratatui::backend::{Backend, CrosstermBackend};
+use ratatui::layout::Rect;
+use ratatui::{Terminal, TerminalOptions, Viewport};
+
+use super::{Renderer, RendererKind, background};
+
+pub(super) type CrosstermTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+impl Renderer {
+    pub fn new(title: &str) -> anyhow::Result<Self> {
+        enable_raw_mode().context("enable raw mode")?;
+        let color_scheme = background::detect_color_scheme();
+        let mut stdout = stdout();
+        execute!(
+            stdout,
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            Hide,
+            SetTitle(title)
+        )
+        .context("prepare terminal")?;
+        let backend = CrosstermBackend::new(stdout);
+        let terminal = Terminal::new(backend).context("build terminal")?;
+        Ok(Self {
+            terminal: RendererKind::Crossterm(terminal),
+            color_scheme,
+            viewport: None,
+        })
+    }
+
+    pub fn with_viewport(title: &str, area: Rect) -> anyhow::Result<Self> {
+        enable_raw_mode().context("enable raw mode")?;
+        let color_scheme = background::detect_color_scheme();
+        let mut stdout = stdout();
+        execute!(stdout, EnableMouseCapture, Hide, SetTitle(title)).context("prepare terminal")?;
+        let backend = CrosstermBackend::new(stdout);
+        let area = area.intersection(backend.size().context("read terminal size")?);
+        let terminal = Terminal::with_options(
+            backend,
+            TerminalOptions {
+                viewport: Viewport::Fixed(area),
+            },
+        )
+        .context("build terminal")?;
+        Ok(Self {
+            terminal: RendererKind::Crossterm(terminal),
+            color_scheme,
+            viewport: Some(area),
+        })
+    }
+}
+
+pub(super) fn release(viewport: Option<Rect>) -> anyhow::Result<()> {
+    disable_raw_mode().context("disable raw mode")?;
+    let mut stdout = stdout();
+    execute!(stdout, Show, DisableMouseCapture).context("release terminal")?;
+    if viewport.is_none() {
+        execute!(stdout, LeaveAlternateScreen).context("leave alternate screen")?;
+    }
+    Ok(())
+}
+
+pub(super) fn reclaim(viewport: Option<Rect>) -> anyhow::Result<()> {
+    enable_raw_mode().context("enable raw mode")?;
+    let mut stdout = stdout();
+    if viewport.is_none() {
+        execute!(stdout, EnterAlternateScreen).context("enter alternate screen")?;
+    }
+    execute!(stdout, EnableMouseCapture, Hide).context("prepare terminal")?;
+    Ok(())
+}
+
+pub fn install_panic_hook() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if is_raw_mode_enabled().unwrap_or(false) {
+                let _ = disable_raw_mode();
+                let _ = execute!(
+                    stdout(),
+                    Show,
+                    DisableMouseCapture,
+                    LeaveAlternateScreen,
+                    SetTitle("Terminal")
+                );
+            }
+            previous(info);
+        }));
+    });
+}
+
+impl Drop for Renderer {
+    fn drop(&mut self) {
+        if let (RendererKind::Crossterm(_), Some(area)) = (&self.terminal, self.viewport) {
+            let _ = disable_raw_mode();
+            let _ = execute!(
+                stdout(),
+                Show,
+                DisableMouseCapture,
+                MoveTo(0, area.bottom()),
+                SetTitle("Terminal")
+            );
+        } else if matches!(self.terminal, RendererKind::Crossterm(_)) {
+            let _ = disable_raw_mode();
+            let mut stdout = stdout();
+            let _ = execute!(
+                stdout,
+                Show,
+                DisableMouseCapture,
+                LeaveAlternateScreen,
+                SetTitle("Terminal")
+            );
+        }
+    }
+}
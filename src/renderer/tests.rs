@@ -0,0 +1,573 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+
+use super::ExportFormat;
+use super::export::export;
+
+fn frame() -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+    buffer.set_string(
+        0,
+        0,
+        "ok",
+        Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+    );
+    buffer.set_string(1, 1, "<x>", Style::new().bg(Color::Rgb(1, 2, 3)));
+    buffer
+}
+
+#[test]
+fn ansi_export_keeps_colors_and_modifiers() {
+    assert_eq!(
+        export(&frame(), ExportFormat::Ansi),
+        "\x1b[0;1;31mok\x1b[0m\n\x1b[0m \x1b[0;48;2;1;2;3m<x>\x1b[0m\n"
+    );
+}
+
+#[test]
+fn html_and_svg_exports_escape_text_and_paint_cells() {
+    let html = export(&frame(), ExportFormat::Html);
+    assert!(html.starts_with("<pre class=\"rustact-frame\""));
+    assert!(html.contains("<span style=\"color:#cd3131;font-weight:bold\">ok</span>\n"));
+    assert!(html.contains("<span style=\"background:#010203\">&lt;x&gt;</span></pre>"));
+
+    let svg = export(&frame(), ExportFormat::Svg);
+    assert!(
+        svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"54\" height=\"36\"")
+    );
+    assert!(svg.contains("<rect x=\"9\" y=\"18\" width=\"27\" height=\"18\" fill=\"#010203\"/>"));
+    assert!(svg.contains("font-weight=\"bold\">ok</text>"));
+    assert!(svg.contains(">&lt;x&gt;</text>"));
+}
+
+#[test]
+fn unchanged_static_widgets_are_copied_from_the_previous_frame() {
+    use std::sync::Arc;
+
+    use parking_lot::Mutex;
+
+    use crate::hooks::StateHandle;
+    use crate::runtime::{App, Element, component};
+
+    let slot: Arc<Mutex<Option<StateHandle<u32>>>> = Arc::default();
+    let handle = slot.clone();
+    let mut app = App::new(
+        "Damage",
+        component("Root", move |ctx| {
+            let (count, set_count) = ctx.use_state(|| 0u32);
+            *handle.lock() = Some(set_count);
+            Element::vstack(vec![
+                Element::text(format!("count {count}")),
+                Element::block(
+                    "Static",
+                    Element::vstack(
+                        (0..4)
+                            .map(|row| Element::text(format!("row {row}")))
+                            .collect(),
+                    ),
+                ),
+            ])
+        }),
+    )
+    .headless_size(20, 12);
+    app.render_once_to_string().expect("first frame");
+    assert_eq!(app.damage_stats().reused, 0);
+
+    slot.lock().as_ref().expect("handle").set(1);
+    let frame = app.render_once_to_string().expect("second frame");
+    assert!(frame.contains("count 1"), "{frame}");
+    assert_eq!(app.damage_stats().reused, 0);
+
+    slot.lock().as_ref().expect("handle").set(2);
+    let frame = app.render_once_to_string().expect("third frame");
+    assert!(frame.contains("count 2"), "{frame}");
+    assert!(frame.contains("row 3"), "{frame}");
+    assert!(frame.contains("Static"), "{frame}");
+    let stats = app.damage_stats();
+    assert_eq!(stats.reused, 6, "{stats:?}");
+    assert_eq!(stats.rendered, 2, "{stats:?}");
+    assert_eq!(stats.reused_cells, 20 * 6, "{stats:?}");
+
+    slot.lock().as_ref().expect("handle").set(3);
+    let frame = app.render_once_to_string().expect("fourth frame");
+    assert!(frame.contains("count 3"), "{frame}");
+    assert!(frame.contains("row 3"), "{frame}");
+    let next = app.damage_stats();
+    assert_eq!(
+        (next.rendered, next.reused, next.reused_cells),
+        (stats.rendered, stats.reused, stats.reused_cells)
+    );
+}
+
+#[test]
+fn ids_inside_reused_views_keep_their_regions() {
+    use std::sync::Arc;
+
+    use parking_lot::Mutex;
+
+    use crate::hooks::StateHandle;
+    use crate::runtime::{App, Element, FlexNode, component};
+
+    let slot: Arc<Mutex<Option<StateHandle<u32>>>> = Arc::default();
+    let handle = slot.clone();
+    let mut app = App::new(
+        "Regions",
+        component("Root", move |ctx| {
+            let (count, set_count) = ctx.use_state(|| 0u32);
+            *handle.lock() = Some(set_count);
+            Element::vstack(vec![
+                Element::text(format!("count {count}")),
+                Element::hstack(vec![
+                    Element::flex(FlexNode::column(vec![Element::text("menu")]).id("sidebar")),
+                    Element::text("body"),
+                ]),
+            ])
+        }),
+    )
+    .headless_size(20, 4);
+    app.render_once_to_string().expect("first frame");
+    let sidebar = app.region("sidebar").expect("sidebar region");
+
+    for count in 1..=3 {
+        slot.lock().as_ref().expect("handle").set(count);
+        app.render_once_to_string().expect("next frame");
+        assert_eq!(app.region("sidebar"), Some(sidebar), "frame {count}");
+    }
+    assert!(app.damage_stats().reused > 0);
+}
+
+fn mouse_down(column: u16, row: u16) -> crate::events::FrameworkEvent {
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+    crate::events::FrameworkEvent::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[tokio::test]
+async fn list_rows_map_clicks_to_item_indices() {
+    use std::sync::Arc;
+
+    use parking_lot::Mutex;
+
+    use crate::hooks::ListStateHandle;
+    use crate::interactions::clicked_list_index;
+    use crate::runtime::{App, Element, ListItemNode, ListNode, component};
+    use crate::testing::TestApp;
+
+    let slot: Arc<Mutex<Option<ListStateHandle>>> = Arc::default();
+    let handle = slot.clone();
+    let app = TestApp::new(
+        App::new(
+            "Files",
+            component("Files", move |ctx| {
+                let list = ctx.use_list_state(6);
+                *handle.lock() = Some(list.clone());
+                let items = (0..6)
+                    .map(|index| ListItemNode::new(format!("file {index}")))
+                    .collect();
+                Element::list(ListNode::new(items).title("Files").id("files").offset(2))
+            }),
+        )
+        .headless_size(20, 5),
+    )
+    .await
+    .expect("start app");
+    app.expect_text("file 2");
+    let _app = app.enter();
+
+    assert_eq!(clicked_list_index(&mouse_down(3, 1), "files"), Some(2));
+    assert_eq!(clicked_list_index(&mouse_down(3, 3), "files"), Some(4));
+    assert_eq!(clicked_list_index(&mouse_down(3, 0), "files"), None);
+    assert_eq!(clicked_list_index(&mouse_down(3, 4), "files"), None);
+    assert_eq!(clicked_list_index(&mouse_down(3, 1), "other"), None);
+
+    let list = slot.lock().clone().expect("list state");
+    assert!(list.handle_event(&mouse_down(3, 2), "files"));
+    assert_eq!(list.selected(), Some(3));
+    assert!(!list.handle_event(&mouse_down(3, 0), "files"));
+}
+
+#[tokio::test]
+async fn virtual_lists_fill_viewports_taller_than_their_old_window() {
+    use crate::interactions::clicked_list_index;
+    use crate::runtime::{App, Element, ListItemNode, VirtualListNode, component};
+    use crate::testing::TestApp;
+
+    let app = TestApp::new(
+        App::new(
+            "Rows",
+            component("Rows", |_ctx| {
+                Element::virtual_list(
+                    VirtualListNode::new(100_000, |index| {
+                        ListItemNode::new(format!("row {index}"))
+                    })
+                    .id("rows")
+                    .offset(1_000)
+                    .highlight(1_250),
+                )
+            }),
+        )
+        .headless_size(20, 260),
+    )
+    .await
+    .expect("start app");
+
+    let text = app.text();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 260);
+    assert!(lines[0].contains("row 1000"));
+    assert!(lines[250].starts_with("▶ row 1250"));
+    assert!(lines[259].contains("row 1259"));
+    assert!(!text.contains("row 1260"));
+
+    let _app = app.enter();
+    assert_eq!(clicked_list_index(&mouse_down(3, 255), "rows"), Some(1_255));
+}
+
+#[tokio::test]
+async fn table_rows_count_the_header_and_the_current_page() {
+    use crate::interactions::clicked_table_row;
+    use crate::runtime::{App, Element, TableCellNode, TableNode, TableRowNode, component};
+    use crate::testing::TestApp;
+
+    let app = TestApp::new(
+        App::new(
+            "Jobs",
+            component("Jobs", |_ctx| {
+                let rows = (0..5)
+                    .map(|index| {
+                        TableRowNode::new(vec![TableCellNode::new(format!("job {index}"))])
+                    })
+                    .collect();
+                Element::table(
+                    TableNode::new(rows)
+                        .header(TableRowNode::new(vec![TableCellNode::new("Name")]))
+                        .id("jobs")
+                        .paginate(2)
+                        .page(1),
+                )
+            }),
+        )
+        .headless_size(20, 8),
+    )
+    .await
+    .expect("start app");
+    app.expect_text("job 2");
+    let _app = app.enter();
+
+    assert_eq!(clicked_table_row(&mouse_down(3, 1), "jobs"), None);
+    assert_eq!(clicked_table_row(&mouse_down(3, 2), "jobs"), Some(2));
+    assert_eq!(clicked_table_row(&mouse_down(3, 3), "jobs"), Some(3));
+    assert_eq!(clicked_table_row(&mouse_down(3, 4), "jobs"), None);
+}
+
+#[tokio::test]
+async fn sorted_paged_tables_select_and_edit_rows_by_source_index() {
+    use std::sync::Arc;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use parking_lot::Mutex;
+
+    use crate::hooks::{CellEdit, ListStateHandle, TableEditHandle};
+    use crate::interactions::{clicked_table_row, table_source_row};
+    use crate::runtime::{
+        App, Element, SortDirection, TableCellNode, TableNode, TableRowNode, View, component,
+    };
+    use crate::testing::TestApp;
+
+    const NAMES: [&str; 5] = ["delta", "alpha", "charlie", "bravo", "echo"];
+    type Handles = Option<(ListStateHandle, TableEditHandle)>;
+    let slot: Arc<Mutex<Handles>> = Arc::default();
+    let handles = slot.clone();
+    let mut app = TestApp::new(
+        App::new(
+            "Jobs",
+            component("Jobs", move |ctx| {
+                let list = ctx.use_list_state(NAMES.len());
+                let editor = ctx.use_table_editor("jobs", 1);
+                *handles.lock() = Some((list.clone(), editor.clone()));
+                let rows = NAMES
+                    .iter()
+                    .map(|name| TableRowNode::new(vec![TableCellNode::new(*name)]))
+                    .collect();
+                Element::table(
+                    TableNode::new(rows)
+                        .header(TableRowNode::new(vec![TableCellNode::new("Name")]))
+                        .id("jobs")
+                        .sortable(0, SortDirection::Ascending)
+                        .paginate(2)
+                        .state(&list)
+                        .editor(&editor),
+                )
+            }),
+        )
+        .headless_size(20, 8),
+    )
+    .await
+    .expect("start app");
+    let (list, editor) = slot.lock().clone().expect("handles");
+
+    list.select(Some(3));
+    app.tick().await.expect("render");
+    app.expect_text("charlie").expect_text("delta");
+    let View::Table(table) = app.view().clone() else {
+        panic!("expected a table, found {:?}", app.view());
+    };
+    assert_eq!(table.sources, [2, 0]);
+    assert_eq!(table.highlight, Some(1));
+
+    let _app = app.enter();
+    assert_eq!(table_source_row("jobs", 3), Some(0));
+    assert_eq!(table_source_row("jobs", 4), Some(4));
+    assert_eq!(table_source_row("jobs", 5), None);
+    let clicked = clicked_table_row(&mouse_down(3, 2), "jobs");
+    assert_eq!(clicked, Some(2));
+    assert_eq!(table_source_row("jobs", 2), Some(2));
+
+    let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+    editor.handle_key(&enter, list.selected(), |row, _| NAMES[row].to_string());
+    assert_eq!(editor.input().value(), "delta");
+    editor.input().set_value("delta-2");
+    assert_eq!(
+        editor.handle_key(&enter, list.selected(), |_, _| unreachable!()),
+        Some(CellEdit {
+            row: 0,
+            column: 0,
+            value: "delta-2".into(),
+        })
+    );
+}
+
+#[tokio::test]
+async fn paging_a_sorted_table_keeps_selection_and_clicks_on_source_rows() {
+    use std::sync::Arc;
+
+    use parking_lot::Mutex;
+
+    use crate::hooks::ListStateHandle;
+    use crate::interactions::{clicked_table_row, table_source_row};
+    use crate::runtime::{
+        App, Element, SortDirection, TableCellNode, TableNode, TableRowNode, View, component,
+    };
+    use crate::testing::TestApp;
+
+    const SIZES: [&str; 5] = ["30", "4", "120", "7", "55"];
+    let slot: Arc<Mutex<Option<ListStateHandle>>> = Arc::default();
+    let handle = slot.clone();
+    let mut app = TestApp::new(
+        App::new(
+            "Sizes",
+            component("Sizes", move |ctx| {
+                let list = ctx.use_list_state(SIZES.len());
+                list.set_page_size(2);
+                *handle.lock() = Some(list.clone());
+                let rows = SIZES
+                    .iter()
+                    .map(|size| TableRowNode::new(vec![TableCellNode::new(*size)]))
+                    .collect();
+                Element::table(
+                    TableNode::new(rows)
+                        .id("sizes")
+                        .sortable(0, SortDirection::Descending)
+                        .paginate(2)
+                        .state(&list),
+                )
+            }),
+        )
+        .headless_size(20, 6),
+    )
+    .await
+    .expect("start app");
+    let list = slot.lock().clone().expect("list state");
+    let sources = |app: &TestApp| match app.view() {
+        View::Table(table) => (table.sources.clone(), table.highlight),
+        view => panic!("expected a table, found {view:?}"),
+    };
+    assert_eq!(sources(&app), (vec![2, 4], Some(0)));
+
+    list.next_page();
+    app.tick().await.expect("render");
+    assert_eq!(list.selected(), Some(2));
+    assert_eq!(sources(&app), (vec![0, 3], Some(0)));
+
+    list.next_page();
+    app.tick().await.expect("render");
+    assert_eq!(list.selected(), Some(4));
+    assert_eq!(sources(&app), (vec![1], Some(0)));
+    app.expect_text("4");
+
+    let _app = app.enter();
+    assert_eq!(clicked_table_row(&mouse_down(3, 1), "sizes"), Some(4));
+    assert_eq!(table_source_row("sizes", 4), Some(1));
+    assert_eq!(SIZES[table_source_row("sizes", 3).expect("row")], "7");
+}
+
+#[tokio::test]
+async fn hovered_rows_and_containers_pick_up_hover_styles() {
+    use crate::runtime::{App, BlockNode, Element, ListItemNode, ListNode, View, component};
+    use crate::styles::Stylesheet;
+    use crate::testing::TestApp;
+
+    let styles = Stylesheet::parse(
+        "block#panel:hover { background-color: blue; }\nlist#files .item:hover { color: red; }",
+    )
+    .expect("stylesheet");
+    let mut app = TestApp::new(
+        App::new(
+            "Hover",
+            component("Hover", |_ctx| {
+                let items = (0..3)
+                    .map(|index| ListItemNode::new(format!("file {index}")))
+                    .collect();
+                Element::block_node(
+                    BlockNode::new(Element::list(ListNode::new(items).id("files"))).id("panel"),
+                )
+            }),
+        )
+        .with_stylesheet(styles)
+        .headless_size(20, 6),
+    )
+    .await
+    .expect("start app");
+    let hover = |app: &TestApp| {
+        let Some(View::List(list)) = app.view().find_by_id("files") else {
+            panic!("list rendered");
+        };
+        let Some(View::Block(block)) = app.view().find_by_id("panel") else {
+            panic!("block rendered");
+        };
+        (list.hovered, list.hover_style.fg, block.background_color)
+    };
+    assert_eq!(hover(&app), (None, None, None));
+
+    app.move_mouse(3, 2).await.expect("move over row");
+    assert_eq!(hover(&app), (Some(1), Some(Color::Red), Some(Color::Blue)));
+
+    app.move_mouse(0, 0).await.expect("move onto border");
+    assert_eq!(hover(&app), (None, None, Some(Color::Blue)));
+}
+
+#[tokio::test]
+async fn only_filled_overlays_block_clicks_on_lower_layers() {
+    use ratatui::style::Color;
+
+    use crate::interactions::is_button_click;
+    use crate::runtime::{App, BlockNode, ButtonNode, Element, LayeredNode, component};
+    use crate::testing::TestApp;
+
+    let app = |overlay: fn() -> Element| {
+        App::new(
+            "Overlay",
+            component("Overlay", move |_ctx| {
+                Element::layers(LayeredNode::new(vec![
+                    Element::button(ButtonNode::new("save", "Save")),
+                    overlay(),
+                ]))
+            }),
+        )
+        .headless_size(20, 4)
+    };
+
+    let hint = TestApp::new(app(|| Element::text("press s to save")))
+        .await
+        .expect("start app");
+    hint.expect_text("press s to save");
+    let _hint = hint.enter();
+    assert!(is_button_click(&mouse_down(3, 1), "save"));
+
+    let cover = TestApp::new(app(|| {
+        Element::block_node(BlockNode::new(Element::text("busy")).background_color(Color::Black))
+    }))
+    .await
+    .expect("start app");
+    cover.expect_text("busy");
+    let _cover = cover.enter();
+    assert!(!is_button_click(&mouse_down(3, 1), "save"));
+}
+
+fn paragraph_lines(node: crate::runtime::ParagraphNode, width: u16, height: u16) -> Vec<String> {
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    use super::render_paragraph;
+    use crate::runtime::ParagraphView;
+
+    let view = ParagraphView {
+        content: node.content,
+        align: node.align,
+        wrap: node.wrap,
+        trim: node.trim,
+        scroll: node.scroll,
+        color: node.color,
+        id: node.id,
+        classes: node.classes,
+    };
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("terminal");
+    terminal
+        .draw(|frame| render_paragraph(frame, frame.size(), &view))
+        .expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..width).map(|x| buffer.get(x, y).symbol()).collect())
+        .collect()
+}
+
+#[test]
+fn paragraphs_wrap_words_and_cut_unwrapped_lines() {
+    use crate::runtime::ParagraphNode;
+
+    let copy = "the quick brown fox jumps";
+    assert_eq!(
+        paragraph_lines(ParagraphNode::new(copy), 10, 4),
+        ["the quick ", "brown fox ", "jumps     ", "          "]
+    );
+    assert_eq!(
+        paragraph_lines(ParagraphNode::new(copy).wrap(false), 10, 2),
+        ["the quick ", "          "]
+    );
+}
+
+#[test]
+fn paragraphs_align_each_line() {
+    use crate::runtime::{ParagraphNode, TextAlign};
+
+    let lines = |align| paragraph_lines(ParagraphNode::new("abc\nde").align(align), 9, 2);
+    assert_eq!(lines(TextAlign::Left), ["abc      ", "de       "]);
+    assert_eq!(lines(TextAlign::Center), ["   abc   ", "   de    "]);
+    assert_eq!(lines(TextAlign::Right), ["      abc", "       de"]);
+}
+
+#[test]
+fn paragraph_trim_controls_leading_whitespace_on_wrapped_lines() {
+    use crate::runtime::ParagraphNode;
+
+    let copy = "alpha\n    beta gamma";
+    assert_eq!(
+        paragraph_lines(ParagraphNode::new(copy), 12, 3),
+        ["alpha       ", "beta gamma  ", "            "]
+    );
+    assert_eq!(
+        paragraph_lines(ParagraphNode::new(copy).trim(false), 12, 3),
+        ["alpha       ", "    beta    ", "gamma       "]
+    );
+}
+
+#[test]
+fn paragraph_scroll_skips_lines_and_clears_past_the_end() {
+    use crate::runtime::ParagraphNode;
+
+    let copy = "one\ntwo\nthree";
+    assert_eq!(
+        paragraph_lines(ParagraphNode::new(copy).scroll(1), 6, 2),
+        ["two   ", "three "]
+    );
+    assert_eq!(
+        paragraph_lines(ParagraphNode::new(copy).scroll(10), 6, 2),
+        ["      ", "      "]
+    );
+}
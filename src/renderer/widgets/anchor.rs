@@ -0,0 +1,29 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::widgets::Clear;
+
+use crate::interactions::register_surface;
+use crate::renderer::measure::{measure_height, measure_width};
+use crate::runtime::AnchoredView;
+
+use super::RenderFn;
+
+pub fn render_anchored(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    view: &AnchoredView,
+    render_child: RenderFn,
+) {
+    let width = view
+        .width
+        .or_else(|| measure_width(&view.content))
+        .unwrap_or(area.width);
+    let height = view.height.unwrap_or_else(|| measure_height(&view.content));
+    let rect = view.anchor.place(area, view.offset, (width, height));
+    if rect.width == 0 || rect.height == 0 {
+        return;
+    }
+    frame.render_widget(Clear, rect);
+    register_surface(rect.into());
+    render_child(frame, rect, view.content.as_ref());
+}
@@ -1,20 +1,32 @@
 use ratatui::Frame;
 use ratatui::layout::Rect;
-use ratatui::widgets::{Block, Borders};
+use ratatui::style::Style;
 
+use crate::interactions::{Hitbox, current_layer, register_surface};
+use crate::renderer::damage::DamageCache;
 use crate::runtime::BlockView;
 
-use super::RenderFn;
+use super::{RenderFn, bordered, inset};
 
 pub fn render_block(frame: &mut Frame<'_>, area: Rect, view: &BlockView, render_child: RenderFn) {
-    let mut widget = Block::default().borders(Borders::ALL);
+    let area = inset(area, view.margin);
+    let mut widget = bordered(view.border_style);
     if let Some(title) = &view.title {
         widget = widget.title(title.as_str());
     }
+    if let Some(color) = view.background_color {
+        widget = widget.style(Style::default().bg(color));
+        // A filled block drawn over lower layers hides the widgets beneath it.
+        if current_layer() > 0 {
+            let hitbox = Hitbox::from(area);
+            register_surface(hitbox);
+            DamageCache::global().capture_surface(hitbox);
+        }
+    }
     frame.render_widget(widget.clone(), area);
 
     if let Some(child) = view.child.as_ref() {
-        let inner = widget.inner(area);
+        let inner = inset(widget.inner(area), view.padding);
         render_child(frame, inner, child);
     }
 }
@@ -10,12 +10,7 @@ use crate::runtime::ButtonView;
 pub fn render_button(frame: &mut Frame<'_>, area: Rect, view: &ButtonView) {
     register_button_hitbox(
         &view.id,
-        Hitbox {
-            x: area.x,
-            y: area.y,
-            width: area.width,
-            height: area.height,
-        },
+        Hitbox::new(area.x, area.y, area.width, area.height),
     );
 
     let mut style = Style::default();
@@ -0,0 +1,39 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use unicode_width::UnicodeWidthStr;
+
+use crate::interactions::{Hitbox, register_checkbox_hitbox};
+use crate::runtime::CheckboxView;
+
+pub fn render_checkbox(frame: &mut Frame<'_>, area: Rect, view: &CheckboxView) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let marker = if view.checked { "[x]" } else { "[ ]" };
+    let width = (UnicodeWidthStr::width(view.label.as_str()) + 4) as u16;
+    register_checkbox_hitbox(
+        &view.id,
+        Hitbox::new(area.x, area.y, width.min(area.width), 1),
+    );
+
+    let accent = view.accent.unwrap_or(Color::Cyan);
+    let mut marker_style = Style::default().fg(accent);
+    let mut label_style = Style::default();
+    if view.checked {
+        marker_style = marker_style.add_modifier(Modifier::BOLD);
+    }
+    if view.focused {
+        label_style = label_style.fg(accent).add_modifier(Modifier::UNDERLINED);
+    }
+    let line = Line::from(vec![
+        Span::styled(marker, marker_style),
+        Span::raw(" "),
+        Span::styled(view.label.clone(), label_style),
+    ]);
+    let row = Rect { height: 1, ..area };
+    frame.render_widget(Paragraph::new(line), row);
+}
@@ -1,23 +1,157 @@
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::layout::{Constraint, Direction, Flex, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::Block;
 
-use crate::runtime::{FlexDirection, FlexView};
+use crate::renderer::measure::{measure_height, measure_width};
+use crate::runtime::{Align, FlexDirection, FlexView, Justify, View};
 
-use super::RenderFn;
+use super::{RenderFn, inset};
 
 pub fn render_flex(frame: &mut Frame<'_>, area: Rect, view: &FlexView, render_child: RenderFn) {
     if view.children.is_empty() {
         return;
     }
 
-    let chunk_count = view.children.len();
-    let constraints = vec![Constraint::Ratio(1, chunk_count as u32); chunk_count];
+    let area = inset(area, view.margin);
+    if let Some(color) = view.background_color {
+        frame.render_widget(Block::default().style(Style::default().bg(color)), area);
+    }
+    let mut area = inset(area, view.padding);
+    let mut children = Vec::new();
+    let mut constraints = Vec::new();
+    let mut bottom = Vec::new();
+    for (index, child) in view.children.iter().enumerate() {
+        match pinned_edge(child) {
+            Some(Edge::Top) if area.height > 0 => {
+                render_child(frame, Rect { height: 1, ..area }, child);
+                area.y += 1;
+                area.height -= 1;
+            }
+            Some(Edge::Bottom) => bottom.push(child),
+            Some(Edge::Top) => {}
+            None => {
+                children.push(child);
+                constraints.push(
+                    view.constraints
+                        .get(index)
+                        .copied()
+                        .flatten()
+                        .unwrap_or_else(|| intrinsic(view, child)),
+                );
+            }
+        }
+    }
+    let bottom_rows = (bottom.len() as u16).min(area.height);
+    area.height -= bottom_rows;
+    for (row, child) in bottom.into_iter().take(bottom_rows as usize).enumerate() {
+        let rect = Rect {
+            y: area.y + area.height + row as u16,
+            height: 1,
+            ..area
+        };
+        render_child(frame, rect, child);
+    }
+    if children.is_empty() {
+        return;
+    }
+
     let layout = Layout::default()
         .direction(Direction::from(view.direction))
-        .constraints(constraints);
+        .constraints(constraints)
+        .spacing(view.gap)
+        .flex(match view.justify_content {
+            Justify::Stretch => Flex::Legacy,
+            Justify::Start => Flex::Start,
+            Justify::Center => Flex::Center,
+            Justify::End => Flex::End,
+            Justify::SpaceBetween => Flex::SpaceBetween,
+        });
     let chunks = layout.split(area);
-    for (child, rect) in view.children.iter().zip(chunks.iter()) {
-        render_child(frame, *rect, child);
+    for (child, rect) in children.into_iter().zip(chunks.iter()) {
+        render_child(frame, align(view, child, *rect), child);
+    }
+}
+
+fn intrinsic(view: &FlexView, child: &View) -> Constraint {
+    if let View::Constrained(constrained) = child {
+        let dimensions = constrained.dimensions;
+        let (fixed, min, max) = match view.direction {
+            FlexDirection::Row => (dimensions.width, dimensions.min_width, dimensions.max_width),
+            FlexDirection::Column => (
+                dimensions.height,
+                dimensions.min_height,
+                dimensions.max_height,
+            ),
+        };
+        if fixed.is_some() {
+            return Constraint::Length(match view.direction {
+                FlexDirection::Row => dimensions.clamp_width(0),
+                FlexDirection::Column => dimensions.clamp_height(0),
+            });
+        }
+        if let Some(max) = max {
+            return Constraint::Max(max);
+        }
+        if min > 0 && view.justify_content == Justify::Stretch {
+            return Constraint::Min(min);
+        }
+    }
+    if view.justify_content == Justify::Stretch {
+        return Constraint::Fill(1);
+    }
+    let size = match view.direction {
+        FlexDirection::Row => measure_width(child),
+        FlexDirection::Column => Some(measure_height(child)),
+    };
+    size.map(Constraint::Length).unwrap_or(Constraint::Fill(1))
+}
+
+fn align(view: &FlexView, child: &View, rect: Rect) -> Rect {
+    if view.align_items == Align::Stretch {
+        return rect;
+    }
+    let (start, available) = match view.direction {
+        FlexDirection::Row => (rect.y, rect.height),
+        FlexDirection::Column => (rect.x, rect.width),
+    };
+    let size = match view.direction {
+        FlexDirection::Row => Some(measure_height(child)),
+        FlexDirection::Column => measure_width(child),
+    };
+    let Some(size) = size.map(|size| size.min(available)) else {
+        return rect;
+    };
+    let offset = match view.align_items {
+        Align::Center => (available - size) / 2,
+        Align::End => available - size,
+        Align::Start | Align::Stretch => 0,
+    };
+    match view.direction {
+        FlexDirection::Row => Rect {
+            y: start + offset,
+            height: size,
+            ..rect
+        },
+        FlexDirection::Column => Rect {
+            x: start + offset,
+            width: size,
+            ..rect
+        },
+    }
+}
+
+enum Edge {
+    Top,
+    Bottom,
+}
+
+fn pinned_edge(view: &View) -> Option<Edge> {
+    match view {
+        View::MenuBar(_) => Some(Edge::Top),
+        View::StatusBar(_) => Some(Edge::Bottom),
+        View::Shared(shared) => pinned_edge(shared),
+        _ => None,
     }
 }
 
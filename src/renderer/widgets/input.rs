@@ -1,14 +1,19 @@
+use std::ops::Range;
+
 use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::Line;
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
 use unicode_width::UnicodeWidthStr;
 
 use crate::interactions::Hitbox;
+use crate::renderer::popover::{self, Popover};
 use crate::runtime::{FormFieldStatus, TextInputView};
 use crate::text_input::TextInputs;
 
+use super::bordered;
+
 pub fn render_text_input(frame: &mut Frame<'_>, area: Rect, input: &TextInputView) {
     if area.width == 0 || area.height == 0 {
         return;
@@ -46,7 +51,7 @@ pub fn render_text_input(frame: &mut Frame<'_>, area: Rect, input: &TextInputVie
     let mut render_area = input_area;
     render_area.width = desired_width.min(input_area.width);
 
-    let mut block = Block::default().borders(Borders::ALL);
+    let mut block = bordered(input.border_style);
     let status_color = status_to_color(input.status);
     let accent = input.accent.unwrap_or(Color::Cyan);
     let default_border = input.border_color.unwrap_or(Color::DarkGray);
@@ -65,12 +70,12 @@ pub fn render_text_input(frame: &mut Frame<'_>, area: Rect, input: &TextInputVie
 
     TextInputs::register_hitbox(
         &input.id,
-        Hitbox {
-            x: render_area.x,
-            y: render_area.y,
-            width: render_area.width,
-            height: render_area.height.max(1),
-        },
+        Hitbox::new(
+            render_area.x,
+            render_area.y,
+            render_area.width,
+            render_area.height.max(1),
+        ),
     );
 
     let background_color = if input.focused {
@@ -100,7 +105,21 @@ pub fn render_text_input(frame: &mut Frame<'_>, area: Rect, input: &TextInputVie
         text_style = text_style.fg(color);
     }
 
-    let mut paragraph = Paragraph::new(Line::from(content)).block(block.clone());
+    let line = if showing_placeholder {
+        Line::from(content)
+    } else {
+        let selection = input.selection.as_ref().map(|range| {
+            if input.secure {
+                let start = input.value[..range.start].chars().count();
+                let end = input.value[..range.end].chars().count();
+                start..end
+            } else {
+                range.clone()
+            }
+        });
+        selection_line(&content, 0, selection.as_ref())
+    };
+    let mut paragraph = Paragraph::new(line).block(block.clone());
     if showing_placeholder {
         let placeholder_color = input.placeholder_color.unwrap_or(Color::DarkGray);
         paragraph = paragraph.style(text_style.fg(placeholder_color));
@@ -109,6 +128,16 @@ pub fn render_text_input(frame: &mut Frame<'_>, area: Rect, input: &TextInputVie
     }
     frame.render_widget(paragraph, render_area);
 
+    if input.focused && !input.suggestions.is_empty() {
+        popover::queue(Popover {
+            anchor: render_area,
+            items: input.suggestions.clone(),
+            highlighted: input.highlighted_suggestion,
+            accent,
+            owner: None,
+        });
+    }
+
     if input.focused && input.cursor_visible {
         let inner = block.inner(render_area);
         if inner.height > 0 {
@@ -131,7 +160,31 @@ pub fn render_text_input(frame: &mut Frame<'_>, area: Rect, input: &TextInputVie
     }
 }
 
-fn status_to_color(status: FormFieldStatus) -> Option<Color> {
+pub(super) fn selection_line(
+    text: &str,
+    offset: usize,
+    selection: Option<&Range<usize>>,
+) -> Line<'static> {
+    let Some(selection) = selection else {
+        return Line::from(text.to_string());
+    };
+    let end = offset + text.len();
+    let start = selection.start.clamp(offset, end) - offset;
+    let stop = selection.end.clamp(offset, end) - offset;
+    if start == stop {
+        return Line::from(text.to_string());
+    }
+    Line::from(vec![
+        Span::raw(text[..start].to_string()),
+        Span::styled(
+            text[start..stop].to_string(),
+            Style::default().add_modifier(Modifier::REVERSED),
+        ),
+        Span::raw(text[stop..].to_string()),
+    ])
+}
+
+pub(super) fn status_to_color(status: FormFieldStatus) -> Option<Color> {
     match status {
         FormFieldStatus::Normal => None,
         FormFieldStatus::Warning => Some(Color::Yellow),
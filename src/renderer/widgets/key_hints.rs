@@ -0,0 +1,33 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::runtime::KeyHintsView;
+
+pub fn render_key_hints(frame: &mut Frame<'_>, area: Rect, view: &KeyHintsView) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let key_style = Style::default()
+        .fg(view.key_color.unwrap_or(Color::Cyan))
+        .add_modifier(Modifier::BOLD);
+    let text_style = Style::default().fg(view.color.unwrap_or(Color::Gray));
+    let mut spans = Vec::new();
+    for (index, hint) in view.hints.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(
+                view.separator.as_str(),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        spans.push(Span::styled(hint.key.as_str(), key_style));
+        spans.push(Span::styled(format!(" {}", hint.description), text_style));
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(spans)),
+        Rect { height: 1, ..area },
+    );
+}
@@ -1,12 +1,19 @@
 use ratatui::Frame;
 use ratatui::layout::Rect;
 
+use crate::interactions::{enter_layer, leave_layer};
 use crate::runtime::LayersView;
 
 use super::RenderFn;
 
 pub fn render_layers(frame: &mut Frame<'_>, area: Rect, view: &LayersView, render_child: RenderFn) {
-    for layer in &view.layers {
+    for (index, layer) in view.layers.iter().enumerate() {
+        if index == 0 {
+            render_child(frame, area, layer);
+            continue;
+        }
+        let previous = enter_layer();
         render_child(frame, area, layer);
+        leave_layer(previous);
     }
 }
@@ -1,43 +1,94 @@
+use std::borrow::Cow;
+
 use ratatui::Frame;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::Line;
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState};
+
+use crate::interactions::{Hitbox, register_rows};
+use crate::runtime::{BorderStyle, ListView};
 
-use crate::runtime::ListView;
+use super::bordered;
 
 pub fn render_list(frame: &mut Frame<'_>, area: Rect, view: &ListView) {
-    let items: Vec<ListItem> = if view.items.is_empty() {
+    let block = (view.border_style != BorderStyle::None || view.title.is_some()).then(|| {
+        let block = bordered(view.border_style);
+        match &view.title {
+            Some(title) => block.title(title.as_str()),
+            None => block,
+        }
+    });
+    let inner = block.as_ref().map_or(area, |block| block.inner(area));
+
+    // Virtual lists build only the rows that fit the area, numbered from `first`.
+    let (first, rows) = match &view.virtual_rows {
+        Some(rows) => (rows.start, Cow::Owned(rows.rows(inner.height))),
+        None => (0, Cow::Borrowed(view.items.as_slice())),
+    };
+
+    let items: Vec<ListItem> = if rows.is_empty() {
         vec![ListItem::new(Line::from("(no entries)"))]
     } else {
-        view.items
-            .iter()
-            .map(|item| {
+        rows.iter()
+            .enumerate()
+            .map(|(position, item)| {
+                let index = first + position;
                 let mut line = Line::from(item.content.clone());
+                if let Some(checked) = &view.checked {
+                    let marker = if checked.contains(&index) {
+                        "[x] "
+                    } else {
+                        "[ ] "
+                    };
+                    line.spans.insert(0, Span::raw(marker));
+                }
+                let mut style = Style::default().add_modifier(item.modifiers);
                 if let Some(color) = item.color {
-                    line = line.style(Style::default().fg(color));
+                    style = style.fg(color);
                 }
+                if view.hovered == Some(index) {
+                    style = style.patch(view.hover_style);
+                }
+                line = line.style(style);
                 ListItem::new(line)
             })
             .collect()
     };
 
     let mut widget = List::new(items);
-    if let Some(title) = &view.title {
-        widget = widget.block(Block::default().borders(Borders::ALL).title(title.as_str()));
+    if let Some(block) = block {
+        widget = widget.block(block);
     }
 
-    if let Some(index) = view.highlight.filter(|_| !view.items.is_empty()) {
-        let mut state = ListState::default();
-        state.select(Some(index.min(view.items.len() - 1)));
-        let highlight_color = view.highlight_color.unwrap_or(Color::Yellow);
-        widget = widget.highlight_symbol("▶ ").highlight_style(
-            Style::default()
-                .fg(highlight_color)
-                .add_modifier(Modifier::BOLD),
-        );
-        frame.render_stateful_widget(widget, area, &mut state);
-    } else {
-        frame.render_widget(widget, area);
+    let highlight = match &view.virtual_rows {
+        Some(_) => view
+            .highlight
+            .and_then(|index| index.checked_sub(first))
+            .filter(|position| *position < rows.len()),
+        None => view
+            .highlight
+            .filter(|_| !rows.is_empty())
+            .map(|index| index.min(rows.len() - 1)),
+    };
+    let mut state = ListState::default().with_offset(view.offset);
+    if let Some(position) = highlight {
+        state.select(Some(position));
+        widget = widget
+            .highlight_symbol("▶ ")
+            .highlight_style(view.highlight_style);
+    }
+    frame.render_stateful_widget(widget, area, &mut state);
+
+    if let Some(id) = &view.id {
+        let rows = (state.offset()..rows.len())
+            .take(inner.height as usize)
+            .enumerate()
+            .map(|(line, position)| {
+                let hitbox = Hitbox::new(inner.x, inner.y + line as u16, inner.width, 1);
+                (hitbox, first + position)
+            })
+            .collect();
+        register_rows(id, rows);
     }
 }
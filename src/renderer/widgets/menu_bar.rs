@@ -0,0 +1,156 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use unicode_width::UnicodeWidthStr;
+
+use crate::interactions::Hitbox;
+use crate::menu::MenuBars;
+use crate::renderer::popover;
+use crate::runtime::{MenuBarView, MenuEntryView, MenuLevelView};
+
+pub fn render_menu_bar(frame: &mut Frame<'_>, area: Rect, view: &MenuBarView) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let bar = Rect { height: 1, ..area };
+    let accent = view.accent.unwrap_or(Color::Cyan);
+    let right = bar.x.saturating_add(bar.width);
+    let mut spans = Vec::new();
+    let mut x = bar.x;
+    let mut anchor = bar.x;
+    for (index, title) in view.titles.iter().enumerate() {
+        let label = format!(" {title} ");
+        let width = (label.width() as u16).min(right.saturating_sub(x));
+        if width == 0 {
+            break;
+        }
+        MenuBars::record_title(&view.id, index, Hitbox::new(x, bar.y, width, 1));
+        let style = if view.active == Some(index) {
+            anchor = x;
+            Style::default()
+                .fg(Color::Black)
+                .bg(accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().add_modifier(Modifier::BOLD)
+        };
+        spans.push(Span::styled(label, style));
+        x = x.saturating_add(width);
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), bar);
+
+    if view.active.is_some() && !view.levels.is_empty() {
+        let id = view.id.clone();
+        let levels = view.levels.clone();
+        let origin = (anchor, bar.y.saturating_add(1));
+        popover::defer(move |frame| render_dropdowns(frame, &id, &levels, origin, accent));
+    }
+}
+
+fn render_dropdowns(
+    frame: &mut Frame<'_>,
+    id: &str,
+    levels: &[MenuLevelView],
+    origin: (u16, u16),
+    accent: Color,
+) {
+    let bounds = frame.size();
+    let right = bounds.x.saturating_add(bounds.width);
+    let bottom = bounds.y.saturating_add(bounds.height);
+    let mut path = Vec::new();
+    let mut previous: Option<Rect> = None;
+    for level in levels {
+        let inner_width = level.items.iter().map(entry_width).max().unwrap_or(0);
+        let width = (inner_width as u16).saturating_add(2).min(bounds.width);
+        let height = (level.items.len() as u16)
+            .saturating_add(2)
+            .min(bounds.height);
+        let (mut x, mut y) = match previous {
+            None => origin,
+            Some(parent) => {
+                let row = parent.y + 1 + path.last().copied().unwrap_or(0) as u16;
+                let beside = parent.x.saturating_add(parent.width);
+                if beside.saturating_add(width) <= right {
+                    (beside, row.saturating_sub(1))
+                } else {
+                    (parent.x.saturating_sub(width), row.saturating_sub(1))
+                }
+            }
+        };
+        x = x.min(right.saturating_sub(width));
+        y = y.min(bottom.saturating_sub(height));
+        let area = Rect {
+            x,
+            y,
+            width,
+            height,
+        };
+        if area.width < 3 || area.height < 3 {
+            return;
+        }
+
+        let inner = area.width.saturating_sub(2) as usize;
+        let lines: Vec<Line> = level
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let marker = if item.has_children { " ▸" } else { "" };
+                let hint = item.shortcut.as_deref().unwrap_or("");
+                let used = item.label.width() + hint.width() + marker.width() + 2;
+                let gap = " ".repeat(inner.saturating_sub(used));
+                let mut style = Style::default();
+                if index == level.highlighted {
+                    style = style
+                        .fg(Color::Black)
+                        .bg(accent)
+                        .add_modifier(Modifier::BOLD);
+                }
+                Line::from(vec![
+                    Span::styled(format!(" {}{gap}", item.label), style),
+                    Span::styled(hint.to_string(), style.add_modifier(Modifier::DIM)),
+                    Span::styled(format!("{marker} "), style),
+                ])
+            })
+            .collect();
+        let visible = level
+            .items
+            .len()
+            .min(area.height.saturating_sub(2) as usize);
+        for row in 0..visible {
+            let mut item_path = path.clone();
+            item_path.push(row);
+            MenuBars::record_item(
+                id,
+                item_path,
+                Hitbox::new(
+                    area.x + 1,
+                    area.y + 1 + row as u16,
+                    area.width.saturating_sub(2),
+                    1,
+                ),
+            );
+        }
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(accent));
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+
+        path.push(level.highlighted);
+        previous = Some(area);
+    }
+}
+
+fn entry_width(entry: &MenuEntryView) -> usize {
+    let hint = entry
+        .shortcut
+        .as_deref()
+        .map(|hint| hint.width() + 2)
+        .unwrap_or(0);
+    let marker = if entry.has_children { 2 } else { 0 };
+    entry.label.width() + hint + marker + 2
+}
@@ -1,36 +1,89 @@
 use ratatui::Frame;
 use ratatui::layout::Rect;
+use ratatui::widgets::{Block, BorderType, Borders};
 
-use crate::runtime::View;
+use crate::runtime::{BorderStyle, Spacing, View};
 
 pub type RenderFn = fn(&mut Frame<'_>, Rect, &View);
 
+pub(crate) fn inset(area: Rect, spacing: Spacing) -> Rect {
+    let x = area.x.saturating_add(spacing.left.min(area.width));
+    let y = area.y.saturating_add(spacing.top.min(area.height));
+    Rect {
+        x,
+        y,
+        width: area.width.saturating_sub(spacing.horizontal()),
+        height: area.height.saturating_sub(spacing.vertical()),
+    }
+}
+
+pub(crate) fn bordered(style: BorderStyle) -> Block<'static> {
+    let border_type = match style {
+        BorderStyle::None => return Block::default(),
+        BorderStyle::Plain => BorderType::Plain,
+        BorderStyle::Rounded => BorderType::Rounded,
+        BorderStyle::Double => BorderType::Double,
+        BorderStyle::Thick => BorderType::Thick,
+    };
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(border_type)
+}
+
+pub mod anchor;
 pub mod block;
 pub mod button;
+pub mod checkbox;
 pub mod flex;
 pub mod form;
 pub mod gauge;
 pub mod input;
+pub mod key_hints;
 pub mod layers;
 pub mod list;
+pub mod menu_bar;
 pub mod modal;
+pub mod progress;
+pub mod scroll;
+pub mod select;
+pub mod spinner;
+pub mod split;
+pub mod status_bar;
+pub mod stepper;
 pub mod table;
 pub mod tabs;
 pub mod text;
+pub mod text_area;
+pub mod time_picker;
 pub mod toast;
+pub mod tooltip;
 pub mod tree;
 
+pub use anchor::render_anchored;
 pub use block::render_block;
 pub use button::render_button;
+pub use checkbox::render_checkbox;
 pub use flex::render_flex;
 pub use form::render_form;
 pub use gauge::render_gauge;
 pub use input::render_text_input;
+pub use key_hints::render_key_hints;
 pub use layers::render_layers;
 pub use list::render_list;
+pub use menu_bar::render_menu_bar;
 pub use modal::render_modal;
+pub use progress::render_progress;
+pub use scroll::render_scroll;
+pub use select::render_select;
+pub use spinner::render_spinner;
+pub use split::render_split;
+pub use status_bar::render_status_bar;
+pub use stepper::render_stepper;
 pub use table::render_table;
 pub use tabs::render_tabs;
-pub use text::render_text;
+pub use text::{render_paragraph, render_text};
+pub use text_area::render_text_area;
+pub use time_picker::render_time_picker;
 pub use toast::render_toast_stack;
+pub use tooltip::render_tooltip;
 pub use tree::render_tree;
@@ -3,6 +3,7 @@ use ratatui::layout::Rect;
 use ratatui::style::{Color, Style};
 use ratatui::widgets::{Block, Borders, Clear};
 
+use crate::interactions::register_surface;
 use crate::runtime::ModalView;
 
 use super::RenderFn;
@@ -15,6 +16,7 @@ pub fn render_modal(frame: &mut Frame<'_>, area: Rect, view: &ModalView, render_
     let modal_area = Rect::new(origin_x, origin_y, width, height);
 
     frame.render_widget(Clear, modal_area);
+    register_surface(modal_area.into());
     let block = Block::default()
         .title(view.title.clone().unwrap_or_else(|| "Modal".to_string()))
         .borders(Borders::ALL)
@@ -0,0 +1,67 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use unicode_width::UnicodeWidthStr;
+
+use crate::runtime::{ProgressStyle, ProgressView};
+
+const STRIPE: usize = 6;
+
+pub fn render_progress(frame: &mut Frame<'_>, area: Rect, view: &ProgressView) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let label_width = view
+        .label
+        .as_deref()
+        .map(|label| UnicodeWidthStr::width(label) as u16 + 1)
+        .unwrap_or(0);
+    let bar_width = area.width.saturating_sub(label_width).max(1) as usize;
+    let (full, partials, track) = glyphs(view.style);
+    let color = view.color.unwrap_or(Color::Cyan);
+    let fill_style = Style::default().fg(color);
+    let track_style = Style::default().fg(Color::DarkGray);
+
+    let mut spans = Vec::new();
+    match view.ratio {
+        Some(ratio) => {
+            let eighths = (ratio.clamp(0.0, 1.0) * bar_width as f64 * 8.0).round() as usize;
+            let whole = eighths / 8;
+            let mut filled = full.repeat(whole);
+            let mut used = whole;
+            if whole < bar_width && eighths % 8 > 0 {
+                filled.push_str(partials[eighths % 8 - 1]);
+                used += 1;
+            }
+            spans.push(Span::styled(filled, fill_style));
+            spans.push(Span::styled(track.repeat(bar_width - used), track_style));
+        }
+        None => {
+            let shift = STRIPE - view.phase % STRIPE;
+            for cell in 0..bar_width {
+                if (cell + shift) % STRIPE < STRIPE / 2 {
+                    spans.push(Span::styled(full, fill_style));
+                } else {
+                    spans.push(Span::styled(track, track_style));
+                }
+            }
+        }
+    }
+    if let Some(label) = &view.label {
+        spans.push(Span::raw(" "));
+        spans.push(Span::raw(label.clone()));
+    }
+
+    let row = Rect { height: 1, ..area };
+    frame.render_widget(Paragraph::new(Line::from(spans)), row);
+}
+
+fn glyphs(style: ProgressStyle) -> (&'static str, [&'static str; 7], &'static str) {
+    match style {
+        ProgressStyle::Blocks => ("█", ["▏", "▎", "▍", "▌", "▋", "▊", "▉"], "░"),
+        ProgressStyle::Braille => ("⣿", ["⡀", "⡄", "⡆", "⡇", "⣇", "⣧", "⣷"], "⣀"),
+    }
+}
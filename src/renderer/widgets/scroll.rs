@@ -0,0 +1,125 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::widgets::{Block, Borders, Scrollbar, ScrollbarOrientation, ScrollbarState};
+
+use crate::interactions::Hitbox;
+use crate::renderer::measure::measure_height;
+use crate::runtime::{FlexDirection, ScrollView, View};
+use crate::scroll::{ScrollMetrics, Scrolls};
+
+use super::RenderFn;
+
+pub fn render_scroll(frame: &mut Frame<'_>, area: Rect, view: &ScrollView, render_child: RenderFn) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let content_height = view
+        .children
+        .iter()
+        .map(measure_height)
+        .fold(0u16, u16::saturating_add);
+    let overflowing = content_height > area.height;
+    let mut viewport = area;
+    if overflowing && view.scrollbar {
+        viewport.width = viewport.width.saturating_sub(1);
+    }
+
+    let max_offset = content_height.saturating_sub(viewport.height);
+    let offset = view.offset.min(max_offset);
+    Scrolls::record(
+        &view.id,
+        Hitbox::new(area.x, area.y, area.width, area.height),
+        ScrollMetrics {
+            offset,
+            content_height,
+            viewport_height: viewport.height,
+        },
+    );
+
+    render_column(frame, viewport, &view.children, offset, render_child);
+
+    if overflowing && view.scrollbar {
+        let mut state = ScrollbarState::new(max_offset as usize)
+            .viewport_content_length(viewport.height as usize)
+            .position(offset as usize);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            area,
+            &mut state,
+        );
+    }
+}
+
+fn render_column(
+    frame: &mut Frame<'_>,
+    viewport: Rect,
+    children: &[View],
+    skip: u16,
+    render_child: RenderFn,
+) {
+    let mut cursor: u16 = 0;
+    for child in children {
+        let height = measure_height(child);
+        let start = cursor;
+        cursor = cursor.saturating_add(height);
+        if height == 0 || cursor <= skip {
+            continue;
+        }
+        let visible_top = start.max(skip) - skip;
+        if visible_top >= viewport.height {
+            break;
+        }
+        let clipped = skip.saturating_sub(start);
+        let rect = Rect {
+            x: viewport.x,
+            y: viewport.y + visible_top,
+            width: viewport.width,
+            height: (height - clipped).min(viewport.height - visible_top),
+        };
+        render_clipped(frame, rect, child, clipped, render_child);
+    }
+}
+
+fn render_clipped(
+    frame: &mut Frame<'_>,
+    rect: Rect,
+    view: &View,
+    clipped: u16,
+    render_child: RenderFn,
+) {
+    // Columns and blocks inside a scroll area are laid out by measured height so
+    // rows can be scrolled past individually; other widgets stay hidden until
+    // their first row is back in view.
+    match view {
+        View::Flex(flex) if flex.direction == FlexDirection::Column => {
+            render_column(frame, rect, &flex.children, clipped, render_child);
+        }
+        View::Block(block) => {
+            let mut borders = Borders::LEFT | Borders::RIGHT;
+            if clipped == 0 {
+                borders |= Borders::TOP;
+            }
+            if measure_height(view).saturating_sub(clipped) <= rect.height {
+                borders |= Borders::BOTTOM;
+            }
+            let mut widget = Block::default().borders(borders);
+            if let Some(title) = block.title.as_ref().filter(|_| clipped == 0) {
+                widget = widget.title(title.as_str());
+            }
+            let inner = widget.inner(rect);
+            frame.render_widget(widget, rect);
+            if let Some(child) = block.child.as_deref() {
+                let skip = clipped.saturating_sub(1);
+                let child_rect = Rect {
+                    height: inner.height.min(measure_height(child).saturating_sub(skip)),
+                    ..inner
+                };
+                render_clipped(frame, child_rect, child, skip, render_child);
+            }
+        }
+        View::Shared(shared) => render_clipped(frame, rect, shared, clipped, render_child),
+        _ if clipped == 0 => render_child(frame, rect, view),
+        _ => {}
+    }
+}
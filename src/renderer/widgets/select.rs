@@ -0,0 +1,77 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use crate::interactions::Hitbox;
+use crate::renderer::popover::{self, Popover};
+use crate::runtime::SelectView;
+use crate::select::Selects;
+
+pub fn render_select(frame: &mut Frame<'_>, area: Rect, view: &SelectView) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let mut render_area = area;
+    render_area.width = view.width.unwrap_or(area.width).min(area.width);
+    render_area.height = render_area.height.min(3);
+    Selects::record(
+        &view.id,
+        Hitbox::new(
+            render_area.x,
+            render_area.y,
+            render_area.width,
+            render_area.height,
+        ),
+    );
+
+    let accent = view.accent.unwrap_or(Color::Cyan);
+    let mut border_style = Style::default().fg(Color::DarkGray);
+    if view.focused || view.open {
+        border_style = Style::default().fg(accent).add_modifier(Modifier::BOLD);
+    }
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    if let Some(label) = &view.label {
+        block = block.title(label.as_str());
+    }
+
+    let current = match view.options.get(view.selected) {
+        Some(option) => Span::raw(option.clone()),
+        None => Span::styled(
+            view.placeholder.clone().unwrap_or_default(),
+            Style::default().fg(Color::DarkGray),
+        ),
+    };
+    let arrow = if view.open { " ▴" } else { " ▾" };
+    let inner = block.inner(render_area);
+    let indicator_width = (arrow.chars().count() as u16).min(inner.width);
+    let value_area = Rect {
+        width: inner.width.saturating_sub(indicator_width),
+        ..inner
+    };
+    let indicator_area = Rect {
+        x: value_area.x + value_area.width,
+        width: indicator_width,
+        ..inner
+    };
+    frame.render_widget(block, render_area);
+    frame.render_widget(Paragraph::new(Line::from(current)), value_area);
+    frame.render_widget(
+        Paragraph::new(Line::styled(arrow, Style::default().fg(accent))),
+        indicator_area,
+    );
+
+    if view.open {
+        popover::queue(Popover {
+            anchor: render_area,
+            items: view.options.clone(),
+            highlighted: Some(view.highlighted),
+            accent,
+            owner: Some(view.id.clone()),
+        });
+    }
+}
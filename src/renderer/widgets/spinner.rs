@@ -0,0 +1,17 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::runtime::SpinnerView;
+
+pub fn render_spinner(frame: &mut Frame<'_>, area: Rect, view: &SpinnerView) {
+    let color = view.color.unwrap_or(Color::Cyan);
+    let mut spans = vec![Span::styled(view.glyph, Style::default().fg(color))];
+    if let Some(label) = &view.label {
+        spans.push(Span::raw(" "));
+        spans.push(Span::raw(label.clone()));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
@@ -0,0 +1,84 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+
+use crate::interactions::Hitbox;
+use crate::runtime::{FlexDirection, SplitView};
+use crate::split::{Splits, pane_sizes};
+
+use super::RenderFn;
+
+pub fn render_split(frame: &mut Frame<'_>, area: Rect, view: &SplitView, render_child: RenderFn) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let (first, divider, second) = match view.direction {
+        FlexDirection::Row => {
+            let (left, right) = pane_sizes(area.width, view.ratio, view.min_size);
+            (
+                Rect {
+                    width: left,
+                    ..area
+                },
+                Rect {
+                    x: area.x + left,
+                    width: 1.min(area.width),
+                    ..area
+                },
+                Rect {
+                    x: area.x + area.width - right,
+                    width: right,
+                    ..area
+                },
+            )
+        }
+        FlexDirection::Column => {
+            let (top, bottom) = pane_sizes(area.height, view.ratio, view.min_size);
+            (
+                Rect {
+                    height: top,
+                    ..area
+                },
+                Rect {
+                    y: area.y + top,
+                    height: 1.min(area.height),
+                    ..area
+                },
+                Rect {
+                    y: area.y + area.height - bottom,
+                    height: bottom,
+                    ..area
+                },
+            )
+        }
+    };
+    render_child(frame, first, view.first.as_ref());
+    render_child(frame, second, view.second.as_ref());
+
+    let color = if view.focused {
+        view.accent.unwrap_or(Color::Cyan)
+    } else {
+        Color::DarkGray
+    };
+    let lines: Vec<Line> = match view.direction {
+        FlexDirection::Row => (0..divider.height).map(|_| Line::from("│")).collect(),
+        FlexDirection::Column => vec![Line::from("─".repeat(divider.width as usize))],
+    };
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(color)),
+        divider,
+    );
+    Splits::record(
+        &view.id,
+        view.direction,
+        to_hitbox(area),
+        to_hitbox(divider),
+    );
+}
+
+fn to_hitbox(rect: Rect) -> Hitbox {
+    Hitbox::new(rect.x, rect.y, rect.width, rect.height)
+}
@@ -0,0 +1,52 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::runtime::{StatusBarView, StatusSegment};
+
+pub fn render_status_bar(frame: &mut Frame<'_>, area: Rect, view: &StatusBarView) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let bar = Rect { height: 1, ..area };
+    let base = Style::default()
+        .fg(view.color.unwrap_or(Color::White))
+        .bg(view.background_color.unwrap_or(Color::DarkGray));
+    frame.render_widget(Paragraph::new("").style(base), bar);
+    for (segments, alignment) in [
+        (&view.center, Alignment::Center),
+        (&view.left, Alignment::Left),
+        (&view.right, Alignment::Right),
+    ] {
+        if segments.is_empty() {
+            continue;
+        }
+        let line = segment_line(segments, &view.separator, base);
+        frame.render_widget(Paragraph::new(line).alignment(alignment), bar);
+    }
+}
+
+fn segment_line<'a>(segments: &'a [StatusSegment], separator: &'a str, base: Style) -> Line<'a> {
+    let mut spans = vec![Span::styled(" ", base)];
+    for (index, segment) in segments.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(separator, base.add_modifier(Modifier::DIM)));
+        }
+        let mut style = base;
+        if let Some(color) = segment.color {
+            style = style.fg(color);
+        }
+        if let Some(color) = segment.background_color {
+            style = style.bg(color);
+        }
+        if segment.bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        spans.push(Span::styled(segment.text.as_str(), style));
+    }
+    spans.push(Span::styled(" ", base));
+    Line::from(spans)
+}
@@ -0,0 +1,45 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::runtime::StepperView;
+
+pub fn render_stepper(frame: &mut Frame<'_>, area: Rect, view: &StepperView) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let accent = view.accent.unwrap_or(Color::Cyan);
+    let mut spans = Vec::new();
+    for (index, step) in view.steps.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(" ── ", Style::default().fg(Color::DarkGray)));
+        }
+        let (marker, style) = if view.completed || index < view.current {
+            ("✓", Style::default().fg(Color::Green))
+        } else if index == view.current {
+            (
+                "●",
+                Style::default().fg(accent).add_modifier(Modifier::BOLD),
+            )
+        } else if index <= view.furthest {
+            ("○", Style::default().fg(Color::Gray))
+        } else {
+            ("○", Style::default().fg(Color::DarkGray))
+        };
+        spans.push(Span::styled(
+            format!("{marker} {}. {step}", index + 1),
+            style,
+        ));
+    }
+    let mut lines = vec![Line::from(spans)];
+    if let Some(error) = &view.error {
+        lines.push(Line::from(Span::styled(
+            format!("✗ {error}"),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    frame.render_widget(Paragraph::new(lines), area);
+}
@@ -1,59 +1,253 @@
+use std::rc::Rc;
+
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Rect};
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::Span;
-use ratatui::widgets::{Block, Borders, Cell, Row, Table, TableState};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Cell, Clear, Paragraph, Row, Table, TableState};
+use unicode_width::UnicodeWidthStr;
+
+use crate::interactions::{
+    Hitbox, PageStep, TableHeaderHit, register_button_hitbox, register_rows, register_table_header,
+    table_pager_id,
+};
+use crate::runtime::{SortDirection, TableEditorView, TablePagerView, TableRowView, TableView};
+
+use super::bordered;
+use super::input::render_text_input;
 
-use crate::runtime::{TableRowView, TableView};
+const PREV_LABEL: &str = "‹ Prev";
+const NEXT_LABEL: &str = "Next ›";
 
 pub fn render_table(frame: &mut Frame<'_>, area: Rect, view: &TableView) {
-    let mut block = Block::default().borders(Borders::ALL);
+    let mut block = bordered(view.border_style);
     if let Some(title) = &view.title {
         block = block.title(title.as_str());
     }
 
-    let rows: Vec<Row> = if view.rows.is_empty() {
-        vec![Row::new(vec![Cell::from("(no rows)")])]
+    let widths = resolve_table_widths(view);
+    let inner = block.inner(area);
+    let column_widths: Vec<u16> = column_rects(inner, &widths)
+        .iter()
+        .map(|rect| rect.width)
+        .collect();
+    let (rows, heights): (Vec<Row>, Vec<u16>) = if view.rows.is_empty() {
+        (vec![Row::new(vec![Cell::from("(no rows)")])], Vec::new())
     } else {
-        view.rows.iter().map(build_table_row).collect()
+        view.rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                let active = view.active_column.filter(|_| view.highlight == Some(index));
+                let (row, height) = build_table_row(row, &column_widths, active);
+                if view.hovered == Some(index) {
+                    (row.style(view.hover_style), height)
+                } else {
+                    (row, height)
+                }
+            })
+            .unzip()
     };
 
-    let widths = resolve_table_widths(view);
-    let mut widget = Table::new(rows, widths).block(block).column_spacing(1);
+    if let (Some(id), Some(_)) = (&view.id, &view.header) {
+        register_header_hitboxes(id, inner, &widths, view.sort);
+    }
+    let mut widget = Table::new(rows, widths.clone())
+        .block(block)
+        .column_spacing(1);
+    let mut header_height = 0;
     if let Some(header) = view.header.as_ref() {
-        widget = widget.header(build_table_row(header));
+        let mut header = header.clone();
+        if let Some(cell) = view
+            .sort
+            .and_then(|(column, _)| header.cells.get_mut(column))
+        {
+            let arrow = match view.sort.map(|(_, direction)| direction) {
+                Some(SortDirection::Descending) => "▼",
+                _ => "▲",
+            };
+            cell.content = format!("{} {arrow}", cell.content);
+        }
+        let (header, height) = build_table_row(&header, &column_widths, None);
+        header_height = height;
+        widget = widget.header(header);
     }
 
+    let mut state = TableState::default().with_offset(view.offset);
     if let Some(index) = view.highlight.filter(|_| !view.rows.is_empty()) {
-        let mut state = TableState::default();
         state.select(Some(index.min(view.rows.len() - 1)));
-        widget = widget.highlight_style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::REVERSED),
+        widget = widget.highlight_style(view.highlight_style);
+    }
+    frame.render_stateful_widget(widget, area, &mut state);
+    if let Some(id) = &view.id {
+        register_row_hitboxes(
+            id,
+            inner,
+            header_height,
+            state.offset(),
+            &heights,
+            view.pager,
         );
-        frame.render_stateful_widget(widget, area, &mut state);
-    } else {
-        frame.render_widget(widget, area);
     }
+    if let Some(editor) = &view.editor {
+        let header = u16::from(view.header.is_some());
+        render_cell_editor(frame, area, inner, &widths, header, state.offset(), editor);
+    }
+    if let Some(pager) = view.pager {
+        render_pager(frame, area, view.id.as_deref(), pager);
+    }
+}
+
+fn render_pager(frame: &mut Frame<'_>, area: Rect, id: Option<&str>, pager: TablePagerView) {
+    if area.height < 2 || area.width < 4 {
+        return;
+    }
+    let status = format!("{}/{}", pager.page + 1, pager.page_count);
+    let segments = [
+        (Some(PageStep::Prev), PREV_LABEL.to_string(), pager.page > 0),
+        (None, status, true),
+        (
+            Some(PageStep::Next),
+            NEXT_LABEL.to_string(),
+            pager.page + 1 < pager.page_count,
+        ),
+    ];
+    let total: u16 = segments
+        .iter()
+        .map(|(_, label, _)| label.width() as u16 + 2)
+        .sum();
+    let right = area.x + area.width - 1;
+    let mut x = right.saturating_sub(total).max(area.x + 1);
+    let y = area.y + area.height - 1;
+    for (step, label, enabled) in segments {
+        let width = (label.width() as u16 + 2).min(right.saturating_sub(x));
+        if width == 0 {
+            break;
+        }
+        let rect = Rect::new(x, y, width, 1);
+        let style = match (step, enabled) {
+            (None, _) => Style::default(),
+            (Some(_), true) => Style::default().fg(Color::Cyan),
+            (Some(_), false) => Style::default().fg(Color::DarkGray),
+        };
+        if let (Some(step), Some(id), true) = (step, id, enabled) {
+            register_button_hitbox(
+                &table_pager_id(id, step),
+                Hitbox::new(rect.x, rect.y, rect.width, rect.height),
+            );
+        }
+        let line = Line::from(Span::styled(format!(" {label} "), style));
+        frame.render_widget(Paragraph::new(line), rect);
+        x += width;
+    }
+}
+
+fn render_cell_editor(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    inner: Rect,
+    widths: &[Constraint],
+    header: u16,
+    offset: usize,
+    editor: &TableEditorView,
+) {
+    let Some(visible_row) = editor.row.checked_sub(offset) else {
+        return;
+    };
+    let y = inner.y + header + visible_row as u16;
+    let Some(column) = column_rects(inner, widths).get(editor.column).copied() else {
+        return;
+    };
+    if y >= inner.bottom() {
+        return;
+    }
+    let x = column.x.saturating_sub(1).max(area.x);
+    let top = y.saturating_sub(1).max(area.y);
+    let overlay = Rect::new(
+        x,
+        top,
+        (column.width + 2).min(area.right() - x),
+        3.min(frame.size().bottom().saturating_sub(top)),
+    );
+    frame.render_widget(Clear, overlay);
+    render_text_input(frame, overlay, &editor.input);
+}
+
+fn column_rects(inner: Rect, widths: &[Constraint]) -> Rc<[Rect]> {
+    Layout::horizontal(widths.iter().copied())
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(Rect::new(inner.x, inner.y, inner.width, 1))
+}
+
+fn register_header_hitboxes(
+    id: &str,
+    inner: Rect,
+    widths: &[Constraint],
+    sort: Option<(usize, SortDirection)>,
+) {
+    if inner.height == 0 {
+        return;
+    }
+    let columns = column_rects(inner, widths)
+        .iter()
+        .map(|rect| Hitbox::new(rect.x, rect.y, rect.width, rect.height))
+        .collect();
+    register_table_header(id, TableHeaderHit { sort, columns });
+}
+
+fn register_row_hitboxes(
+    id: &str,
+    inner: Rect,
+    header_height: u16,
+    offset: usize,
+    heights: &[u16],
+    pager: Option<TablePagerView>,
+) {
+    let first = pager.map_or(0, |pager| pager.page * pager.page_size);
+    let mut y = inner.y.saturating_add(header_height);
+    let mut rows = Vec::new();
+    for (index, height) in heights.iter().enumerate().skip(offset) {
+        if y >= inner.bottom() {
+            break;
+        }
+        let height = (*height).min(inner.bottom() - y);
+        rows.push((Hitbox::new(inner.x, y, inner.width, height), first + index));
+        y += height;
+    }
+    register_rows(id, rows);
 }
 
-fn build_table_row(row: &TableRowView) -> Row<'static> {
+fn build_table_row(
+    row: &TableRowView,
+    widths: &[u16],
+    active: Option<usize>,
+) -> (Row<'static>, u16) {
+    let mut height = 1;
     let cells: Vec<Cell> = row
         .cells
         .iter()
-        .map(|cell| {
-            let mut style = Style::default();
+        .enumerate()
+        .map(|(index, cell)| {
+            let mut style = Style::default().add_modifier(cell.modifiers);
             if let Some(color) = cell.color {
                 style = style.fg(color);
             }
-            if cell.bold {
-                style = style.add_modifier(Modifier::BOLD);
+            if active == Some(index) {
+                style = style.add_modifier(Modifier::UNDERLINED);
             }
-            Cell::from(Span::raw(cell.content.clone())).style(style)
+            let width = widths.get(index).copied().unwrap_or(u16::MAX);
+            let lines: Vec<Line> = cell
+                .overflow
+                .apply(&cell.content, width as usize)
+                .into_iter()
+                .map(Line::from)
+                .collect();
+            height = height.max(lines.len() as u16);
+            Cell::from(Text::from(lines)).style(style)
         })
         .collect();
-    Row::new(cells)
+    (Row::new(cells).height(height), height)
 }
 
 fn resolve_table_widths(table: &TableView) -> Vec<Constraint> {
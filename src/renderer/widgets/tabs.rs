@@ -1,6 +1,5 @@
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, Tabs};
 
@@ -21,12 +20,9 @@ pub fn render_tabs(frame: &mut Frame<'_>, area: Rect, view: &TabsView, render_ch
     let active = view.active.min(view.tabs.len().saturating_sub(1));
 
     let titles = view.tabs.iter().map(|tab| Line::from(tab.label.clone()));
-    let highlight_style = Style::default()
-        .fg(view.accent.unwrap_or(Color::Cyan))
-        .add_modifier(Modifier::BOLD);
     let mut tabs_widget = Tabs::new(titles)
         .select(active)
-        .highlight_style(highlight_style);
+        .highlight_style(view.highlight_style);
     let block = Block::default()
         .borders(Borders::ALL)
         .title(view.title.clone().unwrap_or_else(|| "Tabs".to_string()));
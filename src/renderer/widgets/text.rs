@@ -1,13 +1,38 @@
 use ratatui::Frame;
-use ratatui::layout::Rect;
+use ratatui::layout::{Alignment, Rect};
 use ratatui::style::{Color, Style};
-use ratatui::text::Line;
-use ratatui::widgets::Paragraph;
+use ratatui::text::{Line, Text};
+use ratatui::widgets::{Paragraph, Wrap};
 
-use crate::runtime::TextView;
+use crate::runtime::{ParagraphView, TextAlign, TextView};
 
 pub fn render_text(frame: &mut Frame<'_>, area: Rect, view: &TextView) {
+    let style = Style::default()
+        .fg(view.color.unwrap_or(Color::White))
+        .add_modifier(view.modifiers);
+    let lines: Vec<Line> = view
+        .overflow
+        .apply(&view.content, area.width as usize)
+        .into_iter()
+        .map(Line::from)
+        .collect();
+    let widget = Paragraph::new(lines).style(style);
+    frame.render_widget(widget, area);
+}
+
+pub fn render_paragraph(frame: &mut Frame<'_>, area: Rect, view: &ParagraphView) {
     let style = Style::default().fg(view.color.unwrap_or(Color::White));
-    let widget = Paragraph::new(Line::from(view.content.clone())).style(style);
+    let alignment = match view.align {
+        TextAlign::Left => Alignment::Left,
+        TextAlign::Center => Alignment::Center,
+        TextAlign::Right => Alignment::Right,
+    };
+    let mut widget = Paragraph::new(Text::from(view.content.clone()))
+        .style(style)
+        .alignment(alignment)
+        .scroll((view.scroll, 0));
+    if view.wrap {
+        widget = widget.wrap(Wrap { trim: view.trim });
+    }
     frame.render_widget(widget, area);
 }
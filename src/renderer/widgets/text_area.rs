@@ -0,0 +1,100 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+
+use crate::interactions::Hitbox;
+use crate::runtime::TextAreaView;
+use crate::text_input::{TextInputs, column_width, cursor_row, visual_rows};
+
+use super::bordered;
+use super::input::{selection_line, status_to_color};
+
+pub fn render_text_area(frame: &mut Frame<'_>, area: Rect, view: &TextAreaView) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let mut render_area = area;
+    if let Some(height) = view.height {
+        render_area.height = height.min(area.height);
+    }
+
+    let accent = view.accent.unwrap_or(Color::Cyan);
+    let default_border = view.border_color.unwrap_or(Color::DarkGray);
+    let focus_border = view.border_color.unwrap_or(accent);
+    let border_color = status_to_color(view.status)
+        .or(if view.focused {
+            Some(focus_border)
+        } else {
+            None
+        })
+        .unwrap_or(default_border);
+    let mut border_style = Style::default().fg(border_color);
+    if view.focused {
+        border_style = border_style.add_modifier(Modifier::BOLD);
+    }
+    let mut block = bordered(view.border_style).border_style(border_style);
+    if let Some(label) = &view.label {
+        block = block.title(label.as_str());
+    }
+
+    TextInputs::register_hitbox(
+        &view.id,
+        Hitbox::new(
+            render_area.x,
+            render_area.y,
+            render_area.width,
+            render_area.height,
+        ),
+    );
+
+    let inner = block.inner(render_area);
+    let rows = visual_rows(&view.value, inner.width as usize);
+    let cursor = view.cursor.min(view.value.len());
+    let row = cursor_row(&rows, cursor);
+    let visible = inner.height.max(1) as usize;
+    let mut scroll = view.scroll.min(rows.len().saturating_sub(visible));
+    if row < scroll {
+        scroll = row;
+    } else if row >= scroll + visible {
+        scroll = row + 1 - visible;
+    }
+    TextInputs::record_layout(&view.id, inner.width as usize, scroll);
+
+    let mut text_style = Style::default();
+    if let Some(color) = view.text_color {
+        text_style = text_style.fg(color);
+    }
+    let placeholder = view.placeholder.as_deref().unwrap_or_default();
+    let paragraph = if view.value.is_empty() && !placeholder.is_empty() {
+        let placeholder_color = view.placeholder_color.unwrap_or(Color::DarkGray);
+        Paragraph::new(Line::from(placeholder.to_string())).style(text_style.fg(placeholder_color))
+    } else {
+        let lines: Vec<Line> = rows
+            .iter()
+            .skip(scroll)
+            .take(visible)
+            .map(|range| {
+                selection_line(
+                    &view.value[range.clone()],
+                    range.start,
+                    view.selection.as_ref(),
+                )
+            })
+            .collect();
+        Paragraph::new(lines).style(text_style)
+    };
+    frame.render_widget(paragraph.block(block), render_area);
+
+    if view.focused && view.cursor_visible && inner.width > 0 && inner.height > 0 {
+        let column = column_width(&view.value, &rows[row], cursor) as u16;
+        let cursor_x = inner
+            .x
+            .saturating_add(column)
+            .min(inner.x + inner.width.saturating_sub(1));
+        let cursor_y = inner.y + (row - scroll) as u16;
+        frame.set_cursor(cursor_x, cursor_y);
+    }
+}
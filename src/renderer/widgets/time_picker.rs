@@ -0,0 +1,70 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use crate::interactions::Hitbox;
+use crate::runtime::TimePickerView;
+use crate::time_picker::{TimeField, TimePickers};
+
+pub fn render_time_picker(frame: &mut Frame<'_>, area: Rect, view: &TimePickerView) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let mut render_area = area;
+    render_area.height = render_area.height.min(3);
+
+    let accent = view.accent.unwrap_or(Color::Cyan);
+    let mut border_style = Style::default().fg(Color::DarkGray);
+    if view.focused {
+        border_style = Style::default().fg(accent).add_modifier(Modifier::BOLD);
+    }
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    if let Some(label) = &view.label {
+        block = block.title(label.as_str());
+    }
+    let inner = block.inner(render_area);
+    frame.render_widget(block, render_area);
+
+    let fields: &[TimeField] = if view.show_seconds {
+        &[TimeField::Hour, TimeField::Minute, TimeField::Second]
+    } else {
+        &[TimeField::Hour, TimeField::Minute]
+    };
+    let mut spans = Vec::new();
+    let mut x = inner.x;
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(":", Style::default().fg(Color::DarkGray)));
+            x = x.saturating_add(1);
+        }
+        let style = if view.focused && view.field == *field {
+            Style::default()
+                .fg(Color::Black)
+                .bg(accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        spans.push(Span::styled(
+            format!("{:02}", view.value.get(*field)),
+            style,
+        ));
+        if inner.height > 0 && x < inner.x.saturating_add(inner.width) {
+            TimePickers::record(
+                &view.id,
+                *field,
+                Hitbox::new(x, inner.y, 2.min(inner.x + inner.width - x), 1),
+            );
+        }
+        x = x.saturating_add(2);
+    }
+    if view.focused {
+        spans.push(Span::styled(" ▴▾", Style::default().fg(accent)));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), inner);
+}
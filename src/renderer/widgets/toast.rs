@@ -3,7 +3,9 @@ use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use unicode_width::UnicodeWidthStr;
 
+use crate::interactions::register_surface;
 use crate::runtime::{ToastLevel, ToastStackView};
 
 pub fn render_toast_stack(frame: &mut Frame<'_>, area: Rect, view: &ToastStackView) {
@@ -11,11 +13,23 @@ pub fn render_toast_stack(frame: &mut Frame<'_>, area: Rect, view: &ToastStackVi
         return;
     }
 
-    let width = area.width.clamp(20, 40);
+    let min_width = area.width.clamp(20, 40);
     let mut cursor_y = area.y + area.height;
 
     for toast in view.toasts.iter().rev() {
-        let height = if toast.body.is_some() { 5 } else { 4 };
+        let body: Vec<&str> = toast
+            .body
+            .as_deref()
+            .map_or(Vec::new(), |body| body.lines().collect());
+        let content_width = body
+            .iter()
+            .chain([&toast.title.as_str()])
+            .map(|line| line.width() as u16)
+            .max()
+            .unwrap_or(0)
+            .saturating_add(2);
+        let width = min_width.max(content_width.min(area.width));
+        let height = 4 + body.len() as u16;
         if cursor_y < height {
             break;
         }
@@ -27,6 +41,7 @@ pub fn render_toast_stack(frame: &mut Frame<'_>, area: Rect, view: &ToastStackVi
             height,
         );
         frame.render_widget(Clear, rect);
+        register_surface(rect.into());
         let style = style_for_level(toast.level);
         let block = Block::default().borders(Borders::ALL).style(style);
         frame.render_widget(block.clone(), rect);
@@ -35,9 +50,7 @@ pub fn render_toast_stack(frame: &mut Frame<'_>, area: Rect, view: &ToastStackVi
             toast.title.clone(),
             style.add_modifier(Modifier::BOLD),
         ))];
-        if let Some(body) = &toast.body {
-            lines.push(Line::from(body.clone()));
-        }
+        lines.extend(body.into_iter().map(|line| Line::from(line.to_string())));
         let paragraph = Paragraph::new(lines);
         frame.render_widget(paragraph, inner);
     }
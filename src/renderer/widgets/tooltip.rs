@@ -0,0 +1,76 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use unicode_width::UnicodeWidthStr;
+
+use crate::interactions::Hitbox;
+use crate::renderer::popover;
+use crate::runtime::TooltipView;
+use crate::tooltip::Tooltips;
+
+use super::RenderFn;
+
+pub fn render_tooltip(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    view: &TooltipView,
+    render_child: RenderFn,
+) {
+    render_child(frame, area, view.content.as_ref());
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+    Tooltips::record(
+        &view.id,
+        Hitbox::new(area.x, area.y, area.width, area.height),
+    );
+
+    if view.visible && !view.text.is_empty() {
+        let text = view.text.clone();
+        let style = Style::default()
+            .fg(view.color.unwrap_or(Color::White))
+            .bg(view.background_color.unwrap_or(Color::Black));
+        popover::defer(move |frame| render_bubble(frame, area, &text, style));
+    }
+}
+
+fn render_bubble(frame: &mut Frame<'_>, anchor: Rect, text: &str, style: Style) {
+    let bounds = frame.size();
+    let lines: Vec<Line> = text
+        .lines()
+        .map(|line| Line::from(format!(" {line} ")))
+        .collect();
+    let inner_width = text.lines().map(UnicodeWidthStr::width).max().unwrap_or(0) as u16;
+    let width = inner_width.saturating_add(4).min(bounds.width);
+    let height = (lines.len() as u16).saturating_add(2).min(bounds.height);
+    if width < 3 || height < 3 {
+        return;
+    }
+
+    let below = anchor.y.saturating_add(anchor.height);
+    let bottom = bounds.y.saturating_add(bounds.height);
+    let y = if below.saturating_add(height) <= bottom {
+        below
+    } else if anchor.y.saturating_sub(bounds.y) >= height {
+        anchor.y - height
+    } else {
+        bottom.saturating_sub(height)
+    };
+    let right = bounds.x.saturating_add(bounds.width);
+    let x = anchor.x.min(right.saturating_sub(width));
+    let area = Rect {
+        x,
+        y,
+        width,
+        height,
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .style(style);
+    frame.render_widget(Clear, area);
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
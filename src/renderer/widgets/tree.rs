@@ -1,10 +1,11 @@
 use ratatui::Frame;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Color, Style};
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
 
-use crate::runtime::TreeView;
+use crate::interactions::{Hitbox, register_tree_rows};
+use crate::runtime::{CheckState, TreeView};
 
 pub fn render_tree(frame: &mut Frame<'_>, area: Rect, view: &TreeView) {
     let mut block = Block::default().borders(Borders::ALL);
@@ -24,7 +25,13 @@ pub fn render_tree(frame: &mut Frame<'_>, area: Rect, view: &TreeView) {
                 } else {
                     "  "
                 };
-                let mut line = Line::from(format!("{indent}{marker}{}", row.label));
+                let check = match row.check {
+                    Some(CheckState::Checked) => "[x] ",
+                    Some(CheckState::Partial) => "[-] ",
+                    Some(CheckState::Unchecked) => "[ ] ",
+                    None => "",
+                };
+                let mut line = Line::from(format!("{indent}{marker}{check}{}", row.label));
                 if row.has_children {
                     line = line.style(Style::default().fg(Color::Cyan));
                 }
@@ -33,17 +40,29 @@ pub fn render_tree(frame: &mut Frame<'_>, area: Rect, view: &TreeView) {
             .collect()
     };
 
+    let inner = block.inner(area);
     let mut widget = List::new(items).block(block);
+    let mut state = ListState::default();
     if let Some(index) = view.highlight.filter(|_| !view.rows.is_empty()) {
-        let mut state = ListState::default();
         state.select(Some(index.min(view.rows.len() - 1)));
-        widget = widget.highlight_symbol("› ").highlight_style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        );
-        frame.render_stateful_widget(widget, area, &mut state);
-    } else {
-        frame.render_widget(widget, area);
+        widget = widget
+            .highlight_symbol("› ")
+            .highlight_style(view.highlight_style);
+    }
+    frame.render_stateful_widget(widget, area, &mut state);
+
+    if let Some(id) = &view.id {
+        let rows = view
+            .rows
+            .iter()
+            .skip(state.offset())
+            .take(inner.height as usize)
+            .enumerate()
+            .map(|(line, row)| {
+                let hitbox = Hitbox::new(inner.x, inner.y + line as u16, inner.width, 1);
+                (hitbox, row.path.clone())
+            })
+            .collect();
+        register_tree_rows(id, rows);
     }
 }
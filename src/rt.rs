@@ -0,0 +1,207 @@
+// Tasks and timers. Native builds run on tokio. wasm32 has neither a tokio
+// runtime nor a clock, so tasks run on the browser's event loop through
+// `spawn_local` and timers on `setTimeout`.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::Instant;
+#[cfg(not(target_arch = "wasm32"))]
+pub use tokio::task::JoinHandle;
+#[cfg(target_arch = "wasm32")]
+pub use wasm::JoinHandle;
+#[cfg(target_arch = "wasm32")]
+pub use web_time::Instant;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::spawn(future)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use wasm::spawn;
+
+// Blocking file work moves off the runtime threads natively; the browser has
+// one thread, so it runs in place there.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn blocking<F, T>(work: F) -> anyhow::Result<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Ok(tokio::task::spawn_blocking(work).await?)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn blocking<F, T>(work: F) -> anyhow::Result<T>
+where
+    F: FnOnce() -> T,
+{
+    Ok(work())
+}
+
+pub(crate) mod time {
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) use super::wasm::{Instant, interval, sleep, sleep_until, timeout};
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) use tokio::time::{Instant, interval, sleep, sleep_until, timeout};
+}
+
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+    use std::fmt;
+    use std::future::Future;
+    use std::pin::{Pin, pin};
+    use std::task::{Context, Poll};
+    use std::time::Duration;
+
+    use futures::channel::oneshot;
+    use futures::future::{self, AbortHandle, Abortable, Aborted, Either};
+    use gloo_timers::future::TimeoutFuture;
+
+    pub(crate) use web_time::Instant;
+
+    pub struct JoinHandle<T> {
+        abort: AbortHandle,
+        output: oneshot::Receiver<Result<T, Aborted>>,
+    }
+
+    impl<T> JoinHandle<T> {
+        pub fn abort(&self) {
+            self.abort.abort();
+        }
+    }
+
+    impl<T> Future for JoinHandle<T> {
+        type Output = Result<T, JoinError>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            Pin::new(&mut self.output)
+                .poll(cx)
+                .map(|output| match output {
+                    Ok(Ok(value)) => Ok(value),
+                    Ok(Err(Aborted)) | Err(_) => Err(JoinError),
+                })
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct JoinError;
+
+    impl fmt::Display for JoinError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("task was cancelled")
+        }
+    }
+
+    impl JoinError {
+        // Panics abort on wasm32, so cancellation is the only way a task fails.
+        pub fn is_cancelled(&self) -> bool {
+            true
+        }
+    }
+
+    impl std::error::Error for JoinError {}
+
+    pub(crate) fn spawn<F>(future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + 'static,
+        F::Output: 'static,
+    {
+        let (abort, registration) = AbortHandle::new_pair();
+        let (tx, output) = oneshot::channel();
+        wasm_bindgen_futures::spawn_local(async move {
+            let _ = tx.send(Abortable::new(future, registration).await);
+        });
+        JoinHandle { abort, output }
+    }
+
+    pub(crate) struct Sleep {
+        deadline: Instant,
+        timer: Option<TimeoutFuture>,
+    }
+
+    impl Sleep {
+        pub(crate) fn reset(self: Pin<&mut Self>, deadline: Instant) {
+            let sleep = self.get_mut();
+            sleep.deadline = deadline;
+            sleep.timer = None;
+        }
+    }
+
+    impl Future for Sleep {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            // Timers only have millisecond resolution, so one can fire just
+            // before the deadline; a fresh timer covers the rest.
+            loop {
+                let remaining = self.deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Poll::Ready(());
+                }
+                let millis = remaining.as_millis().max(1).min(u128::from(u32::MAX)) as u32;
+                let timer = self.timer.get_or_insert_with(|| TimeoutFuture::new(millis));
+                match Pin::new(timer).poll(cx) {
+                    Poll::Ready(()) => self.timer = None,
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+
+    pub(crate) fn sleep(duration: Duration) -> Sleep {
+        sleep_until(Instant::now() + duration)
+    }
+
+    pub(crate) fn sleep_until(deadline: Instant) -> Sleep {
+        Sleep {
+            deadline,
+            timer: None,
+        }
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct Elapsed;
+
+    impl fmt::Display for Elapsed {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("deadline has elapsed")
+        }
+    }
+
+    impl std::error::Error for Elapsed {}
+
+    pub(crate) async fn timeout<F: Future>(
+        duration: Duration,
+        future: F,
+    ) -> Result<F::Output, Elapsed> {
+        match future::select(pin!(future), sleep(duration)).await {
+            Either::Left((output, _)) => Ok(output),
+            Either::Right(_) => Err(Elapsed),
+        }
+    }
+
+    pub(crate) struct Interval {
+        period: Duration,
+        next: Instant,
+    }
+
+    impl Interval {
+        pub(crate) async fn tick(&mut self) -> Instant {
+            sleep_until(self.next).await;
+            let now = Instant::now();
+            self.next = (self.next + self.period).max(now);
+            now
+        }
+    }
+
+    pub(crate) fn interval(period: Duration) -> Interval {
+        Interval {
+            period,
+            next: Instant::now(),
+        }
+    }
+}
@@ -1,39 +1,142 @@
-use std::collections::{HashSet, hash_map::DefaultHasher};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::env;
-use std::hash::{Hash, Hasher};
-use std::io::ErrorKind;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use anyhow::Context;
-use tokio::fs;
+use parking_lot::Mutex;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Color, Modifier, Style};
 use tokio::sync::mpsc;
-use tokio::task::JoinHandle;
-use tokio::time::sleep;
-use tracing::{info, trace, warn};
+use tokio::sync::mpsc::error::TryRecvError;
+use tracing::{debug, info, trace, warn};
 
 use crate::context::ContextStack;
-use crate::events::{DEFAULT_TICK_RATE, EventBus};
-use crate::hooks::{EffectInvocation, HookRegistry, Scope};
-use crate::renderer::Renderer;
-use crate::styles::Stylesheet;
+#[cfg(feature = "devtools")]
+use crate::devtools::Devtools;
+use crate::events::{DEFAULT_TICK_RATE, EventBus, FrameworkEvent, KeyCode, is_ctrl_c};
+use crate::hooks::{EffectInvocation, HookRegistry, Scope, TreePath, TreeRowState};
+use crate::interactions::{
+    handle_checkbox_event, hovered_row, is_checkbox_focused, is_hovered, register_table_sources,
+    track_gestures, track_hover,
+};
+use crate::keymap::{
+    DeclaredBinding, KeyBinding, KeymapRegistry, closes_help, help_overlay, is_help_toggle,
+};
+use crate::menu::MenuBars;
+use crate::persistence::PersistentStore;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::persistence::default_state_path;
+use crate::profiling::{RuntimeStats, debug_tree, stats_overlay};
+use crate::recording::{SessionRecorder, frame_hash};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::renderer::install_panic_hook;
+use crate::renderer::{DamageStats, Renderer, RendererBackend};
+use crate::rt::time::{self, sleep_until};
+use crate::rt::{Instant, JoinHandle};
+use crate::scroll::Scrolls;
+use crate::select::Selects;
+use crate::split::Splits;
+use crate::styles::{
+    ComputedStyle, ElementStyle, LayoutStyle, StyleDiagnostic, StyleNode, StyleQuery, StyleState,
+    Stylesheet, Theme,
+};
 use crate::text_input::TextInputs;
+use crate::time_picker::TimePickers;
+use crate::tooltip::Tooltips;
 
-use super::component::{ComponentElement, ComponentId};
+use super::component::{ComponentElement, ComponentId, MemoProps};
+use super::custom::CustomWidgetView;
 use super::dispatcher::{AppMessage, Dispatcher};
-use super::element::{Element, FlexDirection, TreeItemNode};
+use super::element::{
+    BorderStyle, CheckState, Dimensions, Element, FlexDirection, FlexNode, Justify, ParagraphNode,
+    TextAlign, TextInputNode, TreeItemNode, format_progress_label,
+};
+use super::embed::EmbeddedApp;
+use super::registries::Registries;
+use super::stylesheet_watch::{spawn_stylesheet_watcher, stylesheet_error_overlay};
 use super::tasks::{DefaultRuntimeDriver, RuntimeDriver};
 use super::view::{
-    BlockView, ButtonView, FlexView, FormFieldView, FormView, GaugeView, LayersView, ListItemView,
-    ListView, ModalView, TabView, TableCellView, TableRowView, TableView, TabsView, TextInputView,
-    TextView, ToastStackView, ToastView, TreeRowView, TreeView, View,
+    AnchoredView, BlockView, ButtonView, CheckboxView, ConstrainedView, FlexView, FormFieldView,
+    FormView, GaugeView, KeyHintView, KeyHintsView, LayersView, ListItemView, ListView,
+    MenuBarView, MenuEntryView, MenuLevelView, ModalView, ParagraphView, ProgressView, ScrollView,
+    SelectView, SizedView, SpinnerView, SplitView, StatusBarView, StepperView, TabView,
+    TableCellView, TableEditorView, TablePagerView, TableRowView, TableView, TabsView,
+    TextAreaView, TextInputView, TextView, TimePickerView, ToastStackView, ToastView, TooltipView,
+    TreeRowView, TreeView, View,
 };
 
+const DEFAULT_MAX_FPS: u32 = 60;
+const DEFAULT_DOUBLE_CLICK: Duration = Duration::from_millis(400);
+const DEFAULT_LONG_PRESS: Duration = Duration::from_millis(500);
+const MAX_COALESCED_MESSAGES: usize = 256;
+
+pub(crate) struct Session {
+    pub(crate) dispatcher: Dispatcher,
+    pub(crate) rx: mpsc::Receiver<AppMessage>,
+    pub(crate) renderer: Renderer,
+    last_view: Option<View>,
+    live_components: HashSet<ComponentId>,
+    help_open: bool,
+    stats_open: bool,
+    stylesheet_error: Option<String>,
+    recorder: Option<SessionRecorder>,
+    terminal_events: Option<JoinHandle<()>>,
+    suspended: bool,
+    exit_code: i32,
+}
+
+impl Session {
+    pub(crate) fn view(&self) -> Option<&View> {
+        self.last_view.as_ref()
+    }
+
+    pub(crate) fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+}
+
+enum MemoKey {
+    Props(MemoProps),
+    Shared(Arc<Element>),
+}
+
+impl MemoKey {
+    fn same_as(&self, other: &MemoKey) -> bool {
+        match (self, other) {
+            (MemoKey::Props(current), MemoKey::Props(next)) => current.same_as(next),
+            (MemoKey::Shared(current), MemoKey::Shared(next)) => Arc::ptr_eq(current, next),
+            _ => false,
+        }
+    }
+}
+
+struct MemoEntry {
+    key: MemoKey,
+    theme: Option<Arc<Theme>>,
+    style_path: Vec<StyleNode>,
+    components: Vec<ComponentId>,
+    stale: Arc<AtomicBool>,
+    bindings: Vec<DeclaredBinding>,
+    view: Option<View>,
+}
+
+impl MemoEntry {
+    fn matches(&self, key: &MemoKey, context: &ContextStack) -> bool {
+        !self.stale.load(Ordering::Acquire)
+            && self.key.same_as(key)
+            && self.theme == context.get::<Theme>()
+            && self.style_path == context.style_path()
+    }
+}
+
 #[derive(Clone, Copy)]
 enum RendererMode {
     Interactive,
     Headless,
+    Backend,
 }
 
 #[derive(Clone)]
@@ -45,19 +148,86 @@ pub struct App {
     config: AppConfig,
     styles: Arc<Stylesheet>,
     driver: Arc<dyn RuntimeDriver>,
-    stylesheet_watch: Option<PathBuf>,
+    stylesheet_watch: Vec<PathBuf>,
     renderer_mode: RendererMode,
+    backend: Arc<Mutex<Option<Renderer>>>,
+    headless_size: (u16, u16),
+    viewport: Option<Rect>,
+    state_file: Option<PathBuf>,
+    persistence: Arc<PersistentStore>,
+    keymap: Arc<KeymapRegistry>,
+    registries: Arc<Registries>,
+    memo: Arc<Mutex<HashMap<ComponentId, MemoEntry>>>,
+    help_overlay: bool,
+    stats_key: Option<KeyBinding>,
+    validate_hooks: bool,
+    strict_mode: bool,
+    strict_warnings: Arc<Mutex<Vec<ComponentId>>>,
+    #[cfg(feature = "devtools")]
+    devtools: Option<Arc<Devtools>>,
+    stylesheet_errors: bool,
+    recording: Option<PathBuf>,
+    panic_hook: bool,
+    started: Instant,
 }
 
 #[derive(Clone, Copy)]
 pub struct AppConfig {
     pub tick_rate: Duration,
+    pub min_size: Option<(u16, u16)>,
+    pub quit_on_ctrl_c: bool,
+    pub max_fps: Option<u32>,
+    pub double_click: Duration,
+    pub long_press: Duration,
+}
+
+impl AppConfig {
+    pub fn min_size(mut self, width: u16, height: u16) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    pub fn quit_on_ctrl_c(mut self, enabled: bool) -> Self {
+        self.quit_on_ctrl_c = enabled;
+        self
+    }
+
+    pub fn max_fps(mut self, fps: u32) -> Self {
+        self.max_fps = Some(fps);
+        self
+    }
+
+    pub fn uncapped(mut self) -> Self {
+        self.max_fps = None;
+        self
+    }
+
+    pub fn double_click(mut self, window: Duration) -> Self {
+        self.double_click = window;
+        self
+    }
+
+    pub fn long_press(mut self, hold: Duration) -> Self {
+        self.long_press = hold;
+        self
+    }
+
+    fn frame_budget(&self) -> Duration {
+        self.max_fps
+            .filter(|fps| *fps > 0)
+            .map_or(Duration::ZERO, |fps| Duration::from_secs(1) / fps)
+    }
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
             tick_rate: DEFAULT_TICK_RATE,
+            min_size: None,
+            quit_on_ctrl_c: true,
+            max_fps: Some(DEFAULT_MAX_FPS),
+            double_click: DEFAULT_DOUBLE_CLICK,
+            long_press: DEFAULT_LONG_PRESS,
         }
     }
 }
@@ -72,8 +242,27 @@ impl App {
             config: AppConfig::default(),
             styles: Arc::new(Stylesheet::default()),
             driver: Arc::new(DefaultRuntimeDriver),
-            stylesheet_watch: None,
+            stylesheet_watch: Vec::new(),
             renderer_mode: RendererMode::Interactive,
+            backend: Arc::new(Mutex::new(None)),
+            headless_size: (80, 24),
+            viewport: None,
+            state_file: None,
+            persistence: Arc::new(PersistentStore::in_memory()),
+            keymap: Arc::new(KeymapRegistry::default()),
+            registries: Arc::new(Registries::new()),
+            memo: Arc::new(Mutex::new(HashMap::new())),
+            help_overlay: true,
+            stats_key: None,
+            validate_hooks: false,
+            strict_mode: false,
+            strict_warnings: Arc::new(Mutex::new(Vec::new())),
+            #[cfg(feature = "devtools")]
+            devtools: None,
+            stylesheet_errors: true,
+            recording: None,
+            panic_hook: false,
+            started: Instant::now(),
         }
     }
 
@@ -87,6 +276,14 @@ impl App {
         self
     }
 
+    pub fn with_stylesheets(self, stylesheets: impl IntoIterator<Item = Stylesheet>) -> Self {
+        let mut merged = Stylesheet::default();
+        for stylesheet in stylesheets {
+            merged.extend(stylesheet);
+        }
+        self.with_stylesheet(merged)
+    }
+
     pub fn watch_stylesheet<P>(mut self, path: P) -> Self
     where
         P: Into<PathBuf>,
@@ -100,10 +297,17 @@ impl App {
                 Err(_) => candidate,
             }
         };
-        self.stylesheet_watch = Some(resolved);
+        self.stylesheet_watch.push(resolved);
         self
     }
 
+    pub fn watch_stylesheets<P>(self, paths: impl IntoIterator<Item = P>) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        paths.into_iter().fold(self, Self::watch_stylesheet)
+    }
+
     pub fn with_driver<D>(mut self, driver: D) -> Self
     where
         D: RuntimeDriver + 'static,
@@ -112,100 +316,625 @@ impl App {
         self
     }
 
+    pub fn with_state_file<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.state_file = Some(path.into());
+        self
+    }
+
+    pub fn help_overlay(mut self, enabled: bool) -> Self {
+        self.help_overlay = enabled;
+        self
+    }
+
+    pub fn stats_overlay(mut self, key: KeyBinding) -> Self {
+        self.stats_key = Some(key);
+        self
+    }
+
+    pub fn validate_hooks(mut self, enabled: bool) -> Self {
+        self.validate_hooks = enabled;
+        self
+    }
+
+    pub fn strict_mode(mut self, enabled: bool) -> Self {
+        self.strict_mode = enabled;
+        self
+    }
+
+    #[cfg(feature = "devtools")]
+    pub fn devtools(mut self, key: KeyBinding) -> Self {
+        self.devtools = Some(Arc::new(Devtools::new(key)));
+        self
+    }
+
+    pub fn stylesheet_error_overlay(mut self, enabled: bool) -> Self {
+        self.stylesheet_errors = enabled;
+        self
+    }
+
+    pub fn with_panic_hook(mut self) -> Self {
+        self.panic_hook = true;
+        self
+    }
+
+    pub fn record_session<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.recording = Some(path.into());
+        self
+    }
+
+    pub fn with_backend<B>(self, backend: B) -> Self
+    where
+        B: RendererBackend + 'static,
+    {
+        self.with_renderer(Renderer::with_backend(backend))
+    }
+
+    pub(crate) fn with_renderer(mut self, renderer: Renderer) -> Self {
+        self.renderer_mode = RendererMode::Backend;
+        self.backend = Arc::new(Mutex::new(Some(renderer)));
+        self
+    }
+
     pub fn headless(mut self) -> Self {
         self.renderer_mode = RendererMode::Headless;
         self
     }
 
-    pub async fn run(mut self) -> anyhow::Result<()> {
+    pub fn headless_size(mut self, width: u16, height: u16) -> Self {
+        self.headless_size = (width, height);
+        self
+    }
+
+    pub fn viewport(mut self, area: Rect) -> Self {
+        self.viewport = Some(area);
+        self
+    }
+
+    pub fn headless_with_size(self, width: u16, height: u16) -> Self {
+        self.headless().headless_size(width, height)
+    }
+
+    pub fn render_once_to_string(&mut self) -> anyhow::Result<String> {
+        let (tx, _rx) = mpsc::channel(128);
+        let dispatcher =
+            Dispatcher::new(tx, self.event_bus.clone()).with_registries(self.registries.clone());
+        let _app = dispatcher.enter();
+        let mut renderer = self.headless_renderer()?;
+        let area = renderer.size().context("read terminal size")?;
+        Arc::make_mut(&mut self.styles).set_viewport(area.width, area.height);
+        let view = self.render_root(
+            &dispatcher,
+            &mut ContextStack::new(),
+            &mut HashSet::new(),
+            &mut Vec::new(),
+        )?;
+        renderer.draw(&view)?;
+        renderer
+            .buffer_text()
+            .context("headless renderer has no buffer")
+    }
+
+    pub fn damage_stats(&self) -> DamageStats {
+        self.registries.damage.stats()
+    }
+
+    pub fn debug_tree(&self) -> String {
+        debug_tree(
+            self.hooks.slot_counts(),
+            &self.registries.profiler.snapshot(),
+        )
+    }
+
+    pub fn strict_mode_warnings(&self) -> Vec<ComponentId> {
+        self.strict_warnings.lock().clone()
+    }
+
+    pub fn region(&self, id: &str) -> Option<Rect> {
+        self.registries.interactions.region(id)
+    }
+
+    pub async fn run(self) -> anyhow::Result<i32> {
+        let registries = self.registries.clone();
+        registries.scope(self.run_scoped()).await
+    }
+
+    async fn run_scoped(mut self) -> anyhow::Result<i32> {
         info!(app = self.name, "starting runtime");
-        let (tx, mut rx) = mpsc::channel(128);
-        let dispatcher = Dispatcher::new(tx.clone(), self.event_bus.clone());
-        let mut renderer = match self.renderer_mode {
-            RendererMode::Interactive => Renderer::new(self.name).context("initialize renderer")?,
-            RendererMode::Headless => Renderer::headless().context("initialize renderer")?,
-        };
-        let mut last_view: Option<View> = None;
+        self.report_style_diagnostics(self.styles.lint().into_iter().filter(is_static_diagnostic));
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.panic_hook && matches!(self.renderer_mode, RendererMode::Interactive) {
+            install_panic_hook();
+        }
+        let (tx, mut session) = self.start()?;
 
-        let event_task = self.driver.spawn_terminal_events(tx.clone());
+        session.terminal_events = Some(self.driver.spawn_terminal_events(tx.clone()));
         let tick_task = self
             .driver
             .spawn_tick_loop(tx.clone(), self.config.tick_rate);
         let shutdown_task = self.driver.spawn_shutdown_watcher(tx.clone());
-        let stylesheet_task = self
-            .stylesheet_watch
-            .clone()
-            .map(|path| spawn_stylesheet_watcher(path, tx.clone()));
+        let stylesheet_task = (!self.stylesheet_watch.is_empty())
+            .then(|| spawn_stylesheet_watcher(self.stylesheet_watch.clone(), tx.clone()));
 
         if tx.send(AppMessage::RequestRender).await.is_err() {
             warn!(app = self.name, "failed to enqueue initial render request");
         }
-        let mut live_components = HashSet::new();
-
-        while let Some(message) = rx.recv().await {
-            trace!(app = self.name, message = ?message, "processing app message");
-            match message {
-                AppMessage::RequestRender => {
-                    live_components.clear();
-                    let mut effects = Vec::new();
-                    let mut context = ContextStack::new();
-                    let mut path = vec![0usize];
-                    let view = self
+
+        let frame_budget = self.config.frame_budget();
+        let mut render_pending = false;
+        let mut last_frame: Option<time::Instant> = None;
+        'run: loop {
+            if !render_pending {
+                let Some(message) = session.rx.recv().await else {
+                    break;
+                };
+                if !self.queue_message(&mut session, message, &mut render_pending)? {
+                    break;
+                }
+            }
+            for _ in 0..MAX_COALESCED_MESSAGES {
+                match session.rx.try_recv() {
+                    Ok(message) => {
+                        if !self.queue_message(&mut session, message, &mut render_pending)? {
+                            break 'run;
+                        }
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => break 'run,
+                }
+            }
+            if !render_pending {
+                continue;
+            }
+            if let Some(due) = last_frame.map(|at| at + frame_budget) {
+                tokio::select! {
+                    biased;
+                    _ = sleep_until(due) => {}
+                    message = session.rx.recv() => {
+                        let Some(message) = message else {
+                            break;
+                        };
+                        if !self.queue_message(&mut session, message, &mut render_pending)? {
+                            break;
+                        }
+                        continue;
+                    }
+                }
+            }
+            render_pending = false;
+            last_frame = Some(time::Instant::now());
+            if !self.handle_message(&mut session, AppMessage::RequestRender)? {
+                break;
+            }
+        }
+
+        let event_task = session.terminal_events.take();
+        let exit_code = session.exit_code;
+        drop(session);
+        self.finish();
+        trace!(app = self.name, "tearing down runtime tasks");
+        if let Some(task) = event_task {
+            abort_and_log("terminal_events", task).await;
+        }
+        abort_and_log("tick_loop", tick_task).await;
+        abort_and_log("shutdown_watcher", shutdown_task).await;
+        if let Some(task) = stylesheet_task {
+            task.abort();
+        }
+        info!(app = self.name, exit_code, "runtime stopped");
+        Ok(exit_code)
+    }
+
+    pub fn embed(mut self, area: Rect) -> anyhow::Result<EmbeddedApp> {
+        info!(app = self.name, "embedding runtime");
+        self.report_style_diagnostics(self.styles.lint().into_iter().filter(is_static_diagnostic));
+        let (_, session) = self.start_with(Renderer::embedded(area))?;
+        Ok(EmbeddedApp::new(self, session))
+    }
+
+    pub(crate) fn start(&mut self) -> anyhow::Result<(mpsc::Sender<AppMessage>, Session)> {
+        let renderer = match (self.renderer_mode, self.viewport) {
+            #[cfg(not(target_arch = "wasm32"))]
+            (RendererMode::Interactive, None) => {
+                Renderer::new(self.name).context("initialize renderer")?
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            (RendererMode::Interactive, Some(area)) => {
+                Renderer::with_viewport(self.name, area).context("initialize renderer")?
+            }
+            // The browser has no terminal to take over; web apps draw through a backend.
+            #[cfg(target_arch = "wasm32")]
+            (RendererMode::Interactive, _) => {
+                anyhow::bail!("wasm32 builds need a renderer backend, see `web::mount`")
+            }
+            (RendererMode::Headless, _) => self.headless_renderer()?,
+            (RendererMode::Backend, _) => self
+                .backend
+                .lock()
+                .take()
+                .context("renderer backend is already in use by another run")?,
+        };
+        self.start_with(renderer)
+    }
+
+    fn start_with(
+        &mut self,
+        renderer: Renderer,
+    ) -> anyhow::Result<(mpsc::Sender<AppMessage>, Session)> {
+        let (tx, rx) = mpsc::channel(128);
+        let dispatcher = Dispatcher::new(tx.clone(), self.event_bus.clone())
+            .with_registries(self.registries.clone());
+        let _app = dispatcher.enter();
+        let size = renderer.size().context("read terminal size")?;
+        let styles = Arc::make_mut(&mut self.styles);
+        styles.set_viewport(size.width, size.height);
+        if let Some(scheme) = renderer.color_scheme() {
+            styles.set_color_scheme(scheme);
+        }
+        self.persistence = Arc::new(match (&self.state_file, self.renderer_mode) {
+            (Some(path), _) => PersistentStore::open(path),
+            #[cfg(not(target_arch = "wasm32"))]
+            (None, RendererMode::Interactive | RendererMode::Backend) => {
+                PersistentStore::open(default_state_path(self.name))
+            }
+            // There is no state directory in the browser.
+            #[cfg(target_arch = "wasm32")]
+            (None, RendererMode::Interactive | RendererMode::Backend) => {
+                PersistentStore::in_memory()
+            }
+            (None, RendererMode::Headless) => PersistentStore::in_memory(),
+        });
+        let session = Session {
+            dispatcher,
+            rx,
+            renderer,
+            last_view: None,
+            live_components: HashSet::new(),
+            help_open: false,
+            stats_open: false,
+            stylesheet_error: None,
+            recorder: self
+                .recording
+                .as_deref()
+                .map(SessionRecorder::create)
+                .transpose()?,
+            terminal_events: None,
+            suspended: false,
+            exit_code: 0,
+        };
+        Ok((tx, session))
+    }
+
+    pub(crate) fn finish(&self) {
+        self.hooks.prune(&HashSet::new());
+        self.report_style_diagnostics(self.styles.unmatched_selectors());
+        if let Err(err) = self.persistence.flush() {
+            warn!(app = self.name, error = ?err, "failed to persist state on shutdown");
+        }
+    }
+
+    pub(crate) fn resize_viewport(&mut self, area: Rect) {
+        Arc::make_mut(&mut self.styles).set_viewport(area.width, area.height);
+    }
+
+    fn headless_renderer(&self) -> anyhow::Result<Renderer> {
+        let (width, height) = self.headless_size;
+        match self.viewport {
+            Some(area) => Renderer::headless_with_viewport(width, height, area),
+            None => Renderer::headless_with_size(width, height),
+        }
+        .context("initialize renderer")
+    }
+
+    fn queue_message(
+        &mut self,
+        session: &mut Session,
+        message: AppMessage,
+        render_pending: &mut bool,
+    ) -> anyhow::Result<bool> {
+        if matches!(message, AppMessage::RequestRender) {
+            *render_pending = true;
+            return Ok(true);
+        }
+        self.handle_message(session, message)
+    }
+
+    pub(crate) fn handle_message(
+        &mut self,
+        session: &mut Session,
+        message: AppMessage,
+    ) -> anyhow::Result<bool> {
+        trace!(app = self.name, message = ?message, "processing app message");
+        let dispatcher = session.dispatcher.clone();
+        let _app = dispatcher.enter();
+        match message {
+            AppMessage::RequestRender if session.suspended => {
+                trace!(app = self.name, "render skipped while suspended");
+            }
+            AppMessage::RequestRender => {
+                let started = Instant::now();
+                session.live_components.clear();
+                self.keymap.begin_frame();
+                #[cfg(feature = "devtools")]
+                if let Some(devtools) = &self.devtools {
+                    devtools.begin_frame();
+                }
+                let mut effects = Vec::new();
+                let mut context = ContextStack::new();
+                let view = self.render_root(
+                    &dispatcher,
+                    &mut context,
+                    &mut session.live_components,
+                    &mut effects,
+                )?;
+                let view = if session.help_open {
+                    let help = self
                         .render_element(
-                            Element::from(self.root.clone()),
+                            help_overlay(&self.keymap.active()),
                             &dispatcher,
-                            &mut path,
+                            &mut vec![1usize],
                             &mut context,
-                            &mut live_components,
+                            &mut session.live_components,
                             &mut effects,
                         )?
                         .unwrap_or(View::Empty);
+                    View::Layered(LayersView {
+                        layers: vec![view, help],
+                    })
+                } else {
+                    view
+                };
+                let view = match &session.stylesheet_error {
+                    Some(message) => {
+                        let overlay = self
+                            .render_element(
+                                stylesheet_error_overlay(message),
+                                &dispatcher,
+                                &mut vec![2usize],
+                                &mut context,
+                                &mut session.live_components,
+                                &mut effects,
+                            )?
+                            .unwrap_or(View::Empty);
+                        View::Layered(LayersView {
+                            layers: vec![view, overlay],
+                        })
+                    }
+                    None => view,
+                };
+                let view = if session.stats_open {
+                    let stats = RuntimeStats {
+                        live_components: session.live_components.len(),
+                        hook_slots: self.hooks.slot_count(),
+                        event_lag: self.event_bus.lag(),
+                    };
+                    let overlay = self
+                        .render_element(
+                            stats_overlay(&self.registries.profiler.snapshot(), stats),
+                            &dispatcher,
+                            &mut vec![4usize],
+                            &mut context,
+                            &mut session.live_components,
+                            &mut effects,
+                        )?
+                        .unwrap_or(View::Empty);
+                    View::Layered(LayersView {
+                        layers: vec![view, overlay],
+                    })
+                } else {
+                    view
+                };
+                let area = session.renderer.size().context("read terminal size")?;
+                #[cfg(feature = "devtools")]
+                let view = match &self.devtools {
+                    Some(devtools) if devtools.is_open() => {
+                        let overlay = self
+                            .render_element(
+                                devtools.overlay(&self.styles, area.height),
+                                &dispatcher,
+                                &mut vec![5usize],
+                                &mut context,
+                                &mut session.live_components,
+                                &mut effects,
+                            )?
+                            .unwrap_or(View::Empty);
+                        View::Layered(LayersView {
+                            layers: vec![view, overlay],
+                        })
+                    }
+                    _ => view,
+                };
+                let view = match self.config.min_size {
+                    Some((width, height)) if area.width < width || area.height < height => self
+                        .render_element(
+                            terminal_too_small(width, height),
+                            &dispatcher,
+                            &mut vec![3usize],
+                            &mut context,
+                            &mut session.live_components,
+                            &mut effects,
+                        )?
+                        .unwrap_or(View::Empty),
+                    _ => view,
+                };
 
-                    let should_render =
-                        last_view.as_ref().map(|prev| prev != &view).unwrap_or(true);
-                    if should_render {
-                        renderer.draw(&view).map_err(|err| {
-                            warn!(app = self.name, error = ?err, "renderer draw failed");
-                            err
-                        })?;
-                        trace!(app = self.name, "frame drawn");
+                let should_render = session
+                    .last_view
+                    .as_ref()
+                    .map(|prev| prev != &view)
+                    .unwrap_or(true);
+                if should_render {
+                    session.renderer.draw(&view).map_err(|err| {
+                        warn!(app = self.name, error = ?err, "renderer draw failed");
+                        err
+                    })?;
+                    trace!(app = self.name, "frame drawn");
+                    if session.recorder.is_some() || self.driver.observes_frames() {
+                        let hash = frame_hash(&view);
+                        if let Some(recorder) = &mut session.recorder {
+                            recorder.frame(hash);
+                        }
+                        self.driver.frame_drawn(hash);
                     }
-                    last_view = Some(view);
-                    trace!(
-                        app = self.name,
-                        effect_count = effects.len(),
-                        "render completed"
-                    );
-                    self.run_effects(effects, &dispatcher);
-                    self.hooks.prune(&live_components);
                 }
-                AppMessage::ExternalEvent(event) => {
-                    trace!(app = self.name, event = ?event, "dispatching external event");
-                    TextInputs::handle_event(&event, &dispatcher);
-                    self.event_bus.publish(event);
+                session.last_view = Some(view);
+                trace!(
+                    app = self.name,
+                    effect_count = effects.len(),
+                    "render completed"
+                );
+                let profiler = &self.registries.profiler;
+                profiler.frame_drawn(started.elapsed(), &session.live_components);
+                profiler.effects_run(effects.len());
+                self.run_effects(effects, &dispatcher);
+                if self.keymap.commit() {
+                    dispatcher.request_render();
+                }
+                self.hooks.prune(&session.live_components);
+                #[cfg(feature = "devtools")]
+                if let Some(devtools) = &self.devtools {
+                    devtools.prune(&session.live_components);
                 }
-                AppMessage::Shutdown => {
-                    info!(app = self.name, "shutdown requested");
-                    break;
+                #[cfg(feature = "devtools")]
+                self.registries.timelines.prune(&session.live_components);
+                self.memo
+                    .lock()
+                    .retain(|id, _| session.live_components.contains(id));
+                self.persistence.flush_later();
+            }
+            AppMessage::ExternalEvent(event) => {
+                trace!(app = self.name, event = ?event, "dispatching external event");
+                if let Some(recorder) = &mut session.recorder {
+                    recorder.event(&event);
+                }
+                if self.config.quit_on_ctrl_c && is_ctrl_c(&event) {
+                    info!(app = self.name, "ctrl+c pressed; shutting down");
+                    return Ok(false);
                 }
-                AppMessage::StylesheetUpdated(stylesheet) => {
-                    self.styles = stylesheet;
-                    info!(app = self.name, "stylesheet reloaded");
+                if let FrameworkEvent::Resize(width, height) = event {
+                    session.renderer.resize(width, height)?;
+                    let area = session.renderer.size().context("read terminal size")?;
+                    Arc::make_mut(&mut self.styles).set_viewport(area.width, area.height);
+                    session.last_view = None;
+                    dispatcher.request_render();
+                }
+                if let FrameworkEvent::Key(key) = &event {
+                    #[cfg(feature = "devtools")]
+                    if let Some(devtools) = &self.devtools {
+                        if devtools.handle_key(key) {
+                            dispatcher.request_render();
+                            return Ok(true);
+                        }
+                    }
+                    if self.stats_key.is_some_and(|binding| binding.matches(key)) {
+                        session.stats_open = !session.stats_open;
+                        dispatcher.request_render();
+                        return Ok(true);
+                    }
+                    if session.help_open && !is_ctrl_c(&event) {
+                        if closes_help(key) {
+                            session.help_open = false;
+                            dispatcher.request_render();
+                        }
+                        return Ok(true);
+                    }
+                    if session.stylesheet_error.is_some() && key.code == KeyCode::Esc {
+                        session.stylesheet_error = None;
+                        dispatcher.request_render();
+                        return Ok(true);
+                    }
+                    if self.help_overlay
+                        && is_help_toggle(key)
+                        && !TextInputs::has_focus()
+                        && !MenuBars::any_open()
+                        && self.keymap.command_for(key).is_none()
+                    {
+                        session.help_open = true;
+                        dispatcher.request_render();
+                        return Ok(true);
+                    }
+                }
+                let command = match &event {
+                    FrameworkEvent::Key(key) if !MenuBars::any_open() => {
+                        self.keymap.command_for(key)
+                    }
+                    _ => None,
+                };
+                TextInputs::handle_event(&event, &dispatcher);
+                handle_checkbox_event(&event, &dispatcher);
+                track_hover(&event, &dispatcher);
+                track_gestures(&event, self.config.double_click, self.config.long_press);
+                Selects::handle_event(&event, &dispatcher);
+                TimePickers::handle_event(&event, &dispatcher);
+                MenuBars::handle_event(&event, &dispatcher);
+                Splits::handle_event(&event, &dispatcher);
+                Scrolls::handle_event(&event, &dispatcher);
+                Tooltips::handle_event(&event, &dispatcher);
+                if matches!(event, FrameworkEvent::Tick)
+                    && (dispatcher.take_animation_frame() || session.stats_open)
+                {
+                    dispatcher.request_render();
+                }
+                self.event_bus.publish(event);
+                if let Some(command) = command {
+                    self.event_bus.publish(FrameworkEvent::Command(command));
+                }
+            }
+            AppMessage::Shutdown => {
+                info!(app = self.name, "shutdown requested");
+                return Ok(false);
+            }
+            AppMessage::Exit(code) => {
+                info!(app = self.name, code, "exit requested");
+                session.exit_code = code;
+                return Ok(false);
+            }
+            AppMessage::Suspend(suspended) => {
+                debug!(app = self.name, "suspending terminal");
+                if let Some(task) = &session.terminal_events {
+                    task.abort();
+                }
+                session.renderer.suspend()?;
+                session.suspended = true;
+                let _ = suspended.send(());
+            }
+            AppMessage::DebugTree(reply) => {
+                let _ = reply.send(self.debug_tree());
+            }
+            AppMessage::Resume => {
+                debug!(app = self.name, "resuming terminal");
+                session.renderer.resume()?;
+                session.suspended = false;
+                if session.terminal_events.is_some() {
+                    session.terminal_events =
+                        Some(self.driver.spawn_terminal_events(dispatcher.sender()));
+                }
+                session.last_view = None;
+                dispatcher.request_render();
+            }
+            AppMessage::StylesheetUpdated(stylesheet) => {
+                let previous = std::mem::replace(&mut self.styles, stylesheet);
+                Arc::make_mut(&mut self.styles).inherit_environment(&previous);
+                info!(app = self.name, "stylesheet reloaded");
+                self.report_style_diagnostics(
+                    self.styles.lint().into_iter().filter(is_static_diagnostic),
+                );
+                session.stylesheet_error = None;
+                dispatcher.request_render();
+            }
+            AppMessage::StylesheetFailed(message) => {
+                if self.stylesheet_errors {
+                    session.stylesheet_error = Some(message);
                     dispatcher.request_render();
                 }
             }
         }
-
-        drop(renderer);
-        trace!(app = self.name, "tearing down runtime tasks");
-        abort_and_log("terminal_events", event_task).await;
-        abort_and_log("tick_loop", tick_task).await;
-        abort_and_log("shutdown_watcher", shutdown_task).await;
-        if let Some(task) = stylesheet_task {
-            task.abort();
-        }
-        info!(app = self.name, "runtime stopped");
-        Ok(())
+        Ok(true)
     }
 
     fn run_effects(&self, effects: Vec<EffectInvocation>, dispatcher: &Dispatcher) {
@@ -251,65 +980,332 @@ impl App {
             Element::Text(node) => Ok(Some(View::Text(TextView {
                 content: node.content,
                 color: node.color,
+                modifiers: node.modifiers,
+                overflow: node.overflow,
+                id: node.id,
+                classes: node.classes,
+            }))),
+            Element::Paragraph(node) => Ok(Some(View::Paragraph(ParagraphView {
+                content: node.content,
+                align: node.align,
+                wrap: node.wrap,
+                trim: node.trim,
+                scroll: node.scroll,
+                color: node.color,
+                id: node.id,
+                classes: node.classes,
             }))),
             Element::Flex(node) => {
+                let style_node = style_node("flex", node.id.as_deref(), &node.classes);
+                let style = self.container_style(&style_node, &node.style, context);
+                let layout = style.layout();
+                context.push_style(style_node);
                 let mut children = Vec::new();
                 for (index, child) in node.children.into_iter().enumerate() {
                     path.push(index);
-                    if let Some(view) =
-                        self.render_element(child, dispatcher, path, context, live, effects)?
-                    {
-                        children.push(view);
-                    }
+                    let view = self.render_element(child, dispatcher, path, context, live, effects);
                     path.pop();
+                    match view {
+                        Ok(Some(view)) => children.push(view),
+                        Ok(None) => {}
+                        Err(err) => {
+                            context.pop_style();
+                            return Err(err);
+                        }
+                    }
                 }
+                context.pop_style();
                 if children.is_empty() {
                     Ok(Some(View::Empty))
                 } else {
-                    Ok(Some(View::Flex(FlexView {
-                        direction: node.direction,
-                        children,
-                    })))
+                    let flex = View::Flex(FlexView {
+                        gap: layout.gap.unwrap_or(node.gap),
+                        padding: layout.padding.unwrap_or(node.padding),
+                        margin: layout.margin.unwrap_or(node.margin),
+                        justify_content: node.justify_content,
+                        align_items: node.align_items,
+                        background_color: style.color("background-color").or(node.background_color),
+                        id: node.id,
+                        classes: node.classes,
+                        ..FlexView::new(node.direction, children)
+                    });
+                    Ok(Some(apply_layout(flex, layout)))
                 }
             }
+            Element::Sized(node) => {
+                let content =
+                    self.render_element(*node.child, dispatcher, path, context, live, effects)?;
+                Ok(content.map(|content| {
+                    View::Sized(SizedView {
+                        constraint: node.constraint,
+                        content: Box::new(content),
+                    })
+                }))
+            }
+            Element::Constrain(node) => {
+                let content =
+                    self.render_element(*node.child, dispatcher, path, context, live, effects)?;
+                Ok(content.map(|content| {
+                    View::Constrained(ConstrainedView {
+                        dimensions: node.dimensions,
+                        content: Box::new(content),
+                    })
+                }))
+            }
             Element::Block(node) => {
+                let style_node = style_node("block", node.id.as_deref(), &node.classes);
+                let style = self.container_style(&style_node, &node.style, context);
+                let layout = style.layout();
+                context.push_style(style_node);
                 path.push(0);
                 let child =
-                    self.render_element(*node.child, dispatcher, path, context, live, effects)?;
+                    self.render_element(*node.child, dispatcher, path, context, live, effects);
                 path.pop();
-                Ok(Some(View::Block(BlockView {
+                context.pop_style();
+                let child = child?;
+                let block = View::Block(BlockView {
                     title: node.title,
                     child: child.map(Box::new),
-                })))
+                    padding: layout.padding.unwrap_or(node.padding),
+                    margin: layout.margin.unwrap_or(node.margin),
+                    border_style: layout.border_style.unwrap_or(node.border_style),
+                    background_color: style.color("background-color").or(node.background_color),
+                    id: node.id,
+                    classes: node.classes,
+                });
+                Ok(Some(apply_layout(block, layout)))
             }
+            Element::VirtualList(node) => self.render_element(
+                Element::List(node.into_list()),
+                dispatcher,
+                path,
+                context,
+                live,
+                effects,
+            ),
+            Element::FilePicker(node) => self.render_element(
+                Element::List(node.into_list()),
+                dispatcher,
+                path,
+                context,
+                live,
+                effects,
+            ),
             Element::List(node) => {
-                let items = node
-                    .items
-                    .into_iter()
-                    .map(|item| ListItemView {
-                        content: item.content,
-                        color: item.color,
-                    })
-                    .collect();
+                let items = node.items.into_iter().map(ListItemView::from).collect();
+                let border_style = node.border_style.unwrap_or(if node.title.is_some() {
+                    BorderStyle::Plain
+                } else {
+                    BorderStyle::None
+                });
+                let mut highlight_style = self.selected_style(
+                    style_node("list", node.id.as_deref(), &node.classes),
+                    "item",
+                    context,
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                );
+                if let Some(color) = node.highlight_color {
+                    highlight_style = highlight_style.fg(color);
+                }
+                let hovered = node.id.as_deref().and_then(hovered_row);
+                let hover_style = match hovered {
+                    Some(_) => self.hover_style(
+                        style_node("list", node.id.as_deref(), &node.classes),
+                        "item",
+                        context,
+                    ),
+                    None => Style::default(),
+                };
                 Ok(Some(View::List(ListView {
                     title: node.title,
                     items,
                     highlight: node.highlight,
-                    highlight_color: node.highlight_color,
+                    highlight_style,
+                    hovered,
+                    hover_style,
+                    offset: node.offset,
+                    checked: node.checked,
+                    border_style,
+                    id: node.id,
+                    classes: node.classes,
+                    virtual_rows: node.virtual_rows,
                 })))
             }
             Element::Gauge(node) => Ok(Some(View::Gauge(GaugeView {
                 label: node.label,
                 ratio: node.ratio,
                 color: node.color,
+                id: node.id,
+                classes: node.classes,
             }))),
+            Element::Spinner(node) => {
+                dispatcher.request_animation_frame();
+                let interval = node.interval.unwrap_or(self.config.tick_rate);
+                Ok(Some(View::Spinner(SpinnerView {
+                    glyph: node.style.frame_at(self.started.elapsed(), interval),
+                    label: node.label,
+                    color: node.color,
+                    id: node.id,
+                    classes: node.classes,
+                })))
+            }
+            Element::Progress(node) => {
+                let phase = if node.ratio.is_none() {
+                    dispatcher.request_animation_frame();
+                    let step = self.config.tick_rate.as_millis().max(1);
+                    (self.started.elapsed().as_millis() / step) as usize
+                } else {
+                    0
+                };
+                let elapsed = node.started.map(|started| started.elapsed());
+                Ok(Some(View::Progress(ProgressView {
+                    ratio: node.ratio,
+                    phase,
+                    style: node.style,
+                    label: node
+                        .label
+                        .map(|template| format_progress_label(&template, node.ratio, elapsed)),
+                    color: node.color,
+                    id: node.id,
+                    classes: node.classes,
+                })))
+            }
             Element::Button(node) => Ok(Some(View::Button(ButtonView {
                 id: node.id,
                 label: node.label,
                 accent: node.accent,
                 filled: node.filled,
+                classes: node.classes,
+            }))),
+            Element::Checkbox(node) => {
+                let focused = is_checkbox_focused(&node.id);
+                Ok(Some(View::Checkbox(CheckboxView {
+                    id: node.id,
+                    label: node.label,
+                    checked: node.checked,
+                    focused,
+                    accent: node.accent,
+                    classes: node.classes,
+                })))
+            }
+            Element::Select(node) => {
+                let state = Selects::sync(&node.id, node.options, node.selected, node.on_change);
+                Ok(Some(View::Select(SelectView {
+                    focused: Selects::is_focused(&node.id),
+                    id: node.id,
+                    label: node.label,
+                    options: state.options,
+                    selected: state.selected,
+                    placeholder: node.placeholder,
+                    open: state.open,
+                    highlighted: state.highlighted,
+                    width: node.width,
+                    accent: node.accent,
+                    classes: node.classes,
+                })))
+            }
+            Element::MenuBar(node) => {
+                let state = MenuBars::sync(&node.id, node.menus);
+                let levels = state
+                    .path
+                    .iter()
+                    .enumerate()
+                    .map(|(level, highlighted)| MenuLevelView {
+                        items: state
+                            .level_items(level)
+                            .iter()
+                            .map(|item| MenuEntryView {
+                                label: item.label.clone(),
+                                shortcut: item.shortcut.clone(),
+                                has_children: !item.children.is_empty(),
+                            })
+                            .collect(),
+                        highlighted: *highlighted,
+                    })
+                    .collect();
+                Ok(Some(View::MenuBar(MenuBarView {
+                    id: node.id,
+                    titles: state.menus.iter().map(|menu| menu.label.clone()).collect(),
+                    active: state.is_open().then_some(state.active),
+                    levels,
+                    accent: node.accent,
+                    classes: node.classes,
+                })))
+            }
+            Element::StatusBar(node) => Ok(Some(View::StatusBar(StatusBarView {
+                left: node.left,
+                center: node.center,
+                right: node.right,
+                separator: node.separator,
+                color: node.color,
+                background_color: node.background_color,
+                id: node.id,
+                classes: node.classes,
+            }))),
+            Element::KeyHints(node) => Ok(Some(View::KeyHints(KeyHintsView {
+                hints: self
+                    .keymap
+                    .active()
+                    .into_iter()
+                    .map(|entry| KeyHintView {
+                        key: entry.binding.to_string(),
+                        description: entry.description,
+                    })
+                    .collect(),
+                separator: node.separator,
+                key_color: node.key_color,
+                color: node.color,
+                id: node.id,
+                classes: node.classes,
+            }))),
+            Element::Stepper(node) => Ok(Some(View::Stepper(StepperView {
+                steps: node.steps,
+                current: node.current,
+                furthest: node.furthest,
+                completed: node.completed,
+                error: node.error,
+                accent: node.accent,
+                id: node.id,
+                classes: node.classes,
             }))),
+            Element::TimePicker(node) => {
+                let state =
+                    TimePickers::sync(&node.id, node.value, node.show_seconds, node.on_change);
+                Ok(Some(View::TimePicker(TimePickerView {
+                    focused: TimePickers::is_focused(&node.id),
+                    id: node.id,
+                    label: node.label,
+                    value: state.value,
+                    field: state.field,
+                    show_seconds: state.show_seconds,
+                    accent: node.accent,
+                    classes: node.classes,
+                })))
+            }
             Element::Table(node) => {
+                let node = node.sorted();
+                if let Some(id) = node.id.as_deref() {
+                    register_table_sources(id, node.source_rows());
+                }
+                let (mut node, pager) = node.into_page();
+                let editor = match (node.editing, node.edit_input.take()) {
+                    (Some((row, column)), Some(binding)) => {
+                        let input = Element::Input(TextInputNode::new(binding));
+                        match self
+                            .render_element(input, dispatcher, path, context, live, effects)?
+                        {
+                            Some(View::Input(input)) => Some(TableEditorView {
+                                row,
+                                column,
+                                input: Box::new(input),
+                            }),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
                 let header = node.header.map(|row| TableRowView {
                     cells: row
                         .cells
@@ -317,11 +1313,12 @@ impl App {
                         .map(|cell| TableCellView {
                             content: cell.content,
                             color: cell.color,
-                            bold: cell.bold,
+                            modifiers: cell.modifiers,
+                            overflow: cell.overflow,
                         })
                         .collect(),
                 });
-                let rows = node
+                let rows: Vec<_> = node
                     .rows
                     .into_iter()
                     .map(|row| TableRowView {
@@ -331,25 +1328,114 @@ impl App {
                             .map(|cell| TableCellView {
                                 content: cell.content,
                                 color: cell.color,
-                                bold: cell.bold,
+                                modifiers: cell.modifiers,
+                                overflow: cell.overflow,
                             })
                             .collect(),
                     })
                     .collect();
+                let highlight_style = self.selected_style(
+                    style_node("table", node.id.as_deref(), &node.classes),
+                    "row",
+                    context,
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::REVERSED),
+                );
+                let first = pager.map_or(0, |(page, _)| page * node.page_size.unwrap_or_default());
+                let sources = match std::mem::take(&mut node.sources) {
+                    sources if sources.is_empty() => (first..first + rows.len()).collect(),
+                    sources => sources,
+                };
+                let hovered = node
+                    .id
+                    .as_deref()
+                    .and_then(hovered_row)
+                    .and_then(|index| index.checked_sub(first));
+                let hover_style = match hovered {
+                    Some(_) => self.hover_style(
+                        style_node("table", node.id.as_deref(), &node.classes),
+                        "row",
+                        context,
+                    ),
+                    None => Style::default(),
+                };
                 Ok(Some(View::Table(TableView {
                     title: node.title,
                     header,
                     rows,
                     highlight: node.highlight,
+                    highlight_style,
+                    hovered,
+                    hover_style,
                     column_widths: node.column_widths,
+                    offset: node.offset,
+                    id: node.id,
+                    classes: node.classes,
+                    pager: pager.map(|(page, page_count)| TablePagerView {
+                        page,
+                        page_count,
+                        page_size: node.page_size.unwrap_or_default(),
+                    }),
+                    sort: node.sort,
+                    active_column: node.active_column,
+                    editor,
+                    border_style: node.border_style,
+                    sources,
                 })))
             }
             Element::Tree(node) => {
-                let rows = flatten_tree_items(node.items);
+                let overrides = node
+                    .state
+                    .as_ref()
+                    .map(|state| state.snapshot().expanded)
+                    .unwrap_or_default();
+                let mut leaves = Vec::new();
+                if node.state.is_some() {
+                    collect_tree_leaves(&node.items, &mut Vec::new(), &mut leaves);
+                }
+                let mut rows = flatten_tree_items(node.items, &overrides);
+                let mut highlight = node.highlight;
+                if let Some(state) = &node.state {
+                    state.sync(
+                        rows.iter()
+                            .map(|row| TreeRowState {
+                                path: row.path.clone(),
+                                has_children: row.has_children,
+                                expanded: row.expanded,
+                            })
+                            .collect(),
+                        leaves,
+                    );
+                    let highlighted = state.highlighted();
+                    highlight = rows
+                        .iter()
+                        .position(|row| Some(&row.path) == highlighted.as_ref());
+                }
+                if node.checkable {
+                    let selection = node.state.as_ref().map(|state| state.snapshot());
+                    for row in &mut rows {
+                        row.check =
+                            Some(selection.as_ref().map_or(CheckState::Unchecked, |state| {
+                                state.check_state(&row.path)
+                            }));
+                    }
+                }
+                let highlight_style = self.selected_style(
+                    style_node("tree", node.id.as_deref(), &node.classes),
+                    "row",
+                    context,
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                );
                 Ok(Some(View::Tree(TreeView {
                     title: node.title,
                     rows,
-                    highlight: node.highlight,
+                    highlight,
+                    highlight_style,
+                    id: node.id,
+                    classes: node.classes,
                 })))
             }
             Element::Form(node) => {
@@ -366,14 +1452,27 @@ impl App {
                     title: node.title,
                     fields,
                     label_width: node.label_width,
+                    id: node.id,
+                    classes: node.classes,
                 })))
             }
             Element::Input(node) => {
+                node.binding.set_mask(node.mask.clone());
                 let snapshot = node.binding.snapshot();
                 let id = (*snapshot.id).clone();
                 let focused = TextInputs::is_focused(&id);
+                if let Some(provider) = &node.suggestions {
+                    let suggestions = if focused {
+                        provider.suggest(&snapshot.value)
+                    } else {
+                        Vec::new()
+                    };
+                    node.binding.set_suggestions(suggestions);
+                }
+                let (suggestions, highlighted_suggestion) = node.binding.suggestion_popover();
                 let cursor_visible = TextInputs::cursor_visible(&id);
                 let status = snapshot.status.unwrap_or(node.status);
+                let style = self.focus_style("input", &id, &node.classes, focused, context);
                 Ok(Some(View::Input(TextInputView {
                     id,
                     label: node.label,
@@ -382,15 +1481,49 @@ impl App {
                     width: node.width,
                     focused,
                     cursor: snapshot.cursor,
+                    selection: snapshot.selection,
                     secure: node.secure,
-                    accent: node.accent,
-                    border_color: node.border_color,
-                    text_color: node.text_color,
+                    accent: node.accent.or(style.color("accent-color")),
+                    border_color: node.border_color.or(style.color("border-color")),
+                    text_color: node.text_color.or(style.color("color")),
                     placeholder_color: node.placeholder_color,
-                    background_color: node.background_color,
-                    focus_background: node.focus_background,
+                    background_color: node.background_color.or(style.color("background-color")),
+                    focus_background: node
+                        .focus_background
+                        .or(style.color("background-color").filter(|_| focused)),
                     status,
                     cursor_visible,
+                    suggestions,
+                    highlighted_suggestion,
+                    border_style: node.border_style,
+                    classes: node.classes,
+                })))
+            }
+            Element::TextArea(node) => {
+                let snapshot = node.binding.snapshot();
+                let id = (*snapshot.id).clone();
+                let focused = TextInputs::is_focused(&id);
+                let cursor_visible = TextInputs::cursor_visible(&id);
+                let status = snapshot.status.unwrap_or(node.status);
+                let style = self.focus_style("textarea", &id, &node.classes, focused, context);
+                Ok(Some(View::TextArea(TextAreaView {
+                    id,
+                    label: node.label,
+                    value: snapshot.value,
+                    placeholder: node.placeholder,
+                    height: node.height,
+                    focused,
+                    cursor: snapshot.cursor,
+                    selection: snapshot.selection,
+                    scroll: node.binding.scroll(),
+                    accent: node.accent.or(style.color("accent-color")),
+                    border_color: node.border_color.or(style.color("border-color")),
+                    text_color: node.text_color.or(style.color("color")),
+                    placeholder_color: node.placeholder_color,
+                    status,
+                    cursor_visible,
+                    border_style: node.border_style,
+                    classes: node.classes,
                 })))
             }
             Element::Tabs(node) => {
@@ -411,11 +1544,24 @@ impl App {
                     Ok(Some(View::Empty))
                 } else {
                     let clamped = node.active.min(tabs.len().saturating_sub(1));
+                    let mut highlight_style = self.selected_style(
+                        style_node("tabs", None, &[]),
+                        "tab",
+                        context,
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    );
+                    if let Some(color) = node.accent {
+                        highlight_style = highlight_style.fg(color);
+                    }
                     Ok(Some(View::Tabs(TabsView {
                         tabs,
                         active: clamped,
-                        accent: node.accent,
+                        highlight_style,
                         title: node.title,
+                        id: node.id,
+                        classes: node.classes,
                     })))
                 }
             }
@@ -426,20 +1572,27 @@ impl App {
                     if let Some(view) =
                         self.render_element(layer, dispatcher, path, context, live, effects)?
                     {
-                        layers.push(view);
+                        let z_index = node.z_indices.get(index).copied().unwrap_or(0);
+                        layers.push((z_index, view));
                     }
                     path.pop();
                 }
                 if layers.is_empty() {
                     Ok(Some(View::Empty))
                 } else {
-                    Ok(Some(View::Layered(LayersView { layers })))
+                    layers.sort_by_key(|(z_index, _)| *z_index);
+                    Ok(Some(View::Layered(LayersView {
+                        layers: layers.into_iter().map(|(_, view)| view).collect(),
+                    })))
                 }
             }
             Element::Modal(node) => {
                 path.push(0);
+                self.keymap.enter_modal();
                 let content =
-                    self.render_element(*node.content, dispatcher, path, context, live, effects)?;
+                    self.render_element(*node.content, dispatcher, path, context, live, effects);
+                self.keymap.leave_modal();
+                let content = content?;
                 path.pop();
                 if let Some(content) = content {
                     Ok(Some(View::Modal(ModalView {
@@ -447,11 +1600,28 @@ impl App {
                         content: Box::new(content),
                         width: node.width,
                         height: node.height,
+                        id: node.id,
+                        classes: node.classes,
                     })))
                 } else {
                     Ok(Some(View::Empty))
                 }
             }
+            Element::Anchored(node) => {
+                let content =
+                    self.render_element(*node.content, dispatcher, path, context, live, effects)?;
+                Ok(content.map(|content| {
+                    View::Anchored(AnchoredView {
+                        anchor: node.anchor,
+                        offset: node.offset,
+                        width: node.width,
+                        height: node.height,
+                        content: Box::new(content),
+                        id: node.id,
+                        classes: node.classes,
+                    })
+                }))
+            }
             Element::ToastStack(node) => {
                 if node.toasts.is_empty() {
                     return Ok(Some(View::Empty));
@@ -465,8 +1635,80 @@ impl App {
                         level: toast.level,
                     })
                     .collect();
-                Ok(Some(View::ToastStack(ToastStackView { toasts })))
+                Ok(Some(View::ToastStack(ToastStackView {
+                    toasts,
+                    id: node.id,
+                    classes: node.classes,
+                })))
+            }
+            Element::Scroll(node) => {
+                let mut children = Vec::new();
+                for (index, child) in node.children.into_iter().enumerate() {
+                    path.push(index);
+                    if let Some(view) =
+                        self.render_element(child, dispatcher, path, context, live, effects)?
+                    {
+                        children.push(view);
+                    }
+                    path.pop();
+                }
+                let offset = Scrolls::offset(&node.id);
+                Ok(Some(View::Scroll(ScrollView {
+                    id: node.id,
+                    children,
+                    offset,
+                    scrollbar: node.scrollbar,
+                    classes: node.classes,
+                })))
+            }
+            Element::Split(node) => {
+                let mut panes = Vec::with_capacity(2);
+                for (index, pane) in [*node.first, *node.second].into_iter().enumerate() {
+                    path.push(index);
+                    let view =
+                        self.render_element(pane, dispatcher, path, context, live, effects)?;
+                    path.pop();
+                    panes.push(view.unwrap_or(View::Empty));
+                }
+                let second = panes.pop().unwrap_or(View::Empty);
+                let first = panes.pop().unwrap_or(View::Empty);
+                Ok(Some(View::Split(SplitView {
+                    ratio: Splits::sync(&node.id, node.ratio),
+                    focused: Splits::is_focused(&node.id),
+                    id: node.id,
+                    direction: node.direction,
+                    first: Box::new(first),
+                    second: Box::new(second),
+                    min_size: node.min_size,
+                    accent: node.accent,
+                    classes: node.classes,
+                })))
+            }
+            Element::Tooltip(node) => {
+                path.push(0);
+                let content =
+                    self.render_element(*node.target, dispatcher, path, context, live, effects)?;
+                path.pop();
+                let Some(content) = content else {
+                    return Ok(None);
+                };
+                Tooltips::sync(&node.id, node.delay);
+                let visible = Tooltips::is_visible(&node.id)
+                    || node
+                        .focus_target
+                        .as_deref()
+                        .is_some_and(Tooltips::is_target_focused);
+                Ok(Some(View::Tooltip(TooltipView {
+                    id: node.id,
+                    content: Box::new(content),
+                    text: node.text,
+                    visible,
+                    color: node.color,
+                    background_color: node.background_color,
+                    classes: node.classes,
+                })))
             }
+            Element::Custom(node) => Ok(Some(View::Custom(CustomWidgetView::build(node.widget)))),
             Element::Fragment(children) => {
                 let mut views = Vec::new();
                 for (index, child) in children.into_iter().enumerate() {
@@ -483,129 +1725,386 @@ impl App {
                 } else if views.len() == 1 {
                     Ok(views.pop())
                 } else {
-                    Ok(Some(View::Flex(FlexView {
-                        direction: FlexDirection::Column,
-                        children: views,
-                    })))
+                    Ok(Some(View::Flex(FlexView::new(
+                        FlexDirection::Column,
+                        views,
+                    ))))
                 }
             }
+            Element::ThemeProvider(node) => {
+                context.push(node.theme);
+                let view =
+                    self.render_element(*node.child, dispatcher, path, context, live, effects);
+                context.pop_value::<Theme>();
+                view
+            }
             Element::Component(component) => {
                 self.render_component(component, dispatcher, path, context, live, effects)
             }
+            Element::PerfOverlay(node) => {
+                let element = node.element(&self.registries.profiler.snapshot());
+                self.render_element(element, dispatcher, path, context, live, effects)
+            }
+            Element::Shared(element) => {
+                let id = ComponentId::new(path, "Shared", None);
+                self.render_cached(
+                    id,
+                    MemoKey::Shared(element.clone()),
+                    context,
+                    live,
+                    |context, live| {
+                        let element = Arc::unwrap_or_clone(element);
+                        self.render_element(element, dispatcher, path, context, live, effects)
+                    },
+                )
+            }
         }
     }
 
-    fn render_component(
+    fn render_root(
         &self,
-        component: ComponentElement,
         dispatcher: &Dispatcher,
-        path: &mut Vec<usize>,
         context: &mut ContextStack,
         live: &mut HashSet<ComponentId>,
         effects: &mut Vec<EffectInvocation>,
+    ) -> anyhow::Result<View> {
+        if self.registries.take_invalidated() {
+            self.memo.lock().clear();
+        }
+        let view = self.render_element(
+            Element::from(self.root.clone()),
+            dispatcher,
+            &mut vec![0usize],
+            context,
+            live,
+            effects,
+        )?;
+        Ok(view.unwrap_or(View::Empty))
+    }
+
+    fn report_style_diagnostics(&self, diagnostics: impl IntoIterator<Item = StyleDiagnostic>) {
+        for diagnostic in diagnostics {
+            warn!(app = self.name, %diagnostic, "stylesheet diagnostic");
+        }
+    }
+
+    fn container_style(
+        &self,
+        node: &StyleNode,
+        inline: &ElementStyle,
+        context: &ContextStack,
+    ) -> ComputedStyle {
+        if node.id.is_none() && node.classes.is_empty() {
+            return inline.computed();
+        }
+        let classes: Vec<&str> = node.classes.iter().map(String::as_str).collect();
+        let mut query = StyleQuery::element(&node.element)
+            .with_classes(&classes)
+            .with_ancestors(context.style_path());
+        if let Some(id) = node.id.as_deref() {
+            query = query.with_id(id).hovered(is_hovered(id));
+        }
+        let mut style = self.styles.query(query);
+        inline.apply(&mut style);
+        style
+    }
+
+    fn selected_style(
+        &self,
+        widget: StyleNode,
+        part: &str,
+        context: &ContextStack,
+        fallback: Style,
+    ) -> Style {
+        let state = StyleState {
+            selected: true,
+            ..StyleState::default()
+        };
+        self.part_style(widget, part, context, state)
+            .patch_style(fallback)
+    }
+
+    fn hover_style(&self, widget: StyleNode, part: &str, context: &ContextStack) -> Style {
+        let state = StyleState {
+            hover: true,
+            ..StyleState::default()
+        };
+        self.part_style(widget, part, context, state)
+            .patch_style(Style::default())
+    }
+
+    fn part_style(
+        &self,
+        widget: StyleNode,
+        part: &str,
+        context: &ContextStack,
+        state: StyleState,
+    ) -> ComputedStyle {
+        let mut ancestors = context.style_path().to_vec();
+        ancestors.push(widget);
+        let classes = [part];
+        let query = StyleQuery::element(part)
+            .with_classes(&classes)
+            .with_ancestors(&ancestors)
+            .with_state(state);
+        self.styles.query(query)
+    }
+
+    fn focus_style(
+        &self,
+        element: &str,
+        id: &str,
+        classes: &[String],
+        focused: bool,
+        context: &ContextStack,
+    ) -> ComputedStyle {
+        let classes: Vec<&str> = classes.iter().map(String::as_str).collect();
+        let query = StyleQuery::element(element)
+            .with_id(id)
+            .with_classes(&classes)
+            .with_ancestors(context.style_path())
+            .focused(focused);
+        self.styles.query(query)
+    }
+
+    fn render_cached(
+        &self,
+        id: ComponentId,
+        key: MemoKey,
+        context: &mut ContextStack,
+        live: &mut HashSet<ComponentId>,
+        render: impl FnOnce(
+            &mut ContextStack,
+            &mut HashSet<ComponentId>,
+        ) -> anyhow::Result<Option<View>>,
     ) -> anyhow::Result<Option<View>> {
-        let id = ComponentId::new(path, component.name, component.key.as_deref());
+        let reused = self.memo.lock().get(&id).and_then(|entry| {
+            entry.matches(&key, context).then(|| {
+                live.extend(entry.components.iter().cloned());
+                self.keymap.redeclare(&entry.bindings);
+                entry.view.clone()
+            })
+        });
+        if let Some(view) = reused {
+            trace!(component = %id, "reusing memoized subtree");
+            live.insert(id);
+            return Ok(view);
+        }
+        let theme = context.get::<Theme>();
+        let style_path = context.style_path().to_vec();
+        let declared = self.keymap.declared_count();
+        let mut subtree = HashSet::new();
+        // The cached view sits behind an `Arc`, so a hit clones a pointer, not the subtree.
+        let view = render(context, &mut subtree)?.map(View::share);
+        let stale = Arc::new(AtomicBool::new(false));
+        for id in &subtree {
+            self.hooks.store_for(id).lock().watchers().watch(&stale);
+        }
+        let components = subtree.iter().cloned().collect();
+        live.extend(subtree);
         live.insert(id.clone());
-        let store = self.hooks.store_for(&id);
-        let mut scope = Scope::new(
+        self.memo.lock().insert(
+            id,
+            MemoEntry {
+                key,
+                theme,
+                style_path,
+                components,
+                stale,
+                bindings: self.keymap.declared_since(declared),
+                view: view.clone(),
+            },
+        );
+        Ok(view)
+    }
+
+    fn component_scope<'c>(
+        &self,
+        id: &ComponentId,
+        dispatcher: &Dispatcher,
+        context: &'c mut ContextStack,
+    ) -> Scope<'c> {
+        let store = self.hooks.store_for(id);
+        let watchers = store.lock().watchers();
+        Scope::new(
             id.clone(),
             store,
-            dispatcher.clone(),
+            dispatcher.for_component(watchers),
             context,
             self.styles.clone(),
+            self.persistence.clone(),
+            self.keymap.clone(),
+        )
+    }
+
+    fn strict_warning(&self, id: &ComponentId) {
+        let mut warnings = self.strict_warnings.lock();
+        if warnings.contains(id) {
+            return;
+        }
+        warn!(
+            app = self.name,
+            component = %id,
+            "strict mode: rendering twice produced different elements; render functions must not mutate state"
         );
-        let child = (component.render)(&mut scope);
+        warnings.push(id.clone());
+    }
+
+    fn render_component(
+        &self,
+        component: ComponentElement,
+        dispatcher: &Dispatcher,
+        path: &mut Vec<usize>,
+        context: &mut ContextStack,
+        live: &mut HashSet<ComponentId>,
+        effects: &mut Vec<EffectInvocation>,
+    ) -> anyhow::Result<Option<View>> {
+        let id = ComponentId::new(path, component.name, component.key.as_deref());
+        if let Some(props) = component.memo.clone() {
+            let component = ComponentElement {
+                memo: None,
+                ..component
+            };
+            return self.render_cached(
+                id,
+                MemoKey::Props(props),
+                context,
+                live,
+                |context, live| {
+                    self.render_component(component, dispatcher, path, context, live, effects)
+                },
+            );
+        }
+        live.insert(id.clone());
+        #[cfg(feature = "devtools")]
+        if let Some(devtools) = &self.devtools {
+            devtools.enter(&id, component.name, component.key.as_deref());
+        }
+        context.push_style(StyleNode::new(component.name.to_ascii_lowercase()));
+        let declared = self.keymap.declared_count();
+        let started = Instant::now();
+        let mut scope = self.component_scope(&id, dispatcher, context);
+        let mut child = (component.render)(&mut scope);
+        if self.strict_mode && cfg!(debug_assertions) {
+            let first = format!("{child:?}");
+            drop(scope);
+            self.keymap.discard_since(declared);
+            scope = self.component_scope(&id, dispatcher, context);
+            child = (component.render)(&mut scope);
+            if format!("{child:?}") != first {
+                self.strict_warning(&id);
+            }
+        }
+        self.registries
+            .profiler
+            .component_rendered(&id, started.elapsed());
+        if self.validate_hooks {
+            scope.check_hook_count();
+        }
         effects.extend(scope.take_effects());
-        self.render_element(child, dispatcher, path, context, live, effects)
+        #[cfg(feature = "devtools")]
+        if let Some(devtools) = &self.devtools {
+            let hooks = self.hooks.store_for(&id).lock().slot_count();
+            devtools.rendered(&id, hooks, context.style_path());
+        }
+        let view = self.render_element(child, dispatcher, path, context, live, effects);
+        context.pop_style();
+        #[cfg(feature = "devtools")]
+        if let Some(devtools) = &self.devtools {
+            devtools.exit();
+        }
+        view
     }
 }
 
-pub(crate) fn flatten_tree_items(items: Vec<TreeItemNode>) -> Vec<TreeRowView> {
-    let mut rows = Vec::new();
-    push_tree_items(items, 0, &mut rows);
-    rows
+fn terminal_too_small(width: u16, height: u16) -> Element {
+    Element::flex(
+        FlexNode::column(vec![Element::paragraph(
+            ParagraphNode::new(format!("terminal too small (need {width}x{height})"))
+                .align(TextAlign::Center),
+        )])
+        .justify_content(Justify::Center),
+    )
 }
 
-fn spawn_stylesheet_watcher(path: PathBuf, tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
-    tokio::spawn(async move {
-        info!(path = %path.display(), "stylesheet watcher started");
-        let mut snapshot = fingerprint_if_exists(&path).await;
-        loop {
-            match maybe_reload_stylesheet(&path, &mut snapshot).await {
-                Ok(Some(stylesheet)) => {
-                    info!(path = %path.display(), "stylesheet change detected");
-                    if tx
-                        .send(AppMessage::StylesheetUpdated(stylesheet))
-                        .await
-                        .is_err()
-                    {
-                        break;
-                    }
-                }
-                Ok(None) => {}
-                Err(err) => warn!(path = %path.display(), error = ?err, "stylesheet reload failed"),
-            }
-            sleep(Duration::from_millis(400)).await;
-        }
-    })
+fn is_static_diagnostic(diagnostic: &StyleDiagnostic) -> bool {
+    !matches!(diagnostic, StyleDiagnostic::UnmatchedSelector { .. })
 }
 
-async fn fingerprint_if_exists(path: &Path) -> Option<StylesheetSnapshot> {
-    match fs::read_to_string(path).await {
-        Ok(contents) => Some(StylesheetSnapshot {
-            fingerprint: fingerprint(&contents),
-        }),
-        Err(_) => None,
+fn style_node(element: &str, id: Option<&str>, classes: &[String]) -> StyleNode {
+    StyleNode {
+        element: element.to_string(),
+        id: id.map(str::to_string),
+        classes: classes.to_vec(),
+        ..StyleNode::default()
     }
 }
 
-async fn maybe_reload_stylesheet(
-    path: &Path,
-    snapshot: &mut Option<StylesheetSnapshot>,
-) -> anyhow::Result<Option<Arc<Stylesheet>>> {
-    let contents = match fs::read_to_string(path).await {
-        Ok(contents) => contents,
-        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
-        Err(err) => return Err(err.into()),
+fn apply_layout(view: View, layout: LayoutStyle) -> View {
+    let view = if layout.width.is_some() || layout.height.is_some() {
+        View::Constrained(ConstrainedView {
+            dimensions: Dimensions {
+                width: layout.width,
+                height: layout.height,
+                ..Dimensions::default()
+            },
+            content: Box::new(view),
+        })
+    } else {
+        view
     };
-    let fingerprint = fingerprint(&contents);
-    if snapshot
-        .as_ref()
-        .map(|snap| snap.fingerprint == fingerprint)
-        .unwrap_or(false)
-    {
-        return Ok(None);
+    match layout.flex_grow {
+        Some(factor) => View::Sized(SizedView {
+            constraint: Constraint::Fill(factor),
+            content: Box::new(view),
+        }),
+        None => view,
     }
-    let stylesheet = Stylesheet::parse(&contents)
-        .with_context(|| format!("parse stylesheet {}", path.display()))?;
-    *snapshot = Some(StylesheetSnapshot { fingerprint });
-    Ok(Some(Arc::new(stylesheet)))
 }
 
-#[derive(Clone, Copy, Debug, Default)]
-struct StylesheetSnapshot {
-    fingerprint: u64,
+pub(crate) fn flatten_tree_items(
+    items: Vec<TreeItemNode>,
+    overrides: &BTreeMap<TreePath, bool>,
+) -> Vec<TreeRowView> {
+    let mut rows = Vec::new();
+    push_tree_items(items, &mut Vec::new(), overrides, &mut rows);
+    rows
 }
 
-fn fingerprint(input: &str) -> u64 {
-    let mut hasher = DefaultHasher::new();
-    input.hash(&mut hasher);
-    hasher.finish()
+fn collect_tree_leaves(nodes: &[TreeItemNode], path: &mut TreePath, leaves: &mut Vec<TreePath>) {
+    for (index, node) in nodes.iter().enumerate() {
+        path.push(index);
+        if node.children.is_empty() {
+            leaves.push(path.clone());
+        } else {
+            collect_tree_leaves(&node.children, path, leaves);
+        }
+        path.pop();
+    }
 }
 
-fn push_tree_items(nodes: Vec<TreeItemNode>, depth: usize, rows: &mut Vec<TreeRowView>) {
-    for node in nodes {
+fn push_tree_items(
+    nodes: Vec<TreeItemNode>,
+    path: &mut TreePath,
+    overrides: &BTreeMap<TreePath, bool>,
+    rows: &mut Vec<TreeRowView>,
+) {
+    for (index, node) in nodes.into_iter().enumerate() {
+        path.push(index);
         let has_children = !node.children.is_empty();
-        let expanded = node.expanded && has_children;
+        let expanded = overrides.get(path).copied().unwrap_or(node.expanded) && has_children;
         rows.push(TreeRowView {
             label: node.label,
-            depth,
+            depth: path.len() - 1,
             has_children,
             expanded,
+            path: path.clone(),
+            check: None,
         });
         if expanded {
-            push_tree_items(node.children, depth + 1, rows);
+            push_tree_items(node.children, path, overrides, rows);
         }
+        path.pop();
     }
 }
 
@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::fmt;
 use std::sync::Arc;
 
@@ -23,6 +24,25 @@ impl ComponentId {
         id.push_str(name);
         Self(id)
     }
+
+    pub(crate) fn name(&self) -> &str {
+        self.0.rsplit_once(':').map_or(&self.0, |(_, name)| name)
+    }
+
+    pub(crate) fn key(&self) -> Option<&str> {
+        let (prefix, _) = self.0.rsplit_once(':')?;
+        prefix.split_once('#').map(|(_, key)| key)
+    }
+
+    pub(crate) fn path(&self) -> Vec<usize> {
+        self.0
+            .split([':', '#'])
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .filter_map(|segment| segment.parse().ok())
+            .collect()
+    }
 }
 
 impl fmt::Display for ComponentId {
@@ -33,11 +53,41 @@ impl fmt::Display for ComponentId {
 
 pub type ComponentFn = Arc<dyn Fn(&mut Scope) -> Element + Send + Sync>;
 
+type AnyProps = dyn Any + Send + Sync;
+
+#[derive(Clone)]
+pub(crate) struct MemoProps {
+    props: Arc<AnyProps>,
+    same: fn(&AnyProps, &AnyProps) -> bool,
+}
+
+impl MemoProps {
+    fn new<P>(props: Arc<P>) -> Self
+    where
+        P: PartialEq + Send + Sync + 'static,
+    {
+        Self {
+            props,
+            same: |previous, next| {
+                matches!(
+                    (previous.downcast_ref::<P>(), next.downcast_ref::<P>()),
+                    (Some(previous), Some(next)) if previous == next
+                )
+            },
+        }
+    }
+
+    pub(crate) fn same_as(&self, other: &MemoProps) -> bool {
+        (self.same)(self.props.as_ref(), other.props.as_ref())
+    }
+}
+
 #[derive(Clone)]
 pub struct ComponentElement {
     pub(crate) name: &'static str,
     pub(crate) key: Option<String>,
     pub(crate) render: ComponentFn,
+    pub(crate) memo: Option<MemoProps>,
 }
 
 impl ComponentElement {
@@ -49,6 +99,7 @@ impl ComponentElement {
             name,
             key: None,
             render: Arc::new(render),
+            memo: None,
         }
     }
 
@@ -69,6 +120,7 @@ impl fmt::Debug for ComponentElement {
         f.debug_struct("ComponentElement")
             .field("name", &self.name)
             .field("key", &self.key)
+            .field("memo", &self.memo.is_some())
             .finish()
     }
 }
@@ -79,3 +131,16 @@ where
 {
     ComponentElement::new(name, render)
 }
+
+pub fn component_memo<P, F>(name: &'static str, props: P, render: F) -> ComponentElement
+where
+    P: PartialEq + Send + Sync + 'static,
+    F: Fn(&mut Scope, &P) -> Element + Send + Sync + 'static,
+{
+    let props = Arc::new(props);
+    let memo = MemoProps::new(props.clone());
+    ComponentElement {
+        memo: Some(memo),
+        ..ComponentElement::new(name, move |scope| render(scope, &props))
+    }
+}
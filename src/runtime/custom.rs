@@ -0,0 +1,183 @@
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use ratatui::Frame;
+use ratatui::layout::Rect;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub trait CustomWidget: Send + Sync {
+    fn build(&self) -> CustomView;
+
+    fn render(&self, view: &CustomView, frame: &mut Frame<'_>, area: Rect);
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CustomView {
+    pub name: String,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub text: Vec<String>,
+    pub revision: u64,
+    pub width: Option<u16>,
+    pub height: Option<u16>,
+}
+
+impl CustomView {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    pub fn text(mut self, line: impl Into<String>) -> Self {
+        self.text.push(line.into());
+        self
+    }
+
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.revision = revision;
+        self
+    }
+
+    pub fn size(mut self, width: u16, height: u16) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+}
+
+pub(crate) struct RawWidget<F> {
+    render: F,
+    revision: u64,
+}
+
+impl<F> RawWidget<F>
+where
+    F: Fn(&mut Frame<'_>, Rect) + Send + Sync,
+{
+    pub(crate) fn new(render: F) -> Self {
+        static NEXT_REVISION: AtomicU64 = AtomicU64::new(0);
+        Self {
+            render,
+            revision: NEXT_REVISION.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+impl<F> CustomWidget for RawWidget<F>
+where
+    F: Fn(&mut Frame<'_>, Rect) + Send + Sync,
+{
+    fn build(&self) -> CustomView {
+        CustomView::new("raw").revision(self.revision)
+    }
+
+    fn render(&self, _view: &CustomView, frame: &mut Frame<'_>, area: Rect) {
+        (self.render)(frame, area);
+    }
+}
+
+#[derive(Clone)]
+pub struct CustomNode {
+    pub widget: Arc<dyn CustomWidget>,
+}
+
+impl fmt::Debug for CustomNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomNode").finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone)]
+pub struct CustomWidgetView {
+    pub widget: Arc<dyn CustomWidget>,
+    pub view: CustomView,
+}
+
+impl CustomWidgetView {
+    pub(crate) fn build(widget: Arc<dyn CustomWidget>) -> Self {
+        Self {
+            view: widget.build(),
+            widget,
+        }
+    }
+
+    pub(crate) fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+        self.widget.render(&self.view, frame, area);
+    }
+}
+
+impl PartialEq for CustomWidgetView {
+    fn eq(&self, other: &Self) -> bool {
+        self.view == other.view
+    }
+}
+
+impl fmt::Debug for CustomWidgetView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomWidgetView")
+            .field("view", &self.view)
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+mod wire {
+    use std::sync::Arc;
+
+    use ratatui::Frame;
+    use ratatui::layout::Rect;
+    use ratatui::text::Text;
+    use ratatui::widgets::Paragraph;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{CustomView, CustomWidget, CustomWidgetView};
+
+    impl Serialize for CustomWidgetView {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.view.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for CustomWidgetView {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Ok(Self {
+                widget: Arc::new(SnapshotWidget),
+                view: CustomView::deserialize(deserializer)?,
+            })
+        }
+    }
+
+    struct SnapshotWidget;
+
+    impl CustomWidget for SnapshotWidget {
+        fn build(&self) -> CustomView {
+            CustomView::default()
+        }
+
+        fn render(&self, view: &CustomView, frame: &mut Frame<'_>, area: Rect) {
+            let text = Text::from_iter(view.text.iter().map(String::as_str));
+            frame.render_widget(Paragraph::new(text), area);
+        }
+    }
+}
@@ -1,23 +1,81 @@
+use std::future::Future;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+use super::registries::{EnterGuard, Registries};
 use crate::events::{EventBus, FrameworkEvent};
+use crate::hooks::MemoWatchers;
+use crate::profiling::Metrics;
+use crate::rt::{self, JoinHandle};
 use crate::styles::Stylesheet;
-use tokio::sync::mpsc;
+use anyhow::{Context, anyhow};
 use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{mpsc, oneshot};
 use tracing::trace;
 
 #[derive(Clone)]
 pub struct Dispatcher {
     tx: mpsc::Sender<AppMessage>,
     event_bus: EventBus,
+    animation_frame: Arc<AtomicBool>,
+    registries: Arc<Registries>,
+    component: Option<Arc<MemoWatchers>>,
 }
 
 impl Dispatcher {
     pub(crate) fn new(tx: mpsc::Sender<AppMessage>, event_bus: EventBus) -> Self {
-        Self { tx, event_bus }
+        Self {
+            tx,
+            event_bus,
+            animation_frame: Arc::new(AtomicBool::new(false)),
+            registries: Arc::new(Registries::new()),
+            component: None,
+        }
+    }
+
+    pub(crate) fn for_component(&self, watchers: Arc<MemoWatchers>) -> Self {
+        Self {
+            component: Some(watchers),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn with_registries(mut self, registries: Arc<Registries>) -> Self {
+        self.registries = registries;
+        self
+    }
+
+    pub fn enter(&self) -> EnterGuard {
+        self.registries.enter()
+    }
+
+    pub fn scope<F: Future>(&self, future: F) -> impl Future<Output = F::Output> + use<F> {
+        self.registries.scope(future)
+    }
+
+    pub fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        rt::spawn(self.scope(future))
     }
 
     pub fn request_render(&self) {
+        match &self.component {
+            Some(watchers) => watchers.notify(),
+            None => self.registries.invalidate(),
+        }
+        self.send_render_request();
+    }
+
+    pub(crate) fn request_full_render(&self) {
+        self.registries.invalidate();
+        self.send_render_request();
+    }
+
+    fn send_render_request(&self) {
+        self.registries.profiler.render_requested();
         match self.tx.try_send(AppMessage::RequestRender) {
             Ok(_) => trace!("render request queued"),
             Err(TrySendError::Full(_)) => {
@@ -27,15 +85,84 @@ impl Dispatcher {
         }
     }
 
+    pub fn metrics(&self) -> Metrics {
+        self.registries.profiler.snapshot()
+    }
+
+    pub fn request_animation_frame(&self) {
+        self.animation_frame.store(true, Ordering::Release);
+    }
+
+    pub(crate) fn take_animation_frame(&self) -> bool {
+        self.animation_frame.swap(false, Ordering::AcqRel)
+    }
+
     pub fn events(&self) -> EventBus {
         self.event_bus.clone()
     }
+
+    pub fn shutdown(&self) {
+        self.exit(0);
+    }
+
+    pub fn exit(&self, code: i32) {
+        match self.tx.try_send(AppMessage::Exit(code)) {
+            Ok(_) => trace!(code, "exit requested"),
+            Err(TrySendError::Full(message)) => {
+                let tx = self.tx.clone();
+                rt::spawn(async move {
+                    let _ = tx.send(message).await;
+                });
+            }
+            Err(TrySendError::Closed(_)) => trace!("exit dropped because channel closed"),
+        }
+    }
+
+    pub async fn suspend<F, Fut, T>(&self, run: F) -> anyhow::Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let (suspended, released) = oneshot::channel();
+        self.tx
+            .send(AppMessage::Suspend(suspended))
+            .await
+            .map_err(|_| anyhow!("runtime stopped"))?;
+        released
+            .await
+            .context("runtime stopped before suspending")?;
+        let output = run().await;
+        self.tx
+            .send(AppMessage::Resume)
+            .await
+            .map_err(|_| anyhow!("runtime stopped"))?;
+        Ok(output)
+    }
+
+    pub async fn debug_tree(&self) -> anyhow::Result<String> {
+        let (reply, tree) = oneshot::channel();
+        self.tx
+            .send(AppMessage::DebugTree(reply))
+            .await
+            .map_err(|_| anyhow!("runtime stopped"))?;
+        tree.await
+            .context("runtime stopped before dumping the tree")
+    }
+
+    pub(crate) fn sender(&self) -> mpsc::Sender<AppMessage> {
+        self.tx.clone()
+    }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub enum AppMessage {
     RequestRender,
     ExternalEvent(FrameworkEvent),
     Shutdown,
+    Exit(i32),
     StylesheetUpdated(Arc<Stylesheet>),
+    StylesheetFailed(String),
+    Suspend(oneshot::Sender<()>),
+    Resume,
+    DebugTree(oneshot::Sender<String>),
 }
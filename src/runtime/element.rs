@@ -1,245 +1,2043 @@
-use ratatui::style::Color;
-
-use crate::text_input::TextInputHandle;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Color, Modifier};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::file_picker::{FilePickerHandle, breadcrumbs};
+use crate::hooks::{ListStateHandle, TableEditHandle, TreeStateHandle, WizardHandle};
+use crate::profiling::PerfOverlayNode;
+use crate::rt::Instant;
+use crate::select::SelectChangeHandler;
+use crate::styles::{ElementStyle, Theme};
+use crate::text_input::{
+    InputMask, NumericFormat, SuggestionProvider, TextAreaHandle, TextInputHandle,
+};
+use crate::time_picker::{TimeChangeHandler, TimeValue};
 
 use super::component::ComponentElement;
+use super::custom::{CustomNode, CustomWidget, RawWidget};
+use super::view::VirtualRows;
 
 #[derive(Clone, Debug)]
 pub enum Element {
     Empty,
     Text(TextNode),
+    Paragraph(ParagraphNode),
     Flex(FlexNode),
+    Sized(SizedNode),
+    Constrain(ConstrainNode),
     Block(BlockNode),
     List(ListNode),
+    VirtualList(VirtualListNode),
+    FilePicker(FilePickerNode),
     Gauge(GaugeNode),
+    Spinner(SpinnerNode),
+    Progress(ProgressNode),
     Button(ButtonNode),
+    Checkbox(CheckboxNode),
+    Select(SelectNode),
+    MenuBar(MenuBarNode),
+    StatusBar(StatusBarNode),
+    KeyHints(KeyHintsNode),
+    Stepper(StepperNode),
+    TimePicker(TimePickerNode),
     Table(TableNode),
     Tree(TreeNode),
     Form(FormNode),
     Input(TextInputNode),
+    TextArea(TextAreaNode),
     Tabs(TabsNode),
     Layered(LayeredNode),
     Modal(ModalNode),
+    Anchored(AnchorNode),
     ToastStack(ToastStackNode),
+    Scroll(ScrollNode),
+    Split(SplitNode),
+    Tooltip(TooltipNode),
+    Custom(CustomNode),
+    ThemeProvider(ThemeProvider),
     Fragment(Vec<Element>),
     Component(ComponentElement),
+    Shared(Arc<Element>),
+    PerfOverlay(PerfOverlayNode),
 }
 
 #[derive(Clone, Debug)]
 pub struct TextNode {
     pub content: String,
     pub color: Option<Color>,
+    pub modifiers: Modifier,
+    pub overflow: TextOverflow,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
-#[derive(Clone, Debug)]
-pub struct FlexNode {
-    pub direction: FlexDirection,
-    pub children: Vec<Element>,
-}
+impl TextNode {
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            color: None,
+            modifiers: Modifier::empty(),
+            overflow: TextOverflow::default(),
+            id: None,
+            classes: Vec::new(),
+        }
+    }
 
-#[derive(Clone, Debug)]
-pub struct BlockNode {
-    pub title: Option<String>,
-    pub child: Box<Element>,
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn modifiers(mut self, modifiers: Modifier) -> Self {
+        self.modifiers |= modifiers;
+        self
+    }
+
+    pub fn bold(self) -> Self {
+        self.modifiers(Modifier::BOLD)
+    }
+
+    pub fn italic(self) -> Self {
+        self.modifiers(Modifier::ITALIC)
+    }
+
+    pub fn underlined(self) -> Self {
+        self.modifiers(Modifier::UNDERLINED)
+    }
+
+    pub fn dim(self) -> Self {
+        self.modifiers(Modifier::DIM)
+    }
+
+    pub fn overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum FlexDirection {
-    Row,
-    Column,
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BorderStyle {
+    None,
+    #[default]
+    Plain,
+    Rounded,
+    Double,
+    Thick,
 }
 
-impl Element {
-    pub fn text(content: impl Into<String>) -> Self {
-        Element::Text(TextNode {
-            content: content.into(),
-            color: None,
-        })
+impl BorderStyle {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "none" | "hidden" => Some(Self::None),
+            "plain" | "solid" => Some(Self::Plain),
+            "rounded" => Some(Self::Rounded),
+            "double" => Some(Self::Double),
+            "thick" => Some(Self::Thick),
+            _ => None,
+        }
     }
 
-    pub fn colored_text(content: impl Into<String>, color: Color) -> Self {
-        Element::Text(TextNode {
-            content: content.into(),
-            color: Some(color),
-        })
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Plain => "plain",
+            Self::Rounded => "rounded",
+            Self::Double => "double",
+            Self::Thick => "thick",
+        }
     }
 
-    pub fn vstack(children: Vec<Element>) -> Self {
-        Element::Flex(FlexNode {
-            direction: FlexDirection::Column,
-            children,
-        })
+    pub(crate) fn edges(self) -> u16 {
+        match self {
+            Self::None => 0,
+            _ => 2,
+        }
     }
+}
 
-    pub fn hstack(children: Vec<Element>) -> Self {
-        Element::Flex(FlexNode {
-            direction: FlexDirection::Row,
-            children,
-        })
-    }
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextOverflow {
+    #[default]
+    Clip,
+    Ellipsis,
+    Wrap,
+}
 
-    pub fn block(title: impl Into<String>, child: Element) -> Self {
-        Element::Block(BlockNode {
-            title: Some(title.into()),
-            child: Box::new(child),
-        })
+impl TextOverflow {
+    pub(crate) fn apply(self, content: &str, width: usize) -> Vec<String> {
+        content
+            .lines()
+            .flat_map(|line| match self {
+                TextOverflow::Clip => vec![clip_to_width(line, width).0],
+                TextOverflow::Ellipsis => vec![ellipsize(line, width)],
+                TextOverflow::Wrap => wrap_to_width(line, width),
+            })
+            .collect()
     }
+}
 
-    pub fn fragment(children: Vec<Element>) -> Self {
-        Element::Fragment(children)
+fn clip_to_width(line: &str, width: usize) -> (String, usize) {
+    let mut clipped = String::new();
+    let mut used = 0;
+    for ch in line.chars() {
+        let char_width = ch.width().unwrap_or(0);
+        if used + char_width > width {
+            break;
+        }
+        used += char_width;
+        clipped.push(ch);
     }
+    (clipped, used)
+}
 
-    pub fn list(node: ListNode) -> Self {
-        Element::List(node)
+fn ellipsize(line: &str, width: usize) -> String {
+    if line.width() <= width {
+        return line.to_string();
     }
+    if width == 0 {
+        return String::new();
+    }
+    let (mut clipped, _) = clip_to_width(line, width - 1);
+    clipped.push('…');
+    clipped
+}
 
-    pub fn gauge(node: GaugeNode) -> Self {
-        Element::Gauge(node)
+fn wrap_to_width(line: &str, width: usize) -> Vec<String> {
+    let mut rows = Vec::new();
+    let mut current = String::new();
+    let mut used = 0;
+    for word in line.split(' ') {
+        let word_width = word.width();
+        let gap = usize::from(used > 0);
+        if used + gap + word_width <= width {
+            if gap == 1 {
+                current.push(' ');
+            }
+            current.push_str(word);
+            used += gap + word_width;
+            continue;
+        }
+        if used > 0 {
+            rows.push(std::mem::take(&mut current));
+            used = 0;
+        }
+        for ch in word.chars() {
+            let char_width = ch.width().unwrap_or(0);
+            if used > 0 && used + char_width > width {
+                rows.push(std::mem::take(&mut current));
+                used = 0;
+            }
+            current.push(ch);
+            used += char_width;
+        }
     }
+    rows.push(current);
+    rows
+}
 
-    pub fn button(node: ButtonNode) -> Self {
-        Element::Button(node)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn toggled(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
     }
+}
 
-    pub fn table(node: TableNode) -> Self {
-        Element::Table(node)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Partial,
+    Checked,
+}
+
+#[derive(Clone, Debug)]
+pub struct ParagraphNode {
+    pub content: String,
+    pub align: TextAlign,
+    pub wrap: bool,
+    pub trim: bool,
+    pub scroll: u16,
+    pub color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl ParagraphNode {
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            align: TextAlign::Left,
+            wrap: true,
+            trim: true,
+            scroll: 0,
+            color: None,
+            id: None,
+            classes: Vec::new(),
+        }
     }
 
-    pub fn tree(node: TreeNode) -> Self {
-        Element::Tree(node)
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
     }
 
-    pub fn form(node: FormNode) -> Self {
-        Element::Form(node)
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
     }
 
-    pub fn text_input(node: TextInputNode) -> Self {
-        Element::Input(node)
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
     }
 
-    pub fn tabs(node: TabsNode) -> Self {
-        Element::Tabs(node)
+    pub fn scroll(mut self, offset: u16) -> Self {
+        self.scroll = offset;
+        self
     }
 
-    pub fn layers(node: LayeredNode) -> Self {
-        Element::Layered(node)
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
     }
 
-    pub fn modal(node: ModalNode) -> Self {
-        Element::Modal(node)
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
     }
 
-    pub fn toast_stack(node: ToastStackNode) -> Self {
-        Element::ToastStack(node)
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
     }
 }
 
 #[derive(Clone, Debug)]
-pub struct ListNode {
-    pub title: Option<String>,
-    pub items: Vec<ListItemNode>,
-    pub highlight: Option<usize>,
-    pub highlight_color: Option<Color>,
+pub struct FlexNode {
+    pub direction: FlexDirection,
+    pub children: Vec<Element>,
+    pub gap: u16,
+    pub padding: Spacing,
+    pub margin: Spacing,
+    pub justify_content: Justify,
+    pub align_items: Align,
+    pub background_color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub style: ElementStyle,
 }
 
-impl ListNode {
-    pub fn new(items: Vec<ListItemNode>) -> Self {
+impl FlexNode {
+    pub fn row(children: Vec<Element>) -> Self {
+        Self::new(FlexDirection::Row, children)
+    }
+
+    pub fn column(children: Vec<Element>) -> Self {
+        Self::new(FlexDirection::Column, children)
+    }
+
+    fn new(direction: FlexDirection, children: Vec<Element>) -> Self {
         Self {
-            title: None,
-            items,
-            highlight: None,
-            highlight_color: None,
+            direction,
+            children,
+            gap: 0,
+            padding: Spacing::default(),
+            margin: Spacing::default(),
+            justify_content: Justify::default(),
+            align_items: Align::default(),
+            background_color: None,
+            id: None,
+            classes: Vec::new(),
+            style: ElementStyle::default(),
         }
     }
 
-    pub fn title(mut self, title: impl Into<String>) -> Self {
-        self.title = Some(title.into());
+    pub fn style(mut self, style: ElementStyle) -> Self {
+        self.style = self.style.merge(style);
         self
     }
 
-    pub fn highlight(mut self, index: usize) -> Self {
-        self.highlight = Some(index);
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
         self
     }
 
-    pub fn highlight_color(mut self, color: Color) -> Self {
-        self.highlight_color = Some(color);
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    pub fn justify_content(mut self, justify: Justify) -> Self {
+        self.justify_content = justify;
+        self
+    }
+
+    pub fn align_items(mut self, align: Align) -> Self {
+        self.align_items = align;
         self
     }
+
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Spacing>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn margin(mut self, margin: impl Into<Spacing>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Justify {
+    #[default]
+    Stretch,
+    Start,
+    Center,
+    End,
+    SpaceBetween,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Align {
+    #[default]
+    Stretch,
+    Start,
+    Center,
+    End,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Spacing {
+    pub top: u16,
+    pub right: u16,
+    pub bottom: u16,
+    pub left: u16,
+}
+
+impl Spacing {
+    pub fn new(top: u16, right: u16, bottom: u16, left: u16) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    pub fn all(value: u16) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    pub fn symmetric(vertical: u16, horizontal: u16) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    pub fn vertical(&self) -> u16 {
+        self.top.saturating_add(self.bottom)
+    }
+
+    pub fn horizontal(&self) -> u16 {
+        self.left.saturating_add(self.right)
+    }
+}
+
+impl From<u16> for Spacing {
+    fn from(value: u16) -> Self {
+        Spacing::all(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Dimensions {
+    pub width: Option<u16>,
+    pub height: Option<u16>,
+    pub min_width: u16,
+    pub min_height: u16,
+    pub max_width: Option<u16>,
+    pub max_height: Option<u16>,
+}
+
+impl Dimensions {
+    pub fn clamp_width(&self, content: u16) -> u16 {
+        clamp_dimension(
+            self.width.unwrap_or(content),
+            self.min_width,
+            self.max_width,
+        )
+    }
+
+    pub fn clamp_height(&self, content: u16) -> u16 {
+        clamp_dimension(
+            self.height.unwrap_or(content),
+            self.min_height,
+            self.max_height,
+        )
+    }
+}
+
+fn clamp_dimension(value: u16, min: u16, max: Option<u16>) -> u16 {
+    value.min(max.unwrap_or(u16::MAX)).max(min)
 }
 
 #[derive(Clone, Debug)]
-pub struct ListItemNode {
-    pub content: String,
-    pub color: Option<Color>,
+pub struct ConstrainNode {
+    pub dimensions: Dimensions,
+    pub child: Box<Element>,
 }
 
-impl ListItemNode {
-    pub fn new(content: impl Into<String>) -> Self {
+impl ConstrainNode {
+    pub fn new(child: Element) -> Self {
         Self {
-            content: content.into(),
-            color: None,
+            dimensions: Dimensions::default(),
+            child: Box::new(child),
         }
     }
 
-    pub fn color(mut self, color: Color) -> Self {
-        self.color = Some(color);
+    pub fn width(mut self, width: u16) -> Self {
+        self.dimensions.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: u16) -> Self {
+        self.dimensions.height = Some(height);
+        self
+    }
+
+    pub fn min(mut self, width: u16, height: u16) -> Self {
+        self.dimensions.min_width = width;
+        self.dimensions.min_height = height;
+        self
+    }
+
+    pub fn max(mut self, width: u16, height: u16) -> Self {
+        self.dimensions.max_width = Some(width);
+        self.dimensions.max_height = Some(height);
+        self
+    }
+
+    pub fn max_width(mut self, width: u16) -> Self {
+        self.dimensions.max_width = Some(width);
+        self
+    }
+
+    pub fn max_height(mut self, height: u16) -> Self {
+        self.dimensions.max_height = Some(height);
         self
     }
 }
 
 #[derive(Clone, Debug)]
-pub struct GaugeNode {
-    pub label: Option<String>,
-    pub ratio: f64,
-    pub color: Option<Color>,
+pub struct SizedNode {
+    pub constraint: Constraint,
+    pub child: Box<Element>,
 }
 
-impl GaugeNode {
-    pub fn new(ratio: f64) -> Self {
+#[derive(Clone, Debug)]
+pub struct BlockNode {
+    pub title: Option<String>,
+    pub child: Box<Element>,
+    pub padding: Spacing,
+    pub margin: Spacing,
+    pub border_style: BorderStyle,
+    pub background_color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub style: ElementStyle,
+}
+
+impl BlockNode {
+    pub fn new(child: Element) -> Self {
         Self {
-            label: None,
-            ratio,
-            color: None,
+            title: None,
+            child: Box::new(child),
+            padding: Spacing::default(),
+            margin: Spacing::default(),
+            border_style: BorderStyle::default(),
+            background_color: None,
+            id: None,
+            classes: Vec::new(),
+            style: ElementStyle::default(),
         }
     }
 
-    pub fn label(mut self, label: impl Into<String>) -> Self {
-        self.label = Some(label.into());
+    pub fn style(mut self, style: ElementStyle) -> Self {
+        self.style = self.style.merge(style);
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Spacing>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn margin(mut self, margin: impl Into<Spacing>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FlexDirection {
+    Row,
+    Column,
+}
+
+impl Element {
+    pub fn text(content: impl Into<String>) -> Self {
+        Element::Text(TextNode::new(content))
+    }
+
+    pub fn colored_text(content: impl Into<String>, color: Color) -> Self {
+        Element::Text(TextNode::new(content).color(color))
+    }
+
+    pub fn text_node(node: TextNode) -> Self {
+        Element::Text(node)
+    }
+
+    pub fn paragraph(node: ParagraphNode) -> Self {
+        Element::Paragraph(node)
+    }
+
+    pub fn vstack(children: Vec<Element>) -> Self {
+        Element::Flex(FlexNode::column(children))
+    }
+
+    pub fn hstack(children: Vec<Element>) -> Self {
+        Element::Flex(FlexNode::row(children))
+    }
+
+    pub fn flex(node: FlexNode) -> Self {
+        Element::Flex(node)
+    }
+
+    pub fn sized(constraint: Constraint, child: Element) -> Self {
+        Element::Sized(SizedNode {
+            constraint,
+            child: Box::new(child),
+        })
+    }
+
+    pub fn grow(factor: u16, child: Element) -> Self {
+        Element::sized(Constraint::Fill(factor), child)
+    }
+
+    pub fn constrain(node: ConstrainNode) -> Self {
+        Element::Constrain(node)
+    }
+
+    pub fn block(title: impl Into<String>, child: Element) -> Self {
+        Element::Block(BlockNode::new(child).title(title))
+    }
+
+    pub fn block_node(node: BlockNode) -> Self {
+        Element::Block(node)
+    }
+
+    pub fn fragment(children: Vec<Element>) -> Self {
+        Element::Fragment(children)
+    }
+
+    pub fn perf_overlay(node: PerfOverlayNode) -> Self {
+        Element::PerfOverlay(node)
+    }
+
+    pub fn shared(element: impl Into<Arc<Element>>) -> Self {
+        Element::Shared(element.into())
+    }
+
+    pub fn list(node: ListNode) -> Self {
+        Element::List(node)
+    }
+
+    pub fn virtual_list(node: VirtualListNode) -> Self {
+        Element::VirtualList(node)
+    }
+
+    pub fn file_picker(node: FilePickerNode) -> Self {
+        Element::FilePicker(node)
+    }
+
+    pub fn gauge(node: GaugeNode) -> Self {
+        Element::Gauge(node)
+    }
+
+    pub fn spinner(node: SpinnerNode) -> Self {
+        Element::Spinner(node)
+    }
+
+    pub fn progress(node: ProgressNode) -> Self {
+        Element::Progress(node)
+    }
+
+    pub fn button(node: ButtonNode) -> Self {
+        Element::Button(node)
+    }
+
+    pub fn checkbox(node: CheckboxNode) -> Self {
+        Element::Checkbox(node)
+    }
+
+    pub fn select(node: SelectNode) -> Self {
+        Element::Select(node)
+    }
+
+    pub fn menu_bar(node: MenuBarNode) -> Self {
+        Element::MenuBar(node)
+    }
+
+    pub fn status_bar(node: StatusBarNode) -> Self {
+        Element::StatusBar(node)
+    }
+
+    pub fn key_hints(node: KeyHintsNode) -> Self {
+        Element::KeyHints(node)
+    }
+
+    pub fn stepper(node: StepperNode) -> Self {
+        Element::Stepper(node)
+    }
+
+    pub fn time_picker(node: TimePickerNode) -> Self {
+        Element::TimePicker(node)
+    }
+
+    pub fn table(node: TableNode) -> Self {
+        Element::Table(node)
+    }
+
+    pub fn tree(node: TreeNode) -> Self {
+        Element::Tree(node)
+    }
+
+    pub fn form(node: FormNode) -> Self {
+        Element::Form(node)
+    }
+
+    pub fn text_input(node: TextInputNode) -> Self {
+        Element::Input(node)
+    }
+
+    pub fn numeric_input(node: NumericInputNode) -> Self {
+        Element::Input(node.into_text_input())
+    }
+
+    pub fn text_area(node: TextAreaNode) -> Self {
+        Element::TextArea(node)
+    }
+
+    pub fn tabs(node: TabsNode) -> Self {
+        Element::Tabs(node)
+    }
+
+    pub fn layers(node: LayeredNode) -> Self {
+        Element::Layered(node)
+    }
+
+    pub fn modal(node: ModalNode) -> Self {
+        Element::Modal(node)
+    }
+
+    pub fn anchored(node: AnchorNode) -> Self {
+        Element::Anchored(node)
+    }
+
+    pub fn toast_stack(node: ToastStackNode) -> Self {
+        Element::ToastStack(node)
+    }
+
+    pub fn scroll(node: ScrollNode) -> Self {
+        Element::Scroll(node)
+    }
+
+    pub fn split(node: SplitNode) -> Self {
+        Element::Split(node)
+    }
+
+    pub fn tooltip(node: TooltipNode) -> Self {
+        Element::Tooltip(node)
+    }
+
+    pub fn custom(widget: Arc<dyn CustomWidget>) -> Self {
+        Element::Custom(CustomNode { widget })
+    }
+
+    pub fn raw<F>(render: F) -> Self
+    where
+        F: Fn(&mut Frame<'_>, Rect) + Send + Sync + 'static,
+    {
+        Self::custom(Arc::new(RawWidget::new(render)))
+    }
+
+    pub fn theme_provider(theme: Theme, child: Element) -> Self {
+        Element::ThemeProvider(ThemeProvider::new(theme, child))
+    }
+
+    pub fn styled(self, style: ElementStyle) -> Self {
+        let computed = style.computed();
+        let layout = computed.layout();
+        let color = computed.color("color");
+        let accent = computed.color("accent-color");
+        let border = computed.color("border-color");
+        let background = computed.color("background-color");
+        let border_style = computed.border_style("border-style");
+        let modifiers = computed.modifiers();
+        let element = match self {
+            Element::Flex(node) => return Element::Flex(node.style(style)),
+            Element::Block(node) => return Element::Block(node.style(style)),
+            Element::Text(mut node) => {
+                node.color = color.or(node.color);
+                node.modifiers |= modifiers;
+                Element::Text(node)
+            }
+            Element::Paragraph(mut node) => {
+                node.color = color.or(node.color);
+                Element::Paragraph(node)
+            }
+            Element::Gauge(mut node) => {
+                node.color = color.or(node.color);
+                Element::Gauge(node)
+            }
+            Element::Spinner(mut node) => {
+                node.color = color.or(node.color);
+                Element::Spinner(node)
+            }
+            Element::Progress(mut node) => {
+                node.color = color.or(node.color);
+                Element::Progress(node)
+            }
+            Element::KeyHints(mut node) => {
+                node.color = color.or(node.color);
+                Element::KeyHints(node)
+            }
+            Element::StatusBar(mut node) => {
+                node.color = color.or(node.color);
+                node.background_color = background.or(node.background_color);
+                Element::StatusBar(node)
+            }
+            Element::Tooltip(mut node) => {
+                node.color = color.or(node.color);
+                node.background_color = background.or(node.background_color);
+                Element::Tooltip(node)
+            }
+            Element::List(mut node) => {
+                node.highlight_color = accent.or(node.highlight_color);
+                node.border_style = border_style.or(node.border_style);
+                Element::List(node)
+            }
+            Element::Table(mut node) => {
+                node.border_style = border_style.unwrap_or(node.border_style);
+                Element::Table(node)
+            }
+            Element::VirtualList(mut node) => {
+                node.highlight_color = accent.or(node.highlight_color);
+                Element::VirtualList(node)
+            }
+            Element::FilePicker(mut node) => {
+                node.highlight_color = accent.or(node.highlight_color);
+                Element::FilePicker(node)
+            }
+            Element::Button(mut node) => {
+                node.accent = accent.or(node.accent);
+                Element::Button(node)
+            }
+            Element::Checkbox(mut node) => {
+                node.accent = accent.or(node.accent);
+                Element::Checkbox(node)
+            }
+            Element::Select(mut node) => {
+                node.accent = accent.or(node.accent);
+                Element::Select(node)
+            }
+            Element::MenuBar(mut node) => {
+                node.accent = accent.or(node.accent);
+                Element::MenuBar(node)
+            }
+            Element::Stepper(mut node) => {
+                node.accent = accent.or(node.accent);
+                Element::Stepper(node)
+            }
+            Element::TimePicker(mut node) => {
+                node.accent = accent.or(node.accent);
+                Element::TimePicker(node)
+            }
+            Element::Tabs(mut node) => {
+                node.accent = accent.or(node.accent);
+                Element::Tabs(node)
+            }
+            Element::Split(mut node) => {
+                node.accent = accent.or(node.accent);
+                Element::Split(node)
+            }
+            Element::Input(mut node) => {
+                node.accent = accent.or(node.accent);
+                node.text_color = color.or(node.text_color);
+                node.border_color = border.or(node.border_color);
+                node.background_color = background.or(node.background_color);
+                node.border_style = border_style.unwrap_or(node.border_style);
+                Element::Input(node)
+            }
+            Element::TextArea(mut node) => {
+                node.accent = accent.or(node.accent);
+                node.text_color = color.or(node.text_color);
+                node.border_color = border.or(node.border_color);
+                node.border_style = border_style.unwrap_or(node.border_style);
+                Element::TextArea(node)
+            }
+            other => other,
+        };
+        let element = if layout.width.is_some() || layout.height.is_some() {
+            Element::Constrain(ConstrainNode {
+                dimensions: Dimensions {
+                    width: layout.width,
+                    height: layout.height,
+                    ..Dimensions::default()
+                },
+                child: Box::new(element),
+            })
+        } else {
+            element
+        };
+        match layout.flex_grow {
+            Some(factor) => Element::grow(factor, element),
+            None => element,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ThemeProvider {
+    pub theme: Theme,
+    pub child: Box<Element>,
+}
+
+impl ThemeProvider {
+    pub fn new(theme: Theme, child: Element) -> Self {
+        Self {
+            theme,
+            child: Box::new(child),
+        }
+    }
+}
+
+impl From<ThemeProvider> for Element {
+    fn from(value: ThemeProvider) -> Self {
+        Element::ThemeProvider(value)
+    }
+}
+
+impl From<PerfOverlayNode> for Element {
+    fn from(value: PerfOverlayNode) -> Self {
+        Element::PerfOverlay(value)
+    }
+}
+
+impl From<Arc<Element>> for Element {
+    fn from(value: Arc<Element>) -> Self {
+        Element::Shared(value)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListNode {
+    pub title: Option<String>,
+    pub items: Vec<ListItemNode>,
+    pub highlight: Option<usize>,
+    pub highlight_color: Option<Color>,
+    pub offset: usize,
+    pub checked: Option<BTreeSet<usize>>,
+    pub border_style: Option<BorderStyle>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub(crate) virtual_rows: Option<VirtualRows>,
+}
+
+impl ListNode {
+    pub fn new(items: Vec<ListItemNode>) -> Self {
+        Self {
+            title: None,
+            items,
+            highlight: None,
+            highlight_color: None,
+            offset: 0,
+            checked: None,
+            border_style: None,
+            id: None,
+            classes: Vec::new(),
+            virtual_rows: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    pub fn border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = Some(style);
+        self
+    }
+
+    pub fn highlight(mut self, index: usize) -> Self {
+        self.highlight = Some(index);
+        self
+    }
+
+    pub fn highlight_color(mut self, color: Color) -> Self {
+        self.highlight_color = Some(color);
+        self
+    }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn checked(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        self.checked = Some(indices.into_iter().collect());
+        self
+    }
+
+    pub fn state(mut self, state: &ListStateHandle) -> Self {
+        let selection = state.snapshot();
+        self.highlight = selection.selected;
+        self.offset = selection.offset;
+        self
+    }
+
+    pub fn multi_select(mut self, state: &ListStateHandle) -> Self {
+        let selection = state.snapshot();
+        self.highlight = selection.selected;
+        self.offset = selection.offset;
+        self.checked = Some(selection.checked);
+        self
+    }
+}
+
+pub type ListItemFactory = Arc<dyn Fn(usize) -> ListItemNode + Send + Sync>;
+
+#[derive(Clone)]
+pub struct VirtualListNode {
+    pub title: Option<String>,
+    pub count: usize,
+    pub factory: ListItemFactory,
+    pub highlight: Option<usize>,
+    pub highlight_color: Option<Color>,
+    pub offset: usize,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl VirtualListNode {
+    pub fn new<F>(count: usize, factory: F) -> Self
+    where
+        F: Fn(usize) -> ListItemNode + Send + Sync + 'static,
+    {
+        Self {
+            title: None,
+            count,
+            factory: Arc::new(factory),
+            highlight: None,
+            highlight_color: None,
+            offset: 0,
+            id: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn highlight(mut self, index: usize) -> Self {
+        self.highlight = Some(index);
+        self
+    }
+
+    pub fn highlight_color(mut self, color: Color) -> Self {
+        self.highlight_color = Some(color);
+        self
+    }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    pub fn state(mut self, state: &ListStateHandle) -> Self {
+        let selection = state.snapshot();
+        self.highlight = selection.selected;
+        self.offset = selection.offset;
+        self
+    }
+
+    pub(crate) fn into_list(self) -> ListNode {
+        let mut list = ListNode::new(Vec::new());
+        list.title = self.title;
+        list.highlight_color = self.highlight_color;
+        list.highlight = self.highlight;
+        list.id = self.id;
+        list.classes = self.classes;
+        list.virtual_rows = Some(VirtualRows {
+            count: self.count,
+            start: self.offset.min(self.count.saturating_sub(1)),
+            factory: self.factory,
+        });
+        list
+    }
+}
+
+impl fmt::Debug for VirtualListNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualListNode")
+            .field("title", &self.title)
+            .field("count", &self.count)
+            .field("highlight", &self.highlight)
+            .field("offset", &self.offset)
+            .field("id", &self.id)
+            .field("classes", &self.classes)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FilePickerNode {
+    pub picker: FilePickerHandle,
+    pub title: Option<String>,
+    pub highlight_color: Option<Color>,
+    pub dir_color: Option<Color>,
+}
+
+impl FilePickerNode {
+    pub fn new(picker: &FilePickerHandle) -> Self {
+        Self {
+            picker: picker.clone(),
+            title: None,
+            highlight_color: None,
+            dir_color: Some(Color::Cyan),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn highlight_color(mut self, color: Color) -> Self {
+        self.highlight_color = Some(color);
+        self
+    }
+
+    pub fn dir_color(mut self, color: Color) -> Self {
+        self.dir_color = Some(color);
+        self
+    }
+
+    pub(crate) fn into_list(self) -> ListNode {
+        let state = self.picker.snapshot();
+        let mut heading = breadcrumbs(&state.dir);
+        if let Some(title) = self.title {
+            heading = format!("{title} · {heading}");
+        }
+        if !state.filter.is_empty() {
+            heading.push_str(&format!(" · filter: {}", state.filter));
+        }
+        if state.show_hidden {
+            heading.push_str(" · hidden shown");
+        }
+        let items = match &state.error {
+            Some(error) => vec![ListItemNode::new(error.clone()).color(Color::Red)],
+            None => state
+                .visible()
+                .into_iter()
+                .map(|entry| match (entry.is_dir, self.dir_color) {
+                    (true, Some(color)) => {
+                        ListItemNode::new(format!("{}/", entry.name)).color(color)
+                    }
+                    (true, None) => ListItemNode::new(format!("{}/", entry.name)),
+                    (false, _) => ListItemNode::new(entry.name.clone()),
+                })
+                .collect(),
+        };
+        let has_entries = state.error.is_none() && !items.is_empty();
+        let mut list = ListNode::new(items).title(heading);
+        list.highlight_color = self.highlight_color;
+        if has_entries {
+            list.highlight = Some(state.selected);
+        }
+        list
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListItemNode {
+    pub content: String,
+    pub color: Option<Color>,
+    pub modifiers: Modifier,
+}
+
+impl ListItemNode {
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            color: None,
+            modifiers: Modifier::empty(),
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn modifiers(mut self, modifiers: Modifier) -> Self {
+        self.modifiers |= modifiers;
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GaugeNode {
+    pub label: Option<String>,
+    pub ratio: f64,
+    pub color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl GaugeNode {
+    pub fn new(ratio: f64) -> Self {
+        Self {
+            label: None,
+            ratio,
+            color: None,
+            id: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SpinnerStyle {
+    #[default]
+    Dots,
+    Braille,
+    Line,
+}
+
+impl SpinnerStyle {
+    pub fn frames(self) -> &'static [&'static str] {
+        match self {
+            SpinnerStyle::Dots => &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            SpinnerStyle::Braille => &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"],
+            SpinnerStyle::Line => &["-", "\\", "|", "/"],
+        }
+    }
+
+    pub fn frame_at(self, elapsed: Duration, interval: Duration) -> &'static str {
+        let frames = self.frames();
+        let step = elapsed.as_millis() / interval.as_millis().max(1);
+        frames[(step % frames.len() as u128) as usize]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SpinnerNode {
+    pub label: Option<String>,
+    pub style: SpinnerStyle,
+    pub interval: Option<Duration>,
+    pub color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl SpinnerNode {
+    pub fn new() -> Self {
+        Self {
+            label: None,
+            style: SpinnerStyle::default(),
+            interval: None,
+            color: None,
+            id: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn style(mut self, style: SpinnerStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+impl Default for SpinnerNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ProgressStyle {
+    #[default]
+    Blocks,
+    Braille,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProgressNode {
+    pub ratio: Option<f64>,
+    pub style: ProgressStyle,
+    pub label: Option<String>,
+    pub started: Option<Instant>,
+    pub color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl ProgressNode {
+    pub fn new(ratio: f64) -> Self {
+        Self {
+            ratio: Some(ratio),
+            style: ProgressStyle::default(),
+            label: None,
+            started: None,
+            color: None,
+            id: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn indeterminate() -> Self {
+        Self {
+            ratio: None,
+            ..Self::new(0.0)
+        }
+    }
+
+    pub fn style(mut self, style: ProgressStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn label(mut self, template: impl Into<String>) -> Self {
+        self.label = Some(template.into());
+        self
+    }
+
+    pub fn started(mut self, started: Instant) -> Self {
+        self.started = Some(started);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+pub(crate) fn format_progress_label(
+    template: &str,
+    ratio: Option<f64>,
+    elapsed: Option<Duration>,
+) -> String {
+    let percent = ratio
+        .map(|ratio| format!("{:.0}", ratio.clamp(0.0, 1.0) * 100.0))
+        .unwrap_or_default();
+    let eta = match (ratio, elapsed) {
+        (Some(ratio), _) if ratio >= 1.0 => format_duration(Duration::ZERO),
+        (Some(ratio), Some(elapsed)) if ratio > 0.0 => {
+            format_duration(elapsed.mul_f64((1.0 - ratio) / ratio))
+        }
+        _ => "--:--".to_string(),
+    };
+    let elapsed = elapsed.map(format_duration).unwrap_or_default();
+    template
+        .replace("{percent}", &percent)
+        .replace("{eta}", &eta)
+        .replace("{elapsed}", &elapsed)
+}
+
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    } else {
+        format!("{:02}:{:02}", seconds / 60, seconds % 60)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ButtonNode {
+    pub id: String,
+    pub label: String,
+    pub accent: Option<Color>,
+    pub filled: bool,
+    pub classes: Vec<String>,
+}
+
+impl ButtonNode {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            accent: None,
+            filled: false,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn accent(mut self, color: Color) -> Self {
+        self.accent = Some(color);
+        self
+    }
+
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CheckboxNode {
+    pub id: String,
+    pub label: String,
+    pub checked: bool,
+    pub accent: Option<Color>,
+    pub classes: Vec<String>,
+}
+
+impl CheckboxNode {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            checked: false,
+            accent: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn accent(mut self, color: Color) -> Self {
+        self.accent = Some(color);
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SelectNode {
+    pub id: String,
+    pub options: Vec<String>,
+    pub selected: Option<usize>,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    pub width: Option<u16>,
+    pub accent: Option<Color>,
+    pub on_change: Option<SelectChangeHandler>,
+    pub classes: Vec<String>,
+}
+
+impl SelectNode {
+    pub fn new<I, S>(id: impl Into<String>, options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            options: options.into_iter().map(Into::into).collect(),
+            selected: None,
+            label: None,
+            placeholder: None,
+            width: None,
+            accent: None,
+            on_change: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn selected(mut self, index: usize) -> Self {
+        self.selected = Some(index);
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn accent(mut self, color: Color) -> Self {
+        self.accent = Some(color);
+        self
+    }
+
+    pub fn on_change<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(usize, &str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(SelectChangeHandler::new(handler));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MenuBarNode {
+    pub id: String,
+    pub menus: Vec<MenuItemNode>,
+    pub accent: Option<Color>,
+    pub classes: Vec<String>,
+}
+
+impl MenuBarNode {
+    pub fn new(id: impl Into<String>, menus: Vec<MenuItemNode>) -> Self {
+        Self {
+            id: id.into(),
+            menus,
+            accent: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn menu(mut self, menu: MenuItemNode) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    pub fn accent(mut self, color: Color) -> Self {
+        self.accent = Some(color);
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MenuItemNode {
+    pub label: String,
+    pub command: Option<String>,
+    pub shortcut: Option<String>,
+    pub children: Vec<MenuItemNode>,
+}
+
+impl MenuItemNode {
+    pub fn new(label: impl Into<String>, command: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            command: Some(command.into()),
+            shortcut: None,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn submenu(label: impl Into<String>, children: Vec<MenuItemNode>) -> Self {
+        Self {
+            label: label.into(),
+            command: None,
+            shortcut: None,
+            children,
+        }
+    }
+
+    pub fn child(mut self, child: MenuItemNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    pub(crate) fn mnemonic(&self) -> Option<char> {
+        self.label
+            .chars()
+            .find(|c| c.is_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StatusSegment {
+    pub text: String,
+    pub color: Option<Color>,
+    pub background_color: Option<Color>,
+    pub bold: bool,
+}
+
+impl StatusSegment {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            color: None,
+            background_color: None,
+            bold: false,
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+}
+
+impl From<&str> for StatusSegment {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for StatusSegment {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StatusBarNode {
+    pub left: Vec<StatusSegment>,
+    pub center: Vec<StatusSegment>,
+    pub right: Vec<StatusSegment>,
+    pub separator: String,
+    pub color: Option<Color>,
+    pub background_color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl StatusBarNode {
+    pub fn new() -> Self {
+        Self {
+            left: Vec::new(),
+            center: Vec::new(),
+            right: Vec::new(),
+            separator: " │ ".to_string(),
+            color: None,
+            background_color: None,
+            id: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn left(mut self, segment: impl Into<StatusSegment>) -> Self {
+        self.left.push(segment.into());
+        self
+    }
+
+    pub fn center(mut self, segment: impl Into<StatusSegment>) -> Self {
+        self.center.push(segment.into());
+        self
+    }
+
+    pub fn right(mut self, segment: impl Into<StatusSegment>) -> Self {
+        self.right.push(segment.into());
+        self
+    }
+
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+impl Default for StatusBarNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct KeyHintsNode {
+    pub separator: String,
+    pub key_color: Option<Color>,
+    pub color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl KeyHintsNode {
+    pub fn new() -> Self {
+        Self {
+            separator: " • ".to_string(),
+            key_color: None,
+            color: None,
+            id: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    pub fn key_color(mut self, color: Color) -> Self {
+        self.key_color = Some(color);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+impl Default for KeyHintsNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StepperNode {
+    pub steps: Vec<String>,
+    pub current: usize,
+    pub furthest: usize,
+    pub completed: bool,
+    pub error: Option<String>,
+    pub accent: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl StepperNode {
+    pub fn new<I, S>(steps: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            steps: steps.into_iter().map(Into::into).collect(),
+            current: 0,
+            furthest: 0,
+            completed: false,
+            error: None,
+            accent: None,
+            id: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn current(mut self, step: usize) -> Self {
+        self.current = step;
+        self.furthest = self.furthest.max(step);
+        self
+    }
+
+    pub fn state(mut self, wizard: &WizardHandle) -> Self {
+        let state = wizard.snapshot();
+        self.current = state.current;
+        self.furthest = state.furthest;
+        self.completed = state.completed;
+        self.error = state.error;
+        self
+    }
+
+    pub fn accent(mut self, color: Color) -> Self {
+        self.accent = Some(color);
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
         self
     }
 
-    pub fn color(mut self, color: Color) -> Self {
-        self.color = Some(color);
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
         self
     }
 }
 
 #[derive(Clone, Debug)]
-pub struct ButtonNode {
+pub struct TimePickerNode {
     pub id: String,
-    pub label: String,
+    pub value: Option<TimeValue>,
+    pub label: Option<String>,
+    pub show_seconds: bool,
     pub accent: Option<Color>,
-    pub filled: bool,
+    pub on_change: Option<TimeChangeHandler>,
+    pub classes: Vec<String>,
 }
 
-impl ButtonNode {
-    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+impl TimePickerNode {
+    pub fn new(id: impl Into<String>) -> Self {
         Self {
             id: id.into(),
-            label: label.into(),
+            value: None,
+            label: None,
+            show_seconds: true,
             accent: None,
-            filled: false,
+            on_change: None,
+            classes: Vec::new(),
         }
     }
 
+    pub fn value(mut self, value: TimeValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
     pub fn accent(mut self, color: Color) -> Self {
         self.accent = Some(color);
         self
     }
 
-    pub fn filled(mut self, filled: bool) -> Self {
-        self.filled = filled;
+    pub fn on_change<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(TimeValue) + Send + Sync + 'static,
+    {
+        self.on_change = Some(TimeChangeHandler::new(handler));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
         self
     }
 }
@@ -251,6 +2049,19 @@ pub struct TableNode {
     pub rows: Vec<TableRowNode>,
     pub highlight: Option<usize>,
     pub column_widths: Option<Vec<u16>>,
+    pub offset: usize,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub page_size: Option<usize>,
+    pub page: Option<usize>,
+    pub sort: Option<(usize, SortDirection)>,
+    pub active_column: Option<usize>,
+    pub editing: Option<(usize, usize)>,
+    pub edit_input: Option<TextInputHandle>,
+    pub border_style: BorderStyle,
+    // Source index of each row once `sorted` has reordered them; empty while rows
+    // are still in the order they were passed in.
+    pub(crate) sources: Vec<usize>,
 }
 
 impl TableNode {
@@ -261,9 +2072,58 @@ impl TableNode {
             rows,
             highlight: None,
             column_widths: None,
+            offset: 0,
+            id: None,
+            classes: Vec::new(),
+            page_size: None,
+            page: None,
+            sort: None,
+            active_column: None,
+            editing: None,
+            edit_input: None,
+            border_style: BorderStyle::default(),
+            sources: Vec::new(),
         }
     }
 
+    pub fn border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    pub fn editor(mut self, editor: &TableEditHandle) -> Self {
+        let state = editor.snapshot();
+        self.active_column = Some(state.column);
+        self.editing = state.editing;
+        self.edit_input = Some(editor.input().clone());
+        self
+    }
+
+    pub fn sortable(mut self, column: usize, direction: SortDirection) -> Self {
+        self.sort = Some((column, direction));
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    pub fn paginate(mut self, page_size: usize) -> Self {
+        self.page_size = Some(page_size.max(1));
+        self
+    }
+
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = Some(page);
+        self
+    }
+
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
         self
@@ -283,6 +2143,83 @@ impl TableNode {
         self.column_widths = Some(widths);
         self
     }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn state(mut self, state: &ListStateHandle) -> Self {
+        let selection = state.snapshot();
+        self.highlight = selection.selected;
+        self.offset = selection.offset;
+        self
+    }
+
+    pub(crate) fn sorted(mut self) -> Self {
+        let Some((column, direction)) = self.sort else {
+            return self;
+        };
+        let mut rows: Vec<_> = self.rows.into_iter().enumerate().collect();
+        rows.sort_by(|(_, a), (_, b)| {
+            let a = a.cells.get(column).map(|cell| cell.content.as_str());
+            let b = b.cells.get(column).map(|cell| cell.content.as_str());
+            let ordering = compare_cells(a.unwrap_or_default(), b.unwrap_or_default());
+            match direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+        (self.sources, self.rows) = rows.into_iter().unzip();
+        self
+    }
+
+    // Source index of every row in display order, counting earlier pages.
+    pub(crate) fn source_rows(&self) -> Vec<usize> {
+        if self.sources.is_empty() {
+            (0..self.rows.len()).collect()
+        } else {
+            self.sources.clone()
+        }
+    }
+
+    pub(crate) fn into_page(mut self) -> (Self, Option<(usize, usize)>) {
+        let Some(page_size) = self.page_size else {
+            return (self, None);
+        };
+        let page_count = self.rows.len().div_ceil(page_size).max(1);
+        let page = self
+            .page
+            .unwrap_or_else(|| self.highlight.map_or(0, |index| index / page_size))
+            .min(page_count - 1);
+        let start = page * page_size;
+        self.rows = self.rows.into_iter().skip(start).take(page_size).collect();
+        if !self.sources.is_empty() {
+            self.sources = self
+                .sources
+                .into_iter()
+                .skip(start)
+                .take(page_size)
+                .collect();
+        }
+        self.highlight = self
+            .highlight
+            .filter(|index| (start..start + page_size).contains(index))
+            .map(|index| index - start);
+        self.editing = self
+            .editing
+            .filter(|(row, _)| (start..start + page_size).contains(row))
+            .map(|(row, column)| (row - start, column));
+        self.offset = 0;
+        (self, Some((page, page_count)))
+    }
+}
+
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        _ => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -305,7 +2242,8 @@ impl TableRowNode {
 pub struct TableCellNode {
     pub content: String,
     pub color: Option<Color>,
-    pub bold: bool,
+    pub modifiers: Modifier,
+    pub overflow: TextOverflow,
 }
 
 impl TableCellNode {
@@ -313,19 +2251,29 @@ impl TableCellNode {
         Self {
             content: content.into(),
             color: None,
-            bold: false,
+            modifiers: Modifier::empty(),
+            overflow: TextOverflow::default(),
         }
     }
 
+    pub fn overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
     pub fn color(mut self, color: Color) -> Self {
         self.color = Some(color);
         self
     }
 
-    pub fn bold(mut self) -> Self {
-        self.bold = true;
+    pub fn modifiers(mut self, modifiers: Modifier) -> Self {
+        self.modifiers |= modifiers;
         self
     }
+
+    pub fn bold(self) -> Self {
+        self.modifiers(Modifier::BOLD)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -333,6 +2281,10 @@ pub struct TreeNode {
     pub title: Option<String>,
     pub items: Vec<TreeItemNode>,
     pub highlight: Option<usize>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub state: Option<TreeStateHandle>,
+    pub checkable: bool,
 }
 
 impl TreeNode {
@@ -341,9 +2293,33 @@ impl TreeNode {
             title: None,
             items,
             highlight: None,
+            id: None,
+            classes: Vec::new(),
+            state: None,
+            checkable: false,
         }
     }
 
+    pub fn checkable(mut self) -> Self {
+        self.checkable = true;
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    pub fn state(mut self, state: &TreeStateHandle) -> Self {
+        self.state = Some(state.clone());
+        self
+    }
+
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
         self
@@ -381,103 +2357,303 @@ impl TreeItemNode {
         self
     }
 
-    pub fn expanded(mut self, expanded: bool) -> Self {
-        self.expanded = expanded;
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FormNode {
+    pub title: Option<String>,
+    pub fields: Vec<FormFieldNode>,
+    pub label_width: u16,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl FormNode {
+    pub fn new(fields: Vec<FormFieldNode>) -> Self {
+        Self {
+            title: None,
+            fields,
+            label_width: 30,
+            id: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn label_width(mut self, percent: u16) -> Self {
+        self.label_width = percent.clamp(10, 90);
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FormFieldNode {
+    pub label: String,
+    pub value: String,
+    pub status: FormFieldStatus,
+}
+
+impl FormFieldNode {
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            status: FormFieldStatus::Normal,
+        }
+    }
+
+    pub fn status(mut self, status: FormFieldStatus) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FormFieldStatus {
+    Normal,
+    Warning,
+    Error,
+    Success,
+}
+
+#[derive(Clone, Debug)]
+pub struct TextInputNode {
+    pub binding: TextInputHandle,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    pub width: Option<u16>,
+    pub secure: bool,
+    pub accent: Option<Color>,
+    pub border_color: Option<Color>,
+    pub text_color: Option<Color>,
+    pub placeholder_color: Option<Color>,
+    pub background_color: Option<Color>,
+    pub focus_background: Option<Color>,
+    pub status: FormFieldStatus,
+    pub mask: Option<InputMask>,
+    pub suggestions: Option<SuggestionProvider>,
+    pub border_style: BorderStyle,
+    pub classes: Vec<String>,
+}
+
+impl TextInputNode {
+    pub fn new(binding: TextInputHandle) -> Self {
+        Self {
+            binding,
+            label: None,
+            placeholder: None,
+            width: None,
+            secure: false,
+            accent: None,
+            border_color: None,
+            text_color: None,
+            placeholder_color: None,
+            background_color: None,
+            focus_background: None,
+            status: FormFieldStatus::Normal,
+            mask: None,
+            suggestions: None,
+            border_style: BorderStyle::default(),
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    pub fn suggestions(mut self, provider: SuggestionProvider) -> Self {
+        self.suggestions = Some(provider);
+        self
+    }
+
+    pub fn mask(mut self, mask: InputMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    pub fn accent(mut self, color: Color) -> Self {
+        self.accent = Some(color);
+        self
+    }
+
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+
+    pub fn placeholder_color(mut self, color: Color) -> Self {
+        self.placeholder_color = Some(color);
+        self
+    }
+
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    pub fn focus_background(mut self, color: Color) -> Self {
+        self.focus_background = Some(color);
+        self
+    }
+
+    pub fn status(mut self, status: FormFieldStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
         self
     }
 }
 
 #[derive(Clone, Debug)]
-pub struct FormNode {
-    pub title: Option<String>,
-    pub fields: Vec<FormFieldNode>,
-    pub label_width: u16,
+pub struct NumericInputNode {
+    pub input: TextInputNode,
+    pub format: NumericFormat,
 }
 
-impl FormNode {
-    pub fn new(fields: Vec<FormFieldNode>) -> Self {
+impl NumericInputNode {
+    pub fn new(binding: TextInputHandle) -> Self {
         Self {
-            title: None,
-            fields,
-            label_width: 30,
+            input: TextInputNode::new(binding),
+            format: NumericFormat::default(),
         }
     }
 
-    pub fn title(mut self, title: impl Into<String>) -> Self {
-        self.title = Some(title.into());
+    pub fn integer(mut self) -> Self {
+        self.format.decimals = Some(0);
         self
     }
 
-    pub fn label_width(mut self, percent: u16) -> Self {
-        self.label_width = percent.clamp(10, 90);
+    pub fn decimals(mut self, places: u8) -> Self {
+        self.format.decimals = Some(places);
         self
     }
-}
 
-#[derive(Clone, Debug)]
-pub struct FormFieldNode {
-    pub label: String,
-    pub value: String,
-    pub status: FormFieldStatus,
-}
+    pub fn thousands_separator(mut self, enabled: bool) -> Self {
+        self.format.grouping = enabled;
+        self
+    }
 
-impl FormFieldNode {
-    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
-        Self {
-            label: label.into(),
-            value: value.into(),
-            status: FormFieldStatus::Normal,
-        }
+    pub fn signed(mut self, signed: bool) -> Self {
+        self.format.signed = signed;
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.input = self.input.label(label);
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.input = self.input.placeholder(placeholder);
+        self
+    }
+
+    pub fn width(mut self, width: u16) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    pub fn accent(mut self, color: Color) -> Self {
+        self.input = self.input.accent(color);
+        self
     }
 
     pub fn status(mut self, status: FormFieldStatus) -> Self {
-        self.status = status;
+        self.input = self.input.status(status);
         self
     }
-}
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum FormFieldStatus {
-    Normal,
-    Warning,
-    Error,
-    Success,
+    pub fn into_text_input(self) -> TextInputNode {
+        self.input.mask(InputMask::Numeric(self.format))
+    }
 }
 
 #[derive(Clone, Debug)]
-pub struct TextInputNode {
-    pub binding: TextInputHandle,
+pub struct TextAreaNode {
+    pub binding: TextAreaHandle,
     pub label: Option<String>,
     pub placeholder: Option<String>,
-    pub width: Option<u16>,
-    pub secure: bool,
+    pub height: Option<u16>,
     pub accent: Option<Color>,
     pub border_color: Option<Color>,
     pub text_color: Option<Color>,
     pub placeholder_color: Option<Color>,
-    pub background_color: Option<Color>,
-    pub focus_background: Option<Color>,
     pub status: FormFieldStatus,
+    pub border_style: BorderStyle,
+    pub classes: Vec<String>,
 }
 
-impl TextInputNode {
-    pub fn new(binding: TextInputHandle) -> Self {
+impl TextAreaNode {
+    pub fn new(binding: TextAreaHandle) -> Self {
         Self {
             binding,
             label: None,
             placeholder: None,
-            width: None,
-            secure: false,
+            height: None,
             accent: None,
             border_color: None,
             text_color: None,
             placeholder_color: None,
-            background_color: None,
-            focus_background: None,
             status: FormFieldStatus::Normal,
+            border_style: BorderStyle::default(),
+            classes: Vec::new(),
         }
     }
 
+    pub fn border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
     pub fn label(mut self, label: impl Into<String>) -> Self {
         self.label = Some(label.into());
         self
@@ -488,13 +2664,8 @@ impl TextInputNode {
         self
     }
 
-    pub fn width(mut self, width: u16) -> Self {
-        self.width = Some(width);
-        self
-    }
-
-    pub fn secure(mut self, secure: bool) -> Self {
-        self.secure = secure;
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = Some(height);
         self
     }
 
@@ -518,18 +2689,13 @@ impl TextInputNode {
         self
     }
 
-    pub fn background_color(mut self, color: Color) -> Self {
-        self.background_color = Some(color);
-        self
-    }
-
-    pub fn focus_background(mut self, color: Color) -> Self {
-        self.focus_background = Some(color);
+    pub fn status(mut self, status: FormFieldStatus) -> Self {
+        self.status = status;
         self
     }
 
-    pub fn status(mut self, status: FormFieldStatus) -> Self {
-        self.status = status;
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
         self
     }
 }
@@ -540,6 +2706,8 @@ pub struct TabsNode {
     pub active: usize,
     pub accent: Option<Color>,
     pub title: Option<String>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
 impl TabsNode {
@@ -549,6 +2717,8 @@ impl TabsNode {
             active: 0,
             accent: None,
             title: None,
+            id: None,
+            classes: Vec::new(),
         }
     }
 
@@ -566,6 +2736,16 @@ impl TabsNode {
         self.title = Some(title.into());
         self
     }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -586,11 +2766,32 @@ impl TabPaneNode {
 #[derive(Clone, Debug)]
 pub struct LayeredNode {
     pub layers: Vec<Element>,
+    pub z_indices: Vec<i32>,
 }
 
 impl LayeredNode {
     pub fn new(layers: Vec<Element>) -> Self {
-        Self { layers }
+        let z_indices = vec![0; layers.len()];
+        Self { layers, z_indices }
+    }
+
+    pub fn layer(self, layer: Element) -> Self {
+        self.layer_at(0, layer)
+    }
+
+    pub fn layer_at(mut self, z_index: i32, layer: Element) -> Self {
+        self.z_indices.resize(self.layers.len(), 0);
+        self.layers.push(layer);
+        self.z_indices.push(z_index);
+        self
+    }
+
+    pub fn z_index(mut self, index: usize, z_index: i32) -> Self {
+        self.z_indices.resize(self.layers.len(), 0);
+        if let Some(slot) = self.z_indices.get_mut(index) {
+            *slot = z_index;
+        }
+        self
     }
 }
 
@@ -600,6 +2801,8 @@ pub struct ModalNode {
     pub content: Box<Element>,
     pub width: Option<u16>,
     pub height: Option<u16>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
 impl ModalNode {
@@ -609,6 +2812,8 @@ impl ModalNode {
             content: Box::new(content),
             width: None,
             height: None,
+            id: None,
+            classes: Vec::new(),
         }
     }
 
@@ -626,22 +2831,143 @@ impl ModalNode {
         self.height = Some(height);
         self
     }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Anchor {
+    #[default]
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Center,
+}
+
+impl Anchor {
+    pub(crate) fn place(self, area: Rect, offset: (u16, u16), size: (u16, u16)) -> Rect {
+        let width = size.0.min(area.width.saturating_sub(offset.0));
+        let height = size.1.min(area.height.saturating_sub(offset.1));
+        let left = area.x.saturating_add(offset.0);
+        let top = area.y.saturating_add(offset.1);
+        let right = (area.x + area.width).saturating_sub(offset.0 + width);
+        let bottom = (area.y + area.height).saturating_sub(offset.1 + height);
+        let (x, y) = match self {
+            Anchor::TopLeft => (left, top),
+            Anchor::TopRight => (right, top),
+            Anchor::BottomLeft => (left, bottom),
+            Anchor::BottomRight => (right, bottom),
+            Anchor::Center => (
+                area.x + (area.width - width) / 2,
+                area.y + (area.height - height) / 2,
+            ),
+        };
+        Rect::new(x, y, width, height)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AnchorNode {
+    pub anchor: Anchor,
+    pub offset: (u16, u16),
+    pub width: Option<u16>,
+    pub height: Option<u16>,
+    pub content: Box<Element>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl AnchorNode {
+    pub fn new(anchor: Anchor, content: Element) -> Self {
+        Self {
+            anchor,
+            offset: (0, 0),
+            width: None,
+            height: None,
+            content: Box::new(content),
+            id: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn absolute(x: u16, y: u16, width: u16, height: u16, content: Element) -> Self {
+        Self::new(Anchor::TopLeft, content)
+            .offset(x, y)
+            .size(width, height)
+    }
+
+    pub fn offset(mut self, x: u16, y: u16) -> Self {
+        self.offset = (x, y);
+        self
+    }
+
+    pub fn size(mut self, width: u16, height: u16) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct ToastStackNode {
     pub toasts: Vec<ToastNode>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
 impl ToastStackNode {
     pub fn new(toasts: Vec<ToastNode>) -> Self {
-        Self { toasts }
+        Self {
+            toasts,
+            id: None,
+            classes: Vec::new(),
+        }
     }
 
     pub fn push(mut self, toast: ToastNode) -> Self {
         self.toasts.push(toast);
         self
     }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -672,9 +2998,156 @@ impl ToastNode {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ToastLevel {
     Info,
     Success,
     Warning,
     Error,
 }
+
+#[derive(Clone, Debug)]
+pub struct ScrollNode {
+    pub id: String,
+    pub children: Vec<Element>,
+    pub scrollbar: bool,
+    pub classes: Vec<String>,
+}
+
+impl ScrollNode {
+    pub fn new(id: impl Into<String>, children: Vec<Element>) -> Self {
+        Self {
+            id: id.into(),
+            children,
+            scrollbar: true,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn child(mut self, child: Element) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SplitNode {
+    pub id: String,
+    pub direction: FlexDirection,
+    pub first: Box<Element>,
+    pub second: Box<Element>,
+    pub ratio: f64,
+    pub min_size: u16,
+    pub accent: Option<Color>,
+    pub classes: Vec<String>,
+}
+
+impl SplitNode {
+    pub fn horizontal(id: impl Into<String>, left: Element, right: Element) -> Self {
+        Self::new(id, FlexDirection::Row, left, right)
+    }
+
+    pub fn vertical(id: impl Into<String>, top: Element, bottom: Element) -> Self {
+        Self::new(id, FlexDirection::Column, top, bottom)
+    }
+
+    fn new(
+        id: impl Into<String>,
+        direction: FlexDirection,
+        first: Element,
+        second: Element,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            direction,
+            first: Box::new(first),
+            second: Box::new(second),
+            ratio: 0.5,
+            min_size: 3,
+            accent: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn ratio(mut self, ratio: f64) -> Self {
+        self.ratio = ratio;
+        self
+    }
+
+    pub fn min_size(mut self, min_size: u16) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    pub fn accent(mut self, color: Color) -> Self {
+        self.accent = Some(color);
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TooltipNode {
+    pub id: String,
+    pub target: Box<Element>,
+    pub text: String,
+    pub delay: Duration,
+    pub focus_target: Option<String>,
+    pub color: Option<Color>,
+    pub background_color: Option<Color>,
+    pub classes: Vec<String>,
+}
+
+impl TooltipNode {
+    pub fn new(id: impl Into<String>, target: Element, text: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            target: Box::new(target),
+            text: text.into(),
+            delay: Duration::from_millis(500),
+            focus_target: None,
+            color: None,
+            background_color: None,
+            classes: Vec::new(),
+        }
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn on_focus(mut self, id: impl Into<String>) -> Self {
+        self.focus_target = Some(id.into());
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
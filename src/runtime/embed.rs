@@ -0,0 +1,95 @@
+use anyhow::Context;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use tracing::warn;
+
+use crate::events::FrameworkEvent;
+use crate::renderer::render_frame;
+
+use super::app::{App, Session};
+use super::dispatcher::AppMessage;
+use super::view::View;
+
+const MAX_MESSAGES_PER_FRAME: usize = 256;
+
+pub struct EmbeddedApp {
+    app: App,
+    session: Session,
+    running: bool,
+}
+
+impl EmbeddedApp {
+    pub(crate) fn new(app: App, session: Session) -> Self {
+        session.dispatcher.request_render();
+        Self {
+            app,
+            session,
+            running: true,
+        }
+    }
+
+    pub fn handle_event(&mut self, event: FrameworkEvent) -> anyhow::Result<()> {
+        self.handle(AppMessage::ExternalEvent(event))?;
+        self.pump()
+    }
+
+    pub fn render(&mut self, frame: &mut Frame<'_>, area: Rect) -> anyhow::Result<()> {
+        if self.session.renderer.place(area) {
+            self.app.resize_viewport(area);
+            self.session.dispatcher.request_render();
+        }
+        self.pump()?;
+        if let Some(view) = self.session.view() {
+            let _app = self.session.dispatcher.enter();
+            render_frame(frame, area, view);
+        }
+        Ok(())
+    }
+
+    pub fn view(&self) -> &View {
+        self.session.view().unwrap_or(&View::Empty)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    pub fn exit_code(&self) -> Option<i32> {
+        (!self.running).then(|| self.session.exit_code())
+    }
+
+    fn handle(&mut self, message: AppMessage) -> anyhow::Result<()> {
+        if self.running {
+            self.running = self.app.handle_message(&mut self.session, message)?;
+            if !self.running {
+                self.app.finish();
+            }
+        }
+        Ok(())
+    }
+
+    fn pump(&mut self) -> anyhow::Result<()> {
+        let mut render = false;
+        for _ in 0..MAX_MESSAGES_PER_FRAME {
+            let Ok(message) = self.session.rx.try_recv() else {
+                return self.render_if(render);
+            };
+            if matches!(message, AppMessage::RequestRender) {
+                render = true;
+                continue;
+            }
+            self.handle(message)
+                .context("handle embedded app message")?;
+        }
+        warn!("embedded app still has queued messages; deferring to the next frame");
+        self.render_if(render)
+    }
+
+    fn render_if(&mut self, requested: bool) -> anyhow::Result<()> {
+        if requested {
+            self.handle(AppMessage::RequestRender)
+                .context("render embedded app")?;
+        }
+        Ok(())
+    }
+}
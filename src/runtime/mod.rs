@@ -1,29 +1,54 @@
 mod app;
 mod component;
+mod custom;
 mod dispatcher;
 mod element;
+mod embed;
+mod registries;
+mod stylesheet_watch;
 mod tasks;
 mod view;
+mod view_query;
 
 #[cfg(test)]
 mod tests;
 
-pub use ratatui::style::Color;
+pub use ratatui::layout::{Constraint, Rect};
+pub use ratatui::style::{Color, Modifier};
 
+pub use crate::rt::JoinHandle;
 pub use app::{App, AppConfig};
-pub use component::{ComponentElement, ComponentFn, component};
+pub use component::{ComponentElement, ComponentFn, component, component_memo};
+pub use custom::{CustomNode, CustomView, CustomWidget, CustomWidgetView};
 pub use dispatcher::Dispatcher;
 pub use element::{
-    ButtonNode, Element, FlexDirection, FormFieldNode, FormFieldStatus, FormNode, GaugeNode,
-    LayeredNode, ListItemNode, ListNode, ModalNode, TabPaneNode, TableCellNode, TableNode,
-    TableRowNode, TabsNode, TextInputNode, ToastLevel, ToastNode, ToastStackNode, TreeItemNode,
-    TreeNode,
+    Align, Anchor, AnchorNode, BlockNode, BorderStyle, ButtonNode, CheckState, CheckboxNode,
+    ConstrainNode, Dimensions, Element, FilePickerNode, FlexDirection, FlexNode, FormFieldNode,
+    FormFieldStatus, FormNode, GaugeNode, Justify, KeyHintsNode, LayeredNode, ListItemFactory,
+    ListItemNode, ListNode, MenuBarNode, MenuItemNode, ModalNode, NumericInputNode, ParagraphNode,
+    ProgressNode, ProgressStyle, ScrollNode, SelectNode, SizedNode, SortDirection, Spacing,
+    SpinnerNode, SpinnerStyle, SplitNode, StatusBarNode, StatusSegment, StepperNode, TabPaneNode,
+    TableCellNode, TableNode, TableRowNode, TabsNode, TextAlign, TextAreaNode, TextInputNode,
+    TextNode, TextOverflow, ThemeProvider, TimePickerNode, ToastLevel, ToastNode, ToastStackNode,
+    TooltipNode, TreeItemNode, TreeNode, VirtualListNode,
 };
+pub use embed::EmbeddedApp;
 pub use tasks::{DefaultRuntimeDriver, RuntimeDriver};
 pub use view::{
-    BlockView, ButtonView, FlexView, FormFieldView, FormView, GaugeView, LayersView, ListItemView,
-    ListView, ModalView, TabView, TableCellView, TableRowView, TableView, TabsView, TextInputView,
-    TextView, ToastStackView, ToastView, TreeRowView, TreeView, View,
+    AnchoredView, BlockView, ButtonView, CheckboxView, ConstrainedView, FlexView, FormFieldView,
+    FormView, GaugeView, KeyHintView, KeyHintsView, LayersView, ListItemView, ListView,
+    MenuBarView, MenuEntryView, MenuLevelView, ModalView, ParagraphView, ProgressView, ScrollView,
+    SelectView, SizedView, SpinnerView, SplitView, StatusBarView, StepperView, TabView,
+    TableCellView, TableEditorView, TablePagerView, TableRowView, TableView, TabsView,
+    TextAreaView, TextInputView, TextView, TimePickerView, ToastStackView, ToastView, TooltipView,
+    TreeRowView, TreeView, View, VirtualRows,
 };
 
+#[cfg(any(test, feature = "testing"))]
+pub(crate) use app::Session;
 pub(crate) use component::ComponentId;
+pub(crate) use dispatcher::AppMessage;
+pub use registries::EnterGuard;
+pub(crate) use registries::{Current, Registries};
+#[cfg(any(feature = "remote", feature = "web"))]
+pub(crate) use tasks::spawn_tick_loop;
@@ -0,0 +1,136 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "devtools")]
+use crate::devtools::TimelineRegistry;
+use crate::interactions::{ButtonRegistry, CheckboxRegistry, InteractionRegistry};
+use crate::menu::MenuRegistry;
+use crate::profiling::Profiler;
+use crate::renderer::DamageCache;
+use crate::scroll::ScrollRegistry;
+use crate::select::SelectRegistry;
+use crate::split::SplitRegistry;
+use crate::text_input::TextInputRegistry;
+use crate::time_picker::TimePickerRegistry;
+use crate::tooltip::TooltipRegistry;
+
+tokio::task_local! {
+    static SCOPED: Arc<Registries>;
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Option<Arc<Registries>>> = const { RefCell::new(None) };
+}
+
+pub(crate) struct Registries {
+    pub(crate) interactions: InteractionRegistry,
+    pub(crate) buttons: ButtonRegistry,
+    pub(crate) checkboxes: CheckboxRegistry,
+    pub(crate) text_inputs: TextInputRegistry,
+    pub(crate) selects: SelectRegistry,
+    pub(crate) time_pickers: TimePickerRegistry,
+    pub(crate) menus: MenuRegistry,
+    pub(crate) scrolls: ScrollRegistry,
+    pub(crate) splits: SplitRegistry,
+    pub(crate) tooltips: TooltipRegistry,
+    pub(crate) damage: DamageCache,
+    pub(crate) profiler: Profiler,
+    #[cfg(feature = "devtools")]
+    pub(crate) timelines: TimelineRegistry,
+    invalidated: AtomicBool,
+}
+
+impl Registries {
+    pub(crate) fn new() -> Self {
+        Self {
+            interactions: InteractionRegistry::new(),
+            buttons: ButtonRegistry::new(),
+            checkboxes: CheckboxRegistry::new(),
+            text_inputs: TextInputRegistry::new(),
+            selects: SelectRegistry::new(),
+            time_pickers: TimePickerRegistry::new(),
+            menus: MenuRegistry::new(),
+            scrolls: ScrollRegistry::new(),
+            splits: SplitRegistry::new(),
+            tooltips: TooltipRegistry::new(),
+            damage: DamageCache::new(),
+            profiler: Profiler::new(),
+            #[cfg(feature = "devtools")]
+            timelines: TimelineRegistry::new(),
+            invalidated: AtomicBool::new(false),
+        }
+    }
+
+    pub(crate) fn invalidate(&self) {
+        self.invalidated.store(true, Ordering::Release);
+    }
+
+    pub(crate) fn take_invalidated(&self) -> bool {
+        self.invalidated.swap(false, Ordering::AcqRel)
+    }
+
+    pub(crate) fn enter(self: &Arc<Self>) -> EnterGuard {
+        let previous = ACTIVE.with(|active| active.replace(Some(self.clone())));
+        EnterGuard {
+            previous,
+            _thread: PhantomData,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn isolated() -> EnterGuard {
+        Arc::new(Self::new()).enter()
+    }
+
+    pub(crate) fn scope<F: Future>(
+        self: &Arc<Self>,
+        future: F,
+    ) -> impl Future<Output = F::Output> + use<F> {
+        SCOPED.scope(self.clone(), future)
+    }
+
+    // Tasks spawned through `Dispatcher::spawn` carry their app; synchronous runtime code
+    // enters it for the current thread. Anything else has no app to answer for, and a
+    // throwaway registry would make every hit test quietly miss.
+    pub(crate) fn current<T>(field: fn(&Registries) -> &T) -> Current<T> {
+        let registries = SCOPED
+            .try_with(Arc::clone)
+            .ok()
+            .or_else(|| ACTIVE.with(|active| active.borrow().clone()))
+            .expect(
+                "widget helper called outside an app: spawn the task with `Dispatcher::spawn`, \
+                 wrap it in `Dispatcher::scope`, or hold `Dispatcher::enter()`",
+            );
+        Current { registries, field }
+    }
+}
+
+#[must_use = "the app is only entered until the guard is dropped"]
+pub struct EnterGuard {
+    previous: Option<Arc<Registries>>,
+    _thread: PhantomData<*const ()>,
+}
+
+impl Drop for EnterGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        ACTIVE.with(|active| *active.borrow_mut() = previous);
+    }
+}
+
+pub(crate) struct Current<T: 'static> {
+    registries: Arc<Registries>,
+    field: fn(&Registries) -> &T,
+}
+
+impl<T> Deref for Current<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        (self.field)(&self.registries)
+    }
+}
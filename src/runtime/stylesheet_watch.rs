@@ -0,0 +1,212 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "watch")]
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+use crate::rt::time::sleep;
+use crate::rt::{self, JoinHandle};
+use crate::runtime::{Element, ToastLevel, ToastNode, ToastStackNode};
+use crate::styles::{Stylesheet, StylesheetSource, read_stylesheet};
+
+use super::dispatcher::AppMessage;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(400);
+#[cfg(feature = "watch")]
+const SETTLE_DELAY: Duration = Duration::from_millis(30);
+
+pub(crate) fn spawn_stylesheet_watcher(
+    paths: Vec<PathBuf>,
+    tx: mpsc::Sender<AppMessage>,
+) -> JoinHandle<()> {
+    rt::spawn(async move {
+        info!(paths = ?paths, "stylesheet watcher started");
+        let mut watch = StylesheetWatch::new(paths);
+        watch.prime().await;
+        #[cfg(feature = "watch")]
+        match watch_events(&mut watch, &tx).await {
+            Ok(()) => return,
+            Err(err) => {
+                warn!(paths = ?watch.paths, error = ?err, "file notifications unavailable, polling stylesheets")
+            }
+        }
+        while watch.reload(&tx).await {
+            sleep(POLL_INTERVAL).await;
+        }
+    })
+}
+
+struct StylesheetWatch {
+    paths: Vec<PathBuf>,
+    fingerprint: Option<u64>,
+    files: Vec<PathBuf>,
+}
+
+impl StylesheetWatch {
+    fn new(paths: Vec<PathBuf>) -> Self {
+        Self {
+            paths,
+            fingerprint: None,
+            files: Vec::new(),
+        }
+    }
+
+    async fn read(&self) -> anyhow::Result<Vec<StylesheetSource>> {
+        let paths = self.paths.clone();
+        rt::blocking(move || paths.iter().map(|path| read_stylesheet(path)).collect()).await?
+    }
+
+    fn track(&mut self, sources: &[StylesheetSource]) -> u64 {
+        self.files = sources
+            .iter()
+            .flat_map(|source| source.files.iter().cloned())
+            .collect();
+        fingerprint(sources)
+    }
+
+    async fn prime(&mut self) {
+        if let Ok(sources) = self.read().await {
+            self.fingerprint = Some(self.track(&sources));
+        }
+    }
+
+    async fn load(&mut self) -> anyhow::Result<Option<Arc<Stylesheet>>> {
+        let sources = match self.read().await {
+            Ok(sources) => sources,
+            Err(_) if self.paths.iter().any(|path| !path.exists()) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let fingerprint = self.track(&sources);
+        if self.fingerprint.replace(fingerprint) == Some(fingerprint) {
+            return Ok(None);
+        }
+        let mut stylesheet = Stylesheet::default();
+        for (path, source) in self.paths.iter().zip(&sources) {
+            let parsed = Stylesheet::parse(&source.contents)
+                .with_context(|| format!("parse {}", path.display()))?;
+            stylesheet.extend(parsed);
+        }
+        Ok(Some(Arc::new(stylesheet)))
+    }
+
+    async fn reload(&mut self, tx: &mpsc::Sender<AppMessage>) -> bool {
+        match self.load().await {
+            Ok(Some(stylesheet)) => {
+                info!(paths = ?self.paths, "stylesheet change detected");
+                tx.send(AppMessage::StylesheetUpdated(stylesheet))
+                    .await
+                    .is_ok()
+            }
+            Ok(None) => true,
+            Err(err) => {
+                warn!(paths = ?self.paths, error = ?err, "stylesheet reload failed");
+                tx.send(AppMessage::StylesheetFailed(format!("{err:#}")))
+                    .await
+                    .is_ok()
+            }
+        }
+    }
+
+    #[cfg(feature = "watch")]
+    fn directories(&self) -> Vec<PathBuf> {
+        let roots = self.paths.iter().filter_map(|path| {
+            path.parent()
+                .map(|dir| std::fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf()))
+        });
+        let mut directories: Vec<PathBuf> = Vec::new();
+        for dir in roots.chain(
+            self.files
+                .iter()
+                .filter_map(|file| file.parent().map(Path::to_path_buf)),
+        ) {
+            if !directories.contains(&dir) {
+                directories.push(dir);
+            }
+        }
+        directories
+    }
+
+    #[cfg(feature = "watch")]
+    fn is_watched(&self, path: &Path) -> bool {
+        let Some(name) = path.file_name() else {
+            return false;
+        };
+        self.paths.iter().any(|path| path.file_name() == Some(name))
+            || self.files.iter().any(|file| file.file_name() == Some(name))
+    }
+}
+
+#[cfg(feature = "watch")]
+async fn watch_events(
+    watch: &mut StylesheetWatch,
+    tx: &mpsc::Sender<AppMessage>,
+) -> notify::Result<()> {
+    use notify::event::AccessKind;
+    use notify::{EventKind, RecursiveMode, Watcher};
+
+    let (events_tx, mut events) = mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        let _ = events_tx.send(event);
+    })?;
+    let mut directories = Vec::new();
+    for dir in watch.directories() {
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+        directories.push(dir);
+    }
+
+    while let Some(event) = events.recv().await {
+        let event: notify::Event = match event {
+            Ok(event) => event,
+            Err(err) => {
+                warn!(paths = ?watch.paths, error = ?err, "stylesheet watch error");
+                continue;
+            }
+        };
+        if matches!(
+            event.kind,
+            EventKind::Access(AccessKind::Read | AccessKind::Open(_))
+        ) || !event.paths.iter().any(|path| watch.is_watched(path))
+        {
+            continue;
+        }
+        sleep(SETTLE_DELAY).await;
+        while events.try_recv().is_ok() {}
+        if !watch.reload(tx).await {
+            return Ok(());
+        }
+
+        let next = watch.directories();
+        for dir in directories.iter().filter(|dir| !next.contains(dir)) {
+            let _ = watcher.unwatch(dir);
+        }
+        for dir in next.iter().filter(|dir| !directories.contains(dir)) {
+            if let Err(err) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+                warn!(path = %dir.display(), error = ?err, "failed to watch imported stylesheet");
+            }
+        }
+        directories = next;
+    }
+    Ok(())
+}
+
+pub(crate) fn stylesheet_error_overlay(message: &str) -> Element {
+    Element::toast_stack(ToastStackNode::new(vec![
+        ToastNode::new("Stylesheet error (Esc to dismiss)")
+            .body(message)
+            .level(ToastLevel::Error),
+    ]))
+}
+
+fn fingerprint(sources: &[StylesheetSource]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for source in sources {
+        source.contents.hash(&mut hasher);
+    }
+    hasher.finish()
+}
@@ -1,14 +1,21 @@
 use std::time::Duration;
 
+#[cfg(not(target_arch = "wasm32"))]
 use crossterm::event::EventStream;
+#[cfg(not(target_arch = "wasm32"))]
 use futures::StreamExt;
+#[cfg(not(target_arch = "wasm32"))]
 use tokio::signal;
 use tokio::sync::mpsc;
-use tokio::task::JoinHandle;
 
-use tracing::{debug, warn};
+use tracing::debug;
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::warn;
 
-use crate::events::{FrameworkEvent, is_ctrl_c, map_terminal_event};
+use crate::events::FrameworkEvent;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::events::map_terminal_event;
+use crate::rt::{self, JoinHandle, time};
 
 use super::dispatcher::AppMessage;
 
@@ -16,6 +23,12 @@ pub trait RuntimeDriver: Send + Sync {
     fn spawn_terminal_events(&self, tx: mpsc::Sender<AppMessage>) -> JoinHandle<()>;
     fn spawn_tick_loop(&self, tx: mpsc::Sender<AppMessage>, rate: Duration) -> JoinHandle<()>;
     fn spawn_shutdown_watcher(&self, tx: mpsc::Sender<AppMessage>) -> JoinHandle<()>;
+
+    fn observes_frames(&self) -> bool {
+        false
+    }
+
+    fn frame_drawn(&self, _hash: u64) {}
 }
 
 #[derive(Default)]
@@ -35,23 +48,18 @@ impl RuntimeDriver for DefaultRuntimeDriver {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 fn spawn_terminal_events(tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
     debug!("spawning terminal event listener");
-    tokio::spawn(async move {
+    rt::spawn(async move {
         let mut events = EventStream::new();
         while let Some(event) = events.next().await {
             match event {
                 Ok(evt) => {
                     if let Some(mapped) = map_terminal_event(evt) {
-                        let shutdown = is_ctrl_c(&mapped);
                         if tx.send(AppMessage::ExternalEvent(mapped)).await.is_err() {
                             break;
                         }
-                        if shutdown {
-                            debug!("ctrl+c detected; requesting shutdown");
-                            let _ = tx.send(AppMessage::Shutdown).await;
-                            break;
-                        }
                     }
                 }
                 Err(err) => {
@@ -64,10 +72,10 @@ fn spawn_terminal_events(tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
     })
 }
 
-fn spawn_tick_loop(tx: mpsc::Sender<AppMessage>, rate: Duration) -> JoinHandle<()> {
+pub(crate) fn spawn_tick_loop(tx: mpsc::Sender<AppMessage>, rate: Duration) -> JoinHandle<()> {
     debug!(?rate, "spawning tick loop");
-    tokio::spawn(async move {
-        let mut ticker = tokio::time::interval(rate);
+    rt::spawn(async move {
+        let mut ticker = time::interval(rate);
         loop {
             ticker.tick().await;
             if tx
@@ -82,12 +90,25 @@ fn spawn_tick_loop(tx: mpsc::Sender<AppMessage>, rate: Duration) -> JoinHandle<(
     })
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 fn spawn_shutdown_watcher(tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
     debug!("spawning shutdown watcher");
-    tokio::spawn(async move {
+    rt::spawn(async move {
         if signal::ctrl_c().await.is_ok() {
             let _ = tx.send(AppMessage::Shutdown).await;
         }
         debug!("shutdown watcher exited");
     })
 }
+
+// A browser page has no terminal to read or signals to catch. Input arrives
+// through a driver such as `web::WebDriver` instead.
+#[cfg(target_arch = "wasm32")]
+fn spawn_terminal_events(_tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+    rt::spawn(std::future::pending())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn spawn_shutdown_watcher(_tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+    rt::spawn(std::future::pending())
+}
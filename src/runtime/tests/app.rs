@@ -1,14 +1,26 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::backend::TestBackend;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders};
+use ratatui::{Frame, Terminal};
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio::time::timeout;
 
 use super::super::app::flatten_tree_items;
 use super::super::dispatcher::AppMessage;
-use crate::runtime::{App, Element, RuntimeDriver, TreeItemNode, TreeRowView, component};
+use crate::events::FrameworkEvent;
+use crate::renderer::RendererBackend;
+use crate::runtime::{
+    App, AppConfig, CustomView, CustomWidget, Element, EmbeddedApp, Rect, RuntimeDriver,
+    TreeItemNode, TreeRowView, component,
+};
+use crate::testing::ManualDriver;
 
 #[test]
 fn flatten_tree_items_includes_only_expanded_children() {
@@ -17,7 +29,7 @@ fn flatten_tree_items_includes_only_expanded_children() {
         .children(vec![TreeItemNode::new("Hidden")])
         .expanded(false);
 
-    let rows = flatten_tree_items(vec![expanded_parent, collapsed_parent]);
+    let rows = flatten_tree_items(vec![expanded_parent, collapsed_parent], &BTreeMap::new());
 
     assert_eq!(rows.len(), 3);
     assert_row(&rows[0], "Parent", 0, true, true);
@@ -25,6 +37,23 @@ fn flatten_tree_items_includes_only_expanded_children() {
     assert_row(&rows[2], "Collapsed", 0, true, false);
 }
 
+#[test]
+fn flatten_tree_items_applies_expansion_overrides_and_paths() {
+    let items = vec![
+        TreeItemNode::new("ops/")
+            .child(TreeItemNode::new("deploy.rs"))
+            .expanded(false),
+        TreeItemNode::new("web/").child(TreeItemNode::new("main.rs")),
+    ];
+    let overrides = BTreeMap::from([(vec![0], true), (vec![1], false)]);
+
+    let rows = flatten_tree_items(items, &overrides);
+
+    let paths: Vec<_> = rows.iter().map(|row| row.path.clone()).collect();
+    assert_eq!(paths, [vec![0], vec![0, 0], vec![1]]);
+    assert_row(&rows[2], "web/", 0, true, false);
+}
+
 fn assert_row(row: &TreeRowView, label: &str, depth: usize, has_children: bool, expanded: bool) {
     assert_eq!(row.label, label);
     assert_eq!(row.depth, depth);
@@ -50,6 +79,24 @@ async fn app_run_uses_custom_runtime_driver() {
     assert_eq!(shutdown, 1);
 }
 
+#[test]
+fn render_once_to_string_returns_the_headless_buffer() {
+    let mut app = App::new(
+        "SnapshotTest",
+        component("Greeting", |_ctx| {
+            Element::block("Hello", Element::text("Rustact 🦀 ready"))
+        }),
+    )
+    .headless_size(24, 3);
+
+    let snapshot = app.render_once_to_string().expect("render succeeds");
+
+    assert_eq!(
+        snapshot,
+        "┌Hello─────────────────┐\n│Rustact 🦀 ready      │\n└──────────────────────┘"
+    );
+}
+
 #[derive(Clone, Default)]
 struct TestRuntimeDriver {
     inner: Arc<TestRuntimeDriverInner>,
@@ -99,3 +146,262 @@ impl TestRuntimeDriver {
         )
     }
 }
+
+#[test]
+fn viewport_limits_rendering_to_the_given_area() {
+    let mut app = App::new(
+        "ViewportTest",
+        component("Status", |_ctx| {
+            Element::block("Status", Element::text("ok"))
+        }),
+    )
+    .headless_size(12, 5)
+    .viewport(Rect::new(2, 2, 10, 3));
+
+    assert_eq!(
+        app.render_once_to_string().expect("render succeeds"),
+        "\n\n  ┌Status──┐\n  │ok      │\n  └────────┘"
+    );
+}
+
+struct Meter(u16);
+
+impl CustomWidget for Meter {
+    fn build(&self) -> CustomView {
+        CustomView::new("meter")
+            .id("cpu")
+            .text(format!("{}%", self.0))
+            .size(10, 1)
+    }
+
+    fn render(&self, view: &CustomView, frame: &mut Frame<'_>, area: Rect) {
+        let filled = "#".repeat(usize::from(self.0 / 10));
+        frame.buffer_mut().set_string(
+            area.x,
+            area.y,
+            format!("[{filled:<10}] {}", view.text[0]),
+            Style::default(),
+        );
+    }
+}
+
+#[test]
+fn custom_widgets_render_through_the_extension_point() {
+    let mut app = App::new(
+        "CustomTest",
+        component("Meters", |_ctx| {
+            Element::vstack(vec![
+                Element::custom(Arc::new(Meter(40))),
+                Element::text("below"),
+            ])
+        }),
+    )
+    .headless_size(20, 2);
+
+    assert_eq!(
+        app.render_once_to_string().expect("render succeeds"),
+        "[####      ] 40%\nbelow"
+    );
+}
+
+#[test]
+fn raw_elements_render_ratatui_widgets_into_their_area() {
+    let mut app = App::new(
+        "RawTest",
+        component("Raw", |_ctx| {
+            Element::vstack(vec![
+                Element::text("above"),
+                Element::raw(|frame, area| {
+                    frame.render_widget(
+                        ratatui::widgets::Paragraph::new(format!("raw {}x{}", area.width, area.y)),
+                        area,
+                    );
+                }),
+            ])
+        }),
+    )
+    .headless_size(12, 2);
+
+    assert_eq!(
+        app.render_once_to_string().expect("render succeeds"),
+        "above\nraw 12x1"
+    );
+}
+
+#[tokio::test]
+async fn embedded_apps_render_inside_a_host_ratatui_frame() {
+    let app = App::new(
+        "EmbedTest",
+        component("Counter", |ctx| {
+            let (count, set_count) = ctx.use_state(|| 0);
+            ctx.use_effect((), move |dispatcher| {
+                let mut events = dispatcher.events().subscribe();
+                let handle = tokio::spawn(async move {
+                    while let Ok(event) = events.recv().await {
+                        if matches!(event, FrameworkEvent::Key(key) if key.code == KeyCode::Char('+'))
+                        {
+                            set_count.update(|count| *count += 1);
+                        }
+                    }
+                });
+                Some(Box::new(move || handle.abort()))
+            });
+            Element::text(format!("count {count}"))
+        }),
+    );
+    let mut terminal = Terminal::new(TestBackend::new(12, 3)).expect("host terminal");
+    let mut embedded = app.embed(Rect::new(1, 1, 10, 1)).expect("embed app");
+    let mut draw = |embedded: &mut EmbeddedApp| {
+        terminal
+            .draw(|frame| {
+                let block = Block::default().borders(Borders::ALL).title("Host");
+                let inner = block.inner(frame.size());
+                frame.render_widget(block, frame.size());
+                embedded.render(frame, inner).expect("render embedded app");
+            })
+            .expect("host draw")
+            .buffer
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>()
+    };
+
+    assert_eq!(draw(&mut embedded), "┌Host──────┐│count 0   │└──────────┘");
+
+    embedded
+        .handle_event(FrameworkEvent::Key(KeyEvent::new(
+            KeyCode::Char('+'),
+            KeyModifiers::NONE,
+        )))
+        .expect("forward key");
+    for _ in 0..8 {
+        tokio::task::yield_now().await;
+    }
+    assert_eq!(draw(&mut embedded), "┌Host──────┐│count 1   │└──────────┘");
+
+    embedded
+        .handle_event(FrameworkEvent::Key(KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+        )))
+        .expect("forward ctrl+c");
+    assert!(!embedded.is_running());
+    assert_eq!(embedded.exit_code(), Some(0));
+}
+
+struct MemoryBackend {
+    terminal: Terminal<TestBackend>,
+    frames: Arc<parking_lot::Mutex<Vec<String>>>,
+}
+
+impl RendererBackend for MemoryBackend {
+    fn size(&self) -> anyhow::Result<Rect> {
+        Ok(self.terminal.size()?)
+    }
+
+    fn draw(&mut self, render: &mut dyn FnMut(&mut Frame<'_>)) -> anyhow::Result<()> {
+        let frame = self.terminal.draw(|frame| render(frame))?;
+        let text = frame
+            .buffer
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>();
+        self.frames.lock().push(text);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn app_run_draws_through_a_custom_renderer_backend() {
+    let frames = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    let app = App::new(
+        "BackendTest",
+        component("Once", |ctx| {
+            ctx.use_effect((), |dispatcher| {
+                dispatcher.exit(3);
+                None
+            });
+            Element::text("in memory")
+        }),
+    )
+    .with_driver(ManualDriver::new())
+    .with_backend(MemoryBackend {
+        terminal: Terminal::new(TestBackend::new(10, 1)).expect("memory terminal"),
+        frames: frames.clone(),
+    });
+
+    let code = timeout(Duration::from_millis(200), app.run())
+        .await
+        .expect("runtime exited")
+        .expect("app run succeeds");
+
+    assert_eq!(code, 3);
+    assert_eq!(*frames.lock(), ["in memory "]);
+}
+
+#[tokio::test]
+async fn bursts_of_render_requests_coalesce_into_one_frame() {
+    let renders = Arc::new(AtomicUsize::new(0));
+    let counted = renders.clone();
+    let app = App::new(
+        "Burst",
+        component("Burst", move |ctx| {
+            counted.fetch_add(1, Ordering::SeqCst);
+            ctx.use_effect((), |dispatcher| {
+                for _ in 0..50 {
+                    dispatcher.request_render();
+                }
+                None
+            });
+            Element::text("burst")
+        }),
+    )
+    .headless();
+    let driver = ManualDriver::new();
+    let runtime = tokio::spawn(app.with_driver(driver.clone()).run());
+
+    driver.advance_ticks(1).await.expect("advance");
+    driver.shutdown().await.expect("shutdown");
+    runtime.await.expect("join").expect("run");
+
+    assert_eq!(renders.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn max_fps_spaces_frames_by_the_frame_budget() {
+    let frames = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    let recorded = frames.clone();
+    let app = App::new(
+        "Capped",
+        component("Capped", move |ctx| {
+            recorded.lock().push(tokio::time::Instant::now());
+            ctx.use_effect((), |dispatcher| {
+                let handle = tokio::spawn(async move {
+                    for _ in 0..20 {
+                        dispatcher.request_render();
+                        tokio::time::sleep(Duration::from_millis(5)).await;
+                    }
+                });
+                Some(Box::new(move || handle.abort()))
+            });
+            Element::text("capped")
+        }),
+    )
+    .with_config(AppConfig::default().max_fps(10))
+    .headless();
+    let driver = ManualDriver::new();
+    let runtime = tokio::spawn(app.with_driver(driver.clone()).run());
+
+    driver.advance_ticks(1).await.expect("advance");
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    driver.shutdown().await.expect("shutdown");
+    runtime.await.expect("join").expect("run");
+
+    let frames = frames.lock();
+    assert!((3..=6).contains(&frames.len()), "{} frames", frames.len());
+    for pair in frames.windows(2) {
+        assert!(pair[1] - pair[0] >= Duration::from_millis(100));
+    }
+}
@@ -30,3 +30,96 @@ fn events_accessor_returns_shared_bus() {
         other => panic!("unexpected event: {other:?}"),
     }
 }
+
+#[test]
+fn animation_frame_requests_are_consumed_once() {
+    let (tx, _) = mpsc::channel(1);
+    let dispatcher = Dispatcher::new(tx, EventBus::new(2));
+    assert!(!dispatcher.take_animation_frame());
+
+    dispatcher.clone().request_animation_frame();
+
+    assert!(dispatcher.take_animation_frame());
+    assert!(!dispatcher.take_animation_frame());
+}
+
+fn mouse_down(column: u16, row: u16) -> FrameworkEvent {
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+    FrameworkEvent::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+#[should_panic(expected = "widget helper called outside an app")]
+fn widget_helpers_outside_an_app_panic() {
+    crate::interactions::is_button_click(&mouse_down(0, 0), "save");
+}
+
+#[test]
+fn enter_scope_and_spawn_resolve_helpers_against_the_dispatcher() {
+    use crate::interactions::{Hitbox, is_button_click, register_button_hitbox};
+
+    let (tx, _) = mpsc::channel(1);
+    let dispatcher = Dispatcher::new(tx, EventBus::new(2));
+    {
+        let _app = dispatcher.enter();
+        register_button_hitbox("save", Hitbox::new(0, 0, 4, 1));
+        assert!(is_button_click(&mouse_down(1, 0), "save"));
+    }
+
+    let (tx, _) = mpsc::channel(1);
+    let other = Dispatcher::new(tx, EventBus::new(2));
+    {
+        let _app = other.enter();
+        assert!(!is_button_click(&mouse_down(1, 0), "save"));
+    }
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .build()
+        .expect("runtime");
+    let spawned = runtime.block_on(async {
+        dispatcher
+            .spawn(async {
+                tokio::task::yield_now().await;
+                is_button_click(&mouse_down(1, 0), "save")
+            })
+            .await
+    });
+    assert!(spawned.expect("join"));
+    let scoped = runtime.block_on(dispatcher.scope(async {
+        tokio::task::yield_now().await;
+        is_button_click(&mouse_down(1, 0), "save")
+    }));
+    assert!(scoped);
+}
+
+#[test]
+fn handles_use_their_own_app_from_any_thread() {
+    use std::sync::Arc;
+
+    use parking_lot::Mutex;
+
+    use crate::hooks::{ListSelection, ListStateHandle};
+    use crate::interactions::{Hitbox, register_rows};
+
+    let (tx, _) = mpsc::channel(4);
+    let dispatcher = Dispatcher::new(tx, EventBus::new(2));
+    {
+        let _app = dispatcher.enter();
+        register_rows("files", vec![(Hitbox::new(0, 2, 10, 1), 2)]);
+    }
+    let list = ListStateHandle::new(Arc::new(Mutex::new(ListSelection::new(4))), dispatcher);
+    let selected = std::thread::spawn(move || {
+        assert!(list.handle_event(&mouse_down(3, 2), "files"));
+        list.selected()
+    })
+    .join()
+    .expect("thread");
+    assert_eq!(selected, Some(2));
+}
@@ -0,0 +1,282 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use ratatui::layout::Rect;
+
+use crate::runtime::element::format_progress_label;
+use crate::runtime::{
+    Anchor, Color, ConstrainNode, Constraint, Element, FlexDirection, FlexView, ListItemNode,
+    Modifier, SizedView, SortDirection, Spacing, SpinnerStyle, TableCellNode, TableNode,
+    TableRowNode, TextOverflow, TextView, View, VirtualListNode,
+};
+use crate::styles::ElementStyle;
+
+#[test]
+fn spinner_advances_one_frame_per_interval_and_wraps() {
+    let interval = Duration::from_millis(100);
+    let style = SpinnerStyle::Line;
+    assert_eq!(style.frame_at(Duration::ZERO, interval), "-");
+    assert_eq!(style.frame_at(Duration::from_millis(199), interval), "\\");
+    assert_eq!(style.frame_at(Duration::from_millis(300), interval), "/");
+    assert_eq!(style.frame_at(Duration::from_millis(400), interval), "-");
+}
+
+#[test]
+fn spinner_tolerates_zero_interval() {
+    let frames = SpinnerStyle::Dots.frames();
+    let glyph = SpinnerStyle::Dots.frame_at(Duration::from_millis(3), Duration::ZERO);
+    assert_eq!(glyph, frames[3]);
+}
+
+#[test]
+fn progress_label_fills_percent_elapsed_and_eta() {
+    let label = format_progress_label(
+        "{percent}% · {elapsed} elapsed · ETA {eta}",
+        Some(0.25),
+        Some(Duration::from_secs(30)),
+    );
+    assert_eq!(label, "25% · 00:30 elapsed · ETA 01:30");
+}
+
+#[test]
+fn progress_label_without_timing_uses_placeholders() {
+    assert_eq!(
+        format_progress_label("ETA {eta}", Some(0.5), None),
+        "ETA --:--"
+    );
+    assert_eq!(
+        format_progress_label(
+            "{percent}|{eta}",
+            Some(1.0),
+            Some(Duration::from_secs(7200))
+        ),
+        "100|00:00"
+    );
+    assert_eq!(format_progress_label("{percent}", None, None), "");
+}
+
+#[test]
+fn virtual_list_builds_rows_only_for_the_drawn_height() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let list = VirtualListNode::new(100_000, move |index| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        ListItemNode::new(format!("row {index}"))
+    })
+    .offset(5_000)
+    .highlight(5_003)
+    .into_list();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert_eq!(list.highlight, Some(5_003));
+    let rows = list.virtual_rows.expect("virtual rows").rows(20);
+    assert_eq!(calls.load(Ordering::SeqCst), 24);
+    assert_eq!(rows.len(), 24);
+    assert_eq!(rows[0].content, "row 5000");
+}
+
+#[test]
+fn virtual_list_clamps_rows_to_item_count() {
+    let list = VirtualListNode::new(3, |index| ListItemNode::new(index.to_string()))
+        .offset(10)
+        .into_list();
+    let rows = list.virtual_rows.expect("virtual rows").rows(10);
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].content, "2");
+}
+
+fn numbered_rows(count: usize) -> Vec<TableRowNode> {
+    (0..count)
+        .map(|index| TableRowNode::new(vec![TableCellNode::new(index.to_string())]))
+        .collect()
+}
+
+#[test]
+fn paginated_table_keeps_only_the_highlighted_page() {
+    let (table, pager) = TableNode::new(numbered_rows(25))
+        .paginate(10)
+        .highlight(13)
+        .offset(7)
+        .into_page();
+
+    assert_eq!(pager, Some((1, 3)));
+    assert_eq!(table.rows.len(), 10);
+    assert_eq!(table.rows[0].cells[0].content, "10");
+    assert_eq!(table.highlight, Some(3));
+    assert_eq!(table.offset, 0);
+}
+
+#[test]
+fn paginated_table_clamps_explicit_page() {
+    let (table, pager) = TableNode::new(numbered_rows(25))
+        .paginate(10)
+        .page(9)
+        .highlight(0)
+        .into_page();
+
+    assert_eq!(pager, Some((2, 3)));
+    assert_eq!(table.rows.len(), 5);
+    assert_eq!(table.highlight, None);
+}
+
+#[test]
+fn sortable_table_orders_numbers_numerically_and_text_case_insensitively() {
+    let rows = ["10", "9", "100"]
+        .into_iter()
+        .map(|value| TableRowNode::new(vec![TableCellNode::new(value)]))
+        .collect();
+    let table = TableNode::new(rows)
+        .sortable(0, SortDirection::Descending)
+        .sorted();
+    let values: Vec<_> = table
+        .rows
+        .iter()
+        .map(|row| row.cells[0].content.as_str())
+        .collect();
+    assert_eq!(values, ["100", "10", "9"]);
+
+    let rows = ["beta", "Alpha", "gamma"]
+        .into_iter()
+        .map(|value| TableRowNode::new(vec![TableCellNode::new(value)]))
+        .collect();
+    let table = TableNode::new(rows)
+        .sortable(0, SortDirection::Ascending)
+        .sorted();
+    let values: Vec<_> = table
+        .rows
+        .iter()
+        .map(|row| row.cells[0].content.as_str())
+        .collect();
+    assert_eq!(values, ["Alpha", "beta", "gamma"]);
+}
+
+#[test]
+fn sorted_pages_keep_the_source_index_of_each_row() {
+    let rows = ["10", "9", "100", "1"]
+        .into_iter()
+        .map(|value| TableRowNode::new(vec![TableCellNode::new(value)]))
+        .collect();
+    let table = TableNode::new(rows)
+        .sortable(0, SortDirection::Descending)
+        .paginate(2)
+        .highlight(2)
+        .sorted();
+    assert_eq!(table.source_rows(), [2, 0, 1, 3]);
+
+    let (table, pager) = table.into_page();
+    assert_eq!(pager, Some((1, 2)));
+    assert_eq!(table.rows[0].cells[0].content, "9");
+    assert_eq!(table.sources, [1, 3]);
+    assert_eq!(table.highlight, Some(0));
+}
+
+#[test]
+fn flex_view_unwraps_sized_children_into_constraints() {
+    let text = |content: &str| {
+        View::Text(TextView {
+            content: content.to_string(),
+            color: None,
+            modifiers: Modifier::empty(),
+            overflow: TextOverflow::Clip,
+            id: None,
+            classes: Vec::new(),
+        })
+    };
+    let flex = FlexView::new(
+        FlexDirection::Column,
+        vec![
+            View::Sized(SizedView {
+                constraint: Constraint::Length(1),
+                content: Box::new(text("header")),
+            }),
+            text("body"),
+        ],
+    );
+    assert_eq!(flex.children, vec![text("header"), text("body")]);
+    assert_eq!(flex.constraints, vec![Some(Constraint::Length(1)), None]);
+}
+
+#[test]
+fn dimensions_prefer_fixed_size_within_min_and_max() {
+    let capped = ConstrainNode::new(Element::Empty).max_width(80).dimensions;
+    assert_eq!(capped.clamp_width(200), 80);
+    assert_eq!(capped.clamp_width(40), 40);
+
+    let panel = ConstrainNode::new(Element::Empty)
+        .width(30)
+        .min(0, 5)
+        .max(24, 10)
+        .dimensions;
+    assert_eq!(panel.clamp_width(200), 24);
+    assert_eq!(panel.clamp_height(2), 5);
+    assert_eq!(panel.clamp_height(50), 10);
+}
+
+#[test]
+fn anchor_places_rect_inside_area_from_each_corner() {
+    let area = Rect::new(10, 5, 40, 20);
+    assert_eq!(
+        Anchor::TopRight.place(area, (1, 0), (6, 1)),
+        Rect::new(43, 5, 6, 1)
+    );
+    assert_eq!(
+        Anchor::BottomLeft.place(area, (2, 1), (8, 3)),
+        Rect::new(12, 21, 8, 3)
+    );
+    assert_eq!(
+        Anchor::Center.place(area, (0, 0), (10, 4)),
+        Rect::new(25, 13, 10, 4)
+    );
+    assert_eq!(
+        Anchor::BottomRight.place(area, (0, 0), (80, 2)),
+        Rect::new(10, 23, 40, 2)
+    );
+}
+
+#[test]
+fn text_overflow_clips_ellipsizes_and_wraps_by_display_width() {
+    assert_eq!(TextOverflow::Clip.apply("日本語テキスト", 5), ["日本"]);
+    assert_eq!(
+        TextOverflow::Ellipsis.apply("deploy-service-alpha", 8),
+        ["deploy-…"]
+    );
+    assert_eq!(TextOverflow::Ellipsis.apply("日本語テキスト", 6), ["日本…"]);
+    assert_eq!(TextOverflow::Ellipsis.apply("short", 8), ["short"]);
+    assert_eq!(
+        TextOverflow::Wrap.apply("rolling restart of api-gateway", 10),
+        ["rolling", "restart of", "api-gatewa", "y"]
+    );
+}
+
+#[test]
+fn styled_overrides_node_colors_and_wraps_layout() {
+    let style = ElementStyle::new().color(Color::Red).width(12).flex_grow(2);
+    let element = Element::colored_text("hi", Color::Blue).styled(style);
+
+    let Element::Sized(sized) = element else {
+        panic!("expected flex-grow wrapper");
+    };
+    assert_eq!(sized.constraint, Constraint::Fill(2));
+    let Element::Constrain(constrained) = *sized.child else {
+        panic!("expected width wrapper");
+    };
+    assert_eq!(constrained.dimensions.width, Some(12));
+    let Element::Text(text) = *constrained.child else {
+        panic!("expected text");
+    };
+    assert_eq!(text.color, Some(Color::Red));
+}
+
+#[test]
+fn styled_flex_keeps_layout_overrides_on_the_node() {
+    let element = Element::hstack(vec![Element::text("a")])
+        .styled(ElementStyle::new().gap(2))
+        .styled(ElementStyle::new().padding(1));
+    let Element::Flex(flex) = element else {
+        panic!("expected flex");
+    };
+    let layout = flex.style.computed().layout();
+    assert_eq!(layout.gap, Some(2));
+    assert_eq!(layout.padding, Some(Spacing::all(1)));
+}
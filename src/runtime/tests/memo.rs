@@ -0,0 +1,146 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use parking_lot::Mutex;
+
+use crate::events::FrameworkEvent;
+use crate::hooks::StateHandle;
+use crate::runtime::{App, Element, View, component, component_memo};
+use crate::testing::TestApp;
+
+type Slot = Arc<Mutex<Option<StateHandle<u32>>>>;
+
+fn app(renders: Arc<AtomicUsize>, root_state: Slot, memo_state: Slot) -> App {
+    App::new(
+        "Memo",
+        component("Root", move |ctx| {
+            let (count, set_count) = ctx.use_state(|| 0u32);
+            *root_state.lock() = Some(set_count);
+            let (renders, memo_state) = (renders.clone(), memo_state.clone());
+            Element::vstack(vec![
+                Element::text(format!("count {count}")),
+                component_memo("Static", count >= 10, move |ctx, big| {
+                    renders.fetch_add(1, Ordering::SeqCst);
+                    let (local, set_local) = ctx.use_state(|| 0u32);
+                    *memo_state.lock() = Some(set_local);
+                    Element::text(format!("big {big} local {local}"))
+                })
+                .into(),
+            ])
+        }),
+    )
+    .headless_size(30, 4)
+}
+
+fn set(slot: &Slot, value: u32) {
+    slot.lock().as_ref().expect("handle").set(value);
+}
+
+#[test]
+fn memoized_subtrees_render_again_only_when_props_or_their_state_change() {
+    let renders = Arc::new(AtomicUsize::new(0));
+    let (root, local) = (Slot::default(), Slot::default());
+    let mut app = app(renders.clone(), root.clone(), local.clone());
+
+    app.render_once_to_string().expect("first render");
+    assert_eq!(renders.load(Ordering::SeqCst), 1);
+
+    set(&root, 3);
+    let frame = app.render_once_to_string().expect("sibling update");
+    assert!(frame.contains("count 3"), "{frame}");
+    assert!(frame.contains("big false local 0"), "{frame}");
+    assert_eq!(renders.load(Ordering::SeqCst), 1);
+
+    set(&root, 12);
+    let frame = app.render_once_to_string().expect("props change");
+    assert!(frame.contains("big true local 0"), "{frame}");
+    assert_eq!(renders.load(Ordering::SeqCst), 2);
+
+    set(&local, 7);
+    let frame = app.render_once_to_string().expect("local state change");
+    assert!(frame.contains("big true local 7"), "{frame}");
+    assert_eq!(renders.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn runtime_render_requests_invalidate_memoized_subtrees() {
+    let renders = Arc::new(AtomicUsize::new(0));
+    let mut app = TestApp::new(app(renders.clone(), Slot::default(), Slot::default()))
+        .await
+        .expect("start app");
+    let before = renders.load(Ordering::SeqCst);
+
+    app.resize(40, 4).await.expect("resize");
+    assert!(renders.load(Ordering::SeqCst) > before);
+}
+
+#[test]
+fn shared_elements_reuse_their_view_while_the_arc_is_unchanged() {
+    let renders = Arc::new(AtomicUsize::new(0));
+    let root = Slot::default();
+    let (counter, slot) = (renders.clone(), root.clone());
+    let mut app = App::new(
+        "Shared",
+        component("Root", move |ctx| {
+            let (count, set_count) = ctx.use_state(|| 0u32);
+            *slot.lock() = Some(set_count);
+            let counter = counter.clone();
+            let big = count >= 10;
+            let tree = ctx.use_memo(big, move || {
+                Element::vstack(vec![
+                    component("Leaf", move |_| {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        Element::text(format!("big {big}"))
+                    })
+                    .into(),
+                ])
+            });
+            Element::vstack(vec![
+                Element::text(format!("count {count}")),
+                Element::shared(tree),
+            ])
+        }),
+    )
+    .headless_size(30, 4);
+
+    app.render_once_to_string().expect("first render");
+    assert_eq!(renders.load(Ordering::SeqCst), 1);
+
+    set(&root, 3);
+    let frame = app.render_once_to_string().expect("sibling update");
+    assert!(frame.contains("count 3"), "{frame}");
+    assert!(frame.contains("big false"), "{frame}");
+    assert_eq!(renders.load(Ordering::SeqCst), 1);
+
+    set(&root, 12);
+    let frame = app.render_once_to_string().expect("new tree");
+    assert!(frame.contains("big true"), "{frame}");
+    assert_eq!(renders.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn memo_hits_hand_back_the_cached_view_without_cloning_it() {
+    let renders = Arc::new(AtomicUsize::new(0));
+    let (root, local) = (Slot::default(), Slot::default());
+    let mut app = TestApp::new(app(renders.clone(), root.clone(), local.clone()))
+        .await
+        .expect("start app");
+    let shared = |app: &TestApp| match app.view().children()[1] {
+        View::Shared(view) => view.clone(),
+        other => panic!("memoized view is not shared: {other:?}"),
+    };
+    let first = shared(&app);
+    // Ticks redraw everything for the cursor blink, so settle on a command instead.
+    let settle = || FrameworkEvent::Command("noop".into());
+
+    set(&root, 3);
+    app.send(settle()).await.expect("sibling update");
+    app.expect_text("count 3");
+    assert_eq!(renders.load(Ordering::SeqCst), 1);
+    assert!(Arc::ptr_eq(&first, &shared(&app)));
+
+    set(&local, 7);
+    app.send(settle()).await.expect("local state change");
+    app.expect_text("big false local 7");
+    assert!(!Arc::ptr_eq(&first, &shared(&app)));
+}
@@ -1,2 +1,6 @@
 mod app;
 mod dispatcher;
+mod element;
+mod memo;
+mod strict;
+mod view;
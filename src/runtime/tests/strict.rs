@@ -0,0 +1,46 @@
+use crate::runtime::{App, ComponentId, Element, component};
+use crate::testing::TestApp;
+
+fn app() -> App {
+    App::new(
+        "Strict",
+        component("Root", |_| {
+            Element::vstack(vec![
+                component("Pure", |ctx| {
+                    let (count, _) = ctx.use_state(|| 3u32);
+                    Element::text(format!("pure {count}"))
+                })
+                .into(),
+                component("Impure", |ctx| {
+                    let renders = ctx.use_ref(|| 0u32);
+                    let count = renders.with_mut(|count| {
+                        *count += 1;
+                        *count
+                    });
+                    Element::text(format!("impure {count}"))
+                })
+                .into(),
+            ])
+        }),
+    )
+    .headless_size(40, 10)
+}
+
+#[tokio::test]
+async fn strict_mode_renders_twice_and_flags_impure_components() {
+    let app = TestApp::new(app().strict_mode(true))
+        .await
+        .expect("start app");
+    app.expect_text("pure 3").expect_text("impure 2");
+    assert_eq!(
+        app.strict_mode_warnings(),
+        vec![ComponentId::new(&[0, 1], "Impure", None)]
+    );
+}
+
+#[tokio::test]
+async fn components_render_once_without_strict_mode() {
+    let app = TestApp::new(app()).await.expect("start app");
+    app.expect_text("impure 1");
+    assert!(app.strict_mode_warnings().is_empty());
+}
@@ -0,0 +1,180 @@
+use crate::runtime::{
+    App, BlockNode, ButtonNode, Element, FlexNode, GaugeNode, ListItemNode, ListNode, ScrollNode,
+    SelectNode, SpinnerNode, StatusBarNode, TabPaneNode, TableCellNode, TableNode, TableRowNode,
+    TabsNode, TextNode, View, component,
+};
+use crate::testing::TestApp;
+
+fn dashboard() -> App {
+    App::new(
+        "Dashboard",
+        component("Dashboard", |_ctx| {
+            Element::vstack(vec![
+                Element::block(
+                    "Services",
+                    Element::table(
+                        TableNode::new(vec![TableRowNode::new(vec![
+                            TableCellNode::new("api"),
+                            TableCellNode::new("Healthy"),
+                        ])])
+                        .id("services"),
+                    ),
+                ),
+                Element::list(ListNode::new(vec![ListItemNode::new("deploy started")])),
+                Element::list(ListNode::new(vec![ListItemNode::new("cache warmed")])),
+                Element::button(ButtonNode::new("counter:plus", "+")),
+            ])
+        }),
+    )
+}
+
+#[tokio::test]
+async fn view_queries_walk_the_rendered_tree() {
+    let app = TestApp::new(dashboard()).await.expect("start app");
+    let view = app.view();
+
+    let button = view.find_by_id("counter:plus").expect("button rendered");
+    assert!(matches!(button, View::Button(button) if button.label == "+"));
+    assert!(matches!(view.find_by_id("services"), Some(View::Table(_))));
+    assert!(view.find_by_id("missing").is_none());
+
+    let healthy = view.find_text("Healthy").expect("table cell rendered");
+    assert_eq!(healthy.id(), Some("services"));
+    assert!(matches!(view.find_text("Services"), Some(View::Block(_))));
+
+    let lists: Vec<_> = view
+        .all_lists()
+        .into_iter()
+        .map(|list| list.items[0].content.as_str())
+        .collect();
+    assert_eq!(lists, ["deploy started", "cache warmed"]);
+    assert_eq!(view.all_tables().len(), 1);
+    assert_eq!(view.all_buttons().len(), 1);
+}
+
+#[tokio::test]
+async fn containers_and_lists_carry_ids_and_classes() {
+    let app = TestApp::new(
+        App::new(
+            "Panels",
+            component("Panels", |_ctx| {
+                Element::flex(
+                    FlexNode::row(vec![
+                        Element::block_node(
+                            BlockNode::new(Element::list(
+                                ListNode::new(vec![ListItemNode::new("inbox")])
+                                    .id("folders")
+                                    .class("nav"),
+                            ))
+                            .id("sidebar")
+                            .class("panel"),
+                        ),
+                        Element::list(ListNode::new(vec![ListItemNode::new("hello")]).class("nav")),
+                    ])
+                    .id("root")
+                    .class("layout"),
+                )
+            }),
+        )
+        .headless_size(40, 6),
+    )
+    .await
+    .expect("start app");
+    let view = app.view();
+
+    assert!(matches!(view.find_by_id("root"), Some(View::Flex(_))));
+    assert!(matches!(view.find_by_id("sidebar"), Some(View::Block(_))));
+    assert!(matches!(view.find_by_id("folders"), Some(View::List(_))));
+    assert_eq!(
+        view.find_by_class("panel").and_then(View::id),
+        Some("sidebar")
+    );
+    assert_eq!(view.all_with_class("nav").len(), 2);
+    assert!(view.find_by_id("folders").unwrap().has_class("nav"));
+
+    let root = app.region("root").expect("root region");
+    let sidebar = app.region("sidebar").expect("sidebar region");
+    let folders = app.region("folders").expect("folders region");
+    assert_eq!((root.width, root.height), (40, 6));
+    assert_eq!(root.intersection(sidebar), sidebar);
+    assert_eq!(sidebar.intersection(folders), folders);
+    assert!(folders.width < sidebar.width);
+    assert!(app.region("missing").is_none());
+}
+
+#[tokio::test]
+async fn leaf_widgets_carry_ids_and_classes() {
+    let app = TestApp::new(
+        App::new(
+            "Leaves",
+            component("Leaves", |_ctx| {
+                Element::vstack(vec![
+                    Element::text_node(TextNode::new("ready").id("status").class("muted")),
+                    Element::gauge(GaugeNode::new(0.5).id("load").class("meter")),
+                    Element::button(ButtonNode::new("save", "Save").class("primary")),
+                ])
+            }),
+        )
+        .headless_size(20, 6),
+    )
+    .await
+    .expect("start app");
+    let view = app.view();
+
+    assert!(matches!(view.find_by_id("status"), Some(View::Text(_))));
+    assert_eq!(view.find_by_class("meter").and_then(View::id), Some("load"));
+    assert_eq!(
+        view.find_by_class("primary").and_then(View::id),
+        Some("save")
+    );
+    assert_eq!(app.region("status").map(|area| area.y), Some(0));
+}
+
+#[tokio::test]
+async fn every_widget_accepts_ids_and_classes() {
+    let app = TestApp::new(
+        App::new(
+            "Widgets",
+            component("Widgets", |_ctx| {
+                Element::vstack(vec![
+                    Element::spinner(SpinnerNode::new().id("busy").class("muted")),
+                    Element::status_bar(
+                        StatusBarNode::new()
+                            .left("ready")
+                            .id("status")
+                            .class("chrome"),
+                    ),
+                    Element::select(SelectNode::new("theme", ["Dark", "Light"]).class("field")),
+                    Element::tabs(
+                        TabsNode::new(vec![TabPaneNode::new("One", Element::text("first"))])
+                            .id("pages")
+                            .class("chrome"),
+                    ),
+                    Element::scroll(
+                        ScrollNode::new("log", vec![Element::text("line")]).class("panel"),
+                    ),
+                ])
+            }),
+        )
+        .headless_size(30, 12),
+    )
+    .await
+    .expect("start app");
+    let view = app.view();
+
+    assert!(matches!(view.find_by_id("busy"), Some(View::Spinner(_))));
+    assert!(matches!(
+        view.find_by_id("status"),
+        Some(View::StatusBar(_))
+    ));
+    assert!(matches!(view.find_by_id("pages"), Some(View::Tabs(_))));
+    assert_eq!(
+        view.find_by_class("field").and_then(View::id),
+        Some("theme")
+    );
+    assert_eq!(view.find_by_class("panel").and_then(View::id), Some("log"));
+    assert_eq!(view.all_with_class("chrome").len(), 2);
+    assert_eq!(app.region("busy").map(|area| area.y), Some(0));
+    assert!(app.region("status").is_some());
+    assert!(app.region("pages").is_some());
+}
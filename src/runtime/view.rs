@@ -1,117 +1,496 @@
-use ratatui::style::Color;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
 
-use super::element::{FlexDirection, FormFieldStatus, ToastLevel};
+use ratatui::layout::Constraint;
+use ratatui::style::{Color, Modifier, Style};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::time_picker::{TimeField, TimeValue};
+
+use super::custom::CustomWidgetView;
+use super::element::{
+    Align, Anchor, BorderStyle, CheckState, Dimensions, FlexDirection, FormFieldStatus, Justify,
+    ListItemFactory, ListItemNode, ProgressStyle, SortDirection, Spacing, StatusSegment, TextAlign,
+    TextOverflow, ToastLevel,
+};
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum View {
     Empty,
     Text(TextView),
+    Paragraph(ParagraphView),
     Flex(FlexView),
+    Sized(SizedView),
+    Constrained(ConstrainedView),
     Block(BlockView),
     List(ListView),
     Gauge(GaugeView),
+    Spinner(SpinnerView),
+    Progress(ProgressView),
     Button(ButtonView),
+    Checkbox(CheckboxView),
+    Select(SelectView),
+    MenuBar(MenuBarView),
+    StatusBar(StatusBarView),
+    KeyHints(KeyHintsView),
+    Stepper(StepperView),
+    TimePicker(TimePickerView),
     Table(TableView),
     Tree(TreeView),
     Form(FormView),
     Input(TextInputView),
+    TextArea(TextAreaView),
     Tabs(TabsView),
     Layered(LayersView),
     Modal(ModalView),
+    Anchored(AnchoredView),
     ToastStack(ToastStackView),
+    Scroll(ScrollView),
+    Split(SplitView),
+    Tooltip(TooltipView),
+    Custom(CustomWidgetView),
+    // Sent as the view it wraps.
+    #[cfg_attr(feature = "serde", serde(untagged))]
+    Shared(Arc<View>),
 }
 
-#[derive(Clone, Debug, PartialEq)]
+impl View {
+    // Wraps the view in an `Arc` below any sizing wrappers, which parent
+    // layouts read directly.
+    pub(crate) fn share(self) -> View {
+        match self {
+            View::Empty | View::Shared(_) => self,
+            View::Sized(sized) => View::Sized(SizedView {
+                content: Box::new(sized.content.share()),
+                ..sized
+            }),
+            View::Constrained(constrained) => View::Constrained(ConstrainedView {
+                content: Box::new(constrained.content.share()),
+                ..constrained
+            }),
+            view => View::Shared(Arc::new(view)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TextView {
     pub content: String,
     pub color: Option<Color>,
+    pub modifiers: Modifier,
+    pub overflow: TextOverflow,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParagraphView {
+    pub content: String,
+    pub align: TextAlign,
+    pub wrap: bool,
+    pub trim: bool,
+    pub scroll: u16,
+    pub color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FlexView {
     pub direction: FlexDirection,
     pub children: Vec<View>,
+    #[cfg_attr(feature = "serde", serde(with = "wire::constraints"))]
+    pub constraints: Vec<Option<Constraint>>,
+    pub gap: u16,
+    pub padding: Spacing,
+    pub margin: Spacing,
+    pub justify_content: Justify,
+    pub align_items: Align,
+    pub background_color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl FlexView {
+    pub(crate) fn new(direction: FlexDirection, views: Vec<View>) -> Self {
+        let mut children = Vec::with_capacity(views.len());
+        let mut constraints = Vec::with_capacity(views.len());
+        for view in views {
+            match view {
+                View::Sized(sized) => {
+                    children.push(*sized.content);
+                    constraints.push(Some(sized.constraint));
+                }
+                view => {
+                    children.push(view);
+                    constraints.push(None);
+                }
+            }
+        }
+        Self {
+            direction,
+            children,
+            constraints,
+            gap: 0,
+            padding: Spacing::default(),
+            margin: Spacing::default(),
+            justify_content: Justify::default(),
+            align_items: Align::default(),
+            background_color: None,
+            id: None,
+            classes: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SizedView {
+    #[cfg_attr(feature = "serde", serde(with = "wire::ConstraintDef"))]
+    pub constraint: Constraint,
+    pub content: Box<View>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConstrainedView {
+    pub dimensions: Dimensions,
+    pub content: Box<View>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockView {
     pub title: Option<String>,
     pub child: Option<Box<View>>,
+    pub padding: Spacing,
+    pub margin: Spacing,
+    pub border_style: BorderStyle,
+    pub background_color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListView {
     pub title: Option<String>,
     pub items: Vec<ListItemView>,
     pub highlight: Option<usize>,
-    pub highlight_color: Option<Color>,
+    pub highlight_style: Style,
+    pub hovered: Option<usize>,
+    pub hover_style: Style,
+    pub offset: usize,
+    pub checked: Option<BTreeSet<usize>>,
+    pub border_style: BorderStyle,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub virtual_rows: Option<VirtualRows>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListItemView {
     pub content: String,
     pub color: Option<Color>,
+    pub modifiers: Modifier,
+}
+
+impl From<ListItemNode> for ListItemView {
+    fn from(item: ListItemNode) -> Self {
+        Self {
+            content: item.content,
+            color: item.color,
+            modifiers: item.modifiers,
+        }
+    }
+}
+
+// Rows of a virtual list, built by the renderer once it knows the list's area.
+#[derive(Clone)]
+pub struct VirtualRows {
+    pub count: usize,
+    pub start: usize,
+    pub factory: ListItemFactory,
+}
+
+const VIRTUAL_OVERSCAN: usize = 4;
+
+impl VirtualRows {
+    pub fn rows(&self, height: u16) -> Vec<ListItemView> {
+        let end = self
+            .start
+            .saturating_add(usize::from(height) + VIRTUAL_OVERSCAN)
+            .min(self.count);
+        (self.start..end)
+            .map(|index| ListItemView::from((self.factory)(index)))
+            .collect()
+    }
+}
+
+impl PartialEq for VirtualRows {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count
+            && self.start == other.start
+            && Arc::ptr_eq(&self.factory, &other.factory)
+    }
+}
+
+impl fmt::Debug for VirtualRows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualRows")
+            .field("count", &self.count)
+            .field("start", &self.start)
+            .finish_non_exhaustive()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GaugeView {
     pub label: Option<String>,
     pub ratio: f64,
     pub color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SpinnerView {
+    pub glyph: &'static str,
+    pub label: Option<String>,
+    pub color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProgressView {
+    pub ratio: Option<f64>,
+    pub phase: usize,
+    pub style: ProgressStyle,
+    pub label: Option<String>,
+    pub color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ButtonView {
     pub id: String,
     pub label: String,
     pub accent: Option<Color>,
     pub filled: bool,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CheckboxView {
+    pub id: String,
+    pub label: String,
+    pub checked: bool,
+    pub focused: bool,
+    pub accent: Option<Color>,
+    pub classes: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SelectView {
+    pub id: String,
+    pub label: Option<String>,
+    pub options: Vec<String>,
+    pub selected: usize,
+    pub placeholder: Option<String>,
+    pub open: bool,
+    pub highlighted: usize,
+    pub focused: bool,
+    pub width: Option<u16>,
+    pub accent: Option<Color>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MenuBarView {
+    pub id: String,
+    pub titles: Vec<String>,
+    pub active: Option<usize>,
+    pub levels: Vec<MenuLevelView>,
+    pub accent: Option<Color>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MenuLevelView {
+    pub items: Vec<MenuEntryView>,
+    pub highlighted: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MenuEntryView {
+    pub label: String,
+    pub shortcut: Option<String>,
+    pub has_children: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StatusBarView {
+    pub left: Vec<StatusSegment>,
+    pub center: Vec<StatusSegment>,
+    pub right: Vec<StatusSegment>,
+    pub separator: String,
+    pub color: Option<Color>,
+    pub background_color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyHintsView {
+    pub hints: Vec<KeyHintView>,
+    pub separator: String,
+    pub key_color: Option<Color>,
+    pub color: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StepperView {
+    pub steps: Vec<String>,
+    pub current: usize,
+    pub furthest: usize,
+    pub completed: bool,
+    pub error: Option<String>,
+    pub accent: Option<Color>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyHintView {
+    pub key: String,
+    pub description: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimePickerView {
+    pub id: String,
+    pub label: Option<String>,
+    pub value: TimeValue,
+    pub field: TimeField,
+    pub show_seconds: bool,
+    pub focused: bool,
+    pub accent: Option<Color>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableView {
     pub title: Option<String>,
     pub header: Option<TableRowView>,
     pub rows: Vec<TableRowView>,
     pub highlight: Option<usize>,
+    pub highlight_style: Style,
+    pub hovered: Option<usize>,
+    pub hover_style: Style,
     pub column_widths: Option<Vec<u16>>,
+    pub offset: usize,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub pager: Option<TablePagerView>,
+    pub sort: Option<(usize, SortDirection)>,
+    pub active_column: Option<usize>,
+    pub editor: Option<TableEditorView>,
+    pub border_style: BorderStyle,
+    pub sources: Vec<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableEditorView {
+    pub row: usize,
+    pub column: usize,
+    pub input: Box<TextInputView>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TablePagerView {
+    pub page: usize,
+    pub page_count: usize,
+    pub page_size: usize,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableRowView {
     pub cells: Vec<TableCellView>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableCellView {
     pub content: String,
     pub color: Option<Color>,
-    pub bold: bool,
+    pub modifiers: Modifier,
+    pub overflow: TextOverflow,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TreeView {
     pub title: Option<String>,
     pub rows: Vec<TreeRowView>,
     pub highlight: Option<usize>,
+    pub highlight_style: Style,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TreeRowView {
     pub label: String,
     pub depth: usize,
     pub has_children: bool,
     pub expanded: bool,
+    pub path: Vec<usize>,
+    pub check: Option<CheckState>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FormView {
     pub title: Option<String>,
     pub fields: Vec<FormFieldView>,
     pub label_width: u16,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FormFieldView {
     pub label: String,
     pub value: String,
@@ -119,6 +498,7 @@ pub struct FormFieldView {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TextInputView {
     pub id: String,
     pub label: Option<String>,
@@ -127,6 +507,7 @@ pub struct TextInputView {
     pub width: Option<u16>,
     pub focused: bool,
     pub cursor: usize,
+    pub selection: Option<Range<usize>>,
     pub secure: bool,
     pub accent: Option<Color>,
     pub border_color: Option<Color>,
@@ -136,43 +517,266 @@ pub struct TextInputView {
     pub focus_background: Option<Color>,
     pub status: FormFieldStatus,
     pub cursor_visible: bool,
+    pub suggestions: Vec<String>,
+    pub highlighted_suggestion: Option<usize>,
+    pub border_style: BorderStyle,
+    pub classes: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TextAreaView {
+    pub id: String,
+    pub label: Option<String>,
+    pub value: String,
+    pub placeholder: Option<String>,
+    pub height: Option<u16>,
+    pub focused: bool,
+    pub cursor: usize,
+    pub selection: Option<Range<usize>>,
+    pub scroll: usize,
+    pub accent: Option<Color>,
+    pub border_color: Option<Color>,
+    pub text_color: Option<Color>,
+    pub placeholder_color: Option<Color>,
+    pub status: FormFieldStatus,
+    pub cursor_visible: bool,
+    pub border_style: BorderStyle,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TabsView {
     pub tabs: Vec<TabView>,
     pub active: usize,
-    pub accent: Option<Color>,
+    pub highlight_style: Style,
     pub title: Option<String>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TabView {
     pub label: String,
     pub content: View,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LayersView {
     pub layers: Vec<View>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModalView {
     pub title: Option<String>,
     pub content: Box<View>,
     pub width: Option<u16>,
     pub height: Option<u16>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnchoredView {
+    pub anchor: Anchor,
+    pub offset: (u16, u16),
+    pub width: Option<u16>,
+    pub height: Option<u16>,
+    pub content: Box<View>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ToastStackView {
     pub toasts: Vec<ToastView>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ToastView {
     pub title: String,
     pub body: Option<String>,
     pub level: ToastLevel,
 }
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScrollView {
+    pub id: String,
+    pub children: Vec<View>,
+    pub offset: u16,
+    pub scrollbar: bool,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SplitView {
+    pub id: String,
+    pub direction: FlexDirection,
+    pub first: Box<View>,
+    pub second: Box<View>,
+    pub ratio: f64,
+    pub min_size: u16,
+    pub focused: bool,
+    pub accent: Option<Color>,
+    pub classes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TooltipView {
+    pub id: String,
+    pub content: Box<View>,
+    pub text: String,
+    pub visible: bool,
+    pub color: Option<Color>,
+    pub background_color: Option<Color>,
+    pub classes: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+mod wire {
+    use ratatui::layout::Constraint;
+    use ratatui::style::Color;
+    use serde::{Deserialize, Deserializer, Serialize};
+
+    use std::sync::Arc;
+
+    use super::{ListItemView, SpinnerView, VirtualRows};
+    use crate::runtime::element::{ListItemNode, SpinnerStyle};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(remote = "Constraint")]
+    pub(super) enum ConstraintDef {
+        Min(u16),
+        Max(u16),
+        Length(u16),
+        Percentage(u16),
+        Ratio(u32, u32),
+        Fill(u16),
+    }
+
+    pub(super) mod constraints {
+        use ratatui::layout::Constraint;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        #[derive(Serialize, Deserialize)]
+        struct Wire(#[serde(with = "super::ConstraintDef")] Constraint);
+
+        pub(crate) fn serialize<S>(
+            constraints: &[Option<Constraint>],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(constraints.iter().map(|constraint| constraint.map(Wire)))
+        }
+
+        pub(crate) fn deserialize<'de, D>(
+            deserializer: D,
+        ) -> Result<Vec<Option<Constraint>>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let constraints = Vec::<Option<Wire>>::deserialize(deserializer)?;
+            Ok(constraints
+                .into_iter()
+                .map(|constraint| constraint.map(|Wire(constraint)| constraint))
+                .collect())
+        }
+    }
+
+    #[derive()]
+    #[cfg_attr(feature = "serde", derive(Deserialize))]
+    struct SpinnerWire {
+        glyph: String,
+        label: Option<String>,
+        color: Option<Color>,
+        id: Option<String>,
+        classes: Vec<String>,
+    }
+
+    impl<'de> Deserialize<'de> for SpinnerView {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let wire = SpinnerWire::deserialize(deserializer)?;
+            let glyph = [
+                SpinnerStyle::Dots,
+                SpinnerStyle::Braille,
+                SpinnerStyle::Line,
+            ]
+            .into_iter()
+            .flat_map(SpinnerStyle::frames)
+            .find(|frame| **frame == wire.glyph)
+            .copied()
+            .unwrap_or(" ");
+            Ok(Self {
+                glyph,
+                label: wire.label,
+                color: wire.color,
+                id: wire.id,
+                classes: wire.classes,
+            })
+        }
+    }
+
+    // Remote clients draw without the factory, so frames carry a screenful of rows.
+    const WIRE_ROWS: u16 = 256;
+
+    #[derive(Serialize, Deserialize)]
+    struct VirtualRowsWire {
+        count: usize,
+        start: usize,
+        items: Vec<ListItemView>,
+    }
+
+    impl Serialize for VirtualRows {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            VirtualRowsWire {
+                count: self.count,
+                start: self.start,
+                items: self.rows(WIRE_ROWS),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for VirtualRows {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let wire = VirtualRowsWire::deserialize(deserializer)?;
+            let start = wire.start;
+            let items = wire.items;
+            Ok(Self {
+                count: wire.count.min(start + items.len()),
+                start,
+                factory: Arc::new(move |index| {
+                    let item = &items[index - start];
+                    ListItemNode {
+                        content: item.content.clone(),
+                        color: item.color,
+                        modifiers: item.modifiers,
+                    }
+                }),
+            })
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use super::view::{ButtonView, ListView, TableView, View};
+
+impl View {
+    pub fn children(&self) -> Vec<&View> {
+        match self {
+            View::Flex(flex) => flex.children.iter().collect(),
+            View::Sized(sized) => vec![&sized.content],
+            View::Constrained(constrained) => vec![&constrained.content],
+            View::Block(block) => block.child.as_deref().into_iter().collect(),
+            View::Tabs(tabs) => tabs.tabs.iter().map(|tab| &tab.content).collect(),
+            View::Layered(layers) => layers.layers.iter().collect(),
+            View::Modal(modal) => vec![&modal.content],
+            View::Anchored(anchored) => vec![&anchored.content],
+            View::Scroll(scroll) => scroll.children.iter().collect(),
+            View::Split(split) => vec![&split.first, &split.second],
+            View::Tooltip(tooltip) => vec![&tooltip.content],
+            View::Shared(shared) => vec![shared],
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn descendants(&self) -> Vec<&View> {
+        let mut views = Vec::new();
+        let mut stack = vec![self];
+        while let Some(view) = stack.pop() {
+            views.push(view);
+            stack.extend(view.children().into_iter().rev());
+        }
+        views
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            View::Text(text) => text.id.as_deref(),
+            View::Paragraph(paragraph) => paragraph.id.as_deref(),
+            View::Gauge(gauge) => gauge.id.as_deref(),
+            View::Progress(progress) => progress.id.as_deref(),
+            View::Flex(flex) => flex.id.as_deref(),
+            View::Block(block) => block.id.as_deref(),
+            View::List(list) => list.id.as_deref(),
+            View::Button(button) => Some(&button.id),
+            View::Checkbox(checkbox) => Some(&checkbox.id),
+            View::Select(select) => Some(&select.id),
+            View::MenuBar(menu_bar) => Some(&menu_bar.id),
+            View::TimePicker(picker) => Some(&picker.id),
+            View::Table(table) => table.id.as_deref(),
+            View::Tree(tree) => tree.id.as_deref(),
+            View::Input(input) => Some(&input.id),
+            View::TextArea(text_area) => Some(&text_area.id),
+            View::Scroll(scroll) => Some(&scroll.id),
+            View::Split(split) => Some(&split.id),
+            View::Tooltip(tooltip) => Some(&tooltip.id),
+            View::Spinner(spinner) => spinner.id.as_deref(),
+            View::StatusBar(status_bar) => status_bar.id.as_deref(),
+            View::KeyHints(hints) => hints.id.as_deref(),
+            View::Stepper(stepper) => stepper.id.as_deref(),
+            View::Form(form) => form.id.as_deref(),
+            View::Tabs(tabs) => tabs.id.as_deref(),
+            View::Modal(modal) => modal.id.as_deref(),
+            View::Anchored(anchored) => anchored.id.as_deref(),
+            View::ToastStack(toasts) => toasts.id.as_deref(),
+            View::Custom(custom) => custom.view.id.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn classes(&self) -> &[String] {
+        match self {
+            View::Text(text) => &text.classes,
+            View::Paragraph(paragraph) => &paragraph.classes,
+            View::Gauge(gauge) => &gauge.classes,
+            View::Progress(progress) => &progress.classes,
+            View::Flex(flex) => &flex.classes,
+            View::Block(block) => &block.classes,
+            View::List(list) => &list.classes,
+            View::Button(button) => &button.classes,
+            View::Checkbox(checkbox) => &checkbox.classes,
+            View::Input(input) => &input.classes,
+            View::TextArea(text_area) => &text_area.classes,
+            View::Table(table) => &table.classes,
+            View::Tree(tree) => &tree.classes,
+            View::Spinner(spinner) => &spinner.classes,
+            View::StatusBar(status_bar) => &status_bar.classes,
+            View::KeyHints(hints) => &hints.classes,
+            View::Stepper(stepper) => &stepper.classes,
+            View::Form(form) => &form.classes,
+            View::Tabs(tabs) => &tabs.classes,
+            View::Modal(modal) => &modal.classes,
+            View::Anchored(anchored) => &anchored.classes,
+            View::ToastStack(toasts) => &toasts.classes,
+            View::Select(select) => &select.classes,
+            View::MenuBar(menu_bar) => &menu_bar.classes,
+            View::TimePicker(picker) => &picker.classes,
+            View::Scroll(scroll) => &scroll.classes,
+            View::Split(split) => &split.classes,
+            View::Tooltip(tooltip) => &tooltip.classes,
+            View::Custom(custom) => &custom.view.classes,
+            _ => &[],
+        }
+    }
+
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes().iter().any(|candidate| candidate == class)
+    }
+
+    pub fn text(&self) -> Vec<&str> {
+        let mut text: Vec<&str> = Vec::new();
+        match self {
+            View::Text(view) => text.push(&view.content),
+            View::Paragraph(view) => text.push(&view.content),
+            View::Block(view) => text.extend(view.title.as_deref()),
+            View::List(view) => {
+                text.extend(view.title.as_deref());
+                text.extend(view.items.iter().map(|item| item.content.as_str()));
+            }
+            View::Gauge(view) => text.extend(view.label.as_deref()),
+            View::Spinner(view) => text.extend(view.label.as_deref()),
+            View::Progress(view) => text.extend(view.label.as_deref()),
+            View::Button(view) => text.push(&view.label),
+            View::Checkbox(view) => text.push(&view.label),
+            View::Select(view) => {
+                text.extend(view.label.as_deref());
+                text.extend(view.options.get(view.selected).map(String::as_str));
+            }
+            View::MenuBar(view) => text.extend(view.titles.iter().map(String::as_str)),
+            View::StatusBar(view) => text.extend(
+                [&view.left, &view.center, &view.right]
+                    .into_iter()
+                    .flatten()
+                    .map(|segment| segment.text.as_str()),
+            ),
+            View::KeyHints(view) => {
+                for hint in &view.hints {
+                    text.push(&hint.key);
+                    text.push(&hint.description);
+                }
+            }
+            View::Stepper(view) => text.extend(view.steps.iter().map(String::as_str)),
+            View::TimePicker(view) => text.extend(view.label.as_deref()),
+            View::Table(view) => {
+                text.extend(view.title.as_deref());
+                for row in view.header.iter().chain(&view.rows) {
+                    text.extend(row.cells.iter().map(|cell| cell.content.as_str()));
+                }
+            }
+            View::Tree(view) => {
+                text.extend(view.title.as_deref());
+                text.extend(view.rows.iter().map(|row| row.label.as_str()));
+            }
+            View::Form(view) => {
+                text.extend(view.title.as_deref());
+                for field in &view.fields {
+                    text.push(&field.label);
+                    text.push(&field.value);
+                }
+            }
+            View::Input(view) => {
+                text.extend(view.label.as_deref());
+                text.push(&view.value);
+            }
+            View::TextArea(view) => {
+                text.extend(view.label.as_deref());
+                text.push(&view.value);
+            }
+            View::Tabs(view) => {
+                text.extend(view.title.as_deref());
+                text.extend(view.tabs.iter().map(|tab| tab.label.as_str()));
+            }
+            View::Modal(view) => text.extend(view.title.as_deref()),
+            View::ToastStack(view) => {
+                for toast in &view.toasts {
+                    text.push(&toast.title);
+                    text.extend(toast.body.as_deref());
+                }
+            }
+            View::Tooltip(view) if view.visible => text.push(&view.text),
+            View::Custom(view) => text.extend(view.view.text.iter().map(String::as_str)),
+            _ => {}
+        }
+        text
+    }
+
+    pub fn find(&self, predicate: impl Fn(&View) -> bool) -> Option<&View> {
+        self.descendants().into_iter().find(|view| predicate(view))
+    }
+
+    pub fn find_by_id(&self, id: &str) -> Option<&View> {
+        self.find(|view| view.id() == Some(id))
+    }
+
+    pub fn find_by_class(&self, class: &str) -> Option<&View> {
+        self.find(|view| view.has_class(class))
+    }
+
+    pub fn all_with_class(&self, class: &str) -> Vec<&View> {
+        self.descendants()
+            .into_iter()
+            .filter(|view| view.has_class(class))
+            .collect()
+    }
+
+    pub fn find_text(&self, needle: &str) -> Option<&View> {
+        self.find(|view| view.text().iter().any(|text| text.contains(needle)))
+    }
+
+    pub fn all_lists(&self) -> Vec<&ListView> {
+        self.descendants()
+            .into_iter()
+            .filter_map(|view| match view {
+                View::List(list) => Some(list),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn all_tables(&self) -> Vec<&TableView> {
+        self.descendants()
+            .into_iter()
+            .filter_map(|view| match view {
+                View::Table(table) => Some(table),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn all_buttons(&self) -> Vec<&ButtonView> {
+        self.descendants()
+            .into_iter()
+            .filter_map(|view| match view {
+                View::Button(button) => Some(button),
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use parking_lot::{Mutex, RwLock};
+
+use crate::events::{
+    FrameworkEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind, mouse_position,
+};
+use crate::interactions::Hitbox;
+use crate::menu::MenuBars;
+use crate::runtime::{Current, Dispatcher, Registries};
+use crate::select::Selects;
+use crate::split::Splits;
+use crate::text_input::TextInputs;
+use crate::time_picker::TimePickers;
+
+const WHEEL_STEP: u16 = 3;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScrollMetrics {
+    pub offset: u16,
+    pub content_height: u16,
+    pub viewport_height: u16,
+}
+
+impl ScrollMetrics {
+    pub fn max_offset(&self) -> u16 {
+        self.content_height.saturating_sub(self.viewport_height)
+    }
+
+    fn scroll_by(&mut self, delta: i32) -> bool {
+        let next = (self.offset as i32 + delta).clamp(0, self.max_offset() as i32) as u16;
+        let changed = next != self.offset;
+        self.offset = next;
+        changed
+    }
+}
+
+pub(crate) struct ScrollRegistry {
+    areas: RwLock<HashMap<String, ScrollMetrics>>,
+    hitboxes: RwLock<HashMap<String, Hitbox>>,
+    active: Mutex<Option<String>>,
+}
+
+impl ScrollRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            areas: RwLock::new(HashMap::new()),
+            hitboxes: RwLock::new(HashMap::new()),
+            active: Mutex::new(None),
+        }
+    }
+
+    fn global() -> Current<Self> {
+        Registries::current(|registries| &registries.scrolls)
+    }
+
+    fn hit(&self, column: u16, row: u16) -> Option<String> {
+        // Nested scroll areas register after their parents, so prefer the smallest match.
+        self.hitboxes
+            .read()
+            .iter()
+            .filter(|(_, hitbox)| hitbox.contains(column, row))
+            .min_by_key(|(_, hitbox)| hitbox.width as u32 * hitbox.height as u32)
+            .map(|(id, _)| id.clone())
+    }
+
+    fn scroll_by(&self, id: &str, delta: i32, dispatcher: &Dispatcher) {
+        let mut areas = self.areas.write();
+        let metrics = areas.entry(id.to_string()).or_default();
+        if metrics.scroll_by(delta) {
+            dispatcher.request_full_render();
+        }
+    }
+}
+
+pub struct Scrolls;
+
+impl Scrolls {
+    pub fn offset(id: &str) -> u16 {
+        let registry = ScrollRegistry::global();
+        registry
+            .areas
+            .read()
+            .get(id)
+            .map(|metrics| metrics.offset)
+            .unwrap_or(0)
+    }
+
+    pub fn metrics(id: &str) -> Option<ScrollMetrics> {
+        let registry = ScrollRegistry::global();
+        registry.areas.read().get(id).copied()
+    }
+
+    pub fn scroll_to(id: &str, offset: u16, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        let registry = ScrollRegistry::global();
+        let mut areas = registry.areas.write();
+        let metrics = areas.entry(id.to_string()).or_default();
+        if metrics.offset != offset {
+            metrics.offset = offset;
+            dispatcher.request_full_render();
+        }
+    }
+
+    pub fn scroll_by(id: &str, delta: i32, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        ScrollRegistry::global().scroll_by(id, delta, dispatcher);
+    }
+
+    pub(crate) fn record(id: &str, hitbox: Hitbox, metrics: ScrollMetrics) {
+        let registry = ScrollRegistry::global();
+        registry.hitboxes.write().insert(id.to_string(), hitbox);
+        registry.areas.write().insert(id.to_string(), metrics);
+    }
+
+    pub(crate) fn reset_hitboxes() {
+        let registry = ScrollRegistry::global();
+        registry.hitboxes.write().clear();
+    }
+
+    pub fn handle_event(event: &FrameworkEvent, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        let registry = ScrollRegistry::global();
+        match event {
+            FrameworkEvent::Mouse(mouse) => {
+                let Some((column, row)) = mouse_position(event) else {
+                    return;
+                };
+                match mouse.kind {
+                    MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                        if let Some(id) = registry.hit(column, row) {
+                            let delta = if matches!(mouse.kind, MouseEventKind::ScrollUp) {
+                                -(WHEEL_STEP as i32)
+                            } else {
+                                WHEEL_STEP as i32
+                            };
+                            registry.scroll_by(&id, delta, dispatcher);
+                        }
+                    }
+                    MouseEventKind::Down(MouseButton::Left) => {
+                        *registry.active.lock() = registry.hit(column, row);
+                    }
+                    _ => {}
+                }
+            }
+            FrameworkEvent::Key(key) => Self::handle_key(key, dispatcher),
+            _ => {}
+        }
+    }
+
+    fn handle_key(key: &KeyEvent, dispatcher: &Dispatcher) {
+        if TextInputs::has_focus()
+            || Selects::has_focus()
+            || TimePickers::has_focus()
+            || Splits::has_focus()
+            || MenuBars::any_open()
+            || key.modifiers.intersects(KeyModifiers::CONTROL)
+        {
+            return;
+        }
+        let registry = ScrollRegistry::global();
+        let Some(id) = registry.active.lock().clone() else {
+            return;
+        };
+        let Some(metrics) = Self::metrics(&id) else {
+            return;
+        };
+        let page = metrics.viewport_height.max(1) as i32;
+        let delta = match key.code {
+            KeyCode::Up => -1,
+            KeyCode::Down => 1,
+            KeyCode::PageUp => -page,
+            KeyCode::PageDown => page,
+            KeyCode::Home => -(metrics.offset as i32),
+            KeyCode::End => metrics.max_offset() as i32,
+            _ => return,
+        };
+        registry.scroll_by(&id, delta, dispatcher);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,80 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use tokio::sync::mpsc;
+
+use super::*;
+use crate::events::EventBus;
+
+fn test_dispatcher() -> Dispatcher {
+    let (tx, _rx) = mpsc::channel(8);
+    Dispatcher::new(tx, EventBus::new(8))
+}
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> FrameworkEvent {
+    FrameworkEvent::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn record_area(id: &str, y: u16) {
+    Scrolls::record(
+        id,
+        Hitbox::new(0, y, 20, 5),
+        ScrollMetrics {
+            offset: 0,
+            content_height: 12,
+            viewport_height: 5,
+        },
+    );
+}
+
+#[test]
+fn metrics_clamp_offset_to_content() {
+    let mut metrics = ScrollMetrics {
+        offset: 0,
+        content_height: 10,
+        viewport_height: 4,
+    };
+    assert!(!metrics.scroll_by(-3));
+    assert!(metrics.scroll_by(100));
+    assert_eq!(metrics.offset, 6);
+    assert_eq!(metrics.max_offset(), 6);
+}
+
+#[test]
+fn mouse_wheel_scrolls_area_under_pointer() {
+    let dispatcher = test_dispatcher();
+    let _app = dispatcher.enter();
+    record_area("scroll.wheel", 40);
+
+    Scrolls::handle_event(&mouse(MouseEventKind::ScrollDown, 3, 42), &dispatcher);
+    assert_eq!(Scrolls::offset("scroll.wheel"), WHEEL_STEP);
+
+    Scrolls::handle_event(&mouse(MouseEventKind::ScrollDown, 3, 42), &dispatcher);
+    Scrolls::handle_event(&mouse(MouseEventKind::ScrollDown, 3, 42), &dispatcher);
+    assert_eq!(Scrolls::offset("scroll.wheel"), 7);
+
+    Scrolls::handle_event(&mouse(MouseEventKind::ScrollUp, 3, 10), &dispatcher);
+    assert_eq!(Scrolls::offset("scroll.wheel"), 7, "pointer outside area");
+}
+
+#[test]
+fn keys_scroll_the_clicked_area() {
+    let dispatcher = test_dispatcher();
+    let _app = dispatcher.enter();
+    record_area("scroll.keys", 60);
+
+    let key = |code| FrameworkEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    Scrolls::handle_event(
+        &mouse(MouseEventKind::Down(MouseButton::Left), 1, 61),
+        &dispatcher,
+    );
+    Scrolls::handle_event(&key(KeyCode::Down), &dispatcher);
+    assert_eq!(Scrolls::offset("scroll.keys"), 1);
+    Scrolls::handle_event(&key(KeyCode::End), &dispatcher);
+    assert_eq!(Scrolls::offset("scroll.keys"), 7);
+    Scrolls::handle_event(&key(KeyCode::Home), &dispatcher);
+    assert_eq!(Scrolls::offset("scroll.keys"), 0);
+}
@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use parking_lot::{Mutex, RwLock};
+
+use crate::events::{
+    FrameworkEvent, KeyCode, KeyEvent, MouseButton, MouseEventKind, mouse_position,
+};
+use crate::interactions::Hitbox;
+use crate::runtime::{Current, Dispatcher, Registries};
+use crate::text_input::TextInputs;
+
+type ChangeFn = dyn Fn(usize, &str) + Send + Sync;
+
+#[derive(Clone)]
+pub struct SelectChangeHandler {
+    callback: Arc<ChangeFn>,
+}
+
+impl SelectChangeHandler {
+    pub fn new<F>(callback: F) -> Self
+    where
+        F: Fn(usize, &str) + Send + Sync + 'static,
+    {
+        Self {
+            callback: Arc::new(callback),
+        }
+    }
+
+    fn call(&self, index: usize, value: &str) {
+        (self.callback)(index, value)
+    }
+}
+
+impl fmt::Debug for SelectChangeHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SelectChangeHandler")
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SelectState {
+    pub(crate) options: Vec<String>,
+    pub(crate) selected: usize,
+    pub(crate) open: bool,
+    pub(crate) highlighted: usize,
+    on_change: Option<SelectChangeHandler>,
+}
+
+impl SelectState {
+    pub(crate) fn move_highlight(&mut self, delta: isize) {
+        let len = self.options.len();
+        if len == 0 {
+            return;
+        }
+        self.highlighted = (self.highlighted as isize + delta).clamp(0, len as isize - 1) as usize;
+    }
+
+    pub(crate) fn open(&mut self) {
+        self.open = !self.options.is_empty();
+        self.highlighted = self.selected;
+    }
+
+    fn choose(&mut self, index: usize) -> Option<(SelectChangeHandler, usize, String)> {
+        self.open = false;
+        let value = self.options.get(index)?.clone();
+        let changed = index != self.selected;
+        self.selected = index;
+        self.highlighted = index;
+        let handler = self.on_change.clone().filter(|_| changed)?;
+        Some((handler, index, value))
+    }
+}
+
+pub(crate) struct SelectRegistry {
+    states: RwLock<HashMap<String, SelectState>>,
+    hitboxes: RwLock<HashMap<String, Hitbox>>,
+    option_hitboxes: RwLock<Vec<(String, usize, Hitbox)>>,
+    focused: Mutex<Option<String>>,
+}
+
+impl SelectRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            states: RwLock::new(HashMap::new()),
+            hitboxes: RwLock::new(HashMap::new()),
+            option_hitboxes: RwLock::new(Vec::new()),
+            focused: Mutex::new(None),
+        }
+    }
+
+    fn global() -> Current<Self> {
+        Registries::current(|registries| &registries.selects)
+    }
+
+    fn update<R>(&self, id: &str, update: impl FnOnce(&mut SelectState) -> R) -> R {
+        let mut states = self.states.write();
+        update(states.entry(id.to_string()).or_default())
+    }
+
+    fn choose(&self, id: &str, index: usize, dispatcher: &Dispatcher) {
+        let change = self.update(id, |state| state.choose(index));
+        if let Some((handler, index, value)) = change {
+            handler.call(index, &value);
+        }
+        dispatcher.request_full_render();
+    }
+
+    fn close_all(&self) -> bool {
+        let mut closed = false;
+        for state in self.states.write().values_mut() {
+            closed |= std::mem::take(&mut state.open);
+        }
+        closed
+    }
+}
+
+pub struct Selects;
+
+impl Selects {
+    pub fn selected(id: &str) -> Option<usize> {
+        let registry = SelectRegistry::global();
+        registry.states.read().get(id).map(|state| state.selected)
+    }
+
+    pub fn is_open(id: &str) -> bool {
+        let registry = SelectRegistry::global();
+        registry
+            .states
+            .read()
+            .get(id)
+            .is_some_and(|state| state.open)
+    }
+
+    pub fn has_focus() -> bool {
+        SelectRegistry::global().focused.lock().is_some()
+    }
+
+    pub fn is_focused(id: &str) -> bool {
+        SelectRegistry::global().focused.lock().as_deref() == Some(id)
+    }
+
+    pub fn focus(id: Option<&str>, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        let registry = SelectRegistry::global();
+        let mut focused = registry.focused.lock();
+        let next = id.map(str::to_string);
+        if *focused != next {
+            *focused = next;
+            dispatcher.request_full_render();
+        }
+    }
+
+    pub(crate) fn sync(
+        id: &str,
+        options: Vec<String>,
+        selected: Option<usize>,
+        on_change: Option<SelectChangeHandler>,
+    ) -> SelectState {
+        SelectRegistry::global().update(id, |state| {
+            if let Some(selected) = selected {
+                state.selected = selected;
+            }
+            state.selected = state.selected.min(options.len().saturating_sub(1));
+            state.highlighted = state.highlighted.min(options.len().saturating_sub(1));
+            state.open &= !options.is_empty();
+            state.options = options;
+            state.on_change = on_change;
+            state.clone()
+        })
+    }
+
+    pub(crate) fn record(id: &str, hitbox: Hitbox) {
+        let registry = SelectRegistry::global();
+        registry.hitboxes.write().insert(id.to_string(), hitbox);
+    }
+
+    pub(crate) fn record_option(id: &str, index: usize, hitbox: Hitbox) {
+        let registry = SelectRegistry::global();
+        registry
+            .option_hitboxes
+            .write()
+            .push((id.to_string(), index, hitbox));
+    }
+
+    pub(crate) fn reset_hitboxes() {
+        let registry = SelectRegistry::global();
+        registry.hitboxes.write().clear();
+        registry.option_hitboxes.write().clear();
+    }
+
+    pub fn handle_event(event: &FrameworkEvent, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        match event {
+            FrameworkEvent::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+            {
+                if let Some((column, row)) = mouse_position(event) {
+                    Self::handle_click(column, row, dispatcher);
+                }
+            }
+            FrameworkEvent::Key(key) => Self::handle_key(key, dispatcher),
+            _ => {}
+        }
+    }
+
+    fn handle_click(column: u16, row: u16, dispatcher: &Dispatcher) {
+        let registry = SelectRegistry::global();
+        let option = registry
+            .option_hitboxes
+            .read()
+            .iter()
+            .find(|(_, _, hitbox)| hitbox.contains(column, row))
+            .map(|(id, index, _)| (id.clone(), *index));
+        if let Some((id, index)) = option {
+            registry.choose(&id, index, dispatcher);
+            return;
+        }
+        let control = registry
+            .hitboxes
+            .read()
+            .iter()
+            .find(|(_, hitbox)| hitbox.contains(column, row))
+            .map(|(id, _)| id.clone());
+        let closed = registry.close_all();
+        match control {
+            Some(id) => {
+                if !closed || !Self::is_focused(&id) {
+                    registry.update(&id, SelectState::open);
+                }
+                Self::focus(Some(&id), dispatcher);
+                dispatcher.request_full_render();
+            }
+            None => {
+                Self::focus(None, dispatcher);
+                if closed {
+                    dispatcher.request_full_render();
+                }
+            }
+        }
+    }
+
+    fn handle_key(key: &KeyEvent, dispatcher: &Dispatcher) {
+        if TextInputs::has_focus() {
+            return;
+        }
+        let registry = SelectRegistry::global();
+        let Some(id) = registry.focused.lock().clone() else {
+            return;
+        };
+        let open = Self::is_open(&id);
+        match key.code {
+            KeyCode::Enter | KeyCode::Char(' ') if open => {
+                let index = registry.update(&id, |state| state.highlighted);
+                registry.choose(&id, index, dispatcher);
+                return;
+            }
+            KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Down if !open => {
+                registry.update(&id, SelectState::open);
+            }
+            KeyCode::Up if open => registry.update(&id, |state| state.move_highlight(-1)),
+            KeyCode::Down if open => registry.update(&id, |state| state.move_highlight(1)),
+            KeyCode::Home if open => registry.update(&id, |state| state.highlighted = 0),
+            KeyCode::End if open => registry.update(&id, |state| {
+                state.highlighted = state.options.len().saturating_sub(1)
+            }),
+            KeyCode::Esc if open => registry.update(&id, |state| state.open = false),
+            KeyCode::Esc => {
+                Self::focus(None, dispatcher);
+                return;
+            }
+            _ => return,
+        }
+        dispatcher.request_full_render();
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,75 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use tokio::sync::mpsc;
+
+use super::*;
+use crate::events::EventBus;
+use crate::runtime::Registries;
+
+fn test_dispatcher() -> Dispatcher {
+    let (tx, _rx) = mpsc::channel(8);
+    Dispatcher::new(tx, EventBus::new(8))
+}
+
+fn options(items: &[&str]) -> Vec<String> {
+    items.iter().map(|item| item.to_string()).collect()
+}
+
+#[test]
+fn highlight_is_clamped_to_the_option_list() {
+    let mut state = SelectState {
+        options: options(&["low", "medium", "high"]),
+        selected: 1,
+        ..SelectState::default()
+    };
+    state.open();
+    assert!(state.open);
+    assert_eq!(state.highlighted, 1);
+    state.move_highlight(5);
+    assert_eq!(state.highlighted, 2);
+    state.move_highlight(-9);
+    assert_eq!(state.highlighted, 0);
+}
+
+#[test]
+fn sync_keeps_uncontrolled_selection_and_clamps_it() {
+    let _app = Registries::isolated();
+    let state = Selects::sync("select-sync", options(&["a", "b", "c"]), Some(2), None);
+    assert_eq!(state.selected, 2);
+    let state = Selects::sync("select-sync", options(&["a", "b", "c"]), None, None);
+    assert_eq!(state.selected, 2);
+    let state = Selects::sync("select-sync", options(&["a"]), None, None);
+    assert_eq!(state.selected, 0);
+    assert_eq!(Selects::selected("select-sync"), Some(0));
+}
+
+#[test]
+fn clicking_an_option_selects_it_and_calls_on_change() {
+    let dispatcher = test_dispatcher();
+    let _app = dispatcher.enter();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let seen = calls.clone();
+    let handler = SelectChangeHandler::new(move |index, value| {
+        assert_eq!((index, value), (1, "staging"));
+        seen.fetch_add(1, Ordering::SeqCst);
+    });
+    Selects::sync(
+        "select-env",
+        options(&["dev", "staging", "prod"]),
+        None,
+        Some(handler),
+    );
+    SelectRegistry::global().update("select-env", SelectState::open);
+    Selects::record_option("select-env", 1, Hitbox::new(40, 30, 10, 1));
+    let click = FrameworkEvent::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 42,
+        row: 30,
+        modifiers: KeyModifiers::NONE,
+    });
+    Selects::handle_event(&click, &dispatcher);
+    assert_eq!(Selects::selected("select-env"), Some(1));
+    assert!(!Selects::is_open("select-env"));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
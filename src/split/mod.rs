@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use parking_lot::{Mutex, RwLock};
+
+use crate::events::{
+    FrameworkEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind, mouse_position,
+};
+use crate::interactions::Hitbox;
+use crate::runtime::{Current, Dispatcher, FlexDirection, Registries};
+use crate::text_input::TextInputs;
+
+const KEY_STEP: f64 = 0.05;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SplitState {
+    pub(crate) ratio: f64,
+    initial: f64,
+}
+
+impl SplitState {
+    fn new(ratio: f64) -> Self {
+        let ratio = clamp_ratio(ratio);
+        Self {
+            ratio,
+            initial: ratio,
+        }
+    }
+}
+
+pub(crate) fn clamp_ratio(ratio: f64) -> f64 {
+    if ratio.is_finite() {
+        ratio.clamp(0.0, 1.0)
+    } else {
+        0.5
+    }
+}
+
+pub(crate) fn pane_sizes(total: u16, ratio: f64, min_size: u16) -> (u16, u16) {
+    let available = total.saturating_sub(1);
+    let min_size = min_size.min(available / 2);
+    let first = (f64::from(available) * clamp_ratio(ratio)).round() as u16;
+    let first = first.clamp(min_size, available - min_size);
+    (first, available - first)
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Divider {
+    direction: FlexDirection,
+    area: Hitbox,
+    divider: Hitbox,
+}
+
+pub(crate) struct SplitRegistry {
+    states: RwLock<HashMap<String, SplitState>>,
+    dividers: RwLock<Vec<(String, Divider)>>,
+    dragging: Mutex<Option<String>>,
+    focused: Mutex<Option<String>>,
+}
+
+impl SplitRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            states: RwLock::new(HashMap::new()),
+            dividers: RwLock::new(Vec::new()),
+            dragging: Mutex::new(None),
+            focused: Mutex::new(None),
+        }
+    }
+
+    fn global() -> Current<Self> {
+        Registries::current(|registries| &registries.splits)
+    }
+
+    fn divider(&self, id: &str) -> Option<Divider> {
+        self.dividers
+            .read()
+            .iter()
+            .find(|(candidate, _)| candidate == id)
+            .map(|(_, divider)| *divider)
+    }
+
+    fn set(&self, id: &str, update: impl FnOnce(&mut SplitState), dispatcher: &Dispatcher) {
+        let changed = {
+            let mut states = self.states.write();
+            let state = states
+                .entry(id.to_string())
+                .or_insert_with(|| SplitState::new(0.5));
+            let before = state.ratio;
+            update(state);
+            state.ratio = clamp_ratio(state.ratio);
+            state.ratio != before
+        };
+        if changed {
+            dispatcher.request_full_render();
+        }
+    }
+}
+
+fn ratio_at(divider: &Divider, column: u16, row: u16) -> f64 {
+    let (start, length, position) = match divider.direction {
+        FlexDirection::Row => (divider.area.x, divider.area.width, column),
+        FlexDirection::Column => (divider.area.y, divider.area.height, row),
+    };
+    let available = length.saturating_sub(1).max(1);
+    f64::from(position.saturating_sub(start)) / f64::from(available)
+}
+
+pub struct Splits;
+
+impl Splits {
+    pub fn ratio(id: &str) -> Option<f64> {
+        let registry = SplitRegistry::global();
+        registry.states.read().get(id).map(|state| state.ratio)
+    }
+
+    pub fn set_ratio(id: &str, ratio: f64, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        SplitRegistry::global().set(id, |state| state.ratio = ratio, dispatcher);
+    }
+
+    pub fn has_focus() -> bool {
+        SplitRegistry::global().focused.lock().is_some()
+    }
+
+    pub fn is_focused(id: &str) -> bool {
+        SplitRegistry::global().focused.lock().as_deref() == Some(id)
+    }
+
+    pub fn focus(id: Option<&str>, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        let registry = SplitRegistry::global();
+        let mut focused = registry.focused.lock();
+        let next = id.map(str::to_string);
+        if *focused != next {
+            *focused = next;
+            dispatcher.request_full_render();
+        }
+    }
+
+    pub(crate) fn sync(id: &str, ratio: f64) -> f64 {
+        let registry = SplitRegistry::global();
+        let mut states = registry.states.write();
+        states
+            .entry(id.to_string())
+            .or_insert_with(|| SplitState::new(ratio))
+            .ratio
+    }
+
+    pub(crate) fn record(id: &str, direction: FlexDirection, area: Hitbox, divider: Hitbox) {
+        let registry = SplitRegistry::global();
+        registry.dividers.write().push((
+            id.to_string(),
+            Divider {
+                direction,
+                area,
+                divider,
+            },
+        ));
+    }
+
+    pub(crate) fn reset_hitboxes() {
+        SplitRegistry::global().dividers.write().clear();
+    }
+
+    pub fn handle_event(event: &FrameworkEvent, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        match event {
+            FrameworkEvent::Mouse(mouse) => {
+                let Some((column, row)) = mouse_position(event) else {
+                    return;
+                };
+                Self::handle_mouse(mouse.kind, column, row, dispatcher);
+            }
+            FrameworkEvent::Key(key) => Self::handle_key(key, dispatcher),
+            _ => {}
+        }
+    }
+
+    fn handle_mouse(kind: MouseEventKind, column: u16, row: u16, dispatcher: &Dispatcher) {
+        let registry = SplitRegistry::global();
+        match kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let hit = registry
+                    .dividers
+                    .read()
+                    .iter()
+                    .rev()
+                    .find(|(_, divider)| divider.divider.contains(column, row))
+                    .map(|(id, _)| id.clone());
+                Self::focus(hit.as_deref(), dispatcher);
+                *registry.dragging.lock() = hit;
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some(id) = registry.dragging.lock().clone() else {
+                    return;
+                };
+                if let Some(divider) = registry.divider(&id) {
+                    let ratio = ratio_at(&divider, column, row);
+                    registry.set(&id, |state| state.ratio = ratio, dispatcher);
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                registry.dragging.lock().take();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_key(key: &KeyEvent, dispatcher: &Dispatcher) {
+        let registry = SplitRegistry::global();
+        let Some(id) = registry.focused.lock().clone() else {
+            return;
+        };
+        if matches!(key.code, KeyCode::Esc | KeyCode::Tab | KeyCode::BackTab) {
+            Self::focus(None, dispatcher);
+            return;
+        }
+        if TextInputs::has_focus()
+            || key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return;
+        }
+        let direction = registry
+            .divider(&id)
+            .map(|divider| divider.direction)
+            .unwrap_or(FlexDirection::Row);
+        let delta = match (direction, key.code) {
+            (FlexDirection::Row, KeyCode::Left) | (FlexDirection::Column, KeyCode::Up) => -KEY_STEP,
+            (FlexDirection::Row, KeyCode::Right) | (FlexDirection::Column, KeyCode::Down) => {
+                KEY_STEP
+            }
+            (_, KeyCode::Home) => {
+                registry.set(&id, |state| state.ratio = state.initial, dispatcher);
+                return;
+            }
+            _ => return,
+        };
+        registry.set(&id, |state| state.ratio += delta, dispatcher);
+    }
+}
+
+#[cfg(test)]
+mod tests;
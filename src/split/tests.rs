@@ -0,0 +1,48 @@
+use tokio::sync::mpsc;
+
+use super::*;
+use crate::events::EventBus;
+
+fn test_dispatcher() -> Dispatcher {
+    let (tx, _rx) = mpsc::channel(8);
+    Dispatcher::new(tx, EventBus::new(8))
+}
+
+#[test]
+fn pane_sizes_reserve_the_divider_and_respect_minimums() {
+    assert_eq!(pane_sizes(41, 0.5, 3), (20, 20));
+    assert_eq!(pane_sizes(41, 0.25, 3), (10, 30));
+    assert_eq!(pane_sizes(41, 0.0, 5), (5, 35));
+    assert_eq!(pane_sizes(41, 1.0, 5), (35, 5));
+    assert_eq!(pane_sizes(5, 0.9, 10), (2, 2));
+    assert_eq!(pane_sizes(0, 0.5, 3), (0, 0));
+}
+
+#[test]
+fn dragging_maps_the_pointer_onto_the_container() {
+    let divider = Divider {
+        direction: FlexDirection::Row,
+        area: Hitbox::new(10, 0, 41, 10),
+        divider: Hitbox::new(30, 0, 1, 10),
+    };
+    assert_eq!(ratio_at(&divider, 20, 4), 0.25);
+    assert_eq!(ratio_at(&divider, 2, 4), 0.0);
+    let vertical = Divider {
+        direction: FlexDirection::Column,
+        ..divider
+    };
+    assert_eq!(ratio_at(&vertical, 0, 3), 3.0 / 9.0);
+}
+
+#[test]
+fn sync_keeps_the_user_ratio_and_set_ratio_clamps() {
+    let dispatcher = test_dispatcher();
+    let _app = dispatcher.enter();
+    assert_eq!(Splits::sync("split-sync", 0.3), 0.3);
+    Splits::set_ratio("split-sync", 0.8, &dispatcher);
+    assert_eq!(Splits::sync("split-sync", 0.3), 0.8);
+    Splits::set_ratio("split-sync", 4.0, &dispatcher);
+    assert_eq!(Splits::ratio("split-sync"), Some(1.0));
+    Splits::set_ratio("split-sync", f64::NAN, &dispatcher);
+    assert_eq!(Splits::ratio("split-sync"), Some(0.5));
+}
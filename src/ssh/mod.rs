@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use russh::keys::{Algorithm, PrivateKey, PublicKey};
+use russh::server::{self, Auth, ChannelOpenHandle, Handle, Msg, Session};
+use russh::{Channel, ChannelId, Pty};
+use tokio::net::TcpListener;
+use tokio::sync::{Mutex, mpsc};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::events::FrameworkEvent;
+use crate::runtime::{App, AppMessage, RuntimeDriver, spawn_tick_loop};
+use crate::web::{WebTerminal, decode_input};
+
+// Sessions draw on the alternate screen so the client's shell comes back
+// untouched when the app exits.
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h";
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?1049l";
+
+type AppFactory = dyn Fn(&SshUser) -> App + Send + Sync;
+type Authorize = dyn Fn(&str, &PublicKey) -> bool + Send + Sync;
+
+#[derive(Clone, Debug)]
+pub struct SshUser {
+    pub name: String,
+    pub peer: SocketAddr,
+}
+
+pub struct SshServer {
+    config: server::Config,
+    apps: Arc<AppFactory>,
+    authorize: Arc<Authorize>,
+}
+
+impl SshServer {
+    // Starts with a fresh Ed25519 host key and accepts any public key.
+    pub fn new<F>(apps: F) -> anyhow::Result<Self>
+    where
+        F: Fn(&SshUser) -> App + Send + Sync + 'static,
+    {
+        let key = PrivateKey::random(&mut rand::rng(), Algorithm::Ed25519)
+            .context("generate ssh host key")?;
+        Ok(Self {
+            config: server::Config {
+                keys: vec![key],
+                auth_rejection_time: Duration::from_millis(250),
+                auth_rejection_time_initial: Some(Duration::ZERO),
+                nodelay: true,
+                ..Default::default()
+            },
+            apps: Arc::new(apps),
+            authorize: Arc::new(|_, _| true),
+        })
+    }
+
+    pub fn with_host_key(mut self, key: PrivateKey) -> Self {
+        self.config.keys = vec![key];
+        self
+    }
+
+    pub fn authorize<F>(mut self, check: F) -> Self
+    where
+        F: Fn(&str, &PublicKey) -> bool + Send + Sync + 'static,
+    {
+        self.authorize = Arc::new(check);
+        self
+    }
+
+    pub async fn serve(self, listener: TcpListener) -> anyhow::Result<()> {
+        let config = Arc::new(self.config);
+        loop {
+            let (stream, peer) = listener.accept().await.context("accept ssh client")?;
+            debug!(%peer, "ssh client connected");
+            let connection = Connection {
+                apps: self.apps.clone(),
+                authorize: self.authorize.clone(),
+                peer,
+                user: String::new(),
+                shells: HashMap::new(),
+            };
+            let config = config.clone();
+            tokio::spawn(async move {
+                let result = match server::run_stream(config, stream, connection).await {
+                    Ok(session) => session.await,
+                    Err(err) => Err(err),
+                };
+                if let Err(err) = result {
+                    debug!(%peer, error = ?err, "ssh connection ended with an error");
+                }
+            });
+        }
+    }
+}
+
+struct Connection {
+    apps: Arc<AppFactory>,
+    authorize: Arc<Authorize>,
+    peer: SocketAddr,
+    user: String,
+    shells: HashMap<ChannelId, Shell>,
+}
+
+// A session channel. `input` is set once the client asks for a shell and
+// the app is running; dropping it shuts that app down.
+struct Shell {
+    size: (u16, u16),
+    input: Option<mpsc::UnboundedSender<FrameworkEvent>>,
+}
+
+impl server::Handler for Connection {
+    type Error = anyhow::Error;
+
+    async fn auth_publickey(&mut self, user: &str, key: &PublicKey) -> anyhow::Result<Auth> {
+        if !(self.authorize)(user, key) {
+            return Ok(Auth::reject());
+        }
+        self.user = user.to_string();
+        Ok(Auth::Accept)
+    }
+
+    async fn channel_open_session(
+        &mut self,
+        channel: Channel<Msg>,
+        reply: ChannelOpenHandle,
+        _session: &mut Session,
+    ) -> anyhow::Result<()> {
+        self.shells.insert(
+            channel.id(),
+            Shell {
+                size: (80, 24),
+                input: None,
+            },
+        );
+        reply.accept().await;
+        Ok(())
+    }
+
+    async fn pty_request(
+        &mut self,
+        channel: ChannelId,
+        _term: &str,
+        cols: u32,
+        rows: u32,
+        _pix_width: u32,
+        _pix_height: u32,
+        _modes: &[(Pty, u32)],
+        session: &mut Session,
+    ) -> anyhow::Result<()> {
+        if let Some(shell) = self.shells.get_mut(&channel) {
+            shell.size = (cells(cols), cells(rows));
+        }
+        session.channel_success(channel)?;
+        Ok(())
+    }
+
+    async fn window_change_request(
+        &mut self,
+        channel: ChannelId,
+        cols: u32,
+        rows: u32,
+        _pix_width: u32,
+        _pix_height: u32,
+        _session: &mut Session,
+    ) -> anyhow::Result<()> {
+        if let Some(shell) = self.shells.get_mut(&channel) {
+            shell.size = (cells(cols), cells(rows));
+            if let Some(input) = &shell.input {
+                let _ = input.send(FrameworkEvent::Resize(shell.size.0, shell.size.1));
+            }
+        }
+        Ok(())
+    }
+
+    async fn shell_request(
+        &mut self,
+        channel: ChannelId,
+        session: &mut Session,
+    ) -> anyhow::Result<()> {
+        let Some(shell) = self.shells.get_mut(&channel) else {
+            session.channel_failure(channel)?;
+            return Ok(());
+        };
+        if shell.input.is_some() {
+            session.channel_failure(channel)?;
+            return Ok(());
+        }
+        let user = SshUser {
+            name: self.user.clone(),
+            peer: self.peer,
+        };
+        let (output, pending) = mpsc::unbounded_channel();
+        let _ = output.send(ENTER_ALTERNATE_SCREEN.to_string());
+        let frames = output.clone();
+        let (cols, rows) = shell.size;
+        let terminal = WebTerminal::new(cols, rows, move |chunk| {
+            let _ = frames.send(chunk.to_string());
+        })?;
+        let (tx, events) = mpsc::unbounded_channel();
+        let app = (self.apps)(&user)
+            .with_driver(SshDriver {
+                events: Arc::new(Mutex::new(events)),
+            })
+            .with_backend(terminal);
+        shell.input = Some(tx);
+        debug!(user = user.name, peer = %user.peer, "starting ssh session app");
+        tokio::spawn(run_shell(app, session.handle(), channel, output, pending));
+        session.channel_success(channel)?;
+        Ok(())
+    }
+
+    async fn data(
+        &mut self,
+        channel: ChannelId,
+        data: &[u8],
+        _session: &mut Session,
+    ) -> anyhow::Result<()> {
+        if let Some(input) = self
+            .shells
+            .get(&channel)
+            .and_then(|shell| shell.input.as_ref())
+        {
+            for event in decode_input(data) {
+                let _ = input.send(event);
+            }
+        }
+        Ok(())
+    }
+
+    async fn channel_eof(
+        &mut self,
+        channel: ChannelId,
+        _session: &mut Session,
+    ) -> anyhow::Result<()> {
+        self.shells.remove(&channel);
+        Ok(())
+    }
+
+    async fn channel_close(
+        &mut self,
+        channel: ChannelId,
+        _session: &mut Session,
+    ) -> anyhow::Result<()> {
+        self.shells.remove(&channel);
+        Ok(())
+    }
+}
+
+fn cells(count: u32) -> u16 {
+    u16::try_from(count).unwrap_or(u16::MAX).max(1)
+}
+
+async fn run_shell(
+    app: App,
+    handle: Handle,
+    channel: ChannelId,
+    output: mpsc::UnboundedSender<String>,
+    mut pending: mpsc::UnboundedReceiver<String>,
+) {
+    let writer = handle.clone();
+    let forward = tokio::spawn(async move {
+        while let Some(chunk) = pending.recv().await {
+            if writer.data(channel, chunk.into_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
+    let code = match app.run().await {
+        Ok(code) => code,
+        Err(err) => {
+            warn!(error = ?err, "ssh session app failed");
+            1
+        }
+    };
+    let _ = output.send(LEAVE_ALTERNATE_SCREEN.to_string());
+    drop(output);
+    let _ = forward.await;
+    let _ = handle
+        .exit_status_request(channel, u32::try_from(code).unwrap_or(1))
+        .await;
+    let _ = handle.eof(channel).await;
+    let _ = handle.close(channel).await;
+}
+
+struct SshDriver {
+    events: Arc<Mutex<mpsc::UnboundedReceiver<FrameworkEvent>>>,
+}
+
+impl RuntimeDriver for SshDriver {
+    fn spawn_terminal_events(&self, tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+        let events = self.events.clone();
+        tokio::spawn(async move {
+            let mut events = events.lock().await;
+            while let Some(event) = events.recv().await {
+                if tx.send(AppMessage::ExternalEvent(event)).await.is_err() {
+                    return;
+                }
+            }
+            debug!("ssh client disconnected");
+            let _ = tx.send(AppMessage::Shutdown).await;
+        })
+    }
+
+    fn spawn_tick_loop(&self, tx: mpsc::Sender<AppMessage>, rate: Duration) -> JoinHandle<()> {
+        spawn_tick_loop(tx, rate)
+    }
+
+    fn spawn_shutdown_watcher(&self, _tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+        tokio::spawn(std::future::pending())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,106 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use russh::client::{self, Msg};
+use russh::keys::{Algorithm, PrivateKey, PrivateKeyWithHashAlg, PublicKeyOrCertificate};
+use russh::{Channel, ChannelMsg};
+use tokio::net::TcpListener;
+use tokio::time::timeout;
+
+use super::SshServer;
+use crate::runtime::{App, Element, component};
+
+struct Client;
+
+impl client::Handler for Client {
+    type Error = russh::Error;
+
+    async fn check_server_key(
+        &mut self,
+        _key: &PublicKeyOrCertificate,
+    ) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+async fn open_shell(addr: SocketAddr, user: &str) -> (client::Handle<Client>, Channel<Msg>) {
+    let config = Arc::new(client::Config::default());
+    let mut handle = client::connect(config, addr, Client)
+        .await
+        .expect("connect");
+    let key = PrivateKey::random(&mut rand::rng(), Algorithm::Ed25519).expect("client key");
+    let auth = handle
+        .authenticate_publickey(user, PrivateKeyWithHashAlg::new(Arc::new(key), None))
+        .await
+        .expect("authenticate");
+    assert!(auth.success());
+    let channel = handle.channel_open_session().await.expect("open session");
+    channel
+        .request_pty(false, "xterm", 30, 3, 0, 0, &[])
+        .await
+        .expect("request pty");
+    channel.request_shell(false).await.expect("request shell");
+    (handle, channel)
+}
+
+async fn read_until(channel: &mut Channel<Msg>, needle: &str) -> String {
+    let mut output = String::new();
+    while !output.contains(needle) {
+        match channel.wait().await {
+            Some(ChannelMsg::Data { data }) => output.push_str(&String::from_utf8_lossy(&data)),
+            Some(_) => {}
+            None => panic!("channel closed before {needle:?} arrived; got {output:?}"),
+        }
+    }
+    output
+}
+
+async fn exit_status(channel: &mut Channel<Msg>) -> u32 {
+    loop {
+        match channel.wait().await {
+            Some(ChannelMsg::ExitStatus { exit_status }) => return exit_status,
+            Some(_) => {}
+            None => panic!("channel closed without an exit status"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn every_ssh_session_runs_its_own_app() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().expect("local addr");
+    let server = SshServer::new(|user| {
+        let greeting = format!("hello {}", user.name);
+        App::new(
+            "SshTest",
+            component("Greeting", move |_ctx| Element::text(greeting.clone())),
+        )
+    })
+    .expect("ssh server");
+    tokio::spawn(server.serve(listener));
+
+    let wait = Duration::from_secs(10);
+    let ((_alice, mut alice), (_bob, mut bob)) =
+        tokio::join!(open_shell(addr, "alice"), open_shell(addr, "bob"));
+    let alice_screen = timeout(wait, read_until(&mut alice, "hello alice"))
+        .await
+        .expect("alice sees her app");
+    let bob_screen = timeout(wait, read_until(&mut bob, "hello bob"))
+        .await
+        .expect("bob sees his app");
+    assert!(alice_screen.starts_with("\x1b[?1049h"));
+    assert!(!alice_screen.contains("hello bob"));
+    assert!(!bob_screen.contains("hello alice"));
+
+    alice.data(&b"\x03"[..]).await.expect("send ctrl+c");
+    let code = timeout(wait, exit_status(&mut alice))
+        .await
+        .expect("ctrl+c ends alice's session");
+    assert_eq!(code, 0);
+
+    bob.eof().await.expect("send eof");
+    timeout(wait, exit_status(&mut bob))
+        .await
+        .expect("closing the channel stops bob's app");
+}
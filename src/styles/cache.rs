@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use parking_lot::Mutex;
+
+use super::computed::ComputedStyle;
+use super::query::QueryKey;
+
+const MAX_ENTRIES: usize = 4096;
+
+#[derive(Default)]
+pub(crate) struct StyleCache {
+    entries: Mutex<HashMap<QueryKey, ComputedStyle>>,
+}
+
+impl StyleCache {
+    pub(crate) fn get_or_insert_with(
+        &self,
+        key: QueryKey,
+        compute: impl FnOnce() -> ComputedStyle,
+    ) -> ComputedStyle {
+        if let Some(style) = self.entries.lock().get(&key) {
+            return style.clone();
+        }
+        let style = compute();
+        let mut entries = self.entries.lock();
+        if entries.len() >= MAX_ENTRIES {
+            entries.clear();
+        }
+        entries.insert(key, style.clone());
+        style
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.lock().clear();
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+}
+
+impl Clone for StyleCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for StyleCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StyleCache")
+            .field("entries", &self.len())
+            .finish()
+    }
+}
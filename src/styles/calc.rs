@@ -0,0 +1,152 @@
+use super::parser::closing_paren;
+
+pub(crate) fn evaluate_calcs(value: &str) -> Option<String> {
+    let mut evaluated = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("calc(") {
+        evaluated.push_str(&rest[..start]);
+        let inner_start = start + "calc(".len();
+        let inner_len = closing_paren(&rest[inner_start..])?;
+        let result = evaluate(&rest[inner_start..inner_start + inner_len])?;
+        evaluated.push_str(&format_number(result));
+        rest = &rest[inner_start + inner_len + 1..];
+    }
+    evaluated.push_str(rest);
+    Some(evaluated)
+}
+
+pub(crate) fn evaluate(expression: &str) -> Option<f64> {
+    let tokens = tokenize(expression)?;
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+    };
+    let value = parser.expression()?;
+    if parser.position != parser.tokens.len() || !value.is_finite() {
+        return None;
+    }
+    Some(value)
+}
+
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{value}")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Open,
+    Close,
+}
+
+fn tokenize(expression: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        let token = match ch {
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ch if ch.is_ascii_whitespace() => continue,
+            ch if ch.is_ascii_digit() || ch == '.' => {
+                let mut end = index + ch.len_utf8();
+                while let Some(&(next, digit)) = chars.peek() {
+                    if !(digit.is_ascii_digit() || digit == '.') {
+                        break;
+                    }
+                    end = next + digit.len_utf8();
+                    chars.next();
+                }
+                Token::Number(expression[index..end].parse().ok()?)
+            }
+            ch if ch.is_ascii_alphabetic() => {
+                let mut end = index + ch.len_utf8();
+                while let Some(&(next, letter)) = chars.peek() {
+                    if !letter.is_ascii_alphabetic() {
+                        break;
+                    }
+                    end = next + letter.len_utf8();
+                    chars.next();
+                }
+                if &expression[index..end] != "calc" {
+                    return None;
+                }
+                continue;
+            }
+            _ => return None,
+        };
+        tokens.push(token);
+    }
+    Some(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek()?;
+        self.position += 1;
+        Some(token)
+    }
+
+    fn expression(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+        while let Some(token @ (Token::Plus | Token::Minus)) = self.peek() {
+            self.position += 1;
+            let rhs = self.term()?;
+            value = if token == Token::Plus {
+                value + rhs
+            } else {
+                value - rhs
+            };
+        }
+        Some(value)
+    }
+
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.factor()?;
+        while let Some(token @ (Token::Star | Token::Slash)) = self.peek() {
+            self.position += 1;
+            let rhs = self.factor()?;
+            value = if token == Token::Star {
+                value * rhs
+            } else if rhs == 0.0 {
+                return None;
+            } else {
+                value / rhs
+            };
+        }
+        Some(value)
+    }
+
+    fn factor(&mut self) -> Option<f64> {
+        match self.next()? {
+            Token::Number(value) => Some(value),
+            Token::Minus => self.factor().map(|value| -value),
+            Token::Plus => self.factor(),
+            Token::Open => {
+                let value = self.expression()?;
+                (self.next()? == Token::Close).then_some(value)
+            }
+            _ => None,
+        }
+    }
+}
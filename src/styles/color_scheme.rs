@@ -0,0 +1,60 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl ColorScheme {
+    pub fn is_dark(self) -> bool {
+        self == Self::Dark
+    }
+
+    pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        let luminance = 0.2126 * f64::from(r) + 0.7152 * f64::from(g) + 0.0722 * f64::from(b);
+        if luminance < 128.0 {
+            Self::Dark
+        } else {
+            Self::Light
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn from_osc11(response: &str) -> Option<Self> {
+        let (r, g, b) = parse_osc11(response)?;
+        Some(Self::from_rgb(r, g, b))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn from_colorfgbg(value: &str) -> Option<Self> {
+        let background: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+        match background {
+            7 | 9..=15 => Some(Self::Light),
+            0..=6 | 8 => Some(Self::Dark),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_osc11(response: &str) -> Option<(u8, u8, u8)> {
+    let start = response.find("]11;")? + "]11;".len();
+    let body = &response[start..];
+    let end = body.find(['\x07', '\x1b']).unwrap_or(body.len());
+    let spec = body[..end].trim();
+    let channels = spec
+        .strip_prefix("rgb:")
+        .or_else(|| spec.strip_prefix("rgba:"))?;
+    let mut parts = channels.split('/').map(scale_channel);
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn scale_channel(hex: &str) -> Option<u8> {
+    if hex.is_empty() || hex.len() > 4 {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    let max = (1u32 << (4 * hex.len())) - 1;
+    Some((value * 255 / max) as u8)
+}
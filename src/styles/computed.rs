@@ -1,8 +1,22 @@
 use std::collections::HashMap;
 
-use crate::runtime::Color;
+use ratatui::style::Style;
+
+use crate::runtime::{BorderStyle, Color, Modifier, Spacing};
 
 use super::parser::parse_color;
+use super::transition::TransitionSpec;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LayoutStyle {
+    pub width: Option<u16>,
+    pub height: Option<u16>,
+    pub padding: Option<Spacing>,
+    pub margin: Option<Spacing>,
+    pub gap: Option<u16>,
+    pub flex_grow: Option<u16>,
+    pub border_style: Option<BorderStyle>,
+}
 
 #[derive(Clone, Debug, Default)]
 pub struct ComputedStyle {
@@ -59,7 +73,102 @@ impl ComputedStyle {
         if out.is_empty() { None } else { Some(out) }
     }
 
+    pub fn spacing(&self, name: &str) -> Option<Spacing> {
+        match self.list_u16(name)?.as_slice() {
+            [all] => Some(Spacing::all(*all)),
+            [vertical, horizontal] => Some(Spacing::symmetric(*vertical, *horizontal)),
+            [top, horizontal, bottom] => {
+                Some(Spacing::new(*top, *horizontal, *bottom, *horizontal))
+            }
+            [top, right, bottom, left, ..] => Some(Spacing::new(*top, *right, *bottom, *left)),
+            [] => None,
+        }
+    }
+
+    pub fn border_style(&self, name: &str) -> Option<BorderStyle> {
+        self.get(name).and_then(BorderStyle::parse)
+    }
+
+    pub fn modifiers(&self) -> Modifier {
+        let mut modifiers = Modifier::empty();
+        if let Some(weight) = self.get("font-weight") {
+            let weight = weight.trim().to_ascii_lowercase();
+            match (weight.as_str(), weight.parse::<u16>()) {
+                ("bold" | "bolder", _) => modifiers |= Modifier::BOLD,
+                (_, Ok(600..)) => modifiers |= Modifier::BOLD,
+                ("light" | "lighter" | "dim", _) => modifiers |= Modifier::DIM,
+                (_, Ok(..=300)) => modifiers |= Modifier::DIM,
+                _ => {}
+            }
+        }
+        if let Some(style) = self.get("font-style") {
+            if matches!(
+                style.trim().to_ascii_lowercase().as_str(),
+                "italic" | "oblique"
+            ) {
+                modifiers |= Modifier::ITALIC;
+            }
+        }
+        if let Some(decoration) = self.get("text-decoration") {
+            for token in decoration.split_whitespace() {
+                match token.to_ascii_lowercase().as_str() {
+                    "underline" => modifiers |= Modifier::UNDERLINED,
+                    "line-through" => modifiers |= Modifier::CROSSED_OUT,
+                    "blink" => modifiers |= Modifier::SLOW_BLINK,
+                    _ => {}
+                }
+            }
+        }
+        modifiers
+    }
+
+    pub fn patch_style(&self, base: Style) -> Style {
+        let mut style = base;
+        if let Some(color) = self.color("color") {
+            style = style.fg(color);
+        }
+        if let Some(color) = self.color("background-color") {
+            style = style.bg(color);
+        }
+        if ["font-weight", "font-style", "text-decoration"]
+            .iter()
+            .any(|name| self.get(name).is_some())
+        {
+            style.add_modifier = self.modifiers();
+            style.sub_modifier = Modifier::empty();
+        }
+        style
+    }
+
+    pub fn layout(&self) -> LayoutStyle {
+        LayoutStyle {
+            width: self.u16("width"),
+            height: self.u16("height"),
+            padding: self.spacing("padding"),
+            margin: self.spacing("margin"),
+            gap: self.u16("gap"),
+            flex_grow: self.u16("flex-grow"),
+            border_style: self.border_style("border-style"),
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.props.is_empty()
     }
+
+    pub(crate) fn transitions(&self) -> Vec<TransitionSpec> {
+        self.get("transition")
+            .map(TransitionSpec::parse_list)
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn props(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.props
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    pub(crate) fn set(&mut self, name: &str, value: String) {
+        self.props.insert(name.to_ascii_lowercase(), value);
+    }
 }
@@ -0,0 +1,49 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StyleParseError {
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+    pub message: String,
+}
+
+impl StyleParseError {
+    pub(crate) fn at(source: &str, offset: usize, message: impl Into<String>) -> Self {
+        let offset = offset.min(source.len());
+        let line_start = source[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |index| offset + index);
+        Self {
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            source_line: source[line_start..line_end].trim_end().to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn locate(source: &str, fragment: &str, err: anyhow::Error) -> anyhow::Error {
+        if err.is::<StyleParseError>() {
+            return err;
+        }
+        let offset = (fragment.as_ptr() as usize).saturating_sub(source.as_ptr() as usize);
+        Self::at(source, offset, err.to_string()).into()
+    }
+}
+
+impl fmt::Display for StyleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{:gutter$} | {:>width$}", "", "^", width = self.column)
+    }
+}
+
+impl std::error::Error for StyleParseError {}
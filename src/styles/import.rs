@@ -0,0 +1,75 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow};
+
+use super::parser::strip_comments;
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct StylesheetSource {
+    pub(crate) contents: String,
+    pub(crate) files: Vec<PathBuf>,
+}
+
+pub(crate) fn read_stylesheet(path: &Path) -> Result<StylesheetSource> {
+    let mut source = StylesheetSource::default();
+    source.contents = expand(path, &mut source.files)?;
+    Ok(source)
+}
+
+fn expand(path: &Path, files: &mut Vec<PathBuf>) -> Result<String> {
+    let path = fs::canonicalize(path).with_context(|| format!("open {}", path.display()))?;
+    if files.contains(&path) {
+        return Ok(String::new());
+    }
+    files.push(path.clone());
+    let contents = fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?;
+    let contents = strip_comments(&contents);
+    let base = path.parent().unwrap_or(Path::new(""));
+
+    let mut output = String::with_capacity(contents.len());
+    let mut rest = contents.as_str();
+    let mut depth = 0usize;
+    while let Some(index) = rest.find(['{', '}', '@']) {
+        let (head, tail) = rest.split_at(index);
+        output.push_str(head);
+        match tail.as_bytes()[0] {
+            b'{' => depth += 1,
+            b'}' => depth = depth.saturating_sub(1),
+            _ if depth == 0 && tail.starts_with("@import") => {
+                let end = tail
+                    .find(';')
+                    .ok_or_else(|| anyhow!("@import in {} is missing `;`", path.display()))?;
+                let target = import_target(&tail["@import".len()..end])
+                    .ok_or_else(|| anyhow!("invalid @import `{}`", tail[..end].trim()))?;
+                output.push_str(&expand(&base.join(target), files)?);
+                output.push('\n');
+                rest = &tail[end + 1..];
+                continue;
+            }
+            _ => {}
+        }
+        output.push_str(&tail[..1]);
+        rest = &tail[1..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+fn import_target(raw: &str) -> Option<&str> {
+    let raw = raw.trim();
+    let raw = raw
+        .strip_prefix("url(")
+        .and_then(|inner| inner.strip_suffix(')'))
+        .map(str::trim)
+        .unwrap_or(raw);
+    let target = raw
+        .strip_prefix('"')
+        .and_then(|inner| inner.strip_suffix('"'))
+        .or_else(|| {
+            raw.strip_prefix('\'')
+                .and_then(|inner| inner.strip_suffix('\''))
+        })
+        .unwrap_or(raw);
+    (!target.is_empty()).then_some(target)
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::runtime::{BorderStyle, Color, Spacing};
+
+use super::computed::ComputedStyle;
+use super::parser::format_color;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ElementStyle {
+    props: HashMap<String, String>,
+}
+
+impl ElementStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.props.insert(name.to_ascii_lowercase(), value.into());
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        self.set("color", format_color(color))
+    }
+
+    pub fn accent_color(self, color: Color) -> Self {
+        self.set("accent-color", format_color(color))
+    }
+
+    pub fn background_color(self, color: Color) -> Self {
+        self.set("background-color", format_color(color))
+    }
+
+    pub fn border_color(self, color: Color) -> Self {
+        self.set("border-color", format_color(color))
+    }
+
+    pub fn border_style(self, style: BorderStyle) -> Self {
+        self.set("border-style", style.as_str())
+    }
+
+    pub fn width(self, width: u16) -> Self {
+        self.set("width", width.to_string())
+    }
+
+    pub fn height(self, height: u16) -> Self {
+        self.set("height", height.to_string())
+    }
+
+    pub fn padding(self, padding: impl Into<Spacing>) -> Self {
+        self.set("padding", format_spacing(padding.into()))
+    }
+
+    pub fn margin(self, margin: impl Into<Spacing>) -> Self {
+        self.set("margin", format_spacing(margin.into()))
+    }
+
+    pub fn gap(self, gap: u16) -> Self {
+        self.set("gap", gap.to_string())
+    }
+
+    pub fn flex_grow(self, factor: u16) -> Self {
+        self.set("flex-grow", factor.to_string())
+    }
+
+    pub fn merge(mut self, other: ElementStyle) -> Self {
+        self.props.extend(other.props);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.props
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.props.is_empty()
+    }
+
+    pub fn apply(&self, style: &mut ComputedStyle) {
+        for (name, value) in &self.props {
+            style.set(name, value.clone());
+        }
+    }
+
+    pub fn computed(&self) -> ComputedStyle {
+        ComputedStyle::from_props(self.props.clone())
+    }
+}
+
+fn format_spacing(spacing: Spacing) -> String {
+    format!(
+        "{} {} {} {}",
+        spacing.top, spacing.right, spacing.bottom, spacing.left
+    )
+}
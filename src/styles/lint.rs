@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use super::parser::parse_color;
+
+const KNOWN_PROPERTIES: &[&str] = &[
+    "color",
+    "accent-color",
+    "background-color",
+    "border-color",
+    "border",
+    "border-style",
+    "width",
+    "height",
+    "padding",
+    "margin",
+    "gap",
+    "flex-grow",
+    "font-weight",
+    "font-style",
+    "text-decoration",
+    "transition",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StyleDiagnostic {
+    UnknownProperty {
+        selector: String,
+        property: String,
+    },
+    InvalidColor {
+        selector: String,
+        property: String,
+        value: String,
+    },
+    UnmatchedSelector {
+        selector: String,
+    },
+}
+
+impl StyleDiagnostic {
+    pub fn selector(&self) -> &str {
+        match self {
+            Self::UnknownProperty { selector, .. }
+            | Self::InvalidColor { selector, .. }
+            | Self::UnmatchedSelector { selector } => selector,
+        }
+    }
+}
+
+impl fmt::Display for StyleDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownProperty { selector, property } => {
+                write!(f, "`{selector}`: unknown property `{property}`")
+            }
+            Self::InvalidColor {
+                selector,
+                property,
+                value,
+            } => write!(f, "`{selector}`: `{property}: {value}` is not a color"),
+            Self::UnmatchedSelector { selector } => {
+                write!(f, "`{selector}` never matched an element")
+            }
+        }
+    }
+}
+
+pub(crate) fn lint_declarations(
+    selector: &str,
+    declarations: &HashMap<String, String>,
+    diagnostics: &mut Vec<StyleDiagnostic>,
+) {
+    let mut names: Vec<&String> = declarations.keys().collect();
+    names.sort();
+    for name in names {
+        let value = &declarations[name];
+        if !name.starts_with("--") && !KNOWN_PROPERTIES.contains(&name.as_str()) {
+            diagnostics.push(StyleDiagnostic::UnknownProperty {
+                selector: selector.to_string(),
+                property: name.clone(),
+            });
+            continue;
+        }
+        let dynamic = value.contains("var(") || value.contains("calc(");
+        if name.ends_with("color") && !dynamic && parse_color(value).is_none() {
+            diagnostics.push(StyleDiagnostic::InvalidColor {
+                selector: selector.to_string(),
+                property: name.clone(),
+                value: value.clone(),
+            });
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use anyhow::{Result, anyhow};
+
+use super::color_scheme::ColorScheme;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct MediaEnvironment {
+    pub(crate) viewport: Option<(u16, u16)>,
+    pub(crate) color_scheme: Option<ColorScheme>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MediaFeature {
+    MinWidth(u16),
+    MaxWidth(u16),
+    MinHeight(u16),
+    MaxHeight(u16),
+    PrefersColorScheme(ColorScheme),
+}
+
+impl MediaFeature {
+    fn parse(raw: &str) -> Result<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "prefers-dark" => return Ok(Self::PrefersColorScheme(ColorScheme::Dark)),
+            "prefers-light" => return Ok(Self::PrefersColorScheme(ColorScheme::Light)),
+            _ => {}
+        }
+        let (name, value) = raw
+            .split_once(':')
+            .ok_or_else(|| anyhow!("media feature `({raw})` needs a value"))?;
+        let name = name.trim().to_ascii_lowercase();
+        if name == "prefers-color-scheme" {
+            return match value.trim().to_ascii_lowercase().as_str() {
+                "dark" => Ok(Self::PrefersColorScheme(ColorScheme::Dark)),
+                "light" => Ok(Self::PrefersColorScheme(ColorScheme::Light)),
+                other => Err(anyhow!("unsupported color scheme `{other}`")),
+            };
+        }
+        let feature: fn(u16) -> Self = match name.as_str() {
+            "min-width" => Self::MinWidth,
+            "max-width" => Self::MaxWidth,
+            "min-height" => Self::MinHeight,
+            "max-height" => Self::MaxHeight,
+            other => return Err(anyhow!("unsupported media feature `{other}`")),
+        };
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| anyhow!("media feature `({raw})` needs a whole number"))?;
+        Ok(feature(value))
+    }
+
+    fn matches(self, environment: MediaEnvironment) -> bool {
+        let viewport = environment.viewport;
+        match self {
+            Self::MinWidth(min) => viewport.is_some_and(|(width, _)| width >= min),
+            Self::MaxWidth(max) => viewport.is_some_and(|(width, _)| width <= max),
+            Self::MinHeight(min) => viewport.is_some_and(|(_, height)| height >= min),
+            Self::MaxHeight(max) => viewport.is_some_and(|(_, height)| height <= max),
+            Self::PrefersColorScheme(scheme) => environment.color_scheme == Some(scheme),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct MediaQuery {
+    features: Vec<MediaFeature>,
+}
+
+impl MediaQuery {
+    pub(crate) fn parse(raw: &str) -> Result<Self> {
+        let mut query = MediaQuery::default();
+        for part in raw.split(" and ") {
+            let part = part.trim();
+            if part.is_empty() || part.eq_ignore_ascii_case("all") {
+                continue;
+            }
+            let inner = part
+                .strip_prefix('(')
+                .and_then(|part| part.strip_suffix(')'))
+                .ok_or_else(|| anyhow!("expected `(feature: value)` in `@media {raw}`"))?;
+            query.features.push(MediaFeature::parse(inner)?);
+        }
+        Ok(query)
+    }
+
+    pub(crate) fn matches(&self, environment: MediaEnvironment) -> bool {
+        self.features
+            .iter()
+            .all(|feature| feature.matches(environment))
+    }
+}
@@ -1,10 +1,27 @@
+mod cache;
+mod calc;
+mod color_scheme;
 mod computed;
+mod error;
+mod import;
+mod inline;
+mod lint;
+mod media;
 mod parser;
 mod query;
 mod stylesheet;
 #[cfg(test)]
 mod tests;
+mod theme;
+mod transition;
 
-pub use computed::ComputedStyle;
-pub use query::StyleQuery;
+pub use color_scheme::ColorScheme;
+pub use computed::{ComputedStyle, LayoutStyle};
+pub use error::StyleParseError;
+pub(crate) use import::{StylesheetSource, read_stylesheet};
+pub use inline::ElementStyle;
+pub use lint::StyleDiagnostic;
+pub use query::{StyleNode, StyleQuery, StyleState};
 pub use stylesheet::Stylesheet;
+pub use theme::Theme;
+pub(crate) use transition::{TransitionSpec, interpolate, is_animatable};
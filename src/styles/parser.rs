@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use crate::runtime::Color;
 
+use super::calc::evaluate_calcs;
+
 pub(crate) fn strip_comments(input: &str) -> String {
     let mut result = String::with_capacity(input.len());
     let bytes = input.as_bytes();
@@ -10,6 +12,9 @@ pub(crate) fn strip_comments(input: &str) -> String {
         if bytes[i] == b'/' && i + 1 < bytes.len() && bytes[i + 1] == b'*' {
             i += 2;
             while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                if bytes[i] == b'\n' {
+                    result.push('\n');
+                }
                 i += 1;
             }
             i += 2;
@@ -35,6 +40,102 @@ pub(crate) fn parse_declarations(body: &str) -> HashMap<String, String> {
     }
     map
 }
+
+pub(crate) fn split_important(
+    declarations: HashMap<String, String>,
+) -> (HashMap<String, String>, HashMap<String, String>) {
+    let mut normal = HashMap::new();
+    let mut important = HashMap::new();
+    for (name, value) in declarations {
+        let trimmed = value.trim_end();
+        let marker = trimmed.len().saturating_sub("!important".len());
+        match trimmed.get(marker..) {
+            Some(suffix) if suffix.eq_ignore_ascii_case("!important") => {
+                important.insert(name, clean_value(&trimmed[..marker]));
+            }
+            _ => {
+                normal.insert(name, value);
+            }
+        }
+    }
+    (normal, important)
+}
+
+const MAX_VAR_DEPTH: usize = 16;
+
+pub(crate) fn resolve_values(props: &HashMap<String, String>) -> HashMap<String, String> {
+    props
+        .iter()
+        .filter_map(|(key, value)| {
+            let mut value = if value.contains("var(") {
+                resolve_value(value, props, 0)?
+            } else {
+                value.clone()
+            };
+            if value.contains("calc(") {
+                value = evaluate_calcs(&value)?;
+            }
+            Some((key.clone(), value))
+        })
+        .collect()
+}
+
+fn resolve_value(value: &str, props: &HashMap<String, String>, depth: usize) -> Option<String> {
+    if depth > MAX_VAR_DEPTH {
+        return None;
+    }
+    let mut resolved = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("var(") {
+        resolved.push_str(&rest[..start]);
+        let inner_start = start + "var(".len();
+        let inner_len = closing_paren(&rest[inner_start..])?;
+        let inner = &rest[inner_start..inner_start + inner_len];
+        let (name, fallback) = match top_level_comma(inner) {
+            Some(index) => (&inner[..index], Some(&inner[index + 1..])),
+            None => (inner, None),
+        };
+        let reference = props
+            .get(&name.trim().to_ascii_lowercase())
+            .and_then(|value| resolve_value(value, props, depth + 1));
+        let substituted = match (reference, fallback) {
+            (Some(value), _) => value,
+            (None, Some(fallback)) => resolve_value(&clean_value(fallback), props, depth + 1)?,
+            (None, None) => return None,
+        };
+        resolved.push_str(&substituted);
+        rest = &rest[inner_start + inner_len + 1..];
+    }
+    resolved.push_str(rest);
+    Some(resolved)
+}
+
+pub(crate) fn closing_paren(input: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, ch) in input.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(index),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn top_level_comma(input: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, ch) in input.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
 pub(crate) fn clean_value(value: &str) -> String {
     let trimmed = value.trim();
     if trimmed.starts_with('"') && trimmed.ends_with('"') && trimmed.len() >= 2 {
@@ -49,10 +150,7 @@ pub(crate) fn parse_color(value: &str) -> Option<Color> {
     if let Some(hex) = trimmed.strip_prefix('#') {
         return parse_hex_color(hex);
     }
-    if let Some(inner) = trimmed
-        .strip_prefix("rgb(")
-        .and_then(|v| v.strip_suffix(')'))
-    {
+    if let Some(inner) = function_args(trimmed, "rgb") {
         let parts: Vec<u8> = inner
             .split(',')
             .filter_map(|part| part.trim().parse::<u8>().ok())
@@ -60,10 +158,28 @@ pub(crate) fn parse_color(value: &str) -> Option<Color> {
         if parts.len() == 3 {
             return Some(Color::Rgb(parts[0], parts[1], parts[2]));
         }
+        return None;
+    }
+    if let Some(inner) = function_args(trimmed, "hsl") {
+        return parse_hsl(inner);
+    }
+    if let Some(inner) = function_args(trimmed, "ansi") {
+        return inner.trim().parse::<u8>().ok().map(Color::Indexed);
+    }
+    if let Ok(index) = trimmed.parse::<u8>() {
+        return Some(Color::Indexed(index));
     }
     named_color(trimmed)
 }
 
+fn function_args<'a>(value: &'a str, name: &str) -> Option<&'a str> {
+    let (function, rest) = value.split_once('(')?;
+    if !function.trim().eq_ignore_ascii_case(name) {
+        return None;
+    }
+    rest.strip_suffix(')')
+}
+
 fn parse_hex_color(hex: &str) -> Option<Color> {
     match hex.len() {
         3 => {
@@ -82,8 +198,67 @@ fn parse_hex_color(hex: &str) -> Option<Color> {
     }
 }
 
+fn parse_hsl(inner: &str) -> Option<Color> {
+    let mut parts = inner.split(',').map(str::trim);
+    let hue: f64 = parts.next()?.trim_end_matches("deg").parse().ok()?;
+    let saturation: f64 = parts.next()?.trim_end_matches('%').parse().ok()?;
+    let lightness: f64 = parts.next()?.trim_end_matches('%').parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    let (s, l) = (
+        (saturation / 100.0).clamp(0.0, 1.0),
+        (lightness / 100.0).clamp(0.0, 1.0),
+    );
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = l - chroma / 2.0;
+    let channel = |value: f64| ((value + m) * 255.0).round() as u8;
+    Some(Color::Rgb(channel(r), channel(g), channel(b)))
+}
+
+pub(crate) fn format_color(color: Color) -> String {
+    let name = match color {
+        Color::Rgb(r, g, b) => return format!("#{r:02x}{g:02x}{b:02x}"),
+        Color::Indexed(index) => return format!("ansi({index})"),
+        Color::Reset => "reset",
+        Color::Black => "black",
+        Color::Red => "red",
+        Color::Green => "green",
+        Color::Yellow => "yellow",
+        Color::Blue => "blue",
+        Color::Magenta => "magenta",
+        Color::Cyan => "cyan",
+        Color::Gray => "gray",
+        Color::DarkGray => "dark-gray",
+        Color::LightRed => "light-red",
+        Color::LightGreen => "light-green",
+        Color::LightYellow => "light-yellow",
+        Color::LightBlue => "light-blue",
+        Color::LightMagenta => "light-magenta",
+        Color::LightCyan => "light-cyan",
+        Color::White => "white",
+    };
+    name.to_string()
+}
+
 fn named_color(value: &str) -> Option<Color> {
-    match value.to_ascii_lowercase().as_str() {
+    let normalized: String = value
+        .chars()
+        .filter(|ch| !matches!(ch, '-' | '_' | ' '))
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect();
+    match normalized.as_str() {
+        "reset" | "default" => Some(Color::Reset),
         "black" => Some(Color::Black),
         "white" => Some(Color::White),
         "red" => Some(Color::Red),
@@ -93,7 +268,14 @@ fn named_color(value: &str) -> Option<Color> {
         "cyan" => Some(Color::Cyan),
         "magenta" => Some(Color::Magenta),
         "gray" | "grey" => Some(Color::Gray),
+        "darkgray" | "darkgrey" => Some(Color::DarkGray),
         "lightgray" | "lightgrey" => Some(Color::DarkGray),
+        "lightred" => Some(Color::LightRed),
+        "lightgreen" => Some(Color::LightGreen),
+        "lightyellow" => Some(Color::LightYellow),
+        "lightblue" => Some(Color::LightBlue),
+        "lightmagenta" => Some(Color::LightMagenta),
+        "lightcyan" => Some(Color::LightCyan),
         _ => None,
     }
 }
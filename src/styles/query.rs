@@ -1,8 +1,21 @@
+use super::theme::Theme;
+
 #[derive(Clone, Copy, Debug)]
 pub struct StyleQuery<'a> {
     pub(crate) element: &'a str,
     pub(crate) id: Option<&'a str>,
     pub(crate) classes: &'a [&'a str],
+    pub(crate) ancestors: &'a [StyleNode],
+    pub(crate) state: StyleState,
+    pub(crate) theme: Option<&'a Theme>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StyleState {
+    pub focus: bool,
+    pub hover: bool,
+    pub disabled: bool,
+    pub selected: bool,
 }
 
 impl<'a> StyleQuery<'a> {
@@ -11,6 +24,9 @@ impl<'a> StyleQuery<'a> {
             element,
             id: None,
             classes: &[],
+            ancestors: &[],
+            state: StyleState::default(),
+            theme: None,
         }
     }
 
@@ -23,4 +39,107 @@ impl<'a> StyleQuery<'a> {
         self.classes = classes;
         self
     }
+
+    pub fn with_ancestors(mut self, ancestors: &'a [StyleNode]) -> Self {
+        self.ancestors = ancestors;
+        self
+    }
+
+    pub fn with_state(mut self, state: StyleState) -> Self {
+        self.state = state;
+        self
+    }
+
+    pub(crate) fn cache_key(&self) -> QueryKey {
+        QueryKey {
+            element: self.element.to_string(),
+            id: self.id.map(str::to_string),
+            classes: self.classes.iter().map(|class| class.to_string()).collect(),
+            state: self.state,
+            ancestors: self.ancestors.to_vec(),
+            theme: self.theme.cloned(),
+        }
+    }
+
+    pub(crate) fn transition_key(&self) -> String {
+        let mut key = self.element.to_string();
+        if let Some(id) = self.id {
+            key.push('#');
+            key.push_str(id);
+        }
+        for class in self.classes {
+            key.push('.');
+            key.push_str(class);
+        }
+        key
+    }
+
+    pub fn with_theme(mut self, theme: &'a Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.state.focus = focused;
+        self
+    }
+
+    pub fn hovered(mut self, hovered: bool) -> Self {
+        self.state.hover = hovered;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.state.disabled = disabled;
+        self
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.state.selected = selected;
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StyleNode {
+    pub element: String,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub state: StyleState,
+}
+
+impl StyleNode {
+    pub fn new(element: impl Into<String>) -> Self {
+        Self {
+            element: element.into(),
+            id: None,
+            classes: Vec::new(),
+            state: StyleState::default(),
+        }
+    }
+
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn with_class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    pub fn with_state(mut self, state: StyleState) -> Self {
+        self.state = state;
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct QueryKey {
+    element: String,
+    id: Option<String>,
+    classes: Vec<String>,
+    state: StyleState,
+    ancestors: Vec<StyleNode>,
+    theme: Option<Theme>,
 }
@@ -1,103 +1,445 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
-use std::fs;
 use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::{Result, anyhow};
 
+use super::cache::StyleCache;
+use super::color_scheme::ColorScheme;
 use super::computed::ComputedStyle;
-use super::parser::{parse_declarations, strip_comments};
-use super::query::StyleQuery;
+use super::error::StyleParseError;
+use super::import::read_stylesheet;
+use super::lint::{StyleDiagnostic, lint_declarations};
+use super::media::{MediaEnvironment, MediaQuery};
+use super::parser::{
+    format_color, parse_declarations, resolve_values, split_important, strip_comments,
+};
+use super::query::{StyleNode, StyleQuery, StyleState};
 
 #[derive(Clone, Debug, Default)]
 pub struct Stylesheet {
     root: HashMap<String, String>,
+    media_roots: Vec<(MediaQuery, HashMap<String, String>)>,
     rules: Vec<StyleRule>,
+    layers: Vec<String>,
+    environment: MediaEnvironment,
+    cache: StyleCache,
 }
 
 impl Stylesheet {
     pub fn parse(input: &str) -> Result<Self> {
         let mut sheet = Stylesheet::default();
-        let mut order = 0usize;
         let cleaned = strip_comments(input);
-        for block in cleaned.split('}') {
-            if block.trim().is_empty() {
-                continue;
-            }
-            let (selector_raw, body_raw) = match block.split_once('{') {
-                Some(pair) => pair,
-                None => continue,
+        sheet.parse_blocks(&cleaned, &cleaned, None, None)?;
+        Ok(sheet)
+    }
+
+    fn parse_blocks(
+        &mut self,
+        source: &str,
+        input: &str,
+        media: Option<&MediaQuery>,
+        layer: Option<usize>,
+    ) -> Result<()> {
+        let mut rest = input;
+        while let Some(open) = rest.find('{') {
+            let (statements, prelude) = match rest[..open].rfind(';') {
+                Some(end) => (&rest[..end], rest[end + 1..open].trim()),
+                None => ("", rest[..open].trim()),
             };
-            let selector_raw = selector_raw.trim();
-            if selector_raw.is_empty() {
+            for statement in statements.split(';') {
+                let statement = statement.trim();
+                self.parse_statement(statement)
+                    .map_err(|err| StyleParseError::locate(source, statement, err))?;
+            }
+            let body_len = closing_brace(&rest[open + 1..]).ok_or_else(|| {
+                StyleParseError::locate(
+                    source,
+                    prelude,
+                    anyhow!("unclosed block after `{prelude}`"),
+                )
+            })?;
+            let body = &rest[open + 1..open + 1 + body_len];
+            rest = &rest[open + 1 + body_len + 1..];
+            let located = |err| StyleParseError::locate(source, prelude, err);
+            if let Some(condition) = prelude.strip_prefix("@media") {
+                if media.is_some() {
+                    return Err(located(anyhow!("nested @media blocks are not supported")));
+                }
+                let query = MediaQuery::parse(condition).map_err(located)?;
+                self.parse_blocks(source, body, Some(&query), layer)?;
                 continue;
             }
-            let declarations = parse_declarations(body_raw);
-            for selector in selector_raw.split(',') {
-                let selector = selector.trim();
-                if selector.is_empty() {
-                    continue;
+            if let Some(name) = prelude.strip_prefix("@layer") {
+                if layer.is_some() {
+                    return Err(located(anyhow!("nested @layer blocks are not supported")));
                 }
-                if selector == ":root" {
-                    merge_maps(&mut sheet.root, &declarations);
-                    continue;
+                let index = self.declare_layer(name.trim());
+                self.parse_blocks(source, body, media, Some(index))?;
+                continue;
+            }
+            if !prelude.is_empty() {
+                self.push_rule(prelude, parse_declarations(body), media, layer)
+                    .map_err(located)?;
+            }
+        }
+        for statement in rest.split(';') {
+            let statement = statement.trim();
+            if statement.starts_with('@') {
+                self.parse_statement(statement)
+                    .map_err(|err| StyleParseError::locate(source, statement, err))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_statement(&mut self, statement: &str) -> Result<()> {
+        if statement.is_empty() {
+            return Ok(());
+        }
+        if let Some(names) = statement.strip_prefix("@layer") {
+            for name in names.split(',') {
+                self.declare_layer(name.trim());
+            }
+            return Ok(());
+        }
+        if statement.starts_with("@import") {
+            return Err(anyhow!(
+                "@import is only supported in stylesheets loaded from a file"
+            ));
+        }
+        Err(anyhow!("unexpected `{statement};`"))
+    }
+
+    fn declare_layer(&mut self, name: &str) -> usize {
+        if let Some(index) = self
+            .layers
+            .iter()
+            .position(|layer| !name.is_empty() && layer == name)
+        {
+            return index;
+        }
+        self.layers.push(name.to_string());
+        self.layers.len() - 1
+    }
+
+    fn push_rule(
+        &mut self,
+        selector_raw: &str,
+        declarations: HashMap<String, String>,
+        media: Option<&MediaQuery>,
+        layer: Option<usize>,
+    ) -> Result<()> {
+        let (declarations, important) = split_important(declarations);
+        for selector in selector_raw.split(',') {
+            let selector = selector.trim();
+            if selector.is_empty() {
+                continue;
+            }
+            if selector == ":root" {
+                let mut merged = declarations.clone();
+                merge_maps(&mut merged, &important);
+                match media {
+                    Some(query) => self.media_roots.push((query.clone(), merged)),
+                    None => merge_maps(&mut self.root, &merged),
                 }
-                let selector = Selector::parse(selector)?;
-                sheet.rules.push(StyleRule {
-                    selector,
-                    declarations: declarations.clone(),
-                    order,
-                });
-                order += 1;
+                continue;
             }
+            let source = selector.to_string();
+            let selector = Selector::parse(selector)?;
+            let order = self.rules.len();
+            self.rules.push(StyleRule {
+                source,
+                selector,
+                declarations: declarations.clone(),
+                important: important.clone(),
+                media: media.cloned(),
+                layer,
+                order,
+                matched: Arc::new(AtomicBool::new(false)),
+            });
         }
-        Ok(sheet)
+        Ok(())
+    }
+
+    pub fn with_viewport(mut self, width: u16, height: u16) -> Self {
+        self.set_viewport(width, height);
+        self
+    }
+
+    pub fn set_viewport(&mut self, width: u16, height: u16) {
+        self.set_environment(MediaEnvironment {
+            viewport: Some((width, height)),
+            ..self.environment
+        });
+    }
+
+    pub fn viewport(&self) -> Option<(u16, u16)> {
+        self.environment.viewport
+    }
+
+    pub fn with_color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.set_color_scheme(scheme);
+        self
+    }
+
+    pub fn set_color_scheme(&mut self, scheme: ColorScheme) {
+        self.set_environment(MediaEnvironment {
+            color_scheme: Some(scheme),
+            ..self.environment
+        });
+    }
+
+    pub fn color_scheme(&self) -> Option<ColorScheme> {
+        self.environment.color_scheme
+    }
+
+    pub(crate) fn inherit_environment(&mut self, previous: &Stylesheet) {
+        self.set_environment(previous.environment);
+    }
+
+    fn set_environment(&mut self, environment: MediaEnvironment) {
+        if self.environment != environment {
+            self.environment = environment;
+            self.cache.clear();
+        }
+    }
+
+    fn root_props(&self) -> HashMap<String, String> {
+        let mut props = self.root.clone();
+        for (query, declarations) in &self.media_roots {
+            if query.matches(self.environment) {
+                merge_maps(&mut props, declarations);
+            }
+        }
+        props
     }
 
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
-        let contents = fs::read_to_string(path)?;
-        Self::parse(&contents)
+        Self::parse(&read_stylesheet(path.as_ref())?.contents)
+    }
+
+    pub fn extend(&mut self, other: Stylesheet) {
+        merge_maps(&mut self.root, &other.root);
+        self.media_roots.extend(other.media_roots);
+        let layers: Vec<usize> = other
+            .layers
+            .iter()
+            .map(|name| self.declare_layer(name))
+            .collect();
+        for mut rule in other.rules {
+            rule.layer = rule.layer.map(|index| layers[index]);
+            rule.order = self.rules.len();
+            self.rules.push(rule);
+        }
+        self.cache.clear();
     }
 
     pub fn root(&self) -> ComputedStyle {
-        ComputedStyle::from_props(self.root.clone())
+        ComputedStyle::from_props(resolve_values(&self.root_props()))
     }
 
     pub fn query<'a>(&'a self, query: StyleQuery<'a>) -> ComputedStyle {
-        let mut props = self.root.clone();
+        self.cache
+            .get_or_insert_with(query.cache_key(), || self.compute(&query))
+    }
+
+    fn compute(&self, query: &StyleQuery<'_>) -> ComputedStyle {
+        let mut props = HashMap::new();
+        if let Some(theme) = query.theme {
+            for (name, color) in theme.tokens() {
+                props.insert(name.to_string(), format_color(color));
+            }
+        }
+        merge_maps(&mut props, &self.root_props());
         let mut matches: Vec<&StyleRule> = self
             .rules
             .iter()
-            .filter(|rule| rule.selector.matches(&query))
+            .filter(|rule| {
+                rule.media
+                    .as_ref()
+                    .is_none_or(|media| media.matches(self.environment))
+                    && rule.selector.matches(query)
+            })
             .collect();
-        matches.sort_by(|a, b| {
-            a.selector
-                .specificity()
-                .cmp(&b.selector.specificity())
-                .then(a.order.cmp(&b.order))
+        matches.sort_by_key(|rule| (rule.layer_rank(), rule.selector.specificity(), rule.order));
+        for rule in &matches {
+            rule.matched.store(true, Ordering::Relaxed);
+            merge_maps(&mut props, &rule.declarations);
+        }
+        matches.sort_by_key(|rule| {
+            (
+                Reverse(rule.layer_rank()),
+                rule.selector.specificity(),
+                rule.order,
+            )
         });
         for rule in matches {
-            merge_maps(&mut props, &rule.declarations);
+            merge_maps(&mut props, &rule.important);
+        }
+        ComputedStyle::from_props(resolve_values(&props))
+    }
+
+    pub fn lint(&self) -> Vec<StyleDiagnostic> {
+        let mut diagnostics = Vec::new();
+        lint_declarations(":root", &self.root, &mut diagnostics);
+        for (_, declarations) in &self.media_roots {
+            lint_declarations(":root", declarations, &mut diagnostics);
         }
-        ComputedStyle::from_props(props)
+        for rule in &self.rules {
+            lint_declarations(&rule.source, &rule.declarations, &mut diagnostics);
+            lint_declarations(&rule.source, &rule.important, &mut diagnostics);
+        }
+        diagnostics.extend(self.unmatched_selectors());
+        diagnostics
+    }
+
+    pub fn unmatched_selectors(&self) -> Vec<StyleDiagnostic> {
+        self.rules
+            .iter()
+            .filter(|rule| !rule.matched.load(Ordering::Relaxed))
+            .map(|rule| StyleDiagnostic::UnmatchedSelector {
+                selector: rule.source.clone(),
+            })
+            .collect()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.root.is_empty() && self.rules.is_empty()
+        self.root.is_empty() && self.media_roots.is_empty() && self.rules.is_empty()
     }
 }
 
 #[derive(Clone, Debug)]
 struct StyleRule {
+    source: String,
     selector: Selector,
     declarations: HashMap<String, String>,
+    important: HashMap<String, String>,
+    media: Option<MediaQuery>,
+    layer: Option<usize>,
     order: usize,
+    matched: Arc<AtomicBool>,
+}
+
+impl StyleRule {
+    fn layer_rank(&self) -> usize {
+        self.layer.unwrap_or(usize::MAX)
+    }
 }
 
 #[derive(Clone, Debug, Default)]
 struct Selector {
+    compounds: Vec<Compound>,
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+impl Selector {
+    fn parse(raw: &str) -> Result<Self> {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            return Err(anyhow!("empty selector"));
+        }
+        let mut selector = Selector::default();
+        let mut pending = None;
+        for token in trimmed.replace('>', " > ").split_whitespace() {
+            if token == ">" {
+                if selector.compounds.is_empty() || pending.is_some() {
+                    return Err(anyhow!("unexpected `>` in selector `{trimmed}`"));
+                }
+                pending = Some(Combinator::Child);
+                continue;
+            }
+            if !selector.compounds.is_empty() {
+                selector
+                    .combinators
+                    .push(pending.take().unwrap_or(Combinator::Descendant));
+            }
+            selector.compounds.push(Compound::parse(token)?);
+        }
+        if pending.is_some() {
+            return Err(anyhow!("selector `{trimmed}` ends with `>`"));
+        }
+        Ok(selector)
+    }
+
+    fn matches(&self, query: &StyleQuery<'_>) -> bool {
+        let Some((subject, rest)) = self.compounds.split_last() else {
+            return false;
+        };
+        subject.matches(query.element, query.id, query.classes, query.state)
+            && self.matches_ancestors(rest, query.ancestors)
+    }
+
+    fn matches_ancestors(&self, compounds: &[Compound], ancestors: &[StyleNode]) -> bool {
+        let Some((target, rest)) = compounds.split_last() else {
+            return true;
+        };
+        let matches_at = |index: usize| {
+            let node = &ancestors[index];
+            target.matches(&node.element, node.id.as_deref(), &node.classes, node.state)
+                && self.matches_ancestors(rest, &ancestors[..index])
+        };
+        match self.combinators[compounds.len() - 1] {
+            Combinator::Child => !ancestors.is_empty() && matches_at(ancestors.len() - 1),
+            Combinator::Descendant => (0..ancestors.len()).rev().any(matches_at),
+        }
+    }
+
+    fn specificity(&self) -> (u8, u8, u8) {
+        self.compounds.iter().fold((0, 0, 0), |total, compound| {
+            let (ids, classes, elements) = compound.specificity();
+            (
+                total.0.saturating_add(ids),
+                total.1.saturating_add(classes),
+                total.2.saturating_add(elements),
+            )
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Compound {
     element: Option<String>,
     id: Option<String>,
     class: Option<String>,
+    pseudo: Vec<PseudoClass>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PseudoClass {
+    Focus,
+    Hover,
+    Disabled,
+    Selected,
+}
+
+impl PseudoClass {
+    fn parse(raw: &str) -> Result<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "focus" => Ok(Self::Focus),
+            "hover" => Ok(Self::Hover),
+            "disabled" => Ok(Self::Disabled),
+            "selected" => Ok(Self::Selected),
+            _ => Err(anyhow!("unsupported pseudo-class `:{raw}`")),
+        }
+    }
+
+    fn matches(self, state: StyleState) -> bool {
+        match self {
+            Self::Focus => state.focus,
+            Self::Hover => state.hover,
+            Self::Disabled => state.disabled,
+            Self::Selected => state.selected,
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -105,40 +447,41 @@ enum SegmentTarget {
     Element,
     Id,
     Class,
+    Pseudo,
 }
 
-impl Selector {
+impl Compound {
     fn parse(raw: &str) -> Result<Self> {
-        let trimmed = raw.trim();
-        if trimmed.is_empty() {
-            return Err(anyhow!("empty selector"));
-        }
-        let mut selector = Selector::default();
+        let mut compound = Compound::default();
         let mut current = String::new();
         let mut mode = SegmentTarget::Element;
-        for ch in trimmed.chars() {
+        for ch in raw.chars() {
             match ch {
                 '#' => {
-                    selector.push_segment(&mut current, mode)?;
+                    compound.push_segment(&mut current, mode)?;
                     mode = SegmentTarget::Id;
                 }
                 '.' => {
-                    selector.push_segment(&mut current, mode)?;
+                    compound.push_segment(&mut current, mode)?;
                     mode = SegmentTarget::Class;
                 }
+                ':' => {
+                    compound.push_segment(&mut current, mode)?;
+                    mode = SegmentTarget::Pseudo;
+                }
                 _ => current.push(ch),
             }
         }
-        selector.push_segment(&mut current, mode)?;
-        if selector
+        compound.push_segment(&mut current, mode)?;
+        if compound
             .element
             .as_ref()
             .map(|s| s.is_empty())
             .unwrap_or(false)
         {
-            selector.element = None;
+            compound.element = None;
         }
-        Ok(selector)
+        Ok(compound)
     }
 
     fn push_segment(&mut self, buffer: &mut String, mode: SegmentTarget) -> Result<()> {
@@ -167,46 +510,70 @@ impl Selector {
                 }
                 self.class = Some(value.to_ascii_lowercase());
             }
+            SegmentTarget::Pseudo => {
+                let pseudo = PseudoClass::parse(&value)?;
+                if !self.pseudo.contains(&pseudo) {
+                    self.pseudo.push(pseudo);
+                }
+            }
         }
         buffer.clear();
         Ok(())
     }
 
-    fn matches(&self, query: &StyleQuery<'_>) -> bool {
-        if let Some(element) = self.element.as_ref() {
-            if query.element.is_empty() {
+    fn matches(
+        &self,
+        element: &str,
+        id: Option<&str>,
+        classes: &[impl AsRef<str>],
+        state: StyleState,
+    ) -> bool {
+        if let Some(expected) = self.element.as_ref() {
+            if element.is_empty() {
                 return false;
             }
-            if !element.eq_ignore_ascii_case(query.element) {
+            if !expected.eq_ignore_ascii_case(element) {
                 return false;
             }
         }
-        if let Some(id) = self.id.as_ref() {
-            if query.id != Some(id.as_str()) {
+        if let Some(expected) = self.id.as_ref() {
+            if id != Some(expected.as_str()) {
                 return false;
             }
         }
         if let Some(class) = self.class.as_ref() {
-            if !query
-                .classes
+            if !classes
                 .iter()
-                .any(|candidate| candidate.eq_ignore_ascii_case(class))
+                .any(|candidate| candidate.as_ref().eq_ignore_ascii_case(class))
             {
                 return false;
             }
         }
-        true
+        self.pseudo.iter().all(|pseudo| pseudo.matches(state))
     }
 
     fn specificity(&self) -> (u8, u8, u8) {
         (
             if self.id.is_some() { 1 } else { 0 },
-            if self.class.is_some() { 1 } else { 0 },
+            u8::from(self.class.is_some()).saturating_add(self.pseudo.len() as u8),
             if self.element.is_some() { 1 } else { 0 },
         )
     }
 }
 
+fn closing_brace(input: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, ch) in input.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(index),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
 fn merge_maps(into: &mut HashMap<String, String>, from: &HashMap<String, String>) {
     for (key, value) in from {
         into.insert(key.to_ascii_lowercase(), value.clone());
@@ -0,0 +1,28 @@
+use crate::styles::calc::{evaluate, evaluate_calcs};
+
+#[test]
+fn evaluates_arithmetic_with_precedence() {
+    assert_eq!(evaluate("100 - 60"), Some(40.0));
+    assert_eq!(evaluate("2 + 3 * 4"), Some(14.0));
+    assert_eq!(evaluate("(2 + 3) * 4"), Some(20.0));
+    assert_eq!(evaluate("-(1.5 + 0.5) / 4"), Some(-0.5));
+    assert_eq!(evaluate("calc(10 / 2) + 1"), Some(6.0));
+}
+
+#[test]
+fn rejects_malformed_expressions() {
+    assert_eq!(evaluate("1 / 0"), None);
+    assert_eq!(evaluate("1 +"), None);
+    assert_eq!(evaluate("(1 + 2"), None);
+    assert_eq!(evaluate("10px + 2"), None);
+}
+
+#[test]
+fn replaces_calc_calls_within_values() {
+    assert_eq!(evaluate_calcs("calc(100 - 60)").as_deref(), Some("40"));
+    assert_eq!(
+        evaluate_calcs("1 calc(4 / 2) calc(1 / 4)").as_deref(),
+        Some("1 2 0.25")
+    );
+    assert_eq!(evaluate_calcs("calc(1 +").as_deref(), None);
+}
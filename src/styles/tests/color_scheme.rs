@@ -0,0 +1,24 @@
+use crate::styles::ColorScheme;
+
+#[test]
+fn reads_osc11_replies() {
+    let dark = "\x1b]11;rgb:1e1e/1e1e/2e2e\x07\x1b[?62;c";
+    assert_eq!(ColorScheme::from_osc11(dark), Some(ColorScheme::Dark));
+    let light = "\x1b]11;rgb:fdfd/f6f6/e3e3\x1b\\";
+    assert_eq!(ColorScheme::from_osc11(light), Some(ColorScheme::Light));
+    assert_eq!(
+        ColorScheme::from_osc11("\x1b]11;rgb:f/f/f\x07"),
+        Some(ColorScheme::Light)
+    );
+    assert_eq!(ColorScheme::from_osc11("\x1b[?62;c"), None);
+}
+
+#[test]
+fn falls_back_to_colorfgbg() {
+    assert_eq!(ColorScheme::from_colorfgbg("15;0"), Some(ColorScheme::Dark));
+    assert_eq!(
+        ColorScheme::from_colorfgbg("0;default;15"),
+        Some(ColorScheme::Light)
+    );
+    assert_eq!(ColorScheme::from_colorfgbg("default"), None);
+}
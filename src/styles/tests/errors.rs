@@ -1,4 +1,4 @@
-use crate::styles::Stylesheet;
+use crate::styles::{StyleDiagnostic, StyleParseError, StyleQuery, Stylesheet};
 
 #[test]
 fn parse_fails_when_selector_repeats_id_segment() {
@@ -13,3 +13,76 @@ fn parse_fails_when_selector_repeats_class_segment() {
     let err = Stylesheet::parse(css).expect_err("expected duplicate class failure");
     assert!(err.to_string().contains("selector already has class"));
 }
+
+#[test]
+fn parse_fails_on_unknown_media_features() {
+    let css = "@media (orientation: landscape) { panel { color: red; } }";
+    let err = Stylesheet::parse(css).expect_err("expected media feature failure");
+    assert!(err.to_string().contains("unsupported media feature"));
+}
+
+#[test]
+fn lint_reports_unknown_properties_and_invalid_colors() {
+    let css = r#"
+        button { colour: red; color: blurple; border-color: var(--accent-color); --size: 3; }
+    "#;
+    let sheet = Stylesheet::parse(css).expect("stylesheet should parse");
+    let diagnostics = sheet.lint();
+    assert!(diagnostics.contains(&StyleDiagnostic::UnknownProperty {
+        selector: "button".into(),
+        property: "colour".into(),
+    }));
+    assert!(diagnostics.contains(&StyleDiagnostic::InvalidColor {
+        selector: "button".into(),
+        property: "color".into(),
+        value: "blurple".into(),
+    }));
+    assert_eq!(
+        diagnostics
+            .iter()
+            .filter(|diagnostic| !matches!(diagnostic, StyleDiagnostic::UnmatchedSelector { .. }))
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn unmatched_selectors_clear_once_queried() {
+    let sheet = Stylesheet::parse("button { color: red; } #missing { color: blue; }")
+        .expect("stylesheet should parse");
+    assert_eq!(sheet.unmatched_selectors().len(), 2);
+    sheet.query(StyleQuery::element("button"));
+    assert_eq!(
+        sheet.unmatched_selectors(),
+        vec![StyleDiagnostic::UnmatchedSelector {
+            selector: "#missing".into()
+        }]
+    );
+}
+
+#[test]
+fn parse_rejects_imports_outside_files() {
+    let err = Stylesheet::parse("@import \"base.css\";\nbutton { color: red; }")
+        .expect_err("expected import failure");
+    assert!(err.to_string().contains("@import"));
+}
+
+#[test]
+fn parse_fails_on_nested_layers() {
+    let css = "@layer base { @layer inner { button { color: red; } } }";
+    let err = Stylesheet::parse(css).expect_err("expected nested layer failure");
+    assert!(err.to_string().contains("nested @layer"));
+}
+
+#[test]
+fn parse_errors_point_at_the_offending_line() {
+    let css = "/* base\n   rules */\nbutton { color: red; }\n  panel > { gap: 1; }\n";
+    let err = Stylesheet::parse(css).expect_err("expected selector failure");
+    let located = err
+        .downcast_ref::<StyleParseError>()
+        .expect("parse error carries a location");
+    assert_eq!((located.line, located.column), (4, 3));
+    assert_eq!(located.source_line, "  panel > { gap: 1; }");
+    assert!(located.message.contains("ends with `>`"));
+    assert!(err.to_string().contains("4 |   panel > { gap: 1; }"));
+}
@@ -1,3 +1,6 @@
+mod calc;
+mod color_scheme;
 mod errors;
 mod parser;
 mod stylesheet;
+mod theme;
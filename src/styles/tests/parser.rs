@@ -1,5 +1,5 @@
 use crate::runtime::Color;
-use crate::styles::parser::{parse_color, parse_declarations, strip_comments};
+use crate::styles::parser::{format_color, parse_color, parse_declarations, strip_comments};
 
 #[test]
 fn strips_block_comments() {
@@ -20,3 +20,35 @@ fn parses_hex_and_rgb_colors() {
     assert_eq!(parse_color("#0f0"), Some(Color::Rgb(0, 255, 0)));
     assert_eq!(parse_color("rgb(10,20,30)"), Some(Color::Rgb(10, 20, 30)));
 }
+
+#[test]
+fn parses_indexed_hsl_and_extended_named_colors() {
+    assert_eq!(parse_color("ansi(196)"), Some(Color::Indexed(196)));
+    assert_eq!(parse_color("42"), Some(Color::Indexed(42)));
+    assert_eq!(parse_color("256"), None);
+    assert_eq!(
+        parse_color("hsl(0, 100%, 50%)"),
+        Some(Color::Rgb(255, 0, 0))
+    );
+    assert_eq!(
+        parse_color("hsl(210deg, 50%, 40%)"),
+        Some(Color::Rgb(51, 102, 153))
+    );
+    assert_eq!(parse_color("LightRed"), Some(Color::LightRed));
+    assert_eq!(parse_color("dark-gray"), Some(Color::DarkGray));
+    assert_eq!(parse_color("light_cyan"), Some(Color::LightCyan));
+    assert_eq!(parse_color("reset"), Some(Color::Reset));
+}
+
+#[test]
+fn formatted_colors_parse_back() {
+    for color in [
+        Color::Rgb(1, 2, 3),
+        Color::Indexed(208),
+        Color::LightMagenta,
+        Color::DarkGray,
+        Color::Reset,
+    ] {
+        assert_eq!(parse_color(&format_color(color)), Some(color));
+    }
+}
@@ -1,5 +1,7 @@
-use crate::runtime::Color;
-use crate::styles::{StyleQuery, Stylesheet};
+use ratatui::style::Style;
+
+use crate::runtime::{BorderStyle, Color, Modifier, Spacing};
+use crate::styles::{ColorScheme, LayoutStyle, StyleNode, StyleQuery, StyleState, Stylesheet};
 
 #[test]
 fn parses_stylesheet_and_applies_root_properties() {
@@ -35,3 +37,418 @@ fn specificity_and_order_control_overrides() {
     assert_eq!(style.color("color"), Some(Color::Green));
     assert_eq!(style.u16("border"), Some(1));
 }
+
+#[test]
+fn spacing_accepts_css_shorthand() {
+    let css = r"
+        a { padding: 2; }
+        b { padding: 1 3; }
+        c { margin: 1, 2, 3; }
+        d { margin: 1 2 3 4; }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    let spacing = |element, name| sheet.query(StyleQuery::element(element)).spacing(name);
+    assert_eq!(spacing("a", "padding"), Some(Spacing::all(2)));
+    assert_eq!(spacing("b", "padding"), Some(Spacing::symmetric(1, 3)));
+    assert_eq!(spacing("c", "margin"), Some(Spacing::new(1, 2, 3, 2)));
+    assert_eq!(spacing("d", "margin"), Some(Spacing::new(1, 2, 3, 4)));
+    assert_eq!(spacing("a", "margin"), None);
+}
+
+#[test]
+fn layout_reads_sizes_and_spacing_by_id_and_class() {
+    let css = r"
+        flex#panels { gap: 1; padding: 0 1; }
+        .cards { flex-grow: 2; width: 40; }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    let classes = ["cards"];
+    let layout = sheet
+        .query(
+            StyleQuery::element("flex")
+                .with_id("panels")
+                .with_classes(&classes),
+        )
+        .layout();
+    assert_eq!(
+        layout,
+        LayoutStyle {
+            width: Some(40),
+            gap: Some(1),
+            padding: Some(Spacing::symmetric(0, 1)),
+            flex_grow: Some(2),
+            ..LayoutStyle::default()
+        }
+    );
+    let block = sheet
+        .query(StyleQuery::element("block").with_id("panels"))
+        .layout();
+    assert_eq!(block, LayoutStyle::default());
+}
+
+#[test]
+fn combinators_match_against_the_ancestor_chain() {
+    let css = r"
+        list { color: white; }
+        panel list { color: cyan; }
+        panel > list { --max-items: 3; }
+        block#logs > flex list { color: red; }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    let query = |ancestors: &[StyleNode]| {
+        sheet.query(StyleQuery::element("list").with_ancestors(ancestors))
+    };
+
+    let direct = [StyleNode::new("panel")];
+    assert_eq!(query(&direct).color("color"), Some(Color::Cyan));
+    assert_eq!(query(&direct).u16("--max-items"), Some(3));
+
+    let nested = [StyleNode::new("panel"), StyleNode::new("flex")];
+    assert_eq!(query(&nested).color("color"), Some(Color::Cyan));
+    assert_eq!(query(&nested).u16("--max-items"), None);
+
+    let logs = [
+        StyleNode::new("block").with_id("logs"),
+        StyleNode::new("flex"),
+        StyleNode::new("stats"),
+    ];
+    assert_eq!(query(&logs).color("color"), Some(Color::Red));
+    assert_eq!(query(&[]).color("color"), Some(Color::White));
+}
+
+#[test]
+fn rejects_dangling_child_combinators() {
+    assert!(Stylesheet::parse("> list { color: red; }").is_err());
+    assert!(Stylesheet::parse("panel > { color: red; }").is_err());
+}
+
+#[test]
+fn pseudo_classes_match_query_state() {
+    let css = r"
+        input { --border-color: gray; }
+        input:focus { --border-color: cyan; }
+        button:hover { --filled: true; }
+        button:hover:disabled { --filled: false; }
+        form:focus input { color: yellow; }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+
+    let input = StyleQuery::element("input");
+    assert_eq!(
+        sheet.query(input).color("--border-color"),
+        Some(Color::Gray)
+    );
+    assert_eq!(
+        sheet.query(input.focused(true)).color("--border-color"),
+        Some(Color::Cyan)
+    );
+
+    let button = StyleQuery::element("button");
+    assert_eq!(sheet.query(button).bool("--filled"), None);
+    assert_eq!(
+        sheet.query(button.hovered(true)).bool("--filled"),
+        Some(true)
+    );
+    assert_eq!(
+        sheet
+            .query(button.hovered(true).disabled(true))
+            .bool("--filled"),
+        Some(false)
+    );
+
+    let focused_form = [StyleNode::new("form").with_state(StyleState {
+        focus: true,
+        ..StyleState::default()
+    })];
+    assert_eq!(
+        sheet
+            .query(input.with_ancestors(&focused_form))
+            .color("color"),
+        Some(Color::Yellow)
+    );
+    assert!(Stylesheet::parse("input:visited { color: red; }").is_err());
+}
+
+#[test]
+fn var_references_resolve_against_the_cascade() {
+    let css = r"
+        :root { --accent-color: cyan; --gap: 2; }
+        button { color: var(--accent-color); --label: var(--missing, plain); }
+        button#save { --accent-color: #ff0000; padding: var(--gap) var(--pad, var(--gap)); }
+        button.broken { color: var(--missing); --loop: var(--loop); }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+
+    let button = sheet.query(StyleQuery::element("button"));
+    assert_eq!(button.color("color"), Some(Color::Cyan));
+    assert_eq!(button.text("--label"), Some("plain"));
+
+    let save = sheet.query(StyleQuery::element("button").with_id("save"));
+    assert_eq!(save.color("color"), Some(Color::Rgb(255, 0, 0)));
+    assert_eq!(save.spacing("padding"), Some(Spacing::all(2)));
+
+    let broken = sheet.query(StyleQuery::element("button").with_classes(&["broken"]));
+    assert_eq!(broken.color("color"), None);
+    assert_eq!(broken.text("--loop"), None);
+    assert_eq!(sheet.root().u16("--gap"), Some(2));
+}
+
+#[test]
+fn calc_values_feed_numeric_accessors() {
+    let css = r"
+        :root { --gutter: 2; }
+        form { --label-width: calc(100 - 60); padding: calc(var(--gutter) / 2) var(--gutter); }
+        table { --column-widths: 20 calc(100 - 20 - 30) 30; width: calc(1 / 0); }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+
+    let form = sheet.query(StyleQuery::element("form"));
+    assert_eq!(form.u16("--label-width"), Some(40));
+    assert_eq!(form.spacing("padding"), Some(Spacing::symmetric(1, 2)));
+
+    let table = sheet.query(StyleQuery::element("table"));
+    assert_eq!(table.list_u16("--column-widths"), Some(vec![20, 50, 30]));
+    assert_eq!(table.get("width"), None);
+}
+
+#[test]
+fn media_queries_follow_the_viewport() {
+    let css = r"
+        :root { --gap: 2; }
+        panel { padding: var(--gap); }
+        @media (max-width: 100) {
+            :root { --gap: 0; }
+            panel { --decorations: false; }
+        }
+        @media (min-width: 60) and (max-height: 20) {
+            panel { color: red; }
+        }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    let panel = |sheet: &Stylesheet| sheet.query(StyleQuery::element("panel"));
+
+    assert_eq!(panel(&sheet).spacing("padding"), Some(Spacing::all(2)));
+    assert_eq!(panel(&sheet).bool("--decorations"), None);
+
+    let wide = sheet.clone().with_viewport(120, 40);
+    assert_eq!(panel(&wide).spacing("padding"), Some(Spacing::all(2)));
+    assert_eq!(panel(&wide).color("color"), None);
+
+    let compact = sheet.clone().with_viewport(80, 20);
+    assert_eq!(panel(&compact).spacing("padding"), Some(Spacing::all(0)));
+    assert_eq!(panel(&compact).bool("--decorations"), Some(false));
+    assert_eq!(panel(&compact).color("color"), Some(Color::Red));
+    assert_eq!(compact.root().u16("--gap"), Some(0));
+}
+
+#[test]
+fn prefers_color_scheme_selects_media_blocks() {
+    let css = r"
+        :root { --surface: black; }
+        @media (prefers-light) {
+            :root { --surface: white; }
+        }
+        @media (prefers-color-scheme: dark) and (max-width: 100) {
+            panel { --compact: true; }
+        }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    assert_eq!(sheet.root().color("--surface"), Some(Color::Black));
+
+    let light = sheet.clone().with_color_scheme(ColorScheme::Light);
+    assert_eq!(light.root().color("--surface"), Some(Color::White));
+
+    let dark = sheet
+        .with_color_scheme(ColorScheme::Dark)
+        .with_viewport(80, 24);
+    assert_eq!(dark.root().color("--surface"), Some(Color::Black));
+    assert_eq!(
+        dark.query(StyleQuery::element("panel")).bool("--compact"),
+        Some(true)
+    );
+}
+
+#[test]
+fn cached_queries_follow_state_ancestors_and_environment() {
+    let css = r"
+        button { color: white; }
+        button:hover { color: cyan; }
+        panel button { --nested: true; }
+        @media (max-width: 80) { button { --compact: true; } }
+    ";
+    let mut sheet = Stylesheet::parse(css).expect("parse css");
+    sheet.set_viewport(120, 40);
+    let button = StyleQuery::element("button");
+
+    for _ in 0..2 {
+        assert_eq!(sheet.query(button).color("color"), Some(Color::White));
+        assert_eq!(
+            sheet.query(button.hovered(true)).color("color"),
+            Some(Color::Cyan)
+        );
+        let panel = [StyleNode::new("panel")];
+        assert_eq!(
+            sheet.query(button.with_ancestors(&panel)).bool("--nested"),
+            Some(true)
+        );
+        assert_eq!(sheet.query(button).bool("--nested"), None);
+    }
+
+    assert_eq!(sheet.query(button).bool("--compact"), None);
+    sheet.set_viewport(70, 40);
+    assert_eq!(sheet.query(button).bool("--compact"), Some(true));
+}
+
+#[test]
+fn from_file_inlines_imports_relative_to_each_file() {
+    let root = std::env::temp_dir().join(format!("rustact-imports-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("theme")).expect("create dirs");
+    std::fs::write(
+        root.join("theme/base.css"),
+        "@import url(\"../app.css\");\n:root { gap: 1; } button { color: red; }",
+    )
+    .expect("write base");
+    std::fs::write(
+        root.join("app.css"),
+        "@import 'theme/base.css';\nbutton { color: blue; }",
+    )
+    .expect("write app");
+
+    let sheet = Stylesheet::from_file(root.join("app.css")).expect("load stylesheet");
+    let button = sheet.query(StyleQuery::element("button"));
+    assert_eq!(sheet.root().u16("gap"), Some(1));
+    assert_eq!(button.color("color"), Some(Color::Blue));
+
+    std::fs::remove_dir_all(root).ok();
+}
+
+#[test]
+fn extend_appends_rules_after_existing_ones() {
+    let mut sheet = Stylesheet::parse(
+        ":root { gap: 1; padding: 2; } button { color: red; accent-color: green; }",
+    )
+    .expect("parse base");
+    sheet
+        .extend(Stylesheet::parse(":root { gap: 3; } button { color: blue; }").expect("parse app"));
+
+    let root = sheet.root();
+    assert_eq!(root.u16("gap"), Some(3));
+    assert_eq!(root.u16("padding"), Some(2));
+    let button = sheet.query(StyleQuery::element("button"));
+    assert_eq!(button.color("color"), Some(Color::Blue));
+    assert_eq!(button.color("accent-color"), Some(Color::Green));
+}
+
+#[test]
+fn important_declarations_beat_more_specific_rules() {
+    let css = r"
+        button { color: red !important; accent-color: red; }
+        button#save.primary { color: blue; accent-color: blue; }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    let style = sheet.query(
+        StyleQuery::element("button")
+            .with_id("save")
+            .with_classes(&["primary"]),
+    );
+    assert_eq!(style.color("color"), Some(Color::Red));
+    assert_eq!(style.color("accent-color"), Some(Color::Blue));
+}
+
+#[test]
+fn later_layers_and_unlayered_rules_win() {
+    let css = r"
+        @layer base, app;
+        @layer app { button { color: green; } }
+        @layer base {
+            #save { color: red; accent-color: red !important; }
+            button { border-color: red; }
+        }
+        button { border-color: blue; }
+        @layer app { button#save { accent-color: green !important; } }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    let style = sheet.query(StyleQuery::element("button").with_id("save"));
+    assert_eq!(style.color("color"), Some(Color::Green));
+    assert_eq!(style.color("border-color"), Some(Color::Blue));
+    assert_eq!(style.color("accent-color"), Some(Color::Red));
+}
+
+#[test]
+fn extend_keeps_layer_order_by_name() {
+    let mut sheet = Stylesheet::parse("@layer base, app; @layer app { button { color: blue; } }")
+        .expect("parse app");
+    sheet.extend(
+        Stylesheet::parse("@layer base { button#save { color: red; } }").expect("parse base"),
+    );
+    let style = sheet.query(StyleQuery::element("button").with_id("save"));
+    assert_eq!(style.color("color"), Some(Color::Blue));
+}
+
+#[test]
+fn border_style_is_part_of_layout() {
+    let css = "block#logs { border-style: Rounded; } block#raw { border-style: dashed; }";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    let logs = sheet.query(StyleQuery::element("block").with_id("logs"));
+    assert_eq!(logs.layout().border_style, Some(BorderStyle::Rounded));
+    let raw = sheet.query(StyleQuery::element("block").with_id("raw"));
+    assert_eq!(raw.layout().border_style, None);
+}
+
+#[test]
+fn text_properties_map_to_modifiers() {
+    let css = r"
+        text.title { font-weight: bold; text-decoration: underline line-through; }
+        text.hint { font-weight: 300; font-style: italic; }
+        text.plain { font-weight: normal; font-style: normal; text-decoration: none; }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    let modifiers = |class: &str| {
+        sheet
+            .query(StyleQuery::element("text").with_classes(&[class]))
+            .modifiers()
+    };
+    assert_eq!(
+        modifiers("title"),
+        Modifier::BOLD | Modifier::UNDERLINED | Modifier::CROSSED_OUT
+    );
+    assert_eq!(modifiers("hint"), Modifier::DIM | Modifier::ITALIC);
+    assert_eq!(modifiers("plain"), Modifier::empty());
+}
+
+#[test]
+fn selected_item_rules_patch_highlight_style() {
+    let css = r"
+        list .item:selected { color: magenta; font-style: italic; }
+        table#jobs .row:selected { background-color: blue; }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    let fallback = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let query = |widget: StyleNode, part: &'static str, selected: bool| {
+        let ancestors = [widget];
+        let classes = [part];
+        sheet
+            .query(
+                StyleQuery::element(part)
+                    .with_classes(&classes)
+                    .with_ancestors(&ancestors)
+                    .selected(selected),
+            )
+            .patch_style(fallback)
+    };
+
+    assert_eq!(
+        query(StyleNode::new("list"), "item", true),
+        Style::default()
+            .fg(Color::Magenta)
+            .add_modifier(Modifier::ITALIC)
+    );
+    assert_eq!(query(StyleNode::new("list"), "item", false), fallback);
+    assert_eq!(
+        query(StyleNode::new("table").with_id("jobs"), "row", true),
+        fallback.bg(Color::Blue)
+    );
+    assert_eq!(query(StyleNode::new("table"), "row", true), fallback);
+}
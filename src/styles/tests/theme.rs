@@ -0,0 +1,34 @@
+use crate::runtime::Color;
+use crate::styles::{ColorScheme, StyleQuery, Stylesheet, Theme};
+
+#[test]
+fn built_in_palettes_are_selectable_by_name_and_scheme() {
+    assert_eq!(Theme::named("light"), Some(Theme::light()));
+    assert_eq!(Theme::named("High-Contrast"), Some(Theme::high_contrast()));
+    assert_eq!(Theme::named("solarized"), None);
+    assert_eq!(Theme::for_scheme(ColorScheme::Dark), Theme::default());
+
+    let sheet = Stylesheet::parse(":root { --accent-color: #ff0000; }").expect("parse css");
+    let theme = Theme::light().merge_style(&sheet.root());
+    assert_eq!(theme.accent, Color::Rgb(255, 0, 0));
+    assert_eq!(theme.danger, Theme::light().danger);
+}
+
+#[test]
+fn queries_fall_back_to_theme_tokens() {
+    let css = r"
+        :root { --warning-color: yellow; }
+        badge { color: var(--danger-color); border-color: var(--warning-color); }
+    ";
+    let sheet = Stylesheet::parse(css).expect("parse css");
+    let theme = Theme::high_contrast();
+    let style = sheet.query(StyleQuery::element("badge").with_theme(&theme));
+
+    assert_eq!(style.color("color"), Some(Color::Rgb(255, 0, 0)));
+    assert_eq!(style.color("border-color"), Some(Color::Yellow));
+    assert_eq!(style.color("--accent-color"), Some(theme.accent));
+    assert_eq!(
+        sheet.query(StyleQuery::element("badge")).color("color"),
+        None
+    );
+}
@@ -0,0 +1,114 @@
+use crate::runtime::Color;
+
+use super::color_scheme::ColorScheme;
+use super::computed::ComputedStyle;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Theme {
+    pub accent: Color,
+    pub surface: Color,
+    pub text: Color,
+    pub muted: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub danger: Color,
+    pub info: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            accent: Color::Rgb(0x5b, 0xe7, 0xff),
+            surface: Color::Rgb(0x10, 0x15, 0x22),
+            text: Color::Rgb(0xe5, 0xe7, 0xeb),
+            muted: Color::Rgb(0x7c, 0x8d, 0xab),
+            success: Color::Rgb(0x7b, 0xd8, 0x8f),
+            warning: Color::Rgb(0xff, 0xb3, 0x47),
+            danger: Color::Rgb(0xff, 0x6b, 0x6b),
+            info: Color::Rgb(0x7d, 0xd3, 0xfc),
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            accent: Color::Rgb(0x00, 0x77, 0xaa),
+            surface: Color::Rgb(0xfd, 0xf6, 0xe3),
+            text: Color::Rgb(0x1f, 0x29, 0x37),
+            muted: Color::Rgb(0x6b, 0x72, 0x80),
+            success: Color::Rgb(0x2e, 0x7d, 0x32),
+            warning: Color::Rgb(0xb4, 0x53, 0x09),
+            danger: Color::Rgb(0xc6, 0x28, 0x28),
+            info: Color::Rgb(0x1d, 0x4e, 0xd8),
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            accent: Color::Rgb(0x00, 0xff, 0xff),
+            surface: Color::Rgb(0x00, 0x00, 0x00),
+            text: Color::Rgb(0xff, 0xff, 0xff),
+            muted: Color::Rgb(0xc0, 0xc0, 0xc0),
+            success: Color::Rgb(0x00, 0xff, 0x00),
+            warning: Color::Rgb(0xff, 0xff, 0x00),
+            danger: Color::Rgb(0xff, 0x00, 0x00),
+            info: Color::Rgb(0x00, 0xaa, 0xff),
+        }
+    }
+
+    pub fn named(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    pub fn for_scheme(scheme: ColorScheme) -> Self {
+        match scheme {
+            ColorScheme::Dark => Self::dark(),
+            ColorScheme::Light => Self::light(),
+        }
+    }
+
+    pub fn merge_style(mut self, style: &ComputedStyle) -> Self {
+        for (name, slot) in self.slots_mut() {
+            if let Some(color) = style.color(name) {
+                *slot = color;
+            }
+        }
+        self
+    }
+
+    pub fn tokens(&self) -> [(&'static str, Color); 8] {
+        [
+            ("--accent-color", self.accent),
+            ("--surface-color", self.surface),
+            ("--text-color", self.text),
+            ("--muted-color", self.muted),
+            ("--success-color", self.success),
+            ("--warning-color", self.warning),
+            ("--danger-color", self.danger),
+            ("--info-color", self.info),
+        ]
+    }
+
+    fn slots_mut(&mut self) -> [(&'static str, &mut Color); 8] {
+        [
+            ("--accent-color", &mut self.accent),
+            ("--surface-color", &mut self.surface),
+            ("--text-color", &mut self.text),
+            ("--muted-color", &mut self.muted),
+            ("--success-color", &mut self.success),
+            ("--warning-color", &mut self.warning),
+            ("--danger-color", &mut self.danger),
+            ("--info-color", &mut self.info),
+        ]
+    }
+}
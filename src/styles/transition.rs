@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use crate::hooks::Easing;
+use crate::runtime::Color;
+
+use super::parser::{format_color, parse_color};
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct TransitionSpec {
+    pub(crate) property: String,
+    pub(crate) duration: Duration,
+    pub(crate) easing: Easing,
+}
+
+impl TransitionSpec {
+    pub(crate) fn parse_list(value: &str) -> Vec<Self> {
+        value.split(',').filter_map(Self::parse).collect()
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        let mut parts = raw.split_whitespace();
+        let property = parts.next()?.to_ascii_lowercase();
+        let mut duration = None;
+        let mut easing = Easing::default();
+        for part in parts {
+            if let Some(value) = parse_duration(part) {
+                duration.get_or_insert(value);
+            } else if let Some(value) = parse_easing(part) {
+                easing = value;
+            }
+        }
+        Some(Self {
+            property,
+            duration: duration?,
+            easing,
+        })
+    }
+
+    pub(crate) fn applies_to(&self, property: &str) -> bool {
+        self.property == "all" || self.property == property
+    }
+}
+
+fn parse_duration(raw: &str) -> Option<Duration> {
+    let seconds = match raw.strip_suffix("ms") {
+        Some(millis) => millis.parse::<f64>().ok()? / 1000.0,
+        None => raw.strip_suffix('s')?.parse::<f64>().ok()?,
+    };
+    (seconds.is_finite() && seconds >= 0.0).then(|| Duration::from_secs_f64(seconds))
+}
+
+fn parse_easing(raw: &str) -> Option<Easing> {
+    match raw.to_ascii_lowercase().as_str() {
+        "linear" => Some(Easing::Linear),
+        "ease-in" => Some(Easing::EaseIn),
+        "ease-out" => Some(Easing::EaseOut),
+        "ease" | "ease-in-out" => Some(Easing::EaseInOut),
+        "ease-out-cubic" => Some(Easing::EaseOutCubic),
+        _ => None,
+    }
+}
+
+pub(crate) fn is_animatable(value: &str) -> bool {
+    value.trim().parse::<f64>().is_ok() || matches!(parse_color(value), Some(Color::Rgb(..)))
+}
+
+pub(crate) fn interpolate(from: &str, to: &str, progress: f64) -> String {
+    if progress >= 1.0 {
+        return to.to_string();
+    }
+    if let (Ok(start), Ok(end)) = (from.trim().parse::<f64>(), to.trim().parse::<f64>()) {
+        let value = start + (end - start) * progress;
+        let integral = !from.contains('.') && !to.contains('.');
+        return if integral {
+            format!("{}", value.round() as i64)
+        } else {
+            format!("{value}")
+        };
+    }
+    if let (Some(Color::Rgb(r1, g1, b1)), Some(Color::Rgb(r2, g2, b2))) =
+        (parse_color(from), parse_color(to))
+    {
+        let channel =
+            |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * progress).round() as u8;
+        return format_color(Color::Rgb(
+            channel(r1, r2),
+            channel(g1, g2),
+            channel(b1, b2),
+        ));
+    }
+    to.to_string()
+}
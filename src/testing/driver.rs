@@ -0,0 +1,133 @@
+use std::future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use parking_lot::Mutex;
+use tokio::runtime::{Handle, RuntimeFlavor};
+use tokio::sync::{Notify, mpsc};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+use crate::events::FrameworkEvent;
+use crate::runtime::{AppMessage, RuntimeDriver};
+
+const SETTLE_YIELDS: usize = 32;
+const START_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Default)]
+pub struct ManualDriver {
+    clock: Arc<Mutex<ManualClock>>,
+    started: Arc<Notify>,
+}
+
+#[derive(Default)]
+struct ManualClock {
+    tx: Option<mpsc::Sender<AppMessage>>,
+    rate: Duration,
+    ticks: u64,
+    frozen: bool,
+}
+
+impl ManualDriver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ticks(&self) -> u64 {
+        self.clock.lock().ticks
+    }
+
+    pub async fn advance_ticks(&self, count: u32) -> anyhow::Result<()> {
+        for _ in 0..count {
+            let (tx, rate) = self.sender().await?;
+            if self.clock.lock().frozen {
+                tokio::time::advance(rate).await;
+            }
+            tx.send(AppMessage::ExternalEvent(FrameworkEvent::Tick))
+                .await
+                .map_err(|_| anyhow!("runtime stopped"))?;
+            self.clock.lock().ticks += 1;
+            settle().await;
+        }
+        Ok(())
+    }
+
+    pub async fn send(&self, event: FrameworkEvent) -> anyhow::Result<()> {
+        let (tx, _) = self.sender().await?;
+        tx.send(AppMessage::ExternalEvent(event))
+            .await
+            .map_err(|_| anyhow!("runtime stopped"))?;
+        settle().await;
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) -> anyhow::Result<()> {
+        let (tx, _) = self.sender().await?;
+        tx.send(AppMessage::Shutdown)
+            .await
+            .map_err(|_| anyhow!("runtime stopped"))
+    }
+
+    async fn sender(&self) -> anyhow::Result<(mpsc::Sender<AppMessage>, Duration)> {
+        loop {
+            let started = self.started.notified();
+            {
+                let clock = self.clock.lock();
+                if let Some(tx) = &clock.tx {
+                    return Ok((tx.clone(), clock.rate));
+                }
+            }
+            tokio::time::timeout(START_TIMEOUT, started)
+                .await
+                .map_err(|_| anyhow!("the app has not started with this driver"))?;
+        }
+    }
+}
+
+impl RuntimeDriver for ManualDriver {
+    fn spawn_terminal_events(&self, _tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+        tokio::spawn(future::pending())
+    }
+
+    fn spawn_tick_loop(&self, tx: mpsc::Sender<AppMessage>, rate: Duration) -> JoinHandle<()> {
+        let mut clock = self.clock.lock();
+        clock.frozen = freeze_clock();
+        clock.tx = Some(tx);
+        clock.rate = rate;
+        self.started.notify_waiters();
+        tokio::spawn(future::pending())
+    }
+
+    fn spawn_shutdown_watcher(&self, _tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+        tokio::spawn(future::pending())
+    }
+}
+
+// Tokio can only freeze the clock on a current-thread runtime, and panics when
+// asked to freeze it twice (for example under `start_paused`).
+fn freeze_clock() -> bool {
+    if Handle::current().runtime_flavor() != RuntimeFlavor::CurrentThread {
+        return false;
+    }
+    if !clock_is_running() {
+        return true;
+    }
+    tokio::time::pause();
+    true
+}
+
+fn clock_is_running() -> bool {
+    let start = Instant::now();
+    let real = std::time::Instant::now();
+    while real.elapsed().is_zero() {
+        std::hint::spin_loop();
+    }
+    !start.elapsed().is_zero()
+}
+
+async fn settle() {
+    for _ in 0..SETTLE_YIELDS {
+        tokio::task::yield_now().await;
+    }
+}
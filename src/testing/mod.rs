@@ -0,0 +1,201 @@
+use std::time::Duration;
+
+use anyhow::anyhow;
+use ratatui::layout::Rect;
+use tokio::time::sleep;
+
+use crate::events::{
+    FrameworkEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use crate::keymap::KeyBinding;
+use crate::renderer::{DamageStats, ExportFormat};
+use crate::runtime::{App, AppMessage, ComponentId, EnterGuard, Session, View};
+
+mod driver;
+mod snapshot;
+
+pub use driver::ManualDriver;
+pub use snapshot::{FrameText, UPDATE_SNAPSHOTS_ENV, assert_snapshot};
+
+const IDLE_ROUNDS: usize = 3;
+const MAX_ROUNDS: usize = 1000;
+
+pub struct TestApp {
+    app: App,
+    session: Session,
+    running: bool,
+}
+
+impl TestApp {
+    pub async fn new(app: App) -> anyhow::Result<Self> {
+        let mut app = app.headless();
+        let (_, session) = app.start()?;
+        let mut test = Self {
+            app,
+            session,
+            running: true,
+        };
+        test.session.dispatcher.request_render();
+        test.settle().await?;
+        Ok(test)
+    }
+
+    pub async fn press_key(&mut self, key: &str) -> anyhow::Result<()> {
+        let binding = KeyBinding::parse(key).ok_or_else(|| anyhow!("invalid key `{key}`"))?;
+        let event = KeyEvent::new(binding.code, binding.modifiers);
+        self.send(FrameworkEvent::Key(event)).await
+    }
+
+    pub async fn type_text(&mut self, text: &str) -> anyhow::Result<()> {
+        for ch in text.chars() {
+            let event = KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE);
+            self.send(FrameworkEvent::Key(event)).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn click(&mut self, x: u16, y: u16) -> anyhow::Result<()> {
+        for kind in [
+            MouseEventKind::Down(MouseButton::Left),
+            MouseEventKind::Up(MouseButton::Left),
+        ] {
+            let event = MouseEvent {
+                kind,
+                column: x,
+                row: y,
+                modifiers: KeyModifiers::NONE,
+            };
+            self.send(FrameworkEvent::Mouse(event)).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn move_mouse(&mut self, x: u16, y: u16) -> anyhow::Result<()> {
+        let event = MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: x,
+            row: y,
+            modifiers: KeyModifiers::NONE,
+        };
+        self.send(FrameworkEvent::Mouse(event)).await
+    }
+
+    pub async fn click_on(&mut self, id: &str) -> anyhow::Result<()> {
+        let area = self
+            .region(id)
+            .ok_or_else(|| anyhow!("no rendered node with id `{id}`"))?;
+        self.click(area.x + area.width / 2, area.y + area.height / 2)
+            .await
+    }
+
+    pub async fn tick(&mut self) -> anyhow::Result<()> {
+        self.send(FrameworkEvent::Tick).await
+    }
+
+    pub async fn resize(&mut self, width: u16, height: u16) -> anyhow::Result<()> {
+        self.send(FrameworkEvent::Resize(width, height)).await
+    }
+
+    pub async fn send(&mut self, event: FrameworkEvent) -> anyhow::Result<()> {
+        self.handle(AppMessage::ExternalEvent(event))?;
+        self.settle().await
+    }
+
+    pub fn view(&self) -> &View {
+        self.session.view().unwrap_or(&View::Empty)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn view_json(&self) -> String {
+        serde_json::to_string_pretty(self.view()).unwrap_or_default()
+    }
+
+    pub fn text(&self) -> String {
+        self.session.renderer.buffer_text().unwrap_or_default()
+    }
+
+    pub fn export(&self, format: ExportFormat) -> String {
+        self.session.renderer.export(format).unwrap_or_default()
+    }
+
+    #[track_caller]
+    pub fn expect_text(&self, needle: &str) -> &Self {
+        let text = self.text();
+        assert!(
+            text.contains(needle),
+            "expected `{needle}` on screen, found:\n{text}"
+        );
+        self
+    }
+
+    pub fn damage_stats(&self) -> DamageStats {
+        self.app.damage_stats()
+    }
+
+    pub fn debug_tree(&self) -> String {
+        self.app.debug_tree()
+    }
+
+    pub fn strict_mode_warnings(&self) -> Vec<ComponentId> {
+        self.app.strict_mode_warnings()
+    }
+
+    pub fn region(&self, id: &str) -> Option<Rect> {
+        self.app.region(id)
+    }
+
+    // Lets widget helpers called from the test body resolve this app's
+    // hitboxes and focus state.
+    pub fn enter(&self) -> EnterGuard {
+        self.session.dispatcher.enter()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    pub fn exit_code(&self) -> Option<i32> {
+        (!self.running).then(|| self.session.exit_code())
+    }
+
+    fn handle(&mut self, message: AppMessage) -> anyhow::Result<()> {
+        if self.running {
+            self.running = self.app.handle_message(&mut self.session, message)?;
+        }
+        Ok(())
+    }
+
+    async fn settle(&mut self) -> anyhow::Result<()> {
+        let mut idle = 0;
+        for _ in 0..MAX_ROUNDS {
+            let mut handled = false;
+            let mut render = false;
+            while let Ok(message) = self.session.rx.try_recv() {
+                handled = true;
+                match message {
+                    AppMessage::RequestRender => render = true,
+                    message => self.handle(message)?,
+                }
+            }
+            if render {
+                self.handle(AppMessage::RequestRender)?;
+            }
+            if handled {
+                idle = 0;
+                tokio::task::yield_now().await;
+                continue;
+            }
+            idle += 1;
+            if idle == IDLE_ROUNDS {
+                return Ok(());
+            }
+            sleep(Duration::from_millis(1)).await;
+        }
+        Err(anyhow!(
+            "app kept requesting renders after {MAX_ROUNDS} rounds"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
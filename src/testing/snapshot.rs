@@ -0,0 +1,142 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::runtime::App;
+
+use super::TestApp;
+
+pub const UPDATE_SNAPSHOTS_ENV: &str = "RUSTACT_UPDATE_SNAPSHOTS";
+const CONTEXT_LINES: usize = 3;
+
+pub trait FrameText {
+    fn frame_text(&mut self) -> String;
+}
+
+impl FrameText for TestApp {
+    fn frame_text(&mut self) -> String {
+        self.text()
+    }
+}
+
+impl FrameText for App {
+    fn frame_text(&mut self) -> String {
+        self.render_once_to_string()
+            .expect("render frame for snapshot")
+    }
+}
+
+#[macro_export]
+macro_rules! assert_frame_snapshot {
+    ($app:expr, $name:expr $(,)?) => {
+        $crate::testing::assert_snapshot(
+            &::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("snapshots"),
+            $name,
+            &$crate::testing::FrameText::frame_text(&mut $app),
+        )
+    };
+}
+
+#[track_caller]
+pub fn assert_snapshot(dir: &Path, name: &str, frame: &str) {
+    let update = env::var_os(UPDATE_SNAPSHOTS_ENV).is_some_and(|value| value != "0");
+    if let Err(message) = check_snapshot(dir, name, frame, update) {
+        panic!("{message}");
+    }
+}
+
+pub(crate) fn check_snapshot(
+    dir: &Path,
+    name: &str,
+    frame: &str,
+    update: bool,
+) -> Result<(), String> {
+    let path = dir.join(format!("{name}.snap"));
+    let frame = frame.trim_end_matches('\n');
+    match fs::read_to_string(&path) {
+        Ok(expected) if expected.trim_end_matches('\n') == frame => return Ok(()),
+        Ok(expected) if !update => {
+            return Err(format!(
+                "frame snapshot `{name}` does not match {}\nrerun with {UPDATE_SNAPSHOTS_ENV}=1 to accept the new frame\n\n{}",
+                path.display(),
+                unified_diff(&expected, frame, &path.display().to_string(), "rendered"),
+            ));
+        }
+        Ok(_) => {}
+        Err(err) if err.kind() == ErrorKind::NotFound => {}
+        Err(err) => return Err(format!("read {}: {err}", path.display())),
+    }
+    fs::create_dir_all(dir).map_err(|err| format!("create {}: {err}", dir.display()))?;
+    fs::write(&path, format!("{frame}\n")).map_err(|err| format!("write {}: {err}", path.display()))
+}
+
+pub(crate) fn unified_diff(
+    expected: &str,
+    actual: &str,
+    old_label: &str,
+    new_label: &str,
+) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(old.len() + new.len());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            ops.push(('-', old[i]));
+            i += 1;
+        } else {
+            ops.push(('+', new[j]));
+            j += 1;
+        }
+    }
+
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (index, _) in ops.iter().enumerate().filter(|(_, (tag, _))| *tag != ' ') {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + CONTEXT_LINES + 1).min(ops.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+    if hunks.is_empty() {
+        return String::new();
+    }
+
+    let count =
+        |ops: &[(char, &str)], skip: char| ops.iter().filter(|(tag, _)| *tag != skip).count();
+    let mut diff = format!("--- {old_label}\n+++ {new_label}\n");
+    for (start, end) in hunks {
+        let _ = writeln!(
+            diff,
+            "@@ -{},{} +{},{} @@",
+            count(&ops[..start], '+') + 1,
+            count(&ops[start..end], '+'),
+            count(&ops[..start], '-') + 1,
+            count(&ops[start..end], '-'),
+        );
+        for (tag, line) in &ops[start..end] {
+            let _ = writeln!(diff, "{tag}{line}");
+        }
+    }
+    diff
+}
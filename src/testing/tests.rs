@@ -0,0 +1,431 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use crossterm::event::KeyCode;
+use tokio::sync::broadcast::error::RecvError;
+
+use super::snapshot::{check_snapshot, unified_diff};
+use super::{ManualDriver, TestApp};
+use crate::events::FrameworkEvent;
+use crate::is_button_click;
+use crate::runtime::{App, AppConfig, ButtonNode, Element, component};
+
+fn counter_app() -> App {
+    App::new(
+        "Counter",
+        component("Counter", |ctx| {
+            let (count, set_count) = ctx.use_state(|| 0);
+            ctx.use_effect((), move |dispatcher| {
+                let mut events = dispatcher.events().subscribe();
+                let handle = dispatcher.spawn(async move {
+                    loop {
+                        let event = match events.recv().await {
+                            Ok(event) => event,
+                            Err(RecvError::Lagged(_)) => continue,
+                            Err(RecvError::Closed) => break,
+                        };
+                        let increment = match &event {
+                            FrameworkEvent::Key(key) => key.code == KeyCode::Char('+'),
+                            _ => is_button_click(&event, "plus"),
+                        };
+                        if increment {
+                            set_count.update(|count| *count += 1);
+                        }
+                    }
+                });
+                Some(Box::new(move || handle.abort()))
+            });
+            Element::vstack(vec![
+                Element::text(format!("Current count: {count}")),
+                Element::button(ButtonNode::new("plus", "+")),
+            ])
+        }),
+    )
+    .headless_size(30, 6)
+}
+
+#[tokio::test]
+async fn test_app_drives_keys_and_clicks_through_the_runtime() {
+    let mut app = TestApp::new(counter_app()).await.expect("start app");
+    app.expect_text("Current count: 0");
+
+    for _ in 0..2 {
+        app.press_key("+").await.expect("press +");
+    }
+    app.expect_text("Current count: 2");
+
+    app.click(15, 4).await.expect("click button");
+    app.expect_text("Current count: 3");
+    assert!(app.is_running());
+}
+
+#[tokio::test]
+async fn apps_in_one_process_keep_their_own_hitboxes() {
+    let mut counter = TestApp::new(counter_app()).await.expect("start counter");
+    let other = TestApp::new(
+        App::new(
+            "Other",
+            component("Other", |_| {
+                Element::vstack(vec![
+                    Element::button(ButtonNode::new("plus", "+")),
+                    Element::text("elsewhere"),
+                ])
+            }),
+        )
+        .headless_size(30, 6),
+    )
+    .await
+    .expect("start other");
+    other.expect_text("elsewhere");
+
+    counter.click(15, 4).await.expect("click button");
+    counter.expect_text("Current count: 1");
+}
+
+#[tokio::test]
+async fn test_app_clicks_rendered_nodes_by_id() {
+    let mut app = TestApp::new(counter_app()).await.expect("start app");
+    app.click_on("plus").await.expect("click button");
+    app.expect_text("Current count: 1");
+
+    let err = app.click_on("minus").await.expect_err("no such node");
+    assert!(err.to_string().contains("minus"));
+}
+
+#[cfg(feature = "serde")]
+#[tokio::test]
+async fn test_app_view_round_trips_through_json() {
+    let mut app = TestApp::new(counter_app()).await.expect("start app");
+    app.press_key("+").await.expect("press +");
+
+    let json = app.view_json();
+    assert!(json.contains("Current count: 1"), "{json}");
+    let view: crate::runtime::View = serde_json::from_str(&json).expect("parse view");
+    assert_eq!(&view, app.view());
+}
+
+#[tokio::test]
+async fn test_app_rejects_unknown_keys() {
+    let mut app = TestApp::new(counter_app()).await.expect("start app");
+    let err = app
+        .press_key("hyper+x")
+        .await
+        .expect_err("unknown modifier");
+    assert!(err.to_string().contains("hyper+x"));
+}
+
+#[test]
+fn unified_diff_shows_changed_lines_with_context() {
+    let expected = "a\nb\nc\nd\ne\nf\ng\nh\ni";
+    let actual = "a\nb\nc\nd\nE\nf\ng\nh\ni\nj";
+    let diff = unified_diff(expected, actual, "expected", "actual");
+    assert_eq!(
+        diff,
+        "--- expected\n+++ actual\n@@ -2,8 +2,9 @@\n b\n c\n d\n-e\n+E\n f\n g\n h\n i\n+j\n"
+    );
+    assert!(unified_diff(expected, expected, "expected", "actual").is_empty());
+}
+
+#[test]
+fn snapshots_are_written_once_then_compared() {
+    let dir = std::env::temp_dir().join(format!("rustact-snapshots-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    check_snapshot(&dir, "counter", "Count: 1\n", false).expect("new snapshot is written");
+    let stored = std::fs::read_to_string(dir.join("counter.snap")).expect("snapshot file");
+    assert_eq!(stored, "Count: 1\n");
+    check_snapshot(&dir, "counter", "Count: 1", false).expect("same frame matches");
+
+    let err = check_snapshot(&dir, "counter", "Count: 2", false).expect_err("frame changed");
+    assert!(err.contains("-Count: 1\n+Count: 2"));
+    check_snapshot(&dir, "counter", "Count: 2", true).expect("update accepts the frame");
+    check_snapshot(&dir, "counter", "Count: 2", false).expect("updated frame matches");
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn frame_snapshot_macro_compares_against_golden_file() {
+    let mut app = TestApp::new(counter_app()).await.expect("start app");
+    crate::assert_frame_snapshot!(app, "counter_initial");
+}
+
+#[tokio::test]
+async fn test_app_resizes_the_headless_terminal() {
+    let mut app = TestApp::new(counter_app()).await.expect("start app");
+    app.resize(20, 4).await.expect("resize");
+    let text = app.text();
+    assert_eq!(text.lines().count(), 4);
+    assert!(text.lines().all(|line| line.chars().count() <= 20));
+    app.expect_text("Current count: 0");
+    let border = text.lines().nth(2).expect("button border");
+    assert_eq!(border.chars().count(), 20);
+}
+
+#[tokio::test]
+async fn manual_driver_advances_ticks_and_paused_time() {
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let slept = Arc::new(AtomicBool::new(false));
+    let (seen, woke) = (ticks.clone(), slept.clone());
+    let app = App::new(
+        "Clock",
+        component("Clock", move |ctx| {
+            let (seen, woke) = (seen.clone(), woke.clone());
+            ctx.use_effect((), move |dispatcher| {
+                let mut events = dispatcher.events().subscribe();
+                let handle = tokio::spawn(async move {
+                    while let Ok(event) = events.recv().await {
+                        if matches!(event, FrameworkEvent::Tick) {
+                            seen.fetch_add(1, Ordering::SeqCst);
+                        }
+                    }
+                });
+                let timer = tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(450)).await;
+                    woke.store(true, Ordering::SeqCst);
+                });
+                Some(Box::new(move || {
+                    handle.abort();
+                    timer.abort();
+                }))
+            });
+            Element::text("clock")
+        }),
+    )
+    .with_config(AppConfig {
+        tick_rate: Duration::from_millis(100),
+        ..AppConfig::default()
+    })
+    .headless();
+    let driver = ManualDriver::new();
+    let runtime = tokio::spawn(app.with_driver(driver.clone()).run());
+
+    driver.advance_ticks(4).await.expect("advance");
+    assert_eq!(ticks.load(Ordering::SeqCst), 4);
+    assert!(!slept.load(Ordering::SeqCst));
+
+    driver.advance_ticks(1).await.expect("advance");
+    assert_eq!(driver.ticks(), 5);
+    assert!(slept.load(Ordering::SeqCst));
+
+    driver.shutdown().await.expect("shutdown");
+    runtime.await.expect("join").expect("run");
+}
+
+#[tokio::test(start_paused = true)]
+async fn manual_driver_accepts_an_already_paused_clock() {
+    let start = tokio::time::Instant::now();
+    let driver = ManualDriver::new();
+    let app = counter_app().headless().with_driver(driver.clone());
+    let runtime = tokio::spawn(app.run());
+
+    driver.advance_ticks(3).await.expect("advance");
+    assert_eq!(driver.ticks(), 3);
+    assert!(start.elapsed() >= AppConfig::default().tick_rate * 3);
+
+    driver.shutdown().await.expect("shutdown");
+    runtime.await.expect("join").expect("run");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn manual_driver_ticks_on_a_multi_thread_runtime() {
+    let driver = ManualDriver::new();
+    let app = counter_app().headless().with_driver(driver.clone());
+    let runtime = tokio::spawn(app.run());
+
+    driver.advance_ticks(2).await.expect("advance");
+    assert_eq!(driver.ticks(), 2);
+
+    driver.shutdown().await.expect("shutdown");
+    runtime.await.expect("join").expect("run");
+}
+
+fn click_counter(hits: Arc<AtomicUsize>, button_first: bool) -> App {
+    App::new(
+        "Clicks",
+        component("Clicks", move |ctx| {
+            let hits = hits.clone();
+            ctx.use_effect((), move |dispatcher| {
+                let mut events = dispatcher.events().subscribe();
+                let handle = dispatcher.spawn(async move {
+                    while let Ok(event) = events.recv().await {
+                        if is_button_click(&event, "plus") {
+                            hits.fetch_add(1, Ordering::SeqCst);
+                        }
+                    }
+                });
+                Some(Box::new(move || handle.abort()))
+            });
+            let mut children = vec![
+                Element::text("clicks"),
+                Element::button(ButtonNode::new("plus", "+")),
+            ];
+            if button_first {
+                children.reverse();
+            }
+            Element::vstack(children)
+        }),
+    )
+    .headless_with_size(30, 6)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn concurrent_apps_resolve_clicks_against_their_own_hitboxes() {
+    let (below, above) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+    let drivers = [ManualDriver::new(), ManualDriver::new()];
+    let runtimes = [
+        tokio::spawn(
+            click_counter(below.clone(), false)
+                .with_driver(drivers[0].clone())
+                .run(),
+        ),
+        tokio::spawn(
+            click_counter(above.clone(), true)
+                .with_driver(drivers[1].clone())
+                .run(),
+        ),
+    ];
+
+    let click = |kind| {
+        FrameworkEvent::Mouse(crossterm::event::MouseEvent {
+            kind,
+            column: 15,
+            row: 4,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        })
+    };
+    for driver in &drivers {
+        driver.advance_ticks(1).await.expect("first frame");
+    }
+    for _ in 0..3 {
+        for driver in &drivers {
+            for kind in [
+                crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::Left),
+                crossterm::event::MouseEventKind::Up(crossterm::event::MouseButton::Left),
+            ] {
+                driver.send(click(kind)).await.expect("click");
+            }
+        }
+    }
+
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while below.load(Ordering::SeqCst) < 3 && std::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(below.load(Ordering::SeqCst), 3);
+    assert_eq!(above.load(Ordering::SeqCst), 0);
+
+    for (driver, runtime) in drivers.iter().zip(runtimes) {
+        driver.shutdown().await.expect("shutdown");
+        runtime.await.expect("join").expect("run");
+    }
+}
+
+#[tokio::test]
+async fn min_size_guard_replaces_the_frame_until_the_terminal_grows() {
+    let app = counter_app().with_config(AppConfig::default().min_size(30, 6));
+    let mut app = TestApp::new(app).await.expect("start app");
+    app.expect_text("Current count: 0");
+
+    app.resize(24, 5).await.expect("shrink");
+    let text = app.text();
+    assert!(!text.contains("Current count"));
+    assert_eq!(text.lines().nth(2), Some("terminal too small (need"));
+
+    app.resize(30, 6).await.expect("grow");
+    app.expect_text("Current count: 0");
+}
+
+#[tokio::test]
+async fn suspend_runs_the_closure_then_redraws() {
+    let app = App::new(
+        "Editor",
+        component("Editor", |ctx| {
+            let (status, set_status) = ctx.use_state(|| "idle".to_string());
+            ctx.use_effect((), move |dispatcher| {
+                let mut events = dispatcher.events().subscribe();
+                let handle = tokio::spawn(async move {
+                    while let Ok(event) = events.recv().await {
+                        if !matches!(&event, FrameworkEvent::Key(key) if key.code == KeyCode::Char('e')) {
+                            continue;
+                        }
+                        let editing = set_status.clone();
+                        let edited = dispatcher
+                            .suspend(|| async move {
+                                editing.set("editing".to_string());
+                                "saved"
+                            })
+                            .await
+                            .expect("suspend");
+                        set_status.set(edited.to_string());
+                    }
+                });
+                Some(Box::new(move || handle.abort()))
+            });
+            Element::text(format!("status: {status}"))
+        }),
+    )
+    .headless_size(20, 1);
+    let mut app = TestApp::new(app).await.expect("start app");
+    app.expect_text("status: idle");
+
+    app.press_key("e").await.expect("press e");
+    app.expect_text("status: saved");
+}
+
+#[tokio::test]
+async fn exit_stops_the_runtime_with_the_requested_code() {
+    let cleaned_up = Arc::new(AtomicBool::new(false));
+    let cleanup_flag = cleaned_up.clone();
+    let app = App::new(
+        "Quit",
+        component("Quit", move |ctx| {
+            let cleanup_flag = cleanup_flag.clone();
+            ctx.use_effect((), move |dispatcher| {
+                let mut events = dispatcher.events().subscribe();
+                let dispatcher = dispatcher.clone();
+                let handle = tokio::spawn(async move {
+                    while let Ok(event) = events.recv().await {
+                        if matches!(&event, FrameworkEvent::Key(key) if key.code == KeyCode::Char('q')) {
+                            dispatcher.exit(3);
+                        }
+                    }
+                });
+                Some(Box::new(move || {
+                    handle.abort();
+                    cleanup_flag.store(true, Ordering::SeqCst);
+                }))
+            });
+            Element::text("press q")
+        }),
+    )
+    .headless();
+    let driver = ManualDriver::new();
+    let runtime = tokio::spawn(app.with_driver(driver.clone()).run());
+
+    driver
+        .send(FrameworkEvent::Key(crossterm::event::KeyEvent::new(
+            KeyCode::Char('q'),
+            crossterm::event::KeyModifiers::NONE,
+        )))
+        .await
+        .expect("send q");
+    assert_eq!(runtime.await.expect("join").expect("run"), 3);
+    assert!(cleaned_up.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn ctrl_c_quits_unless_disabled() {
+    let mut app = TestApp::new(counter_app()).await.expect("start app");
+    app.press_key("ctrl+c").await.expect("press ctrl+c");
+    assert_eq!(app.exit_code(), Some(0));
+
+    let config = AppConfig::default().quit_on_ctrl_c(false);
+    let mut app = TestApp::new(counter_app().with_config(config))
+        .await
+        .expect("start app");
+    app.press_key("ctrl+c").await.expect("press ctrl+c");
+    assert!(app.is_running());
+    assert_eq!(app.exit_code(), None);
+}
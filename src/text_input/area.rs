@@ -0,0 +1,95 @@
+use std::fmt;
+
+use crate::runtime::{Dispatcher, FormFieldStatus};
+
+use super::handle::TextInputHandle;
+use super::state::TextInputSnapshot;
+
+#[derive(Clone)]
+pub struct TextAreaHandle {
+    input: TextInputHandle,
+}
+
+impl TextAreaHandle {
+    pub(crate) fn new(id: String, initial: String, dispatcher: Dispatcher) -> Self {
+        Self {
+            input: TextInputHandle::new_multiline(id, initial, dispatcher),
+        }
+    }
+
+    pub(crate) fn from_input(input: TextInputHandle) -> Self {
+        Self { input }
+    }
+
+    pub(crate) fn input(&self) -> &TextInputHandle {
+        &self.input
+    }
+
+    pub fn id(&self) -> &str {
+        self.input.id()
+    }
+
+    pub fn value(&self) -> String {
+        self.input.value()
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.value().split('\n').map(str::to_string).collect()
+    }
+
+    pub fn set_value(&self, next: impl Into<String>) {
+        self.input.set_value(next);
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.input.cursor()
+    }
+
+    pub fn set_cursor(&self, cursor: usize) {
+        self.input.set_cursor(cursor);
+    }
+
+    pub fn cursor_position(&self) -> (usize, usize) {
+        let snapshot = self.input.snapshot();
+        let before = &snapshot.value[..snapshot.cursor];
+        let line = before.matches('\n').count();
+        let column = before
+            .rsplit('\n')
+            .next()
+            .map(|tail| tail.chars().count())
+            .unwrap_or(0);
+        (line, column)
+    }
+
+    pub fn scroll(&self) -> usize {
+        self.input.scroll()
+    }
+
+    pub fn snapshot(&self) -> TextInputSnapshot {
+        self.input.snapshot()
+    }
+
+    pub fn status(&self) -> Option<FormFieldStatus> {
+        self.input.status()
+    }
+
+    pub fn set_status(&self, status: FormFieldStatus) {
+        self.input.set_status(status);
+    }
+
+    pub fn clear_status(&self) {
+        self.input.clear_status();
+    }
+
+    pub fn focus(&self) {
+        self.input.focus();
+    }
+}
+
+impl fmt::Debug for TextAreaHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextAreaHandle")
+            .field("id", &self.id())
+            .finish()
+    }
+}
@@ -5,6 +5,7 @@ use parking_lot::Mutex;
 
 use crate::runtime::{Dispatcher, FormFieldStatus};
 
+use super::mask::InputMask;
 use super::registry::TextInputs;
 use super::state::{TextInputSnapshot, TextInputState};
 
@@ -17,8 +18,19 @@ pub struct TextInputHandle {
 
 impl TextInputHandle {
     pub(crate) fn new(id: String, initial: String, dispatcher: Dispatcher) -> Self {
-        let state = Arc::new(Mutex::new(TextInputState::new(initial)));
-        TextInputs::register_binding(&id, state.clone());
+        Self::with_state(id, TextInputState::new(initial), dispatcher)
+    }
+
+    pub(crate) fn new_multiline(id: String, initial: String, dispatcher: Dispatcher) -> Self {
+        Self::with_state(id, TextInputState::multiline(initial), dispatcher)
+    }
+
+    fn with_state(id: String, state: TextInputState, dispatcher: Dispatcher) -> Self {
+        let state = Arc::new(Mutex::new(state));
+        {
+            let _app = dispatcher.enter();
+            TextInputs::register_binding(&id, state.clone());
+        }
         Self {
             id: Arc::new(id),
             state,
@@ -34,11 +46,41 @@ impl TextInputHandle {
         self.state.lock().value.clone()
     }
 
+    pub fn as_f64(&self) -> Option<f64> {
+        self.state.lock().value.replace(',', "").parse().ok()
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        self.state.lock().value.replace(',', "").parse().ok()
+    }
+
+    pub(crate) fn set_suggestions(&self, suggestions: Vec<String>) {
+        self.state.lock().set_suggestions(suggestions);
+    }
+
+    pub(crate) fn suggestion_popover(&self) -> (Vec<String>, Option<usize>) {
+        let guard = self.state.lock();
+        if guard.suggestions_open() {
+            (guard.suggestions.clone(), guard.highlighted)
+        } else {
+            (Vec::new(), None)
+        }
+    }
+
+    pub(crate) fn set_mask(&self, mask: Option<InputMask>) {
+        let mut guard = self.state.lock();
+        if guard.mask != mask {
+            guard.mask = mask;
+            guard.apply_mask();
+        }
+    }
+
     pub fn set_value(&self, next: impl Into<String>) {
         let mut guard = self.state.lock();
         guard.value = next.into();
         guard.cursor = guard.value.len().min(guard.cursor);
-        self.dispatcher.request_render();
+        guard.selection_anchor = None;
+        self.dispatcher.request_full_render();
     }
 
     pub fn cursor(&self) -> usize {
@@ -48,7 +90,11 @@ impl TextInputHandle {
     pub fn set_cursor(&self, cursor: usize) {
         let mut guard = self.state.lock();
         guard.cursor = cursor.min(guard.value.len());
-        self.dispatcher.request_render();
+        self.dispatcher.request_full_render();
+    }
+
+    pub(crate) fn scroll(&self) -> usize {
+        self.state.lock().scroll
     }
 
     pub fn snapshot(&self) -> TextInputSnapshot {
@@ -58,6 +104,7 @@ impl TextInputHandle {
             value: guard.value.clone(),
             cursor: guard.cursor,
             status: guard.status,
+            selection: guard.selection(),
         }
     }
 
@@ -71,13 +118,13 @@ impl TextInputHandle {
             return;
         }
         guard.status = Some(status);
-        self.dispatcher.request_render();
+        self.dispatcher.request_full_render();
     }
 
     pub fn clear_status(&self) {
         let mut guard = self.state.lock();
         if guard.status.take().is_some() {
-            self.dispatcher.request_render();
+            self.dispatcher.request_full_render();
         }
     }
 
@@ -0,0 +1,92 @@
+use std::collections::VecDeque;
+
+const HISTORY_LIMIT: usize = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Edit {
+    Insert(char),
+    Delete(char),
+    Replace,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Revision {
+    pub(crate) value: String,
+    pub(crate) cursor: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Group {
+    insert: bool,
+    cursor: usize,
+    whitespace: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EditHistory {
+    undo: VecDeque<Revision>,
+    redo: Vec<Revision>,
+    group: Option<Group>,
+}
+
+impl EditHistory {
+    pub(crate) fn record(&mut self, edit: Edit, value: &str, cursor: usize, next_cursor: usize) {
+        let next_group = match edit {
+            Edit::Insert(c) => Some((true, c)),
+            Edit::Delete(c) => Some((false, c)),
+            Edit::Replace => None,
+        };
+        let continues = match (self.group, next_group) {
+            (Some(group), Some((insert, c))) => {
+                group.insert == insert
+                    && group.cursor == cursor
+                    && (c.is_whitespace() || !group.whitespace)
+            }
+            _ => false,
+        };
+        if !continues {
+            self.push_undo(value, cursor);
+        }
+        self.redo.clear();
+        self.group = next_group.map(|(insert, c)| Group {
+            insert,
+            cursor: next_cursor,
+            whitespace: c.is_whitespace(),
+        });
+    }
+
+    pub(crate) fn undo(&mut self, value: &str, cursor: usize) -> Option<Revision> {
+        let revision = self.undo.pop_back()?;
+        self.redo.push(Revision {
+            value: value.to_string(),
+            cursor,
+        });
+        self.group = None;
+        Some(revision)
+    }
+
+    pub(crate) fn redo(&mut self, value: &str, cursor: usize) -> Option<Revision> {
+        let revision = self.redo.pop()?;
+        self.push_undo(value, cursor);
+        self.group = None;
+        Some(revision)
+    }
+
+    pub(crate) fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub(crate) fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    fn push_undo(&mut self, value: &str, cursor: usize) {
+        if self.undo.len() == HISTORY_LIMIT {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(Revision {
+            value: value.to_string(),
+            cursor,
+        });
+    }
+}
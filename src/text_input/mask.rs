@@ -0,0 +1,201 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputMask {
+    Digits,
+    Alphanumeric,
+    Pattern(String),
+    Numeric(NumericFormat),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumericFormat {
+    pub decimals: Option<u8>,
+    pub grouping: bool,
+    pub signed: bool,
+}
+
+impl Default for NumericFormat {
+    fn default() -> Self {
+        Self {
+            decimals: None,
+            grouping: false,
+            signed: true,
+        }
+    }
+}
+
+impl NumericFormat {
+    pub fn integer() -> Self {
+        Self {
+            decimals: Some(0),
+            ..Self::default()
+        }
+    }
+
+    pub fn decimal(places: u8) -> Self {
+        Self {
+            decimals: Some(places),
+            ..Self::default()
+        }
+    }
+
+    pub fn grouping(mut self, grouping: bool) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    pub fn signed(mut self, signed: bool) -> Self {
+        self.signed = signed;
+        self
+    }
+}
+
+impl InputMask {
+    pub fn pattern(pattern: impl Into<String>) -> Self {
+        InputMask::Pattern(pattern.into())
+    }
+
+    pub fn numeric(format: NumericFormat) -> Self {
+        InputMask::Numeric(format)
+    }
+
+    pub(crate) fn apply(&self, value: &str, cursor: usize) -> (String, usize) {
+        match self {
+            InputMask::Digits => filter(value, cursor, |c| c.is_ascii_digit()),
+            InputMask::Alphanumeric => filter(value, cursor, char::is_alphanumeric),
+            InputMask::Pattern(pattern) => apply_pattern(pattern, value, cursor),
+            InputMask::Numeric(format) => apply_numeric(*format, value, cursor),
+        }
+    }
+
+    pub(crate) fn finish(&self, value: &str) -> String {
+        let InputMask::Numeric(format) = self else {
+            return value.to_string();
+        };
+        if !value.chars().any(|c| c.is_ascii_digit()) {
+            return String::new();
+        }
+        let mut formatted = value.to_string();
+        if let Some(places) = format.decimals.filter(|places| *places > 0) {
+            let decimals = match formatted.find('.') {
+                Some(dot) => formatted.len() - dot - 1,
+                None => {
+                    formatted.push('.');
+                    0
+                }
+            };
+            for _ in decimals..places as usize {
+                formatted.push('0');
+            }
+        }
+        let digits_start = usize::from(formatted.starts_with('-'));
+        if formatted[digits_start..].starts_with('.') {
+            formatted.insert(digits_start, '0');
+        }
+        formatted
+    }
+}
+
+fn filter(value: &str, cursor: usize, keep: impl Fn(char) -> bool) -> (String, usize) {
+    let mut output = String::with_capacity(value.len());
+    let mut output_cursor = 0;
+    for (index, c) in value.char_indices() {
+        if keep(c) {
+            output.push(c);
+            if index < cursor {
+                output_cursor = output.len();
+            }
+        }
+    }
+    (output, output_cursor)
+}
+
+fn pattern_slot(slot: char) -> Option<fn(char) -> bool> {
+    match slot {
+        '#' => Some(|c| c.is_ascii_digit()),
+        'A' => Some(char::is_alphabetic),
+        '*' => Some(|c| !c.is_whitespace()),
+        _ => None,
+    }
+}
+
+fn apply_pattern(pattern: &str, value: &str, cursor: usize) -> (String, usize) {
+    let mut raw = value
+        .char_indices()
+        .filter(|(_, c)| pattern_slot(*c).is_some() || !pattern.contains(*c))
+        .peekable();
+    let mut output = String::with_capacity(pattern.len());
+    let mut output_cursor = 0;
+    'slots: for slot in pattern.chars() {
+        let Some(accepts) = pattern_slot(slot) else {
+            if raw.peek().is_none() {
+                break;
+            }
+            output.push(slot);
+            continue;
+        };
+        loop {
+            let Some((index, c)) = raw.next() else {
+                break 'slots;
+            };
+            if accepts(c) {
+                output.push(c);
+                if index < cursor {
+                    output_cursor = output.len();
+                }
+                break;
+            }
+        }
+    }
+    (output, output_cursor)
+}
+
+fn apply_numeric(format: NumericFormat, value: &str, cursor: usize) -> (String, usize) {
+    let mut negative = None;
+    let mut integer = Vec::new();
+    let mut dot = None;
+    let mut fraction = Vec::new();
+    for (index, c) in value.char_indices() {
+        match c {
+            '-' if format.signed && negative.is_none() && integer.is_empty() && dot.is_none() => {
+                negative = Some(index);
+            }
+            '.' if dot.is_none() && format.decimals != Some(0) => dot = Some(index),
+            c if c.is_ascii_digit() => {
+                if dot.is_none() {
+                    integer.push((index, c));
+                } else if format
+                    .decimals
+                    .is_none_or(|places| fraction.len() < places as usize)
+                {
+                    fraction.push((index, c));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut output = String::with_capacity(value.len());
+    let mut output_cursor = 0;
+    let mut emit = |output: &mut String, index: usize, c: char| {
+        output.push(c);
+        if index < cursor {
+            output_cursor = output.len();
+        }
+    };
+    if let Some(index) = negative {
+        emit(&mut output, index, '-');
+    }
+    for (position, (index, c)) in integer.iter().enumerate() {
+        if format.grouping && position > 0 && (integer.len() - position) % 3 == 0 {
+            output.push(',');
+        }
+        emit(&mut output, *index, *c);
+    }
+    if let Some(index) = dot {
+        emit(&mut output, index, '.');
+    }
+    for (index, c) in fraction {
+        emit(&mut output, index, c);
+    }
+    (output, output_cursor)
+}
@@ -1,9 +1,20 @@
+mod area;
 mod handle;
+mod history;
+mod mask;
 mod registry;
 mod state;
+mod suggestions;
 #[cfg(test)]
 mod tests;
+mod wrap;
 
+pub use area::TextAreaHandle;
 pub use handle::TextInputHandle;
+pub use mask::{InputMask, NumericFormat};
 pub use registry::TextInputs;
 pub use state::{TextInputSnapshot, TextInputState};
+pub use suggestions::SuggestionProvider;
+
+pub(crate) use registry::TextInputRegistry;
+pub(crate) use wrap::{column_width, cursor_row, visual_rows};
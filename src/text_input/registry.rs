@@ -1,38 +1,40 @@
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::sync::OnceLock;
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind};
 use parking_lot::{Mutex, RwLock};
 
-use crate::events::{FrameworkEvent, mouse_position};
+use crate::events::{
+    FrameworkEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind, mouse_position,
+};
 use crate::interactions::Hitbox;
-use crate::runtime::Dispatcher;
+use crate::runtime::{Current, Dispatcher, Registries};
 
 use super::state::TextInputState;
+use super::wrap::{column_width, cursor_row, index_at_column, visual_rows};
 
-pub struct TextInputRegistry {
+pub(crate) struct TextInputRegistry {
     bindings: RwLock<HashMap<String, Arc<Mutex<TextInputState>>>>,
     order: RwLock<Vec<String>>,
     hitboxes: RwLock<HashMap<String, Hitbox>>,
     focused: Mutex<Option<String>>,
     cursor_visible: Mutex<bool>,
+    clipboard: Mutex<String>,
 }
 
 impl TextInputRegistry {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             bindings: RwLock::new(HashMap::new()),
             order: RwLock::new(Vec::new()),
             hitboxes: RwLock::new(HashMap::new()),
             focused: Mutex::new(None),
             cursor_visible: Mutex::new(true),
+            clipboard: Mutex::new(String::new()),
         }
     }
 
-    fn singleton() -> &'static Self {
-        static REGISTRY: OnceLock<TextInputRegistry> = OnceLock::new();
-        REGISTRY.get_or_init(Self::new)
+    fn singleton() -> Current<Self> {
+        Registries::current(|registries| &registries.text_inputs)
     }
 
     pub(crate) fn register_binding(id: &str, state: Arc<Mutex<TextInputState>>) {
@@ -69,26 +71,22 @@ impl TextInputRegistry {
     }
 
     fn hitbox_contains(&self, column: u16, row: u16) -> Option<String> {
-        self.hitboxes.read().iter().find_map(|(id, hitbox)| {
-            if column >= hitbox.x
-                && column < hitbox.x.saturating_add(hitbox.width)
-                && row >= hitbox.y
-                && row < hitbox.y.saturating_add(hitbox.height)
-            {
-                Some(id.clone())
-            } else {
-                None
-            }
-        })
+        self.hitboxes
+            .read()
+            .iter()
+            .find_map(|(id, hitbox)| hitbox.contains(column, row).then(|| id.clone()))
     }
 
     fn focus(&self, id: Option<&str>, dispatcher: &Dispatcher) {
         let mut guard = self.focused.lock();
         let next = id.map(|value| value.to_string());
         if guard.as_ref() != next.as_ref() {
+            if let Some(previous) = guard.as_deref().and_then(|previous| self.binding(previous)) {
+                previous.lock().finish_mask();
+            }
             *guard = next;
             *self.cursor_visible.lock() = true;
-            dispatcher.request_render();
+            dispatcher.request_full_render();
         }
     }
 
@@ -139,12 +137,31 @@ impl TextInputRegistry {
         *self.cursor_visible.lock()
     }
 
+    fn set_clipboard(&self, text: String) {
+        *self.clipboard.lock() = text;
+    }
+
+    fn copy_selection(&self, state: &TextInputState) {
+        if let Some(text) = state.selected_text() {
+            self.set_clipboard(text.to_string());
+        }
+    }
+
+    fn paste_into(&self, state: &mut TextInputState) {
+        let clipboard = self.clipboard.lock().clone();
+        if state.multiline {
+            state.insert_str(&clipboard);
+        } else {
+            state.insert_str(&clipboard.replace(['\r', '\n'], " "));
+        }
+    }
+
     fn tick(&self, dispatcher: &Dispatcher) {
         if self.focused().is_none() {
             let mut visible = self.cursor_visible.lock();
             if *visible {
                 *visible = false;
-                dispatcher.request_render();
+                dispatcher.request_full_render();
             }
             return;
         }
@@ -152,7 +169,7 @@ impl TextInputRegistry {
             let mut visible = self.cursor_visible.lock();
             *visible = !*visible;
         }
-        dispatcher.request_render();
+        dispatcher.request_full_render();
     }
 }
 
@@ -175,22 +192,48 @@ impl TextInputs {
         TextInputRegistry::reset_hitboxes_internal();
     }
 
+    pub(crate) fn record_layout(id: &str, wrap_width: usize, scroll: usize) {
+        let registry = TextInputRegistry::singleton();
+        if let Some(binding) = registry.binding(id) {
+            let mut state = binding.lock();
+            state.wrap_width = Some(wrap_width);
+            state.scroll = scroll;
+        }
+    }
+
     pub fn is_focused(id: &str) -> bool {
         let registry = TextInputRegistry::singleton();
         registry.focused().as_deref() == Some(id)
     }
 
+    pub fn has_focus() -> bool {
+        let registry = TextInputRegistry::singleton();
+        registry.focused().is_some()
+    }
+
+    pub fn clipboard() -> String {
+        let registry = TextInputRegistry::singleton();
+        registry.clipboard.lock().clone()
+    }
+
+    pub fn set_clipboard(text: impl Into<String>) {
+        let registry = TextInputRegistry::singleton();
+        registry.set_clipboard(text.into());
+    }
+
     pub fn cursor_visible(id: &str) -> bool {
         let registry = TextInputRegistry::singleton();
         registry.cursor_visible(id)
     }
 
     pub fn focus(id: Option<&str>, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
         let registry = TextInputRegistry::singleton();
         registry.focus(id, dispatcher);
     }
 
     pub fn handle_event(event: &FrameworkEvent, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
         match event {
             FrameworkEvent::Mouse(mouse)
                 if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
@@ -225,57 +268,193 @@ impl TextInputs {
         };
         if let Some(binding) = registry.binding(&focused_id) {
             let mut state = binding.lock();
+            let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            let alt = key.modifiers.contains(KeyModifiers::ALT);
             match key.code {
+                KeyCode::Down if state.suggestions_open() => state.highlight_suggestion(1),
+                KeyCode::Up if state.suggestions_open() => state.highlight_suggestion(-1),
+                KeyCode::Enter if state.suggestions_open() && state.highlighted.is_some() => {
+                    state.accept_suggestion();
+                }
+                KeyCode::Esc if state.suggestions_open() => state.suggestions_dismissed = true,
+                KeyCode::Char('a') if alt => state.select_all(),
+                KeyCode::Char('a') if ctrl => {
+                    let target = line_start(&state.value, state.cursor);
+                    state.move_cursor(target, shift);
+                }
+                KeyCode::Char('e') if ctrl => {
+                    let target = line_end(&state.value, state.cursor);
+                    state.move_cursor(target, shift);
+                }
+                KeyCode::Char('b') if alt => {
+                    let target = prev_word_boundary(&state.value, state.cursor);
+                    state.move_cursor(target, shift);
+                }
+                KeyCode::Char('f') if alt => {
+                    let target = next_word_boundary(&state.value, state.cursor);
+                    state.move_cursor(target, shift);
+                }
+                KeyCode::Left if ctrl => {
+                    let target = prev_word_boundary(&state.value, state.cursor);
+                    state.move_cursor(target, shift);
+                }
+                KeyCode::Right if ctrl => {
+                    let target = next_word_boundary(&state.value, state.cursor);
+                    state.move_cursor(target, shift);
+                }
+                KeyCode::Char('w') if ctrl => {
+                    let target = prev_word_boundary(&state.value, state.cursor);
+                    match state.delete_to(target) {
+                        Some(text) => registry.set_clipboard(text),
+                        None => return,
+                    }
+                }
+                KeyCode::Char('u') if ctrl => {
+                    let target = line_start(&state.value, state.cursor);
+                    match state.delete_to(target) {
+                        Some(text) => registry.set_clipboard(text),
+                        None => return,
+                    }
+                }
+                KeyCode::Char('x') if ctrl => match state.delete_selection() {
+                    Some(text) => registry.set_clipboard(text),
+                    None => return,
+                },
+                KeyCode::Char('c') if alt => {
+                    registry.copy_selection(&state);
+                    return;
+                }
+                KeyCode::Insert if ctrl => {
+                    registry.copy_selection(&state);
+                    return;
+                }
+                KeyCode::Char('z') | KeyCode::Char('Z') if ctrl => {
+                    let redo = shift || key.code == KeyCode::Char('Z');
+                    let changed = if redo { state.redo() } else { state.undo() };
+                    if !changed {
+                        return;
+                    }
+                }
+                KeyCode::Char('y') if ctrl => {
+                    if !state.redo() {
+                        return;
+                    }
+                }
+                KeyCode::Char('v') if ctrl => registry.paste_into(&mut state),
+                KeyCode::Insert if shift => registry.paste_into(&mut state),
                 KeyCode::Char(c) => {
-                    if key.modifiers.contains(KeyModifiers::CONTROL)
-                        || key.modifiers.contains(KeyModifiers::ALT)
-                    {
+                    if ctrl || alt {
                         return;
                     }
-                    let cursor = state.cursor;
-                    state.value.insert(cursor, c);
-                    state.cursor = cursor + c.len_utf8();
+                    let mut buffer = [0u8; 4];
+                    state.insert_str(c.encode_utf8(&mut buffer));
                 }
                 KeyCode::Backspace => {
-                    if state.cursor > 0 {
-                        let cursor = state.cursor;
-                        if let Some(prev_index) = prev_char_boundary(&state.value, cursor) {
-                            state.value.replace_range(prev_index..cursor, "");
-                            state.cursor = prev_index;
-                        }
+                    if !state.delete_backward() {
+                        return;
                     }
                 }
                 KeyCode::Delete => {
-                    if state.cursor < state.value.len() {
-                        let cursor = state.cursor;
-                        if let Some(next_index) = next_char_boundary(&state.value, cursor) {
-                            state.value.replace_range(cursor..next_index, "");
-                        }
+                    if !state.delete_forward() {
+                        return;
                     }
                 }
                 KeyCode::Left => {
-                    if let Some(prev) = prev_char_boundary(&state.value, state.cursor) {
-                        state.cursor = prev;
-                    }
+                    let target =
+                        prev_char_boundary(&state.value, state.cursor).unwrap_or(state.cursor);
+                    state.move_cursor(target, shift);
                 }
                 KeyCode::Right => {
-                    if let Some(next) = next_char_boundary(&state.value, state.cursor) {
-                        state.cursor = next;
-                    }
+                    let target =
+                        next_char_boundary(&state.value, state.cursor).unwrap_or(state.cursor);
+                    state.move_cursor(target, shift);
+                }
+                KeyCode::Enter if state.multiline => state.insert_str("\n"),
+                KeyCode::Up if state.multiline => move_vertically(&mut state, -1, shift),
+                KeyCode::Down if state.multiline => move_vertically(&mut state, 1, shift),
+                KeyCode::Home if state.multiline => {
+                    let rows = visual_rows(&state.value, state.wrap_width.unwrap_or(usize::MAX));
+                    let target = rows[cursor_row(&rows, state.cursor)].start;
+                    state.move_cursor(target, shift);
+                }
+                KeyCode::End if state.multiline => {
+                    let rows = visual_rows(&state.value, state.wrap_width.unwrap_or(usize::MAX));
+                    let target = rows[cursor_row(&rows, state.cursor)].end;
+                    state.move_cursor(target, shift);
+                }
+                KeyCode::Home => state.move_cursor(0, shift),
+                KeyCode::End => {
+                    let target = state.value.len();
+                    state.move_cursor(target, shift);
                 }
-                KeyCode::Home => state.cursor = 0,
-                KeyCode::End => state.cursor = state.value.len(),
                 KeyCode::Esc => {
+                    drop(state);
                     registry.focus(None, dispatcher);
                     return;
                 }
                 _ => return,
             }
-            dispatcher.request_render();
+            state.apply_mask();
+            dispatcher.request_full_render();
         }
     }
 }
 
+pub(super) fn move_vertically(state: &mut TextInputState, delta: isize, extend: bool) {
+    let rows = visual_rows(&state.value, state.wrap_width.unwrap_or(usize::MAX));
+    let current = cursor_row(&rows, state.cursor);
+    let Some(target) = current
+        .checked_add_signed(delta)
+        .filter(|row| *row < rows.len())
+    else {
+        return;
+    };
+    let column = column_width(&state.value, &rows[current], state.cursor);
+    let index = index_at_column(&state.value, &rows[target], column);
+    state.move_cursor(index, extend);
+}
+
+pub(super) fn prev_word_boundary(value: &str, index: usize) -> usize {
+    let mut boundary = index;
+    let mut seen_word = false;
+    for (idx, ch) in value[..index].char_indices().rev() {
+        if ch.is_whitespace() {
+            if seen_word {
+                break;
+            }
+        } else {
+            seen_word = true;
+        }
+        boundary = idx;
+    }
+    boundary
+}
+
+pub(super) fn next_word_boundary(value: &str, index: usize) -> usize {
+    let mut seen_word = false;
+    for (offset, ch) in value[index..].char_indices() {
+        if ch.is_whitespace() {
+            if seen_word {
+                return index + offset;
+            }
+        } else {
+            seen_word = true;
+        }
+    }
+    value.len()
+}
+
+fn line_start(value: &str, index: usize) -> usize {
+    value[..index].rfind('\n').map_or(0, |idx| idx + 1)
+}
+
+fn line_end(value: &str, index: usize) -> usize {
+    value[index..]
+        .find('\n')
+        .map_or(value.len(), |idx| index + idx)
+}
+
 fn prev_char_boundary(value: &str, index: usize) -> Option<usize> {
     value[..index].char_indices().last().map(|(idx, _)| idx)
 }
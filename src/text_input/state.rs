@@ -1,12 +1,25 @@
+use std::ops::Range;
 use std::sync::Arc;
 
 use crate::runtime::FormFieldStatus;
 
+use super::history::{Edit, EditHistory, Revision};
+use super::mask::InputMask;
+
 #[derive(Clone, Debug)]
 pub struct TextInputState {
     pub value: String,
     pub cursor: usize,
     pub status: Option<FormFieldStatus>,
+    pub multiline: bool,
+    pub selection_anchor: Option<usize>,
+    pub(crate) scroll: usize,
+    pub(crate) wrap_width: Option<usize>,
+    pub(crate) history: EditHistory,
+    pub(crate) mask: Option<InputMask>,
+    pub(crate) suggestions: Vec<String>,
+    pub(crate) highlighted: Option<usize>,
+    pub(crate) suggestions_dismissed: bool,
 }
 
 impl TextInputState {
@@ -16,7 +29,222 @@ impl TextInputState {
             value: initial,
             cursor,
             status: None,
+            multiline: false,
+            selection_anchor: None,
+            scroll: 0,
+            wrap_width: None,
+            history: EditHistory::default(),
+            mask: None,
+            suggestions: Vec::new(),
+            highlighted: None,
+            suggestions_dismissed: false,
+        }
+    }
+
+    pub fn multiline(initial: String) -> Self {
+        Self {
+            multiline: true,
+            ..Self::new(initial)
+        }
+    }
+
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.selection_anchor?.min(self.value.len());
+        match anchor.cmp(&self.cursor) {
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Less => Some(anchor..self.cursor),
+            std::cmp::Ordering::Greater => Some(self.cursor..anchor),
+        }
+    }
+
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.value[range])
+    }
+
+    pub(crate) fn select_all(&mut self) {
+        self.selection_anchor = Some(0);
+        self.cursor = self.value.len();
+    }
+
+    pub(crate) fn move_cursor(&mut self, to: usize, extend: bool) {
+        if extend {
+            self.selection_anchor.get_or_insert(self.cursor);
+        } else {
+            self.selection_anchor = None;
+        }
+        self.cursor = to.min(self.value.len());
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    pub(crate) fn undo(&mut self) -> bool {
+        match self.history.undo(&self.value, self.cursor) {
+            Some(revision) => {
+                self.restore(revision);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn redo(&mut self) -> bool {
+        match self.history.redo(&self.value, self.cursor) {
+            Some(revision) => {
+                self.restore(revision);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn delete_selection(&mut self) -> Option<String> {
+        let range = self.selection()?;
+        self.record(Edit::Replace, range.start);
+        Some(self.remove_range(range))
+    }
+
+    pub(crate) fn insert_str(&mut self, text: &str) {
+        let mut chars = text.chars();
+        let edit = match (chars.next(), chars.next(), self.selection()) {
+            (Some(c), None, None) => Edit::Insert(c),
+            _ => Edit::Replace,
+        };
+        let start = self.selection().map_or(self.cursor, |range| range.start);
+        self.record(edit, start + text.len());
+        if let Some(range) = self.selection() {
+            self.remove_range(range);
+        }
+        self.selection_anchor = None;
+        self.value.insert_str(start, text);
+        self.cursor = start + text.len();
+    }
+
+    pub(crate) fn delete_backward(&mut self) -> bool {
+        if self.delete_selection().is_some() {
+            return true;
+        }
+        let Some(c) = self.value[..self.cursor].chars().next_back() else {
+            return false;
+        };
+        let start = self.cursor - c.len_utf8();
+        self.record(Edit::Delete(c), start);
+        self.remove_range(start..self.cursor);
+        true
+    }
+
+    pub(crate) fn delete_forward(&mut self) -> bool {
+        if self.delete_selection().is_some() {
+            return true;
+        }
+        let Some(c) = self.value[self.cursor..].chars().next() else {
+            return false;
+        };
+        let end = self.cursor + c.len_utf8();
+        self.record(Edit::Delete(c), self.cursor);
+        self.remove_range(self.cursor..end);
+        true
+    }
+
+    pub(crate) fn delete_to(&mut self, target: usize) -> Option<String> {
+        if let Some(removed) = self.delete_selection() {
+            return Some(removed);
         }
+        let target = target.min(self.value.len());
+        if target == self.cursor {
+            return None;
+        }
+        let range = self.cursor.min(target)..self.cursor.max(target);
+        self.record(Edit::Replace, range.start);
+        Some(self.remove_range(range))
+    }
+
+    pub(crate) fn apply_mask(&mut self) {
+        let Some(mask) = &self.mask else {
+            return;
+        };
+        let (value, cursor) = mask.apply(&self.value, self.cursor);
+        if value != self.value {
+            self.value = value;
+            self.cursor = cursor;
+            self.selection_anchor = None;
+        }
+    }
+
+    pub(crate) fn finish_mask(&mut self) {
+        let Some(mask) = &self.mask else {
+            return;
+        };
+        let value = mask.finish(&self.value);
+        if value != self.value {
+            self.value = value;
+            self.cursor = self.value.len();
+            self.selection_anchor = None;
+        }
+    }
+
+    pub(crate) fn suggestions_open(&self) -> bool {
+        !self.suggestions.is_empty() && !self.suggestions_dismissed
+    }
+
+    pub(crate) fn set_suggestions(&mut self, suggestions: Vec<String>) {
+        if self.suggestions != suggestions {
+            self.suggestions = suggestions;
+            self.highlighted = None;
+        }
+    }
+
+    pub(crate) fn highlight_suggestion(&mut self, delta: isize) {
+        let len = self.suggestions.len();
+        if len == 0 {
+            return;
+        }
+        self.highlighted = Some(match self.highlighted {
+            None if delta < 0 => len - 1,
+            None => 0,
+            Some(index) => (index as isize + delta).rem_euclid(len as isize) as usize,
+        });
+    }
+
+    pub(crate) fn accept_suggestion(&mut self) -> bool {
+        let Some(suggestion) = self
+            .highlighted
+            .and_then(|index| self.suggestions.get(index))
+            .cloned()
+        else {
+            return false;
+        };
+        self.record(Edit::Replace, suggestion.len());
+        self.cursor = suggestion.len();
+        self.value = suggestion;
+        self.selection_anchor = None;
+        self.suggestions_dismissed = true;
+        true
+    }
+
+    fn record(&mut self, edit: Edit, next_cursor: usize) {
+        self.history
+            .record(edit, &self.value, self.cursor, next_cursor);
+        self.suggestions_dismissed = false;
+    }
+
+    fn remove_range(&mut self, range: Range<usize>) -> String {
+        let removed = self.value[range.clone()].to_string();
+        self.value.replace_range(range.clone(), "");
+        self.cursor = range.start;
+        self.selection_anchor = None;
+        removed
+    }
+
+    fn restore(&mut self, revision: Revision) {
+        self.value = revision.value;
+        self.cursor = revision.cursor.min(self.value.len());
+        self.selection_anchor = None;
     }
 }
 
@@ -26,4 +254,5 @@ pub struct TextInputSnapshot {
     pub value: String,
     pub cursor: usize,
     pub status: Option<FormFieldStatus>,
+    pub selection: Option<Range<usize>>,
 }
@@ -0,0 +1,61 @@
+use std::fmt;
+use std::sync::Arc;
+
+type SuggestFn = dyn Fn(&str) -> Vec<String> + Send + Sync;
+
+#[derive(Clone)]
+pub struct SuggestionProvider {
+    suggest: Arc<SuggestFn>,
+}
+
+impl SuggestionProvider {
+    pub fn new<F>(suggest: F) -> Self
+    where
+        F: Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    {
+        Self {
+            suggest: Arc::new(suggest),
+        }
+    }
+
+    pub fn from_items<I, S>(items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let items: Vec<String> = items.into_iter().map(Into::into).collect();
+        Self::new(move |query| filter_items(&items, query))
+    }
+
+    pub fn suggest(&self, query: &str) -> Vec<String> {
+        (self.suggest)(query)
+    }
+}
+
+impl fmt::Debug for SuggestionProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SuggestionProvider").finish_non_exhaustive()
+    }
+}
+
+pub(crate) fn filter_items(items: &[String], query: &str) -> Vec<String> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut prefixed = Vec::new();
+    let mut containing = Vec::new();
+    for item in items {
+        let lower = item.to_lowercase();
+        if lower == query {
+            continue;
+        }
+        if lower.starts_with(&query) {
+            prefixed.push(item.clone());
+        } else if lower.contains(&query) {
+            containing.push(item.clone());
+        }
+    }
+    prefixed.extend(containing);
+    prefixed
+}
@@ -0,0 +1,35 @@
+use crate::text_input::TextInputState;
+use crate::text_input::registry::{next_word_boundary, prev_word_boundary};
+
+#[test]
+fn word_boundaries_skip_whitespace_then_word() {
+    let value = "cargo  run --release";
+    assert_eq!(prev_word_boundary(value, value.len()), 11);
+    assert_eq!(prev_word_boundary(value, 11), 7);
+    assert_eq!(prev_word_boundary(value, 7), 0);
+    assert_eq!(prev_word_boundary(value, 0), 0);
+    assert_eq!(next_word_boundary(value, 0), 5);
+    assert_eq!(next_word_boundary(value, 5), 10);
+    assert_eq!(next_word_boundary(value, 10), value.len());
+}
+
+#[test]
+fn word_boundaries_respect_multibyte_chars() {
+    let value = "naïve café";
+    assert_eq!(prev_word_boundary(value, value.len()), 7);
+    assert_eq!(next_word_boundary(value, 0), 6);
+}
+
+#[test]
+fn delete_to_removes_in_either_direction_and_undoes() {
+    let mut state = TextInputState::new("hello brave world".into());
+    state.cursor = 12;
+    assert_eq!(state.delete_to(6).as_deref(), Some("brave "));
+    assert_eq!(state.value, "hello world");
+    assert_eq!(state.cursor, 6);
+    assert_eq!(state.delete_to(11).as_deref(), Some("world"));
+    assert_eq!(state.value, "hello ");
+    assert!(state.delete_to(6).is_none());
+    state.undo();
+    assert_eq!(state.value, "hello world");
+}
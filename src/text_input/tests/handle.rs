@@ -12,6 +12,7 @@ fn test_dispatcher() -> Dispatcher {
 #[test]
 fn handle_updates_value_cursor_and_status() {
     let dispatcher = test_dispatcher();
+    let _app = dispatcher.enter();
     let handle = TextInputHandle::new("field".into(), "hi".into(), dispatcher.clone());
     assert_eq!(handle.id(), "field");
     assert_eq!(handle.value(), "hi");
@@ -39,6 +40,7 @@ fn handle_updates_value_cursor_and_status() {
 #[test]
 fn handle_focuses_registered_input() {
     let dispatcher = test_dispatcher();
+    let _app = dispatcher.enter();
     let handle = TextInputHandle::new("field.focus".into(), String::new(), dispatcher);
     handle.focus();
     assert!(TextInputs::is_focused(handle.id()));
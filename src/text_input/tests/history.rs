@@ -0,0 +1,62 @@
+use crate::text_input::TextInputState;
+
+fn type_text(state: &mut TextInputState, text: &str) {
+    for c in text.chars() {
+        let mut buffer = [0u8; 4];
+        state.insert_str(c.encode_utf8(&mut buffer));
+    }
+}
+
+#[test]
+fn undo_reverts_typing_a_word_at_a_time() {
+    let mut state = TextInputState::new(String::new());
+    type_text(&mut state, "hello big world");
+    assert!(state.undo());
+    assert_eq!(state.value, "hello big ");
+    assert!(state.undo());
+    assert_eq!(state.value, "hello ");
+    assert!(state.undo());
+    assert_eq!(state.value, "");
+    assert!(!state.undo());
+}
+
+#[test]
+fn redo_replays_undone_edits_until_a_new_edit() {
+    let mut state = TextInputState::new(String::new());
+    type_text(&mut state, "one two");
+    state.undo();
+    state.undo();
+    assert!(state.redo());
+    assert_eq!(state.value, "one ");
+    assert!(state.redo());
+    assert_eq!(state.value, "one two");
+    assert_eq!(state.cursor, 7);
+
+    state.undo();
+    type_text(&mut state, "x");
+    assert!(!state.can_redo());
+}
+
+#[test]
+fn deletions_group_separately_from_insertions() {
+    let mut state = TextInputState::new(String::new());
+    type_text(&mut state, "hello world");
+    for _ in 0..3 {
+        state.delete_backward();
+    }
+    assert_eq!(state.value, "hello wo");
+    assert!(state.undo());
+    assert_eq!(state.value, "hello world");
+    assert_eq!(state.cursor, 11);
+}
+
+#[test]
+fn moving_the_cursor_starts_a_new_chunk() {
+    let mut state = TextInputState::new(String::new());
+    type_text(&mut state, "abc");
+    state.move_cursor(0, false);
+    type_text(&mut state, "xy");
+    assert_eq!(state.value, "xyabc");
+    assert!(state.undo());
+    assert_eq!(state.value, "abc");
+}
@@ -0,0 +1,57 @@
+use crate::text_input::{InputMask, NumericFormat, TextInputState};
+
+#[test]
+fn digits_mask_drops_other_characters_and_keeps_cursor() {
+    let (value, cursor) = InputMask::Digits.apply("8a0b8", 3);
+    assert_eq!(value, "808");
+    assert_eq!(cursor, 2);
+}
+
+#[test]
+fn pattern_mask_inserts_literals_as_slots_fill() {
+    let mask = InputMask::pattern("(###) ###-####");
+    assert_eq!(mask.apply("555", 3), ("(555".to_string(), 4));
+    assert_eq!(mask.apply("(5551", 5), ("(555) 1".to_string(), 7));
+    assert_eq!(
+        mask.apply("5551234567xyz", 13).0,
+        "(555) 123-4567".to_string()
+    );
+}
+
+#[test]
+fn numeric_mask_groups_thousands_and_caps_decimals() {
+    let mask = InputMask::numeric(NumericFormat::decimal(2).grouping(true));
+    assert_eq!(
+        mask.apply("1234567.891", 11),
+        ("1,234,567.89".to_string(), 12)
+    );
+    assert_eq!(mask.apply("1,2345", 6), ("12,345".to_string(), 6));
+    assert_eq!(mask.apply("-1-2.3.4", 8), ("-12.34".to_string(), 6));
+}
+
+#[test]
+fn numeric_mask_respects_integer_and_unsigned_formats() {
+    let mask = InputMask::numeric(NumericFormat::integer().signed(false));
+    assert_eq!(mask.apply("-80.80", 6).0, "8080");
+}
+
+#[test]
+fn finishing_numeric_input_pads_fixed_decimals() {
+    let mask = InputMask::numeric(NumericFormat::decimal(2));
+    assert_eq!(mask.finish("12"), "12.00");
+    assert_eq!(mask.finish("-.5"), "-0.50");
+    assert_eq!(mask.finish("-"), "");
+}
+
+#[test]
+fn state_applies_mask_after_edits() {
+    let mut state = TextInputState::new(String::new());
+    state.mask = Some(InputMask::numeric(NumericFormat::integer().grouping(true)));
+    for c in "12a34".chars() {
+        let mut buffer = [0u8; 4];
+        state.insert_str(c.encode_utf8(&mut buffer));
+        state.apply_mask();
+    }
+    assert_eq!(state.value, "1,234");
+    assert_eq!(state.cursor, 5);
+}
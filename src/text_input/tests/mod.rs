@@ -1,2 +1,7 @@
+mod editing;
 mod handle;
+mod history;
+mod mask;
 mod state;
+mod suggestions;
+mod wrap;
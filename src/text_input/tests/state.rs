@@ -22,9 +22,43 @@ fn snapshot_copies_runtime_values() {
         value: base.value.clone(),
         cursor: base.cursor,
         status: base.status,
+        selection: base.selection(),
     };
     assert!(Arc::ptr_eq(&snapshot.id, &id));
     assert_eq!(snapshot.value, "abc");
     assert_eq!(snapshot.cursor, 1);
     assert_eq!(snapshot.status, base.status);
 }
+
+#[test]
+fn extending_cursor_tracks_selection_in_either_direction() {
+    let mut state = TextInputState::new("hello world".into());
+    state.move_cursor(6, true);
+    assert_eq!(state.selection(), Some(6..11));
+    assert_eq!(state.selected_text(), Some("world"));
+    state.move_cursor(11, true);
+    assert_eq!(state.selection(), None);
+    state.move_cursor(0, false);
+    assert!(state.selection_anchor.is_none());
+}
+
+#[test]
+fn inserting_replaces_selection() {
+    let mut state = TextInputState::new("hello world".into());
+    state.cursor = 0;
+    state.move_cursor(5, true);
+    state.insert_str("howdy");
+    assert_eq!(state.value, "howdy world");
+    assert_eq!(state.cursor, 5);
+    assert!(state.selection().is_none());
+}
+
+#[test]
+fn delete_selection_returns_removed_text() {
+    let mut state = TextInputState::new("héllo".into());
+    state.select_all();
+    assert_eq!(state.delete_selection().as_deref(), Some("héllo"));
+    assert_eq!(state.value, "");
+    assert_eq!(state.cursor, 0);
+    assert!(state.delete_selection().is_none());
+}
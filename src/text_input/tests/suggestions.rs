@@ -0,0 +1,40 @@
+use crate::text_input::{SuggestionProvider, TextInputState};
+
+#[test]
+fn item_provider_ranks_prefix_matches_first() {
+    let provider = SuggestionProvider::from_items(["backend", "frontend", "Bash", "ops"]);
+    assert_eq!(provider.suggest("ba"), vec!["backend", "Bash"]);
+    assert_eq!(provider.suggest("end"), vec!["backend", "frontend"]);
+    assert!(provider.suggest("").is_empty());
+    assert!(provider.suggest("ops").is_empty());
+}
+
+#[test]
+fn highlight_wraps_and_resets_when_suggestions_change() {
+    let mut state = TextInputState::new("b".into());
+    state.set_suggestions(vec!["bash".into(), "backend".into()]);
+    state.highlight_suggestion(-1);
+    assert_eq!(state.highlighted, Some(1));
+    state.highlight_suggestion(1);
+    assert_eq!(state.highlighted, Some(0));
+    state.set_suggestions(vec!["bash".into()]);
+    assert_eq!(state.highlighted, None);
+}
+
+#[test]
+fn accepting_replaces_value_and_dismisses_until_next_edit() {
+    let mut state = TextInputState::new("ba".into());
+    state.set_suggestions(vec!["bash".into(), "backend".into()]);
+    assert!(!state.accept_suggestion());
+    state.highlight_suggestion(1);
+    state.highlight_suggestion(1);
+    assert!(state.accept_suggestion());
+    assert_eq!(state.value, "backend");
+    assert_eq!(state.cursor, 7);
+    assert!(!state.suggestions_open());
+    state.delete_backward();
+    assert!(state.suggestions_open());
+    state.undo();
+    state.undo();
+    assert_eq!(state.value, "ba");
+}
@@ -0,0 +1,41 @@
+use crate::text_input::TextInputState;
+use crate::text_input::registry::move_vertically;
+use crate::text_input::wrap::{column_width, cursor_row, index_at_column, visual_rows};
+
+#[test]
+fn visual_rows_split_on_newlines_and_width() {
+    let value = "abcdef\ngh";
+    let rows = visual_rows(value, 4);
+    assert_eq!(rows, vec![0..4, 4..6, 7..9]);
+
+    let rows = visual_rows("", 4);
+    assert_eq!(rows, vec![0..0]);
+
+    let rows = visual_rows("a\n", 4);
+    assert_eq!(rows, vec![0..1, 2..2]);
+}
+
+#[test]
+fn cursor_on_wrap_boundary_belongs_to_continuation_row() {
+    let value = "abcdef";
+    let rows = visual_rows(value, 4);
+    assert_eq!(cursor_row(&rows, 4), 1);
+    assert_eq!(cursor_row(&rows, 6), 1);
+    assert_eq!(column_width(value, &rows[1], 6), 2);
+    assert_eq!(index_at_column(value, &rows[0], 2), 2);
+    assert_eq!(index_at_column(value, &rows[1], 9), 6);
+}
+
+#[test]
+fn vertical_movement_preserves_column() {
+    let mut state = TextInputState::multiline("hello\nhi\nworld".into());
+    state.cursor = 4;
+    move_vertically(&mut state, 1, false);
+    assert_eq!(state.cursor, 8, "clamped to end of shorter line");
+    move_vertically(&mut state, 1, false);
+    assert_eq!(state.cursor, 11);
+    move_vertically(&mut state, 1, false);
+    assert_eq!(state.cursor, 11, "no row below the last one");
+    move_vertically(&mut state, -2, false);
+    assert_eq!(state.cursor, 2);
+}
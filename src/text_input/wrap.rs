@@ -0,0 +1,53 @@
+use std::ops::Range;
+
+use unicode_width::UnicodeWidthChar;
+
+pub(crate) fn visual_rows(value: &str, width: usize) -> Vec<Range<usize>> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+    let mut line_start = 0;
+    for line in value.split('\n') {
+        let mut row_start = line_start;
+        let mut row_width = 0;
+        for (offset, ch) in line.char_indices() {
+            let ch_width = ch.width().unwrap_or(0);
+            if row_width + ch_width > width && row_width > 0 {
+                rows.push(row_start..line_start + offset);
+                row_start = line_start + offset;
+                row_width = 0;
+            }
+            row_width += ch_width;
+        }
+        rows.push(row_start..line_start + line.len());
+        line_start += line.len() + 1;
+    }
+    rows
+}
+
+pub(crate) fn cursor_row(rows: &[Range<usize>], cursor: usize) -> usize {
+    // A cursor sitting on a soft wrap boundary belongs to the continuation row.
+    rows.iter()
+        .position(|row| cursor >= row.start && cursor < row.end)
+        .or_else(|| rows.iter().rposition(|row| cursor == row.end))
+        .unwrap_or(rows.len().saturating_sub(1))
+}
+
+pub(crate) fn column_width(value: &str, row: &Range<usize>, cursor: usize) -> usize {
+    let end = cursor.clamp(row.start, row.end);
+    value[row.start..end]
+        .chars()
+        .map(|ch| ch.width().unwrap_or(0))
+        .sum()
+}
+
+pub(crate) fn index_at_column(value: &str, row: &Range<usize>, column: usize) -> usize {
+    let mut width = 0;
+    for (offset, ch) in value[row.start..row.end].char_indices() {
+        let ch_width = ch.width().unwrap_or(0);
+        if width + ch_width > column {
+            return row.start + offset;
+        }
+        width += ch_width;
+    }
+    row.end
+}
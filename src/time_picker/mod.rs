@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use parking_lot::{Mutex, RwLock};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    FrameworkEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind, mouse_position,
+};
+use crate::interactions::Hitbox;
+use crate::runtime::{Current, Dispatcher, Registries};
+use crate::text_input::TextInputs;
+
+type ChangeFn = dyn Fn(TimeValue) + Send + Sync;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimeValue {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl TimeValue {
+    pub fn new(hour: u8, minute: u8, second: u8) -> Self {
+        Self {
+            hour: hour.min(TimeField::Hour.max()),
+            minute: minute.min(TimeField::Minute.max()),
+            second: second.min(TimeField::Second.max()),
+        }
+    }
+
+    pub fn get(self, field: TimeField) -> u8 {
+        match field {
+            TimeField::Hour => self.hour,
+            TimeField::Minute => self.minute,
+            TimeField::Second => self.second,
+        }
+    }
+
+    pub fn total_seconds(self) -> u32 {
+        u32::from(self.hour) * 3600 + u32::from(self.minute) * 60 + u32::from(self.second)
+    }
+
+    fn set(&mut self, field: TimeField, value: u8) {
+        let value = value.min(field.max());
+        match field {
+            TimeField::Hour => self.hour = value,
+            TimeField::Minute => self.minute = value,
+            TimeField::Second => self.second = value,
+        }
+    }
+}
+
+impl fmt::Display for TimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TimeField {
+    #[default]
+    Hour,
+    Minute,
+    Second,
+}
+
+impl TimeField {
+    pub fn max(self) -> u8 {
+        match self {
+            TimeField::Hour => 23,
+            TimeField::Minute | TimeField::Second => 59,
+        }
+    }
+
+    fn fields(show_seconds: bool) -> &'static [TimeField] {
+        if show_seconds {
+            &[TimeField::Hour, TimeField::Minute, TimeField::Second]
+        } else {
+            &[TimeField::Hour, TimeField::Minute]
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TimeChangeHandler {
+    callback: Arc<ChangeFn>,
+}
+
+impl TimeChangeHandler {
+    pub fn new<F>(callback: F) -> Self
+    where
+        F: Fn(TimeValue) + Send + Sync + 'static,
+    {
+        Self {
+            callback: Arc::new(callback),
+        }
+    }
+
+    fn call(&self, value: TimeValue) {
+        (self.callback)(value)
+    }
+}
+
+impl fmt::Debug for TimeChangeHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimeChangeHandler").finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TimePickerState {
+    pub(crate) value: TimeValue,
+    pub(crate) field: TimeField,
+    pub(crate) show_seconds: bool,
+    pending: Option<u8>,
+    on_change: Option<TimeChangeHandler>,
+}
+
+impl TimePickerState {
+    pub(crate) fn step(&mut self, delta: i32) {
+        let span = i32::from(self.field.max()) + 1;
+        let next = (i32::from(self.value.get(self.field)) + delta).rem_euclid(span);
+        self.value.set(self.field, next as u8);
+        self.pending = None;
+    }
+
+    pub(crate) fn move_field(&mut self, delta: isize) {
+        let fields = TimeField::fields(self.show_seconds);
+        let current = fields
+            .iter()
+            .position(|field| *field == self.field)
+            .unwrap_or(0);
+        let next = current.saturating_add_signed(delta).min(fields.len() - 1);
+        self.field = fields[next];
+        self.pending = None;
+    }
+
+    pub(crate) fn enter_digit(&mut self, digit: u8) {
+        let max = self.field.max();
+        match self.pending.take() {
+            Some(first) => {
+                self.value.set(self.field, first * 10 + digit);
+                self.move_field(1);
+            }
+            None if digit * 10 > max => {
+                self.value.set(self.field, digit);
+                self.move_field(1);
+            }
+            None => {
+                self.value.set(self.field, digit);
+                self.pending = Some(digit);
+            }
+        }
+    }
+
+    fn select(&mut self, field: TimeField) {
+        self.field = field;
+        self.pending = None;
+    }
+}
+
+pub(crate) struct TimePickerRegistry {
+    states: RwLock<HashMap<String, TimePickerState>>,
+    hitboxes: RwLock<Vec<(String, TimeField, Hitbox)>>,
+    focused: Mutex<Option<String>>,
+}
+
+impl TimePickerRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            states: RwLock::new(HashMap::new()),
+            hitboxes: RwLock::new(Vec::new()),
+            focused: Mutex::new(None),
+        }
+    }
+
+    fn global() -> Current<Self> {
+        Registries::current(|registries| &registries.time_pickers)
+    }
+
+    fn update<R>(&self, id: &str, update: impl FnOnce(&mut TimePickerState) -> R) -> R {
+        let mut states = self.states.write();
+        update(states.entry(id.to_string()).or_default())
+    }
+
+    fn edit(&self, id: &str, edit: impl FnOnce(&mut TimePickerState), dispatcher: &Dispatcher) {
+        let change = self.update(id, |state| {
+            let before = state.value;
+            edit(state);
+            let handler = state.on_change.clone().filter(|_| state.value != before)?;
+            Some((handler, state.value))
+        });
+        if let Some((handler, value)) = change {
+            handler.call(value);
+        }
+        dispatcher.request_full_render();
+    }
+}
+
+pub struct TimePickers;
+
+impl TimePickers {
+    pub fn value(id: &str) -> Option<TimeValue> {
+        let registry = TimePickerRegistry::global();
+        registry.states.read().get(id).map(|state| state.value)
+    }
+
+    pub fn field(id: &str) -> Option<TimeField> {
+        let registry = TimePickerRegistry::global();
+        registry.states.read().get(id).map(|state| state.field)
+    }
+
+    pub fn has_focus() -> bool {
+        TimePickerRegistry::global().focused.lock().is_some()
+    }
+
+    pub fn is_focused(id: &str) -> bool {
+        TimePickerRegistry::global().focused.lock().as_deref() == Some(id)
+    }
+
+    pub fn focus(id: Option<&str>, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        let registry = TimePickerRegistry::global();
+        let mut focused = registry.focused.lock();
+        let next = id.map(str::to_string);
+        if *focused != next {
+            *focused = next;
+            dispatcher.request_full_render();
+        }
+    }
+
+    pub(crate) fn sync(
+        id: &str,
+        value: Option<TimeValue>,
+        show_seconds: bool,
+        on_change: Option<TimeChangeHandler>,
+    ) -> TimePickerState {
+        TimePickerRegistry::global().update(id, |state| {
+            if let Some(value) = value {
+                state.value = value;
+            }
+            state.show_seconds = show_seconds;
+            if !show_seconds && state.field == TimeField::Second {
+                state.select(TimeField::Minute);
+            }
+            state.on_change = on_change;
+            state.clone()
+        })
+    }
+
+    pub(crate) fn record(id: &str, field: TimeField, hitbox: Hitbox) {
+        let registry = TimePickerRegistry::global();
+        registry
+            .hitboxes
+            .write()
+            .push((id.to_string(), field, hitbox));
+    }
+
+    pub(crate) fn reset_hitboxes() {
+        TimePickerRegistry::global().hitboxes.write().clear();
+    }
+
+    pub fn handle_event(event: &FrameworkEvent, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        match event {
+            FrameworkEvent::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+            {
+                if let Some((column, row)) = mouse_position(event) {
+                    Self::handle_click(column, row, dispatcher);
+                }
+            }
+            FrameworkEvent::Key(key) => Self::handle_key(key, dispatcher),
+            _ => {}
+        }
+    }
+
+    fn handle_click(column: u16, row: u16, dispatcher: &Dispatcher) {
+        let registry = TimePickerRegistry::global();
+        let hit = registry
+            .hitboxes
+            .read()
+            .iter()
+            .find(|(_, _, hitbox)| hitbox.contains(column, row))
+            .map(|(id, field, _)| (id.clone(), *field));
+        match hit {
+            Some((id, field)) => {
+                registry.update(&id, |state| state.select(field));
+                Self::focus(Some(&id), dispatcher);
+                dispatcher.request_full_render();
+            }
+            None => Self::focus(None, dispatcher),
+        }
+    }
+
+    fn handle_key(key: &KeyEvent, dispatcher: &Dispatcher) {
+        let registry = TimePickerRegistry::global();
+        let Some(id) = registry.focused.lock().clone() else {
+            return;
+        };
+        if matches!(key.code, KeyCode::Tab | KeyCode::BackTab) {
+            Self::focus(None, dispatcher);
+            return;
+        }
+        if TextInputs::has_focus() {
+            return;
+        }
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return;
+        }
+        match key.code {
+            KeyCode::Left => registry.edit(&id, |state| state.move_field(-1), dispatcher),
+            KeyCode::Right => registry.edit(&id, |state| state.move_field(1), dispatcher),
+            KeyCode::Up => registry.edit(&id, |state| state.step(1), dispatcher),
+            KeyCode::Down => registry.edit(&id, |state| state.step(-1), dispatcher),
+            KeyCode::PageUp => registry.edit(&id, |state| state.step(10), dispatcher),
+            KeyCode::PageDown => registry.edit(&id, |state| state.step(-10), dispatcher),
+            KeyCode::Backspace | KeyCode::Delete => registry.edit(
+                &id,
+                |state| {
+                    let field = state.field;
+                    state.value.set(field, 0);
+                    state.pending = None;
+                },
+                dispatcher,
+            ),
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                let digit = c as u8 - b'0';
+                registry.edit(&id, |state| state.enter_digit(digit), dispatcher);
+            }
+            KeyCode::Char(':') => registry.edit(&id, |state| state.move_field(1), dispatcher),
+            KeyCode::Esc => Self::focus(None, dispatcher),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,76 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tokio::sync::mpsc;
+
+use super::*;
+use crate::events::EventBus;
+
+fn test_dispatcher() -> Dispatcher {
+    let (tx, _rx) = mpsc::channel(8);
+    Dispatcher::new(tx, EventBus::new(8))
+}
+
+#[test]
+fn digits_fill_a_field_and_advance() {
+    let mut state = TimePickerState {
+        show_seconds: true,
+        ..TimePickerState::default()
+    };
+    state.enter_digit(0);
+    assert_eq!(state.field, TimeField::Hour);
+    state.enter_digit(9);
+    assert_eq!(state.value.hour, 9);
+    assert_eq!(state.field, TimeField::Minute);
+    state.enter_digit(7);
+    assert_eq!(state.value.minute, 7);
+    assert_eq!(state.field, TimeField::Second);
+    state.enter_digit(5);
+    state.enter_digit(9);
+    assert_eq!(state.value, TimeValue::new(9, 7, 59));
+    assert_eq!(state.value.to_string(), "09:07:59");
+}
+
+#[test]
+fn stepping_wraps_and_fields_respect_hidden_seconds() {
+    let mut state = TimePickerState::default();
+    state.step(-1);
+    assert_eq!(state.value.hour, 23);
+    state.step(2);
+    assert_eq!(state.value.hour, 1);
+    state.move_field(5);
+    assert_eq!(state.field, TimeField::Minute);
+    state.step(-10);
+    assert_eq!(state.value.minute, 50);
+    state.move_field(-5);
+    assert_eq!(state.field, TimeField::Hour);
+}
+
+#[test]
+fn edits_report_changes_through_on_change() {
+    let dispatcher = test_dispatcher();
+    let _app = dispatcher.enter();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let seen = calls.clone();
+    let handler = TimeChangeHandler::new(move |value| {
+        assert_eq!(value, TimeValue::new(8, 30, 0));
+        seen.fetch_add(1, Ordering::SeqCst);
+    });
+    let state = TimePickers::sync(
+        "time-change",
+        Some(TimeValue::new(7, 30, 0)),
+        false,
+        Some(handler),
+    );
+    assert!(!state.show_seconds);
+    let registry = TimePickerRegistry::global();
+    registry.edit("time-change", |state| state.step(1), &dispatcher);
+    registry.edit("time-change", |state| state.move_field(1), &dispatcher);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(TimePickers::field("time-change"), Some(TimeField::Minute));
+
+    TimePickers::sync("time-change", None, false, None);
+    assert_eq!(
+        TimePickers::value("time-change"),
+        Some(TimeValue::new(8, 30, 0))
+    );
+}
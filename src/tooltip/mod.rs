@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use parking_lot::{Mutex, RwLock};
+
+use crate::events::{FrameworkEvent, MouseEventKind, mouse_position};
+use crate::interactions::{Hitbox, is_focused};
+use crate::rt::Instant;
+use crate::runtime::{Current, Dispatcher, Registries};
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct HoverState {
+    target: Option<(String, Instant)>,
+    shown: bool,
+}
+
+impl HoverState {
+    pub(crate) fn is_visible(&self, id: &str) -> bool {
+        self.shown && self.target.as_ref().is_some_and(|(target, _)| target == id)
+    }
+
+    pub(crate) fn target(&self) -> Option<&str> {
+        self.target.as_ref().map(|(target, _)| target.as_str())
+    }
+
+    pub(crate) fn enter(&mut self, target: Option<&str>, now: Instant) -> bool {
+        if self.target() == target {
+            return false;
+        }
+        let was_shown = self.shown;
+        self.target = target.map(|target| (target.to_string(), now));
+        self.shown = false;
+        was_shown
+    }
+
+    pub(crate) fn advance(&mut self, now: Instant, delay: Duration) -> bool {
+        match &self.target {
+            Some((_, since)) if !self.shown && now.duration_since(*since) >= delay => {
+                self.shown = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn dismiss(&mut self) -> bool {
+        let was_shown = self.shown;
+        self.target = None;
+        self.shown = false;
+        was_shown
+    }
+}
+
+pub(crate) struct TooltipRegistry {
+    delays: RwLock<HashMap<String, Duration>>,
+    hitboxes: RwLock<Vec<(String, Hitbox)>>,
+    hover: Mutex<HoverState>,
+}
+
+impl TooltipRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            delays: RwLock::new(HashMap::new()),
+            hitboxes: RwLock::new(Vec::new()),
+            hover: Mutex::new(HoverState::default()),
+        }
+    }
+
+    fn global() -> Current<Self> {
+        Registries::current(|registries| &registries.tooltips)
+    }
+
+    fn delay(&self, id: &str) -> Duration {
+        self.delays.read().get(id).copied().unwrap_or_default()
+    }
+}
+
+pub struct Tooltips;
+
+impl Tooltips {
+    pub fn is_visible(id: &str) -> bool {
+        TooltipRegistry::global().hover.lock().is_visible(id)
+    }
+
+    pub(crate) fn is_target_focused(target: &str) -> bool {
+        is_focused(target)
+    }
+
+    pub(crate) fn sync(id: &str, delay: Duration) {
+        let registry = TooltipRegistry::global();
+        registry.delays.write().insert(id.to_string(), delay);
+    }
+
+    pub(crate) fn record(id: &str, hitbox: Hitbox) {
+        let registry = TooltipRegistry::global();
+        registry.hitboxes.write().push((id.to_string(), hitbox));
+    }
+
+    pub(crate) fn reset_hitboxes() {
+        TooltipRegistry::global().hitboxes.write().clear();
+    }
+
+    pub fn handle_event(event: &FrameworkEvent, dispatcher: &Dispatcher) {
+        let _app = dispatcher.enter();
+        let registry = TooltipRegistry::global();
+        let now = Instant::now();
+        let changed = match event {
+            FrameworkEvent::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Moved | MouseEventKind::Drag(_)) =>
+            {
+                let Some((column, row)) = mouse_position(event) else {
+                    return;
+                };
+                let target = registry
+                    .hitboxes
+                    .read()
+                    .iter()
+                    .rev()
+                    .find(|(_, hitbox)| hitbox.contains(column, row))
+                    .map(|(id, _)| id.clone());
+                let delay = target
+                    .as_deref()
+                    .map(|id| registry.delay(id))
+                    .unwrap_or_default();
+                let mut hover = registry.hover.lock();
+                hover.enter(target.as_deref(), now) | hover.advance(now, delay)
+            }
+            FrameworkEvent::Tick => {
+                let mut hover = registry.hover.lock();
+                let delay = hover
+                    .target()
+                    .map(|id| registry.delay(id))
+                    .unwrap_or_default();
+                hover.advance(now, delay)
+            }
+            FrameworkEvent::Key(_) | FrameworkEvent::Mouse(_) => registry.hover.lock().dismiss(),
+            _ => false,
+        };
+        if changed {
+            dispatcher.request_full_render();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
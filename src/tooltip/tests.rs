@@ -0,0 +1,42 @@
+use super::*;
+
+#[test]
+fn hover_shows_after_the_delay_and_hides_on_leave() {
+    let start = Instant::now();
+    let delay = Duration::from_millis(500);
+    let mut hover = HoverState::default();
+    assert!(!hover.enter(Some("save"), start));
+    assert!(!hover.advance(start + Duration::from_millis(200), delay));
+    assert!(!hover.is_visible("save"));
+    assert!(hover.advance(start + delay, delay));
+    assert!(hover.is_visible("save"));
+    assert!(!hover.is_visible("open"));
+    assert!(!hover.enter(Some("save"), start + delay * 2));
+    assert!(hover.is_visible("save"));
+    assert!(hover.enter(None, start + delay * 2));
+    assert!(!hover.is_visible("save"));
+}
+
+#[test]
+fn moving_to_another_target_restarts_the_delay() {
+    let start = Instant::now();
+    let delay = Duration::from_millis(300);
+    let mut hover = HoverState::default();
+    hover.enter(Some("a"), start);
+    hover.advance(start + delay, delay);
+    assert!(hover.enter(Some("b"), start + delay));
+    assert!(!hover.advance(start + delay + Duration::from_millis(100), delay));
+    assert!(hover.advance(start + delay * 2, delay));
+    assert!(hover.is_visible("b"));
+    assert!(hover.dismiss());
+    assert!(!hover.dismiss());
+}
+
+#[test]
+fn zero_delay_shows_immediately() {
+    let now = Instant::now();
+    let mut hover = HoverState::default();
+    hover.enter(Some("tip"), now);
+    assert!(hover.advance(now, Duration::ZERO));
+    assert!(hover.is_visible("tip"));
+}
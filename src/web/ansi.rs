@@ -0,0 +1,197 @@
+use std::io::{self, Write};
+
+use ratatui::backend::{Backend, ClearType, WindowSize};
+use ratatui::buffer::Cell;
+use ratatui::layout::{Rect, Size};
+use ratatui::style::{Color, Modifier};
+
+// Writes frames as plain ANSI sequences, the same ones crossterm would emit,
+// so it works on targets crossterm does not build for.
+pub struct AnsiBackend<W: Write> {
+    writer: W,
+    size: Rect,
+    cursor: (u16, u16),
+}
+
+impl<W: Write> AnsiBackend<W> {
+    pub fn new(writer: W, width: u16, height: u16) -> Self {
+        Self {
+            writer,
+            size: Rect::new(0, 0, width, height),
+            cursor: (0, 0),
+        }
+    }
+
+    pub fn set_size(&mut self, width: u16, height: u16) {
+        self.size = Rect::new(0, 0, width, height);
+    }
+}
+
+impl<W: Write> Backend for AnsiBackend<W> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let mut fg = Color::Reset;
+        let mut bg = Color::Reset;
+        let mut modifier = Modifier::empty();
+        let mut last_pos: Option<(u16, u16)> = None;
+        for (x, y, cell) in content {
+            if !matches!(last_pos, Some(p) if x == p.0 + 1 && y == p.1) {
+                write!(self.writer, "\x1b[{};{}H", y + 1, x + 1)?;
+            }
+            last_pos = Some((x, y));
+            if cell.modifier != modifier {
+                write_modifier_diff(&mut self.writer, modifier, cell.modifier)?;
+                modifier = cell.modifier;
+            }
+            if cell.fg != fg {
+                write_color(&mut self.writer, cell.fg, 30)?;
+                fg = cell.fg;
+            }
+            if cell.bg != bg {
+                write_color(&mut self.writer, cell.bg, 40)?;
+                bg = cell.bg;
+            }
+            self.writer.write_all(cell.symbol().as_bytes())?;
+        }
+        self.writer.write_all(b"\x1b[39m\x1b[49m\x1b[0m")
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.writer.write_all(b"\x1b[?25l")
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.writer.write_all(b"\x1b[?25h")
+    }
+
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        Ok(self.cursor)
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.cursor = (x, y);
+        write!(self.writer, "\x1b[{};{}H", y + 1, x + 1)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.clear_region(ClearType::All)
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        let sequence: &[u8] = match clear_type {
+            ClearType::All => b"\x1b[2J",
+            ClearType::AfterCursor => b"\x1b[J",
+            ClearType::BeforeCursor => b"\x1b[1J",
+            ClearType::CurrentLine => b"\x1b[2K",
+            ClearType::UntilNewLine => b"\x1b[K",
+        };
+        self.writer.write_all(sequence)
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        Ok(self.size)
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        Ok(WindowSize {
+            columns_rows: Size::new(self.size.width, self.size.height),
+            pixels: Size::default(),
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Write for AnsiBackend<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+// `base` is 30 for foreground and 40 for background; the other SGR ranges
+// sit at fixed offsets from it.
+fn write_color(writer: &mut impl Write, color: Color, base: u8) -> io::Result<()> {
+    let named = |index: u8| {
+        if index < 8 {
+            base + index
+        } else {
+            base + 60 + index - 8
+        }
+    };
+    match color {
+        Color::Reset => write!(writer, "\x1b[{}m", base + 9),
+        Color::Black => write!(writer, "\x1b[{}m", named(0)),
+        Color::Red => write!(writer, "\x1b[{}m", named(1)),
+        Color::Green => write!(writer, "\x1b[{}m", named(2)),
+        Color::Yellow => write!(writer, "\x1b[{}m", named(3)),
+        Color::Blue => write!(writer, "\x1b[{}m", named(4)),
+        Color::Magenta => write!(writer, "\x1b[{}m", named(5)),
+        Color::Cyan => write!(writer, "\x1b[{}m", named(6)),
+        Color::Gray => write!(writer, "\x1b[{}m", named(7)),
+        Color::DarkGray => write!(writer, "\x1b[{}m", named(8)),
+        Color::LightRed => write!(writer, "\x1b[{}m", named(9)),
+        Color::LightGreen => write!(writer, "\x1b[{}m", named(10)),
+        Color::LightYellow => write!(writer, "\x1b[{}m", named(11)),
+        Color::LightBlue => write!(writer, "\x1b[{}m", named(12)),
+        Color::LightMagenta => write!(writer, "\x1b[{}m", named(13)),
+        Color::LightCyan => write!(writer, "\x1b[{}m", named(14)),
+        Color::White => write!(writer, "\x1b[{}m", named(15)),
+        Color::Indexed(index) => write!(writer, "\x1b[{};5;{index}m", base + 8),
+        Color::Rgb(r, g, b) => write!(writer, "\x1b[{};2;{r};{g};{b}m", base + 8),
+    }
+}
+
+fn write_modifier_diff(writer: &mut impl Write, from: Modifier, to: Modifier) -> io::Result<()> {
+    let removed = from - to;
+    if removed.contains(Modifier::REVERSED) {
+        writer.write_all(b"\x1b[27m")?;
+    }
+    if removed.intersects(Modifier::BOLD | Modifier::DIM) {
+        // One code resets both weights, so put back the one that stays.
+        writer.write_all(b"\x1b[22m")?;
+        if (from & to).contains(Modifier::DIM) {
+            writer.write_all(b"\x1b[2m")?;
+        }
+        if (from & to).contains(Modifier::BOLD) {
+            writer.write_all(b"\x1b[1m")?;
+        }
+    }
+    if removed.contains(Modifier::ITALIC) {
+        writer.write_all(b"\x1b[23m")?;
+    }
+    if removed.contains(Modifier::UNDERLINED) {
+        writer.write_all(b"\x1b[24m")?;
+    }
+    if removed.contains(Modifier::CROSSED_OUT) {
+        writer.write_all(b"\x1b[29m")?;
+    }
+    if removed.intersects(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK) {
+        writer.write_all(b"\x1b[25m")?;
+    }
+
+    let added = to - from;
+    let codes = [
+        (Modifier::REVERSED, 7),
+        (Modifier::BOLD, 1),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::DIM, 2),
+        (Modifier::CROSSED_OUT, 9),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::RAPID_BLINK, 6),
+    ];
+    for (modifier, code) in codes {
+        if added.contains(modifier) {
+            write!(writer, "\x1b[{code}m")?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use crate::events::{
+    FrameworkEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+
+pub fn decode_input(data: &[u8]) -> Vec<FrameworkEvent> {
+    let mut events = Vec::new();
+    let mut rest = data;
+    while !rest.is_empty() {
+        let (event, used) = decode_one(rest);
+        events.extend(event);
+        rest = &rest[used.max(1)..];
+    }
+    events
+}
+
+fn decode_one(data: &[u8]) -> (Option<FrameworkEvent>, usize) {
+    match data[0] {
+        0x1b => decode_escape(data),
+        b'\r' | b'\n' => (key(KeyCode::Enter, KeyModifiers::NONE), 1),
+        b'\t' => (key(KeyCode::Tab, KeyModifiers::NONE), 1),
+        0x7f | 0x08 => (key(KeyCode::Backspace, KeyModifiers::NONE), 1),
+        0x00 => (key(KeyCode::Char(' '), KeyModifiers::CONTROL), 1),
+        byte @ 0x01..=0x1a => (
+            key(
+                KeyCode::Char(char::from(byte - 1 + b'a')),
+                KeyModifiers::CONTROL,
+            ),
+            1,
+        ),
+        byte @ 0x1c..=0x1f => (
+            key(
+                KeyCode::Char(char::from(byte + 0x40)),
+                KeyModifiers::CONTROL,
+            ),
+            1,
+        ),
+        _ => decode_char(data),
+    }
+}
+
+fn decode_char(data: &[u8]) -> (Option<FrameworkEvent>, usize) {
+    let len = match data[0] {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => return (None, 1),
+    };
+    let Some(ch) = data
+        .get(..len)
+        .and_then(|bytes| std::str::from_utf8(bytes).ok())
+        .and_then(|text| text.chars().next())
+    else {
+        return (None, 1);
+    };
+    let modifiers = if ch.is_uppercase() {
+        KeyModifiers::SHIFT
+    } else {
+        KeyModifiers::NONE
+    };
+    (key(KeyCode::Char(ch), modifiers), len)
+}
+
+fn decode_escape(data: &[u8]) -> (Option<FrameworkEvent>, usize) {
+    match data.get(1) {
+        None | Some(0x1b) => (key(KeyCode::Esc, KeyModifiers::NONE), 1),
+        Some(b'[') => decode_csi(data),
+        Some(b'O') if data.len() > 2 => {
+            let code = match data[2] {
+                b'P' => KeyCode::F(1),
+                b'Q' => KeyCode::F(2),
+                b'R' => KeyCode::F(3),
+                b'S' => KeyCode::F(4),
+                other => match cursor_key(other) {
+                    Some(code) => code,
+                    None => return (None, 3),
+                },
+            };
+            (key(code, KeyModifiers::NONE), 3)
+        }
+        Some(_) => {
+            let (event, used) = decode_one(&data[1..]);
+            let event = match event {
+                Some(FrameworkEvent::Key(mut key)) => {
+                    key.modifiers |= KeyModifiers::ALT;
+                    Some(FrameworkEvent::Key(key))
+                }
+                other => other,
+            };
+            (event, used + 1)
+        }
+    }
+}
+
+fn decode_csi(data: &[u8]) -> (Option<FrameworkEvent>, usize) {
+    let Some(end) = data[2..]
+        .iter()
+        .position(|byte| (0x40..=0x7e).contains(byte))
+        .map(|offset| offset + 2)
+    else {
+        return (None, data.len());
+    };
+    let used = end + 1;
+    let params = std::str::from_utf8(&data[2..end]).unwrap_or_default();
+    if let Some(mouse) = params.strip_prefix('<') {
+        return (decode_sgr_mouse(mouse, data[end] == b'M'), used);
+    }
+    let mut numbers = params
+        .split(';')
+        .map(|value| value.parse::<u8>().unwrap_or(1));
+    let first = numbers.next().unwrap_or(1);
+    let modifiers = numbers.next().map_or(KeyModifiers::NONE, modifiers_from);
+    let code = match data[end] {
+        b'Z' => return (key(KeyCode::BackTab, KeyModifiers::SHIFT), used),
+        b'P' => KeyCode::F(1),
+        b'Q' => KeyCode::F(2),
+        b'R' => KeyCode::F(3),
+        b'S' => KeyCode::F(4),
+        b'~' => match first {
+            1 | 7 => KeyCode::Home,
+            2 => KeyCode::Insert,
+            3 => KeyCode::Delete,
+            4 | 8 => KeyCode::End,
+            5 => KeyCode::PageUp,
+            6 => KeyCode::PageDown,
+            11..=15 => KeyCode::F(first - 10),
+            17..=21 => KeyCode::F(first - 11),
+            23 | 24 => KeyCode::F(first - 12),
+            _ => return (None, used),
+        },
+        other => match cursor_key(other) {
+            Some(code) => code,
+            None => return (None, used),
+        },
+    };
+    (key(code, modifiers), used)
+}
+
+fn decode_sgr_mouse(params: &str, pressed: bool) -> Option<FrameworkEvent> {
+    let mut numbers = params.split(';').map(|value| value.parse::<u16>().ok());
+    let (Some(Some(code)), Some(Some(column)), Some(Some(row))) =
+        (numbers.next(), numbers.next(), numbers.next())
+    else {
+        return None;
+    };
+    let button = match code & 0b11 {
+        0 => Some(MouseButton::Left),
+        1 => Some(MouseButton::Middle),
+        2 => Some(MouseButton::Right),
+        _ => None,
+    };
+    let kind = if code & 64 != 0 {
+        if code & 1 == 0 {
+            MouseEventKind::ScrollUp
+        } else {
+            MouseEventKind::ScrollDown
+        }
+    } else if code & 32 != 0 {
+        match button {
+            Some(button) => MouseEventKind::Drag(button),
+            None => MouseEventKind::Moved,
+        }
+    } else if pressed {
+        MouseEventKind::Down(button?)
+    } else {
+        MouseEventKind::Up(button.unwrap_or(MouseButton::Left))
+    };
+    let mut modifiers = KeyModifiers::NONE;
+    if code & 4 != 0 {
+        modifiers |= KeyModifiers::SHIFT;
+    }
+    if code & 8 != 0 {
+        modifiers |= KeyModifiers::ALT;
+    }
+    if code & 16 != 0 {
+        modifiers |= KeyModifiers::CONTROL;
+    }
+    Some(FrameworkEvent::Mouse(MouseEvent {
+        kind,
+        column: column.saturating_sub(1),
+        row: row.saturating_sub(1),
+        modifiers,
+    }))
+}
+
+fn cursor_key(byte: u8) -> Option<KeyCode> {
+    match byte {
+        b'A' => Some(KeyCode::Up),
+        b'B' => Some(KeyCode::Down),
+        b'C' => Some(KeyCode::Right),
+        b'D' => Some(KeyCode::Left),
+        b'H' => Some(KeyCode::Home),
+        b'F' => Some(KeyCode::End),
+        _ => None,
+    }
+}
+
+fn modifiers_from(value: u8) -> KeyModifiers {
+    let bits = value.saturating_sub(1);
+    let mut modifiers = KeyModifiers::NONE;
+    if bits & 1 != 0 {
+        modifiers |= KeyModifiers::SHIFT;
+    }
+    if bits & 2 != 0 {
+        modifiers |= KeyModifiers::ALT;
+    }
+    if bits & 4 != 0 {
+        modifiers |= KeyModifiers::CONTROL;
+    }
+    modifiers
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Option<FrameworkEvent> {
+    Some(FrameworkEvent::Key(KeyEvent::new(code, modifiers)))
+}
@@ -0,0 +1,167 @@
+use std::future;
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use ratatui::layout::Rect;
+use ratatui::{Frame, Terminal};
+use tokio::sync::{Mutex, mpsc};
+use tracing::debug;
+
+use crate::events::FrameworkEvent;
+use crate::renderer::RendererBackend;
+use crate::rt::{self, JoinHandle};
+use crate::runtime::{AppMessage, RuntimeDriver, spawn_tick_loop};
+
+mod ansi;
+mod input;
+#[cfg(target_arch = "wasm32")]
+mod xterm;
+
+pub use ansi::AnsiBackend;
+pub use input::decode_input;
+#[cfg(target_arch = "wasm32")]
+pub use xterm::{Xterm, mount};
+
+// The mouse modes crossterm's `EnableMouseCapture` turns on, plus hiding the cursor.
+const CAPTURE: &str = "\x1b[?1000h\x1b[?1002h\x1b[?1003h\x1b[?1015h\x1b[?1006h\x1b[?25l";
+const RELEASE: &str = "\x1b[?25h\x1b[?1006l\x1b[?1015l\x1b[?1003l\x1b[?1002l\x1b[?1000l";
+
+pub struct WebTerminal {
+    terminal: Terminal<AnsiBackend<WebOutput>>,
+}
+
+impl WebTerminal {
+    pub fn new<F>(cols: u16, rows: u16, write: F) -> anyhow::Result<Self>
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        let mut backend = AnsiBackend::new(
+            WebOutput {
+                pending: Vec::new(),
+                write: Box::new(write),
+            },
+            cols,
+            rows,
+        );
+        send(&mut backend, CAPTURE).context("prepare web terminal")?;
+        let terminal = Terminal::new(backend).context("build web terminal")?;
+        Ok(Self { terminal })
+    }
+}
+
+impl RendererBackend for WebTerminal {
+    fn size(&self) -> anyhow::Result<Rect> {
+        Ok(self.terminal.size()?)
+    }
+
+    fn draw(&mut self, render: &mut dyn FnMut(&mut Frame<'_>)) -> anyhow::Result<()> {
+        self.terminal.draw(|frame| render(frame))?;
+        Ok(())
+    }
+
+    fn resize(&mut self, width: u16, height: u16) -> anyhow::Result<()> {
+        self.terminal.backend_mut().set_size(width, height);
+        self.terminal.resize(Rect::new(0, 0, width, height))?;
+        Ok(())
+    }
+
+    fn suspend(&mut self) -> anyhow::Result<()> {
+        send(self.terminal.backend_mut(), RELEASE).context("release web terminal")
+    }
+
+    fn resume(&mut self) -> anyhow::Result<()> {
+        send(self.terminal.backend_mut(), CAPTURE).context("prepare web terminal")?;
+        self.terminal.clear()?;
+        Ok(())
+    }
+}
+
+impl Drop for WebTerminal {
+    fn drop(&mut self) {
+        let _ = send(self.terminal.backend_mut(), RELEASE);
+    }
+}
+
+fn send(backend: &mut AnsiBackend<WebOutput>, sequence: &str) -> io::Result<()> {
+    backend.write_all(sequence.as_bytes())?;
+    backend.flush()
+}
+
+struct WebOutput {
+    pending: Vec<u8>,
+    write: Box<dyn Fn(&str) + Send + Sync>,
+}
+
+impl Write for WebOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.pending.is_empty() {
+            (self.write)(&String::from_utf8_lossy(&self.pending));
+            self.pending.clear();
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct WebInput {
+    tx: mpsc::UnboundedSender<FrameworkEvent>,
+}
+
+impl WebInput {
+    pub fn data(&self, data: &str) {
+        for event in decode_input(data.as_bytes()) {
+            let _ = self.tx.send(event);
+        }
+    }
+
+    pub fn resize(&self, cols: u16, rows: u16) {
+        let _ = self.tx.send(FrameworkEvent::Resize(cols, rows));
+    }
+}
+
+pub struct WebDriver {
+    events: Arc<Mutex<mpsc::UnboundedReceiver<FrameworkEvent>>>,
+}
+
+impl WebDriver {
+    pub fn new() -> (Self, WebInput) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let driver = Self {
+            events: Arc::new(Mutex::new(rx)),
+        };
+        (driver, WebInput { tx })
+    }
+}
+
+impl RuntimeDriver for WebDriver {
+    fn spawn_terminal_events(&self, tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+        let events = self.events.clone();
+        rt::spawn(async move {
+            let mut events = events.lock().await;
+            while let Some(event) = events.recv().await {
+                if tx.send(AppMessage::ExternalEvent(event)).await.is_err() {
+                    break;
+                }
+            }
+            debug!("web input listener exited");
+        })
+    }
+
+    fn spawn_tick_loop(&self, tx: mpsc::Sender<AppMessage>, rate: Duration) -> JoinHandle<()> {
+        spawn_tick_loop(tx, rate)
+    }
+
+    fn spawn_shutdown_watcher(&self, _tx: mpsc::Sender<AppMessage>) -> JoinHandle<()> {
+        rt::spawn(future::pending())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEventKind};
+use parking_lot::Mutex;
+use ratatui::backend::Backend;
+use ratatui::buffer::Cell;
+use ratatui::style::{Color, Modifier, Style};
+
+use super::{AnsiBackend, WebDriver, WebTerminal, decode_input};
+use crate::events::FrameworkEvent;
+use crate::renderer::Renderer;
+use crate::runtime::{App, Element, Registries, component};
+
+fn keys(data: &[u8]) -> Vec<(KeyCode, KeyModifiers)> {
+    decode_input(data)
+        .into_iter()
+        .map(|event| match event {
+            FrameworkEvent::Key(key) => (key.code, key.modifiers),
+            other => panic!("expected a key, got {other:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn decode_input_maps_xterm_key_sequences() {
+    assert_eq!(
+        keys(b"aB\r\x7f\t\x1b[Z\x03"),
+        [
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('B'), KeyModifiers::SHIFT),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::BackTab, KeyModifiers::SHIFT),
+            (KeyCode::Char('c'), KeyModifiers::CONTROL),
+        ]
+    );
+    assert_eq!(
+        keys("\x1b[A\x1b[1;5C\x1bOP\x1b[3~\x1b[15~\x1bxé\x1b".as_bytes()),
+        [
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Right, KeyModifiers::CONTROL),
+            (KeyCode::F(1), KeyModifiers::NONE),
+            (KeyCode::Delete, KeyModifiers::NONE),
+            (KeyCode::F(5), KeyModifiers::NONE),
+            (KeyCode::Char('x'), KeyModifiers::ALT),
+            (KeyCode::Char('é'), KeyModifiers::NONE),
+            (KeyCode::Esc, KeyModifiers::NONE),
+        ]
+    );
+}
+
+#[test]
+fn decode_input_maps_sgr_mouse_reports() {
+    let events = decode_input(b"\x1b[<0;5;3M\x1b[<0;5;3m\x1b[<65;1;1M\x1b[<35;2;2M");
+    let kinds: Vec<_> = events
+        .iter()
+        .map(|event| match event {
+            FrameworkEvent::Mouse(mouse) => (mouse.kind, mouse.column, mouse.row),
+            other => panic!("expected a mouse event, got {other:?}"),
+        })
+        .collect();
+
+    assert_eq!(
+        kinds,
+        [
+            (MouseEventKind::Down(MouseButton::Left), 4, 2),
+            (MouseEventKind::Up(MouseButton::Left), 4, 2),
+            (MouseEventKind::ScrollDown, 0, 0),
+            (MouseEventKind::Moved, 1, 1),
+        ]
+    );
+}
+
+#[test]
+fn web_terminal_streams_ansi_frames_and_follows_resizes() {
+    let _app = Registries::isolated();
+    let output = Arc::new(Mutex::new(String::new()));
+    let sink = output.clone();
+    let terminal =
+        WebTerminal::new(12, 2, move |chunk| sink.lock().push_str(chunk)).expect("web terminal");
+    let mut renderer = Renderer::with_backend(terminal);
+    assert!(output.lock().contains("\x1b[?1006h"));
+
+    renderer
+        .draw(&crate::runtime::View::Empty)
+        .expect("draw empty frame");
+    renderer.resize(20, 3).expect("resize");
+    assert_eq!(
+        renderer.size().expect("size"),
+        crate::runtime::Rect::new(0, 0, 20, 3)
+    );
+}
+
+#[test]
+fn ansi_backend_writes_positions_colors_and_modifiers() {
+    let mut styled = Cell::default();
+    styled.set_symbol("a").set_style(
+        Style::default()
+            .fg(Color::Red)
+            .bg(Color::Rgb(1, 2, 3))
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut next = styled.clone();
+    next.set_symbol("b");
+    let mut plain = Cell::default();
+    plain.set_symbol("c");
+
+    let mut out = Vec::new();
+    AnsiBackend::new(&mut out, 10, 3)
+        .draw([(2, 1, &styled), (3, 1, &next), (0, 2, &plain)].into_iter())
+        .expect("draw cells");
+
+    assert_eq!(
+        String::from_utf8(out).expect("utf-8 output"),
+        "\x1b[2;3H\x1b[1m\x1b[31m\x1b[48;2;1;2;3mab\x1b[3;1H\x1b[22m\x1b[39m\x1b[49mc\x1b[39m\x1b[49m\x1b[0m"
+    );
+}
+
+#[tokio::test]
+async fn web_driver_feeds_browser_input_to_the_runtime() {
+    let output = Arc::new(Mutex::new(String::new()));
+    let sink = output.clone();
+    let (driver, input) = WebDriver::new();
+    let app = App::new(
+        "WebTest",
+        component("Hello", |_ctx| Element::text("hello web")),
+    )
+    .with_driver(driver)
+    .with_backend(
+        WebTerminal::new(20, 2, move |chunk| sink.lock().push_str(chunk)).expect("web terminal"),
+    );
+
+    input.data("\x03");
+    let code = tokio::time::timeout(std::time::Duration::from_millis(500), app.run())
+        .await
+        .expect("ctrl+c from the browser stops the app")
+        .expect("app run succeeds");
+
+    assert_eq!(code, 0);
+    assert!(output.lock().contains("hello web"));
+}
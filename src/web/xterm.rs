@@ -0,0 +1,70 @@
+use futures::StreamExt;
+use futures::channel::mpsc;
+use tracing::warn;
+use wasm_bindgen::prelude::*;
+
+use crate::runtime::App;
+
+use super::{WebDriver, WebTerminal};
+
+#[wasm_bindgen]
+extern "C" {
+    // An xterm.js `Terminal`, already opened on a page element.
+    #[wasm_bindgen(js_name = Terminal)]
+    pub type Xterm;
+
+    #[wasm_bindgen(method, getter)]
+    fn cols(this: &Xterm) -> u16;
+
+    #[wasm_bindgen(method, getter)]
+    fn rows(this: &Xterm) -> u16;
+
+    #[wasm_bindgen(method)]
+    fn write(this: &Xterm, data: &str);
+
+    #[wasm_bindgen(method, js_name = onData)]
+    fn on_data(this: &Xterm, listener: &Closure<dyn FnMut(String)>);
+
+    #[wasm_bindgen(method, js_name = onResize)]
+    fn on_resize(this: &Xterm, listener: &Closure<dyn FnMut(ResizeEvent)>);
+
+    type ResizeEvent;
+
+    #[wasm_bindgen(method, getter)]
+    fn cols(this: &ResizeEvent) -> u16;
+
+    #[wasm_bindgen(method, getter)]
+    fn rows(this: &ResizeEvent) -> u16;
+}
+
+// Runs `app` inside `terminal` on the page's event loop. Frames reach xterm
+// through a channel, since the JS object cannot sit in the `Send` renderer.
+pub fn mount(app: App, terminal: Xterm) -> anyhow::Result<()> {
+    let (frames, mut pending) = mpsc::unbounded::<String>();
+    let backend = WebTerminal::new(terminal.cols(), terminal.rows(), move |chunk| {
+        let _ = frames.unbounded_send(chunk.to_string());
+    })?;
+    let (driver, input) = WebDriver::new();
+
+    let data = input.clone();
+    let on_data = Closure::<dyn FnMut(String)>::new(move |chunk: String| data.data(&chunk));
+    terminal.on_data(&on_data);
+    on_data.forget();
+    let on_resize = Closure::<dyn FnMut(ResizeEvent)>::new(move |event: ResizeEvent| {
+        input.resize(event.cols(), event.rows());
+    });
+    terminal.on_resize(&on_resize);
+    on_resize.forget();
+
+    wasm_bindgen_futures::spawn_local(async move {
+        while let Some(chunk) = pending.next().await {
+            terminal.write(&chunk);
+        }
+    });
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Err(err) = app.with_driver(driver).with_backend(backend).run().await {
+            warn!(error = ?err, "web app stopped with an error");
+        }
+    });
+    Ok(())
+}
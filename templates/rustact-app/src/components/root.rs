@@ -18,7 +18,7 @@ pub fn root(ctx: &mut Scope) -> Element {
         let mut events = dispatcher.events().subscribe();
         let decrement = set_count.clone();
         let increment = set_count.clone();
-        tokio::spawn(async move {
+        dispatcher.spawn(async move {
             while let Ok(event) = events.recv().await {
                 handle_event(&event, &decrement, &increment);
             }
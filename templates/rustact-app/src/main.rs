@@ -10,8 +10,7 @@ use components::root::root;
 #[tokio::main]
 async fn main() -> Result<()> {
     let stylesheet = Stylesheet::parse(include_str!("../styles/app.css"))?;
-    App::new("{{ project-name }}", component("Root", root))
-        .with_stylesheet(stylesheet)
-        .run()
-        .await
+    let app = App::new("{{ project-name }}", component("Root", root)).with_stylesheet(stylesheet);
+    let code = app.run().await?;
+    std::process::exit(code)
 }